@@ -0,0 +1,26 @@
+//! Runtime API definition for the basic outbound channel.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_core::H256;
+use sp_std::prelude::*;
+
+pub use artemis_core::{MerkleProof, MessageNonce, MessageStatus};
+pub use basic_channel::outbound::Message;
+
+sp_api::decl_runtime_apis! {
+	pub trait BasicOutboundChannelApi {
+		/// Offchain storage key under which the messages of the commitment
+		/// with `commitment_hash` are indexed.
+		fn commitment_key(commitment_hash: H256) -> Vec<u8>;
+
+		/// Build a proof that the message with `nonce` is included in the
+		/// Merkle commitment of `messages`. Returns `None` if no message in
+		/// `messages` has that nonce.
+		fn message_proof(messages: Vec<Message>, nonce: MessageNonce) -> Option<MerkleProof>;
+
+		/// Progress of the message with `nonce`, from being queued to having
+		/// its delivery acknowledged by Ethereum.
+		fn message_status(nonce: MessageNonce) -> MessageStatus;
+	}
+}
@@ -39,8 +39,8 @@ benchmarks! {
 		let envelope: envelope::Envelope = rlp::decode::<Log>(&message.data)
 			.map(|log| log.try_into().unwrap())
 			.unwrap();
-		Nonce::put(envelope.nonce - 1);
-		SourceChannel::put(envelope.channel);
+		Nonce::insert(envelope.channel, envelope.nonce - 1);
+		SourceChannels::<T>::insert(envelope.channel, SourceChannelInfo::default());
 
 		T::Verifier::initialize_storage(
 			vec![header],
@@ -50,14 +50,82 @@ benchmarks! {
 
 	}: _(RawOrigin::Signed(caller.clone()), message)
 	verify {
-		assert_eq!(envelope.nonce, Nonce::get());
+		assert_eq!(envelope.nonce, Nonce::get(envelope.channel));
 
-		let message_id = MessageId::new(ChannelId::Basic, envelope.nonce);
+		let message_id = MessageId::new(ChannelId::BASIC, envelope.nonce);
 		if let Some(event) = T::MessageDispatch::successful_dispatch_event(message_id) {
 			assert_last_event::<T>(event);
 		}
 	}
 
+	// Benchmark `submit_batch` extrinsic with `m` messages, each of which
+	// is dispatched in order.
+	submit_batch {
+		let m in 1 .. T::MaxMessagesPerBatch::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		let (header, messages) = batch_data();
+		let messages: Vec<Message> = messages.into_iter().take(m as usize).collect();
+		let envelope: envelope::Envelope = rlp::decode::<Log>(&messages[0].data)
+			.map(|log| log.try_into().unwrap())
+			.unwrap();
+		let channel = envelope.channel;
+		SourceChannels::<T>::insert(channel, SourceChannelInfo::default());
+
+		T::Verifier::initialize_storage(
+			vec![header],
+			0.into(),
+			0, // forces all headers to be finalized
+		)?;
+
+	}: _(RawOrigin::Signed(caller.clone()), messages)
+	verify {
+		assert_eq!(m as u64, Nonce::get(channel));
+		assert_last_event::<T>(<T as Config>::Event::from(RawEvent::BatchMessageAccepted(m - 1, m as u64)).into());
+	}
+
+	// Benchmark `register_source_channel` under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	register_source_channel {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let channel = H160::repeat_byte(1);
+
+	}: _(authorized_origin, channel)
+	verify {
+		assert!(SourceChannels::<T>::contains_key(channel));
+	}
+
+	// Benchmark `set_source_channel_sunset` under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	set_source_channel_sunset {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let channel = H160::repeat_byte(1);
+		let sunset: T::BlockNumber = 10u32.into();
+		SourceChannels::<T>::insert(channel, SourceChannelInfo::default());
+
+	}: _(authorized_origin, channel, Some(sunset))
+	verify {
+		assert_eq!(SourceChannels::<T>::get(channel).unwrap().sunset, Some(sunset));
+	}
+
+	set_receipt_channel {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let channel = H160::repeat_byte(1);
+
+	}: _(authorized_origin, Some(channel))
+	verify {
+		assert_eq!(ReceiptChannel::get(), Some(channel));
+	}
+
 	#[extra]
 	submit_eth_mint {
 		let caller: T::AccountId = whitelisted_caller();
@@ -65,8 +133,8 @@ benchmarks! {
 		let envelope: envelope::Envelope = rlp::decode::<Log>(&message.data)
 			.map(|log| log.try_into().unwrap())
 			.unwrap();
-		Nonce::put(envelope.nonce - 1);
-		SourceChannel::put(envelope.channel);
+		Nonce::insert(envelope.channel, envelope.nonce - 1);
+		SourceChannels::<T>::insert(envelope.channel, SourceChannelInfo::default());
 
 		T::Verifier::initialize_storage(
 			vec![header],
@@ -76,9 +144,9 @@ benchmarks! {
 
 	}: submit(RawOrigin::Signed(caller.clone()), message)
 	verify {
-		assert_eq!(envelope.nonce, Nonce::get());
+		assert_eq!(envelope.nonce, Nonce::get(envelope.channel));
 
-		let message_id = MessageId::new(ChannelId::Basic, envelope.nonce);
+		let message_id = MessageId::new(ChannelId::BASIC, envelope.nonce);
 		if let Some(event) = T::MessageDispatch::successful_dispatch_event(message_id) {
 			assert_last_event::<T>(event);
 		}
@@ -91,8 +159,8 @@ benchmarks! {
 		let envelope: envelope::Envelope = rlp::decode::<Log>(&message.data)
 			.map(|log| log.try_into().unwrap())
 			.unwrap();
-		Nonce::put(envelope.nonce - 1);
-		SourceChannel::put(envelope.channel);
+		Nonce::insert(envelope.channel, envelope.nonce - 1);
+		SourceChannels::<T>::insert(envelope.channel, SourceChannelInfo::default());
 
 		T::Verifier::initialize_storage(
 			vec![header],
@@ -102,9 +170,9 @@ benchmarks! {
 
 	}: submit(RawOrigin::Signed(caller.clone()), message)
 	verify {
-		assert_eq!(envelope.nonce, Nonce::get());
+		assert_eq!(envelope.nonce, Nonce::get(envelope.channel));
 
-		let message_id = MessageId::new(ChannelId::Basic, envelope.nonce);
+		let message_id = MessageId::new(ChannelId::BASIC, envelope.nonce);
 		if let Some(event) = T::MessageDispatch::successful_dispatch_event(message_id) {
 			assert_last_event::<T>(event);
 		}
@@ -135,6 +203,7 @@ fn eth_mint_data() -> (Header, Message) {
 				hex!("a00000000000000000000000000000000000000000000000000000000000000000").to_vec(),
 				hex!("880000000000000000").to_vec(),
 			],
+			base_fee_per_gas: None,
 		},
 		Message {
 			data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
@@ -174,6 +243,7 @@ fn erc20_mint_data() -> (Header, Message) {
 				hex!("a00000000000000000000000000000000000000000000000000000000000000000").to_vec(),
 				hex!("880000000000000000").to_vec(),
 			],
+			base_fee_per_gas: None,
 		},
 		Message {
 			data: hex!("f9013a942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb9010000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
@@ -219,6 +289,7 @@ fn dot_unlock_data() -> (Header, Message) {
 				hex!("a00000000000000000000000000000000000000000000000000000000000000000").to_vec(),
 				hex!("880000000000000000").to_vec(),
 			],
+			base_fee_per_gas: None,
 		},
 		Message {
 			data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
@@ -234,6 +305,338 @@ fn dot_unlock_data() -> (Header, Message) {
 	)
 }
 
+// Messages with consecutive nonces from the same source channel, all included
+// in one block. The block and its receipts are synthetic: each receipt copies
+// one of the receipts above with the nonce of its channel log replaced.
+fn batch_data() -> (Header, Vec<Message>) {
+	(
+		Header {
+			parent_hash: hex!("07966c0314890b0a506ac5e29c934a1a7d77245ce088fb5786eec3a6f1b855c8").into(),
+			timestamp: 1619679100u64.into(),
+			number: 100u64.into(),
+			author: hex!("0000000000000000000000000000000000000000").into(),
+			transactions_root: hex!("979b37112184a16bc05f7a6a12eb0b6bd277c1188741315e92aba3517329b091").into(),
+			ommers_hash: hex!("1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347").into(),
+			extra_data: hex!("").into(),
+			state_root: hex!("4e8278dec2498fd15d8d6d46f42204f4907161adceb6f3a6ad046cb3e939d403").into(),
+			receipts_root: hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").into(),
+			logs_bloom: (&hex!("00000008000040000000000000000200000000000000400000000000010080000000000000000000000000000000000000000000000000000000000000200080000000000000000400000008002000000000000000008000000000000000000000000000020000000000000000000800200011000400000000000010001000000000000008000000000000000400000000100000000000000000840000008000020000000000000001000000000000000020000000000001000000000200080000000002000004000000020000000000000000000008000000800000200c20402010000000000000000000000000000020000000000000000000000000000000")).into(),
+			gas_used: 1274704u64.into(),
+			gas_limit: 6721975u64.into(),
+			difficulty: 0u64.into(),
+			seal: vec![
+				hex!("a00000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				hex!("880000000000000000").to_vec(),
+			],
+			base_fee_per_gas: None,
+		},
+		vec![
+			Message {
+				data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 0,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("7ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc94").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f9040a30b90406f904030183014441b9010000000008000040000000000000000200000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000000000000000000000000400000000100000000000000000040000008000000000000000000000000000000000000000000000000001000000000200000000000002000004000000020000000000000000000000000000000000000820400000000000000000000000000000000000000000000000000000000000000000f902f8f9013c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8c00000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000020d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb9010000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 1,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("6c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311a").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f9044420b90440f9043d018302a996b9010000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000008002000000000000000000000000000000000000000000000000000000000000000000000200010000000000000000010001000000000000008000000000000000000000000100000000000000000840000008000020000000000000001000000000000000000000000000001000000000200000000000002000004000000020000000000000000000008000000800000200c00000010000000000000000000000000000020000000000000000000000000000000f90332f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a000000000000000000000000000000000000000000000000000000000000003e8f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a00000000000000000000000000000000000000000000000000000000000000000f8b99483428c7db9815f482a39a1715684dcf755021997e1a01e7b27577112ed83d53de87b38aee59ab80d8a9ba4acd90aad6cfee917534c79b880000000000000000000000000f8f7758fbcefd546eaeff7de24aff666b6228e7300000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d00000000000000000000000000000000000000000000000000000000000003e8f9013a942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb9010000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 2,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("00d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f902c920b902c5f902c2018303a303b9010000000008000000000000000000000000000000000000400000000000010000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000008000000000000000000000000000000000000020000000000000000000000000080000000000000004000000020000000000000000000000000000000000000800002000000000000000000000000000000000000000000000000000000000000000f901b7f89994774667629726ec1fabebcec0d9139bd1c8f72a23e1a0caae0f5e72020d428da73a237d1f9bf162e158dda6d4908769b8b60c095b01f4b86000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000000000000000000000000000000000000000000000000002386f26fc10000f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 3,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("34714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825d").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f9040a20b90406f90403018304e744b9010000000008000040000000000000000200000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000000000000000000000000400000000100000000000000000040000008000000000000000000000000000000000000000000000000001000000000200000000000002000004000000020000000000000000000000000000000000000820400000000000000000000000000000000000000000000000000000000000000000f902f8f9013c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8c00000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000020d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb9010000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000050000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 4,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("9901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546de").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f9044420b90440f9043d0183064c99b9010000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000008002000000000000000000000000000000000000000000000000000000000000000000000200010000000000000000010001000000000000008000000000000000000000000100000000000000000840000008000020000000000000001000000000000000000000000000001000000000200000000000002000004000000020000000000000000000008000000800000200c00000010000000000000000000000000000020000000000000000000000000000000f90332f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a000000000000000000000000000000000000000000000000000000000000003e8f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a00000000000000000000000000000000000000000000000000000000000000000f8b99483428c7db9815f482a39a1715684dcf755021997e1a01e7b27577112ed83d53de87b38aee59ab80d8a9ba4acd90aad6cfee917534c79b880000000000000000000000000f8f7758fbcefd546eaeff7de24aff666b6228e7300000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d00000000000000000000000000000000000000000000000000000000000003e8f9013a942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb9010000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000050000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 5,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("4f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f902c920b902c5f902c20183074606b9010000000008000000000000000000000000000000000000400000000000010000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000008000000000000000000000000000000000000020000000000000000000000000080000000000000004000000020000000000000000000000000000000000000800002000000000000000000000000000000000000000000000000000000000000000f901b7f89994774667629726ec1fabebcec0d9139bd1c8f72a23e1a0caae0f5e72020d428da73a237d1f9bf162e158dda6d4908769b8b60c095b01f4b86000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000000000000000000000000000000000000000000000000002386f26fc10000f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000700000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 6,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989ce").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f9040a20b90406f904030183088a47b9010000000008000040000000000000000200000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000000000000000000000000400000000100000000000000000040000008000000000000000000000000000000000000000000000000001000000000200000000000002000004000000020000000000000000000000000000000000000820400000000000000000000000000000000000000000000000000000000000000000f902f8f9013c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8c00000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000020d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000700000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb9010000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 7,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("1ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f9044420b90440f9043d018309ef9cb9010000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000008002000000000000000000000000000000000000000000000000000000000000000000000200010000000000000000010001000000000000008000000000000000000000000100000000000000000840000008000020000000000000001000000000000000000000000000001000000000200000000000002000004000000020000000000000000000008000000800000200c00000010000000000000000000000000000020000000000000000000000000000000f90332f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a000000000000000000000000000000000000000000000000000000000000003e8f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a00000000000000000000000000000000000000000000000000000000000000000f8b99483428c7db9815f482a39a1715684dcf755021997e1a01e7b27577112ed83d53de87b38aee59ab80d8a9ba4acd90aad6cfee917534c79b880000000000000000000000000f8f7758fbcefd546eaeff7de24aff666b6228e7300000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d00000000000000000000000000000000000000000000000000000000000003e8f9013a942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb9010000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000900000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 8,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("2188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f902c920b902c5f902c201830ae909b9010000000008000000000000000000000000000000000000400000000000010000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000008000000000000000000000000000000000000020000000000000000000000000080000000000000004000000020000000000000000000000000000000000000800002000000000000000000000000000000000000000000000000000000000000000f901b7f89994774667629726ec1fabebcec0d9139bd1c8f72a23e1a0caae0f5e72020d428da73a237d1f9bf162e158dda6d4908769b8b60c095b01f4b86000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000000000000000000000000000000000000000000000000002386f26fc10000f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000900000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 9,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("9fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0a").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f9040a20b90406f9040301830c2d4ab9010000000008000040000000000000000200000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000000000000000000000000400000000100000000000000000040000008000000000000000000000000000000000000000000000000001000000000200000000000002000004000000020000000000000000000000000000000000000820400000000000000000000000000000000000000000000000000000000000000000f902f8f9013c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8c00000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000020d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb9010000000000000000000000000083428c7db9815f482a39a1715684dcf755021997000000000000000000000000000000000000000000000000000000000000000b0000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 10,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f9044420b90440f9043d01830d929fb9010000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000008002000000000000000000000000000000000000000000000000000000000000000000000200010000000000000000010001000000000000008000000000000000000000000100000000000000000840000008000020000000000000001000000000000000000000000000001000000000200000000000002000004000000020000000000000000000008000000800000200c00000010000000000000000000000000000020000000000000000000000000000000f90332f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a000000000000000000000000000000000000000000000000000000000000003e8f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a00000000000000000000000000000000000000000000000000000000000000000f8b99483428c7db9815f482a39a1715684dcf755021997e1a01e7b27577112ed83d53de87b38aee59ab80d8a9ba4acd90aad6cfee917534c79b880000000000000000000000000f8f7758fbcefd546eaeff7de24aff666b6228e7300000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d00000000000000000000000000000000000000000000000000000000000003e8f9013a942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb9010000000000000000000000000083428c7db9815f482a39a1715684dcf755021997000000000000000000000000000000000000000000000000000000000000000b0000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 11,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3e").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f902c920b902c5f902c201830e8c0cb9010000000008000000000000000000000000000000000000400000000000010000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000008000000000000000000000000000000000000020000000000000000000000000080000000000000004000000020000000000000000000000000000000000000800002000000000000000000000000000000000000000000000000000000000000000f901b7f89994774667629726ec1fabebcec0d9139bd1c8f72a23e1a0caae0f5e72020d428da73a237d1f9bf162e158dda6d4908769b8b60c095b01f4b86000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000000000000000000000000000000000000000000000000002386f26fc10000f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000d00000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 12,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("47cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4ec").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f9040a20b90406f9040301830fd04db9010000000008000040000000000000000200000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000000000000000000000000400000000100000000000000000040000008000000000000000000000000000000000000000000000000001000000000200000000000002000004000000020000000000000000000000000000000000000820400000000000000000000000000000000000000000000000000000000000000000f902f8f9013c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8c00000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000020d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000d00000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb9010000000000000000000000000083428c7db9815f482a39a1715684dcf755021997000000000000000000000000000000000000000000000000000000000000000e0000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 13,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f9044420b90440f9043d01831135a2b9010000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000008002000000000000000000000000000000000000000000000000000000000000000000000200010000000000000000010001000000000000008000000000000000000000000100000000000000000840000008000020000000000000001000000000000000000000000000001000000000200000000000002000004000000020000000000000000000008000000800000200c00000010000000000000000000000000000020000000000000000000000000000000f90332f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a000000000000000000000000000000000000000000000000000000000000003e8f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a00000000000000000000000000000000000000000000000000000000000000000f8b99483428c7db9815f482a39a1715684dcf755021997e1a01e7b27577112ed83d53de87b38aee59ab80d8a9ba4acd90aad6cfee917534c79b880000000000000000000000000f8f7758fbcefd546eaeff7de24aff666b6228e7300000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d00000000000000000000000000000000000000000000000000000000000003e8f9013a942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb9010000000000000000000000000083428c7db9815f482a39a1715684dcf755021997000000000000000000000000000000000000000000000000000000000000000e0000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000f00000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 14,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dc").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f902c920b902c5f902c20183122f0fb9010000000008000000000000000000000000000000000000400000000000010000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000008000000000000000000000000000000000000020000000000000000000000000080000000000000004000000020000000000000000000000000000000000000800002000000000000000000000000000000000000000000000000000000000000000f901b7f89994774667629726ec1fabebcec0d9139bd1c8f72a23e1a0caae0f5e72020d428da73a237d1f9bf162e158dda6d4908769b8b60c095b01f4b86000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000000000000000000000000000000000000000000000000002386f26fc10000f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000f00000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("3303549fa93792009145665739343c7ef6844d4e909439a4d7f4195b3e6e2d11").into(),
+					tx_index: 15,
+					data: (
+						vec![
+							hex!("efd77123422926549be249b5aa60d0c625e75e2ec59143adee011931936600e3").to_vec(),
+							hex!("b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a").to_vec(),
+							hex!("0448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f1").to_vec(),
+						],
+						vec![
+							hex!("f851a0b80bda535c3afc979a39944523e5abb74e02597516864902dc0bc27356fb4d8a80808080808080a07ffe023ae599a96e4ad0266145a306d9c33cc7cf8f23de4d3e4ee98b3dd2dc948080808080808080").to_vec(),
+							hex!("f901f180a06c53dcb1bd52753b13322df697cd238ab17f7c981b5563149000c10efb9e311aa000d84ed7ffa92a37d62353e488e87e11494db2ace25ef590c48e2737c603e062a034714b00e697972c1a48c25c775a2b19e87527b986780138628956c296f7825da09901cb62e6ba28f1aa801fcea905debf3740bac9c5cd24d723214db84a8546dea04f7c17c50f75364610b219fb514fbce9809e7bebaccb84e864209891321d2ea1a0662010701bf6f5b83e0d5efd93abfef7fa403bc67c3d5d2ba05291841a9989cea01ad9ff357b476cb47cee71bfe4b19b8b9e0eed261379b56ab0911f1d859f0d25a02188948f78c6a3c2102b31c5633497e4236bb3930236548bf173a4c479f672e2a09fd93f6d82694763f7162e48bc10b1810f17eaf8215f09313a4ded6cc7bcab0aa0cff966cf53495001784518288aeff6e9d2627415d76a1bf19e442887c1ca33a6a0fe529aaabb773260566cd8bc28b560843918bf8dad05e263bebeb4ca65d0cc3ea047cc7302367b0694e12b54a58f510a885460057b457d9dcfef3d39a99089d4eca0cc7cb8007b513b31a40c877adc7b82e7006ec396ada019c58b6018ed78e09b57a0fb94d9ad345da6f7cc9586d5facf981f448fda9d0e3049baf81da614dd9585dca00448c8c03c1bd4895edeae194dbcaca5aefc8a0463d7776733a3b2a9c59c05f180").to_vec(),
+							hex!("f9040a20b90406f904030183137350b9010000000008000040000000000000000200000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000000000000000000000000400000000100000000000000000040000008000000000000000000000000000000000000000000000000001000000000200000000000002000004000000020000000000000000000000000000000000000820400000000000000000000000000000000000000000000000000000000000000000f902f8f9013c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8c00000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000020d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f90119942ffa5ecdbe006d30397c7636d3e015eee251369fe1a0779b38144a38cfc4351816442048b17fe24ba2b0e0c63446b576e8281160b15bb8e0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+		],
+	)
+}
+
 impl_benchmark_test_suite!(
 	BasicInboundChannel,
 	crate::inbound::test::new_tester(Default::default()),
@@ -1,6 +1,7 @@
 use ethabi::{Event, Param, ParamKind, Token};
 use artemis_ethereum::{log::Log, H160};
 
+use codec::{Encode, Decode};
 use sp_core::RuntimeDebug;
 use sp_std::prelude::*;
 use sp_std::convert::TryFrom;
@@ -17,7 +18,7 @@ static EVENT_ABI: &Event = &Event {
 };
 
 /// An inbound message that has had its outer envelope decoded.
-#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
 pub struct Envelope {
 	/// The address of the outbound channel on Ethereum that forwarded this message.
 	pub channel: H160,
@@ -1,18 +1,22 @@
 use frame_support::{
 	decl_error, decl_event, decl_module, decl_storage,
-	dispatch::DispatchResult,
+	dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo},
+	storage::migration,
+	traits::{EnsureOrigin, Get},
 	weights::Weight,
 };
 use frame_system::{self as system, ensure_signed};
-use sp_core::H160;
+use sp_core::{H160, H256};
 use sp_std::prelude::*;
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::convert::TryFrom;
 use artemis_core::{
-	ChannelId, Message, MessageId,
-	MessageDispatch, Verifier,
+	Channel, ChannelId, InboundChannel, Message, MessageId, MessageNonce,
+	MessageDelivery, MessageDispatch, SourceChannelInfo, Verifier,
 };
 
 use envelope::Envelope;
+use receipt::Receipt;
 
 mod benchmarking;
 
@@ -21,17 +25,27 @@ mod test;
 
 mod envelope;
 
+mod receipt;
+
 /// Weight functions needed for this pallet.
 pub trait WeightInfo {
 	fn submit() -> Weight;
+	fn submit_batch(m: u32) -> Weight;
+	fn register_source_channel() -> Weight;
+	fn set_source_channel_sunset() -> Weight;
+	fn set_receipt_channel() -> Weight;
 }
 
 impl WeightInfo for () {
 	fn submit() -> Weight { 0 }
+	fn submit_batch(_m: u32) -> Weight { 0 }
+	fn register_source_channel() -> Weight { 0 }
+	fn set_source_channel_sunset() -> Weight { 0 }
+	fn set_receipt_channel() -> Weight { 0 }
 }
 
 pub trait Config: system::Config {
-	type Event: From<Event> + Into<<Self as system::Config>::Event>;
+	type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
 
 	/// Verifier module for message verification.
 	type Verifier: Verifier;
@@ -39,20 +53,75 @@ pub trait Config: system::Config {
 	/// Verifier module for message verification.
 	type MessageDispatch: MessageDispatch<Self, MessageId>;
 
+	/// Outbound channel that delivery receipts for its messages are passed to.
+	type MessageDelivery: MessageDelivery;
+
+	/// Max number of verified messages with nonces ahead of the next expected
+	/// nonce that are buffered until the gap is filled. Zero disables buffering.
+	type MaxPendingMessages: Get<u64>;
+
+	/// Max number of messages that can be submitted in a single batch.
+	type MaxMessagesPerBatch: Get<u32>;
+
+	/// The origin which may register source channels and set their sunset.
+	type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
 	/// Weight information for extrinsics in this pallet
 	type WeightInfo: WeightInfo;
 }
 
 decl_storage! {
 	trait Store for Module<T: Config> as BasicInboundModule {
-		pub SourceChannel get(fn source_channel) config(): H160;
-		pub Nonce: u64;
+		/// Outbound channels on the Ethereum side that messages are accepted from.
+		pub SourceChannels get(fn source_channels): map hasher(twox_64_concat) H160 => Option<SourceChannelInfo<T::BlockNumber>>;
+
+		/// Nonce of the last message dispatched from each source channel.
+		pub Nonce: map hasher(twox_64_concat) H160 => MessageNonce;
+
+		/// Verified messages waiting for all messages with lower nonces from the
+		/// same source channel to be dispatched.
+		pub PendingMessages: double_map hasher(twox_64_concat) H160, hasher(twox_64_concat) MessageNonce => Option<Envelope>;
+
+		/// Inbound channel on the Ethereum side that delivery receipts are accepted from.
+		pub ReceiptChannel get(fn receipt_channel): Option<H160>;
+	}
+	add_extra_genesis {
+		config(source_channels): Vec<H160>;
+		build(|config: &GenesisConfig| {
+			for channel in config.source_channels.iter() {
+				SourceChannels::<T>::insert(channel, SourceChannelInfo::default());
+			}
+		});
 	}
 }
 
 decl_event! {
-	pub enum Event {
-
+	pub enum Event<T>
+	where
+		AccountId = <T as system::Config>::AccountId,
+		BlockNumber = <T as system::Config>::BlockNumber,
+	{
+		/// A message with a valid proof was accepted from a relayer.
+		/// [channel, nonce, source, relayer, block_hash]
+		MessageReceived(ChannelId, MessageNonce, H160, AccountId, H256),
+		/// A message arrived ahead of its turn and is buffered until the gap is filled.
+		/// [source_channel, nonce]
+		MessageBuffered(H160, MessageNonce),
+		/// A message in a batch was accepted for dispatch or buffering.
+		/// [index, nonce]
+		BatchMessageAccepted(u32, MessageNonce),
+		/// A message in a batch was rejected.
+		/// [index, error]
+		BatchMessageRejected(u32, DispatchError),
+		/// A source channel was registered.
+		/// [channel]
+		SourceChannelRegistered(H160),
+		/// The sunset block of a source channel was set or cleared.
+		/// [channel, sunset]
+		SourceChannelSunsetSet(H160, Option<BlockNumber>),
+		/// The inbound channel that delivery receipts are accepted from was set or cleared.
+		/// [channel]
+		ReceiptChannelSet(Option<H160>),
 	}
 }
 
@@ -60,10 +129,27 @@ decl_error! {
 	pub enum Error for Module<T: Config> {
 		/// Message came from an invalid outbound channel on the Ethereum side.
 		InvalidSourceChannel,
+		/// Message came from a source channel that has passed its sunset block.
+		SourceChannelSunset,
+		/// Source channel is already registered.
+		SourceChannelAlreadyRegistered,
+		/// Source channel is not registered.
+		UnknownSourceChannel,
 		/// Message has an invalid envelope.
 		InvalidEnvelope,
-		/// Message has an unexpected nonce.
+		/// Message has an unexpected nonce, is too far ahead of the next
+		/// expected nonce, or is already buffered.
 		InvalidNonce,
+		/// Batch contains more than `MaxMessagesPerBatch` messages.
+		BatchTooLarge,
+		/// Message would dispatch the buffered messages of a second source
+		/// channel in the same batch. It can be submitted in another batch.
+		BufferAlreadyDrained,
+		/// Receipt came from an inbound channel on the Ethereum side other than
+		/// the receipt channel.
+		InvalidReceiptChannel,
+		/// Receipt has an invalid format.
+		InvalidReceipt,
 	}
 }
 
@@ -74,35 +160,248 @@ decl_module! {
 
 		fn deposit_event() = default;
 
-		#[weight = T::WeightInfo::submit()]
-		pub fn submit(origin, message: Message) -> DispatchResult {
-			ensure_signed(origin)?;
-			// submit message to verifier for verification
-			let log = T::Verifier::verify(&message)?;
+		fn on_runtime_upgrade() -> Weight {
+			Self::migrate_source_channel()
+		}
 
-			// Decode log into an Envelope
-			let envelope = Envelope::try_from(log).map_err(|_| Error::<T>::InvalidEnvelope)?;
+		/// Submit a message. The weight of the dispatched calls is refunded
+		/// down to what they actually consumed.
+		#[weight = T::WeightInfo::submit().saturating_add(Module::<T>::max_accept_weight(1))]
+		pub fn submit(origin, message: Message) -> DispatchResultWithPostInfo {
+			let relayer = ensure_signed(origin)?;
+			let dispatch_weight = <Self as InboundChannel<T::AccountId>>::submit(&relayer, &message)?;
+			Ok(Some(T::WeightInfo::submit().saturating_add(dispatch_weight)).into())
+		}
 
-			// Verify that the message was submitted to us from a known
-			// outbound channel on the ethereum side
-			if envelope.channel != SourceChannel::get() {
-				return Err(Error::<T>::InvalidSourceChannel.into())
+		/// Submit a batch of messages. Each message is verified and accepted
+		/// independently, and the outcome for each one is reported in an event.
+		/// Nonces from each source channel must be strictly increasing across
+		/// the batch, and buffered messages of at most one source channel are
+		/// dispatched by the batch.
+		#[weight = T::WeightInfo::submit_batch(messages.len() as u32)
+			.saturating_add(Module::<T>::max_accept_weight(messages.len() as u64))]
+		pub fn submit_batch(origin, messages: Vec<Message>) -> DispatchResultWithPostInfo {
+			let relayer = ensure_signed(origin)?;
+			if messages.len() > T::MaxMessagesPerBatch::get() as usize {
+				return Err(Error::<T>::BatchTooLarge.into())
 			}
 
-			// Verify message nonce
-			Nonce::try_mutate(|nonce| -> DispatchResult {
-				if envelope.nonce != *nonce + 1 {
-					Err(Error::<T>::InvalidNonce.into())
-				} else {
-					*nonce += 1;
-					Ok(())
-				}
+			let mut last_nonces: BTreeMap<H160, MessageNonce> = BTreeMap::new();
+			let mut drained_channel: Option<H160> = None;
+			let mut dispatch_weight: Weight = 0;
+			for (index, message) in messages.iter().enumerate() {
+				let result = Self::verify_message(message).and_then(|envelope| {
+					let (channel, nonce) = (envelope.channel, envelope.nonce);
+					if last_nonces.get(&channel).map_or(false, |last| nonce <= *last) {
+						return Err(Error::<T>::InvalidNonce.into())
+					}
+					let drains = Self::unblocks_buffered_messages(channel, nonce);
+					if drains && drained_channel.map_or(false, |drained| drained != channel) {
+						return Err(Error::<T>::BufferAlreadyDrained.into())
+					}
+					Self::accept(relayer.clone(), message.proof.block_hash, envelope)
+						.map(|weight| (channel, nonce, drains, weight))
+				});
+
+				let event = match result {
+					Ok((channel, nonce, drains, weight)) => {
+						last_nonces.insert(channel, nonce);
+						if drains {
+							drained_channel = Some(channel);
+						}
+						dispatch_weight = dispatch_weight.saturating_add(weight);
+						RawEvent::BatchMessageAccepted(index as u32, nonce)
+					},
+					Err(err) => RawEvent::BatchMessageRejected(index as u32, err),
+				};
+				Self::deposit_event(event);
+				dispatch_weight = dispatch_weight.saturating_add(T::DbWeight::get().reads(2));
+			}
+
+			Ok(Some(T::WeightInfo::submit_batch(messages.len() as u32).saturating_add(dispatch_weight)).into())
+		}
+
+		#[weight = T::WeightInfo::register_source_channel()]
+		pub fn register_source_channel(origin, channel: H160) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if SourceChannels::<T>::contains_key(channel) {
+				return Err(Error::<T>::SourceChannelAlreadyRegistered.into())
+			}
+			SourceChannels::<T>::insert(channel, SourceChannelInfo::default());
+			Self::deposit_event(RawEvent::SourceChannelRegistered(channel));
+			Ok(())
+		}
+
+		/// Stop accepting messages from `channel` from block `sunset` onwards,
+		/// or lift a previously set sunset if `sunset` is `None`.
+		#[weight = T::WeightInfo::set_source_channel_sunset()]
+		pub fn set_source_channel_sunset(origin, channel: H160, sunset: Option<T::BlockNumber>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			SourceChannels::<T>::try_mutate(channel, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::UnknownSourceChannel)?;
+				info.sunset = sunset;
+				Ok::<_, DispatchError>(())
 			})?;
+			Self::deposit_event(RawEvent::SourceChannelSunsetSet(channel, sunset));
+			Ok(())
+		}
 
-			let message_id = MessageId::new(ChannelId::Basic, envelope.nonce);
-			T::MessageDispatch::dispatch(envelope.source, message_id, &envelope.payload);
+		/// Submit a receipt emitted by the inbound channel on Ethereum after
+		/// executing a message from the outbound channel. Receipts must be
+		/// submitted in nonce order.
+		///
+		/// Verifying the receipt dominates the cost, so it is charged as a
+		/// message submission.
+		#[weight = T::WeightInfo::submit()]
+		pub fn submit_receipt(origin, message: Message) -> DispatchResult {
+			ensure_signed(origin)?;
+			let log = T::Verifier::verify(&message)?;
+			let receipt = Receipt::try_from(log).map_err(|_| Error::<T>::InvalidReceipt)?;
+			if Self::receipt_channel() != Some(receipt.channel) {
+				return Err(Error::<T>::InvalidReceiptChannel.into())
+			}
+			T::MessageDelivery::on_delivered(receipt.nonce, receipt.success)
+		}
 
+		/// Accept delivery receipts from `channel`, or stop accepting them if
+		/// `channel` is `None`.
+		#[weight = T::WeightInfo::set_receipt_channel()]
+		pub fn set_receipt_channel(origin, channel: Option<H160>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ReceiptChannel::set(channel);
+			Self::deposit_event(RawEvent::ReceiptChannelSet(channel));
 			Ok(())
 		}
 	}
 }
+
+impl<T: Config> Module<T> {
+	fn verify_message(message: &Message) -> Result<Envelope, DispatchError> {
+		// submit message to verifier for verification
+		let log = T::Verifier::verify(message)?;
+
+		// Decode log into an Envelope
+		let envelope = Envelope::try_from(log).map_err(|_| Error::<T>::InvalidEnvelope)?;
+
+		// Verify that the message was submitted to us from a known
+		// outbound channel on the ethereum side
+		let info = SourceChannels::<T>::get(envelope.channel).ok_or(Error::<T>::InvalidSourceChannel)?;
+		if info.sunset.map_or(false, |sunset| <frame_system::Pallet<T>>::block_number() >= sunset) {
+			return Err(Error::<T>::SourceChannelSunset.into())
+		}
+
+		Ok(envelope)
+	}
+
+	// Verify message nonce, then buffer the message if it's ahead of its turn,
+	// or dispatch it along with the buffered messages that it unblocks.
+	//
+	// Returns the actual weight of accepting this message, including the
+	// messages it dispatches. A buffered message is charged for its dispatch
+	// once it's dispatched.
+	fn accept(relayer: T::AccountId, block_hash: H256, envelope: Envelope) -> Result<Weight, DispatchError> {
+		let next_nonce = Nonce::get(envelope.channel) + 1;
+		let buffer = envelope.nonce > next_nonce;
+		if envelope.nonce < next_nonce
+			|| (buffer && (envelope.nonce - next_nonce > T::MaxPendingMessages::get()
+				|| PendingMessages::contains_key(envelope.channel, envelope.nonce)))
+		{
+			return Err(Error::<T>::InvalidNonce.into())
+		}
+
+		Self::deposit_event(RawEvent::MessageReceived(
+			Self::id(),
+			envelope.nonce,
+			envelope.source,
+			relayer,
+			block_hash,
+		));
+
+		let channel = envelope.channel;
+		if buffer {
+			let nonce = envelope.nonce;
+			PendingMessages::insert(channel, nonce, envelope);
+			Self::deposit_event(RawEvent::MessageBuffered(channel, nonce));
+			Ok(Self::buffered_message_db_weight())
+		} else {
+			let mut weight = Self::dispatch(envelope);
+			while let Some(envelope) = PendingMessages::take(channel, Nonce::get(channel) + 1) {
+				weight = weight
+					.saturating_add(Self::dispatch(envelope))
+					.saturating_add(Self::drained_message_db_weight());
+			}
+			Ok(weight.saturating_add(T::DbWeight::get().reads(2)))
+		}
+	}
+
+	// Whether a message with `nonce` from `channel` is next in turn and
+	// dispatches buffered messages when accepted.
+	fn unblocks_buffered_messages(channel: H160, nonce: MessageNonce) -> bool {
+		nonce == Nonce::get(channel) + 1 && PendingMessages::contains_key(channel, nonce + 1)
+	}
+
+	// Max weight of accepting `num_messages` messages in one call. Each of them
+	// may be dispatched, along with the buffered messages of one source channel,
+	// up to MaxPendingMessages.
+	fn max_accept_weight(num_messages: u64) -> Weight {
+		let max_dispatches = T::MaxPendingMessages::get().saturating_add(num_messages);
+		T::MessageDispatch::max_dispatch_weight()
+			.saturating_add(Self::drained_message_db_weight())
+			.saturating_mul(max_dispatches)
+	}
+
+	// Storage accesses for buffering a message: reading the nonce, checking
+	// the buffer and inserting the message.
+	fn buffered_message_db_weight() -> Weight {
+		T::DbWeight::get().reads_writes(2, 1)
+	}
+
+	// Storage accesses for dispatching a buffered message: taking it from the
+	// buffer and updating the nonce.
+	fn drained_message_db_weight() -> Weight {
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+
+	fn dispatch(envelope: Envelope) -> Weight {
+		Nonce::insert(envelope.channel, envelope.nonce);
+		let message_id = MessageId::new(Self::id(), envelope.nonce);
+		T::MessageDispatch::dispatch(envelope.source, message_id, &envelope.payload)
+	}
+
+	// Move the single source channel and its nonce from the legacy storage
+	// layout into the per-channel maps.
+	fn migrate_source_channel() -> Weight {
+		let legacy_channel: Option<H160> = migration::take_storage_value(
+			b"BasicInboundModule",
+			b"SourceChannel",
+			&[],
+		);
+		let channel = match legacy_channel {
+			Some(channel) => channel,
+			None => return T::DbWeight::get().reads(1),
+		};
+
+		let nonce: Option<MessageNonce> = migration::take_storage_value(
+			b"BasicInboundModule",
+			b"Nonce",
+			&[],
+		);
+		SourceChannels::<T>::insert(channel, SourceChannelInfo::default());
+		Nonce::insert(channel, nonce.unwrap_or_default());
+
+		T::DbWeight::get().reads_writes(2, 4)
+	}
+}
+
+impl<T: Config> Channel for Module<T> {
+	fn id() -> ChannelId {
+		ChannelId::BASIC
+	}
+}
+
+impl<T: Config> InboundChannel<T::AccountId> for Module<T> {
+	fn submit(relayer: &T::AccountId, message: &Message) -> Result<Weight, DispatchError> {
+		let envelope = Self::verify_message(message)?;
+		Self::accept(relayer.clone(), message.proof.block_hash, envelope)
+	}
+}
@@ -0,0 +1,87 @@
+use ethabi::{Event, Param, ParamKind, Token};
+use artemis_ethereum::{log::Log, H160};
+
+use codec::{Encode, Decode};
+use sp_core::RuntimeDebug;
+use sp_std::prelude::*;
+use sp_std::convert::TryFrom;
+
+// Used to decode a raw Ethereum log into a [`Receipt`].
+static EVENT_ABI: &Event = &Event {
+	signature: "MessageDispatched(uint64,bool)",
+	inputs: &[
+		Param { kind: ParamKind::Uint(64), indexed: false },
+		Param { kind: ParamKind::Bool, indexed: false },
+	],
+	anonymous: false
+};
+
+/// A receipt emitted by the inbound channel on Ethereum after executing a
+/// message from the parachain.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+pub struct Receipt {
+	/// The address of the inbound channel on Ethereum that executed the message.
+	pub channel: H160,
+	/// The nonce of the executed message.
+	pub nonce: u64,
+	/// Whether the call to the target application succeeded.
+	pub success: bool,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ReceiptDecodeError;
+
+impl TryFrom<Log> for Receipt {
+	type Error = ReceiptDecodeError;
+
+	fn try_from(log: Log) -> Result<Self, Self::Error> {
+		let tokens = EVENT_ABI.decode(log.topics, log.data)
+			.map_err(|_| ReceiptDecodeError)?;
+
+		let mut iter = tokens.into_iter();
+
+		let nonce = match iter.next().ok_or(ReceiptDecodeError)? {
+			Token::Uint(value) => {
+				value.low_u64()
+			}
+			_ => return Err(ReceiptDecodeError)
+		};
+
+		let success = match iter.next().ok_or(ReceiptDecodeError)? {
+			Token::Bool(success) => success,
+			_ => return Err(ReceiptDecodeError)
+		};
+
+		Ok(Self {
+			channel: log.address,
+			nonce,
+			success,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hex_literal::hex;
+
+	const LOG: [u8; 123] = hex!("
+		f879944b6b7ed1a9ea5b6c3fad0a8e3a0a8d21f52f4a7ae1a0504b093d860dc8
+		27c72a879d052fd8ac6b4c2af80c5f3a634654f172690bf10ab8400000000000
+		0000000000000000000000000000000000000000000000000000070000000000
+		000000000000000000000000000000000000000000000000000001
+	");
+
+	#[test]
+	fn test_try_from_log() {
+		let log: Log = rlp::decode(&LOG).unwrap();
+		let receipt = Receipt::try_from(log).unwrap();
+
+		assert_eq!(receipt,
+			Receipt {
+				channel: hex!["4b6b7ed1a9ea5b6c3fad0a8e3a0a8d21f52f4a7a"].into(),
+				nonce: 7,
+				success: true,
+			})
+	}
+}
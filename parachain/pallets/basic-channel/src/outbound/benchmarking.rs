@@ -22,7 +22,7 @@ benchmarks! {
 
 		for _ in 0 .. m {
 			let payload: Vec<u8> = (0..).take(p as usize).collect();
-			MessageQueue::append(Message {
+			BasicOutboundChannel::<T>::enqueue(Message {
 				target: H160::zero(),
 				nonce: 0u64,
 				payload,
@@ -33,13 +33,14 @@ benchmarks! {
 
 	}: { BasicOutboundChannel::<T>::on_initialize(block_number) }
 	verify {
-		assert_eq!(MessageQueue::get().len(), 0);
+		// Messages beyond MaxBytesPerCommit stay queued
+		assert!(BasicOutboundChannel::<T>::queue_depth() < m as u64);
 	}
 
 	// Benchmark 'on_initialize` for the best case, i.e. nothing is done
 	// because it's not a commitment interval.
 	on_initialize_non_interval {
-		MessageQueue::append(Message {
+		BasicOutboundChannel::<T>::enqueue(Message {
 			target: H160::zero(),
 			nonce: 0u64,
 			payload: vec![1u8; T::MaxMessagePayloadSize::get()],
@@ -50,13 +51,13 @@ benchmarks! {
 
 	}: { BasicOutboundChannel::<T>::on_initialize(block_number) }
 	verify {
-		assert_eq!(MessageQueue::get().len(), 1);
+		assert_eq!(BasicOutboundChannel::<T>::queue_depth(), 1);
 	}
 
 	// Benchmark 'on_initialize` for the case where it is a commitment interval
 	// but there are no messages in the queue.
 	on_initialize_no_messages {
-		MessageQueue::kill();
+		assert_eq!(BasicOutboundChannel::<T>::queue_depth(), 0);
 
 		let block_number = Interval::<T>::get();
 
@@ -4,18 +4,27 @@ use frame_support::{
 	decl_error, decl_event, decl_module, decl_storage,
 	weights::Weight,
 	dispatch::DispatchResult,
+	storage::migration,
 	traits::{Get, EnsureOrigin},
 	ensure,
 };
 use frame_system::{self as system};
-use sp_core::{H160, H256, RuntimeDebug};
+use sp_core::{H160, H256, U256, RuntimeDebug};
 use sp_io::offchain_index;
 use sp_runtime::{
 	traits::{Hash, Zero, StaticLookup},
 };
 use sp_std::prelude::*;
 
-use artemis_core::{ChannelId, MessageNonce, types::AuxiliaryDigestItem};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+
+use artemis_core::{
+	Channel, ChannelId, CommitmentMode, MessageDelivery, MessageNonce, MessageStatus,
+	MerkleProof, OutboundChannel,
+	merkle::{merkle_proof, merkle_root},
+	types::AuxiliaryDigestItem,
+};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
@@ -25,13 +34,15 @@ mod test;
 
 /// Wire-format for committed messages
 #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Message {
 	/// Target application on the Ethereum side.
-	target: H160,
+	pub target: H160,
 	/// A nonce for replay protection and ordering.
-	nonce: u64,
+	pub nonce: u64,
 	/// Payload for target application.
-	payload: Vec<u8>,
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub payload: Vec<u8>,
 }
 
 /// Weight functions needed for this pallet.
@@ -60,9 +71,19 @@ pub trait Config: system::Config {
 	// Max bytes in a message payload
 	type MaxMessagePayloadSize: Get<usize>;
 
-	/// Max number of messages that can be queued and committed in one go for a given channel.
+	/// How messages are committed to in the block header digest.
+	type CommitmentMode: Get<CommitmentMode>;
+
+	/// Max number of messages that can be committed in one go for a given channel.
 	type MaxMessagesPerCommit: Get<usize>;
 
+	/// Max total payload bytes that can be committed in one go for a given channel.
+	/// Always admits at least one message.
+	type MaxBytesPerCommit: Get<usize>;
+
+	/// Max number of messages waiting to be committed.
+	type MaxQueuedMessages: Get<u64>;
+
 	type SetPrincipalOrigin: EnsureOrigin<Self::Origin>;
 
 	/// Weight information for extrinsics in this pallet
@@ -74,19 +95,37 @@ decl_storage! {
 		/// Interval between committing messages.
 		Interval get(fn interval) config(): T::BlockNumber;
 
-		/// Messages waiting to be committed.
-		MessageQueue: Vec<Message>;
+		/// Messages waiting to be committed, by position in the queue.
+		MessageQueue: map hasher(twox_64_concat) u64 => Option<Message>;
+
+		/// Position of the oldest message waiting to be committed.
+		MessageQueueHead: u64;
+
+		/// Position at which the next submitted message is queued.
+		MessageQueueTail: u64;
 
 		/// The Account authorized to submit messages
 		Principal get(fn principal) config(): T::AccountId;
 
 		pub Nonce: u64;
+
+		/// Nonce of the last message that Ethereum acknowledged delivering.
+		pub DeliveredNonce get(fn delivered_nonce): u64;
 	}
 }
 
 decl_event! {
 	pub enum Event {
 		MessageAccepted(MessageNonce),
+		/// Messages have been committed.
+		/// [commitment hash, number of messages committed, number of messages still queued]
+		MessagesCommitted(H256, u32, u64),
+		/// Ethereum executed a message successfully.
+		/// [nonce]
+		MessageDelivered(MessageNonce),
+		/// Ethereum executed a message, but the call to the target application failed.
+		/// [nonce]
+		MessageFailed(MessageNonce),
 	}
 }
 
@@ -94,12 +133,15 @@ decl_error! {
 	pub enum Error for Module<T: Config> {
 		/// The message payload exceeds byte limit.
 		PayloadTooLarge,
-		/// No more messages can be queued for the channel during this commit cycle.
+		/// No more messages can be queued for the channel until queued messages are committed.
 		QueueSizeLimitReached,
 		/// Cannot increment nonce
 		Overflow,
 		/// Not authorized to send message
 		NotAuthorized,
+		/// Delivery receipt is not for the committed message following the
+		/// last delivered one.
+		UnexpectedDeliveryNonce,
 	}
 }
 
@@ -108,10 +150,15 @@ decl_module! {
 		type Error = Error<T>;
 		fn deposit_event() = default;
 
+		fn on_runtime_upgrade() -> Weight {
+			Self::migrate_message_queue()
+		}
+
 		// Generate a message commitment every [`Interval`] blocks.
 		//
 		// The commitment hash is included in an [`AuxiliaryDigestItem`] in the block header,
-		// with the corresponding commitment is persisted offchain.
+		// with the corresponding commitment is persisted offchain. Messages that don't
+		// fit in a commitment stay queued for the next one.
 		fn on_initialize(now: T::BlockNumber) -> Weight {
 			if (now % Self::interval()).is_zero() {
 				Self::commit()
@@ -139,7 +186,7 @@ impl<T: Config> Module<T> {
 			Error::<T>::NotAuthorized,
 		);
 		ensure!(
-			MessageQueue::decode_len().unwrap_or(0) < T::MaxMessagesPerCommit::get(),
+			Self::queue_depth() < T::MaxQueuedMessages::get(),
 			Error::<T>::QueueSizeLimitReached,
 		);
 		ensure!(
@@ -154,7 +201,7 @@ impl<T: Config> Module<T> {
 				return Err(Error::<T>::Overflow.into())
 			}
 
-			MessageQueue::append(
+			Self::enqueue(
 				Message {
 					target,
 					nonce: *nonce,
@@ -166,8 +213,62 @@ impl<T: Config> Module<T> {
 		})
 	}
 
+	/// Progress of the message with `nonce`.
+	pub fn message_status(nonce: MessageNonce) -> MessageStatus {
+		if nonce == 0 || nonce > Nonce::get() {
+			return MessageStatus::Unknown;
+		}
+		if nonce <= DeliveredNonce::get() {
+			return MessageStatus::Delivered;
+		}
+		// Messages are committed in nonce order, so the oldest queued message
+		// has the lowest nonce still waiting to be committed.
+		match MessageQueue::get(MessageQueueHead::get()) {
+			Some(oldest) if nonce >= oldest.nonce => MessageStatus::Queued,
+			_ => MessageStatus::Committed,
+		}
+	}
+
+	/// Number of messages waiting to be committed.
+	pub fn queue_depth() -> u64 {
+		MessageQueueTail::get() - MessageQueueHead::get()
+	}
+
+	fn enqueue(message: Message) {
+		let tail = MessageQueueTail::get();
+		MessageQueue::insert(tail, message);
+		MessageQueueTail::put(tail + 1);
+	}
+
+	// Remove the oldest queued messages that fit within the commitment budget
+	fn dequeue_for_commit() -> Vec<Message> {
+		let head = MessageQueueHead::get();
+		let tail = MessageQueueTail::get();
+		let mut messages: Vec<Message> = Vec::new();
+		let mut payload_size: usize = 0;
+
+		for index in head..tail {
+			if messages.len() >= T::MaxMessagesPerCommit::get() {
+				break;
+			}
+			let message = match MessageQueue::get(index) {
+				Some(message) => message,
+				None => break,
+			};
+			payload_size = payload_size.saturating_add(message.payload.len());
+			if !messages.is_empty() && payload_size > T::MaxBytesPerCommit::get() {
+				break;
+			}
+			MessageQueue::remove(index);
+			messages.push(message);
+		}
+
+		MessageQueueHead::put(head + messages.len() as u64);
+		messages
+	}
+
 	fn commit() -> Weight {
-		let messages: Vec<Message> = MessageQueue::take();
+		let messages = Self::dequeue_for_commit();
 		if messages.is_empty() {
 			return T::WeightInfo::on_initialize_no_messages();
 		}
@@ -176,7 +277,7 @@ impl<T: Config> Module<T> {
 		let average_payload_size = Self::average_payload_size(&messages);
 
 		let digest_item = AuxiliaryDigestItem::Commitment(
-			ChannelId::Basic,
+			Self::id(),
 			commitment_hash.clone()
 		).into();
 		<frame_system::Pallet<T>>::deposit_log(digest_item);
@@ -184,6 +285,12 @@ impl<T: Config> Module<T> {
 		let key = Self::make_offchain_key(commitment_hash);
 		offchain_index::set(&*key, &messages.encode());
 
+		Self::deposit_event(Event::MessagesCommitted(
+			commitment_hash,
+			messages.len() as u32,
+			Self::queue_depth(),
+		));
+
 		T::WeightInfo::on_initialize(
 			messages.len() as u32,
 			average_payload_size as u32
@@ -191,18 +298,41 @@ impl<T: Config> Module<T> {
 	}
 
 	fn make_commitment_hash(messages: &[Message]) -> H256 {
-		let messages: Vec<Token> = messages
+		match T::CommitmentMode::get() {
+			CommitmentMode::Batch => {
+				let messages: Vec<Token> = messages.iter().map(Self::make_message_token).collect();
+				let input = ethabi::encode(&vec![Token::Array(messages)]);
+				<T as Config>::Hashing::hash(&input)
+			},
+			CommitmentMode::MerkleTree => {
+				merkle_root::<<T as Config>::Hashing>(&Self::make_message_leaves(messages))
+			},
+		}
+	}
+
+	/// Build a proof that the message with `nonce` is included in the
+	/// [`CommitmentMode::MerkleTree`] commitment of `messages`.
+	pub fn make_message_proof(messages: &[Message], nonce: MessageNonce) -> Option<MerkleProof> {
+		let index = messages.iter().position(|message| message.nonce == nonce)?;
+		merkle_proof::<<T as Config>::Hashing>(&Self::make_message_leaves(messages), index)
+	}
+
+	fn make_message_leaves(messages: &[Message]) -> Vec<H256> {
+		messages
 			.iter()
 			.map(|message| {
-				Token::Tuple(vec![
-					Token::Address(message.target),
-					Token::Uint(message.nonce.into()),
-					Token::Bytes(message.payload.clone())
-				])
+				let input = ethabi::encode(&vec![Self::make_message_token(message)]);
+				<T as Config>::Hashing::hash(&input)
 			})
-			.collect();
-		let input = ethabi::encode(&vec![Token::Array(messages)]);
-		<T as Config>::Hashing::hash(&input)
+			.collect()
+	}
+
+	fn make_message_token(message: &Message) -> Token {
+		Token::Tuple(vec![
+			Token::Address(message.target),
+			Token::Uint(message.nonce.into()),
+			Token::Bytes(message.payload.clone())
+		])
 	}
 
 	fn average_payload_size(messages: &[Message]) -> usize {
@@ -213,7 +343,58 @@ impl<T: Config> Module<T> {
 		(sum / messages.len()).saturating_add(1)
 	}
 
-	fn make_offchain_key(hash: H256) -> Vec<u8> {
-		(T::INDEXING_PREFIX, ChannelId::Basic, hash).encode()
+	// Move messages from the single-value queue of previous releases into the
+	// paged queue, ahead of any messages submitted after the upgrade.
+	fn migrate_message_queue() -> Weight {
+		let legacy_queue: Option<Vec<Message>> = migration::take_storage_value(
+			b"BasicOutboundModule",
+			b"MessageQueue",
+			&[],
+		);
+		match legacy_queue {
+			Some(messages) => {
+				let num_messages = messages.len() as Weight;
+				messages.into_iter().for_each(Self::enqueue);
+				T::DbWeight::get().reads_writes(1 + 2 * num_messages, 1 + 2 * num_messages)
+			},
+			None => T::DbWeight::get().reads(1),
+		}
+	}
+
+	/// Offchain storage key under which the messages of commitment `hash` are indexed.
+	pub fn make_offchain_key(hash: H256) -> Vec<u8> {
+		(T::INDEXING_PREFIX, Self::id(), hash).encode()
+	}
+}
+
+impl<T: Config> Channel for Module<T> {
+	fn id() -> ChannelId {
+		ChannelId::BASIC
+	}
+}
+
+impl<T: Config> OutboundChannel<T::AccountId> for Module<T> {
+	// The basic channel does not charge fees, so `max_fee` never applies.
+	fn submit(who: &T::AccountId, target: H160, payload: &[u8], _max_fee: Option<U256>) -> DispatchResult {
+		Module::<T>::submit(who, target, payload)
+	}
+}
+
+impl<T: Config> MessageDelivery for Module<T> {
+	fn on_delivered(nonce: MessageNonce, success: bool) -> DispatchResult {
+		ensure!(
+			nonce == DeliveredNonce::get() + 1
+				&& Self::message_status(nonce) == MessageStatus::Committed,
+			Error::<T>::UnexpectedDeliveryNonce,
+		);
+		DeliveredNonce::put(nonce);
+
+		let event = if success {
+			Event::MessageDelivered(nonce)
+		} else {
+			Event::MessageFailed(nonce)
+		};
+		Self::deposit_event(event);
+		Ok(())
 	}
 }
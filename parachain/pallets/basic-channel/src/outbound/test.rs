@@ -12,6 +12,8 @@ use sp_runtime::{
 use sp_keyring::AccountKeyring as Keyring;
 use sp_std::convert::From;
 
+use artemis_core::merkle::verify_merkle_proof;
+
 use crate::outbound as basic_outbound_channel;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -64,6 +66,9 @@ impl system::Config for Test {
 parameter_types! {
 	pub const MaxMessagePayloadSize: usize = 128;
 	pub const MaxMessagesPerCommit: usize = 5;
+	pub const MaxBytesPerCommit: usize = 256;
+	pub const OutboundCommitmentMode: CommitmentMode = CommitmentMode::MerkleTree;
+	pub const MaxQueuedMessages: u64 = 8;
 }
 
 impl basic_outbound_channel::Config for Test {
@@ -71,7 +76,10 @@ impl basic_outbound_channel::Config for Test {
 	type Event = Event;
 	type Hashing = Keccak256;
 	type MaxMessagePayloadSize = MaxMessagePayloadSize;
+	type CommitmentMode = OutboundCommitmentMode;
 	type MaxMessagesPerCommit = MaxMessagesPerCommit;
+	type MaxBytesPerCommit = MaxBytesPerCommit;
+	type MaxQueuedMessages = MaxQueuedMessages;
 	type SetPrincipalOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type WeightInfo = ();
 }
@@ -91,6 +99,20 @@ pub fn new_tester() -> sp_io::TestExternalities {
 	ext
 }
 
+fn last_event() -> Event {
+	System::events().pop().expect("Event expected").event
+}
+
+fn queued_messages() -> Vec<Message> {
+	(MessageQueueHead::get()..MessageQueueTail::get())
+		.map(|index| MessageQueue::get(index).unwrap())
+		.collect()
+}
+
+fn queued_nonces() -> Vec<u64> {
+	queued_messages().iter().map(|message| message.nonce).collect()
+}
+
 #[test]
 fn test_submit() {
 	new_tester().execute_with(|| {
@@ -111,7 +133,7 @@ fn test_submit_exceeds_queue_limit() {
 		let target = H160::zero();
 		let who: AccountId = Keyring::Bob.into();
 
-		let max_messages = MaxMessagesPerCommit::get();
+		let max_messages = MaxQueuedMessages::get();
 		(0..max_messages).for_each(
 			|_| BasicOutboundChannel::submit(&who, target, &vec![0, 1, 2]).unwrap()
 		);
@@ -123,6 +145,111 @@ fn test_submit_exceeds_queue_limit() {
 	})
 }
 
+#[test]
+fn test_commit_carries_excess_messages_into_later_commits() {
+	new_tester().execute_with(|| {
+		let target = H160::zero();
+		let who: AccountId = Keyring::Bob.into();
+
+		let max_messages = MaxMessagesPerCommit::get() as u64;
+		(0..max_messages + 2).for_each(
+			|_| BasicOutboundChannel::submit(&who, target, &vec![0, 1, 2]).unwrap()
+		);
+		assert_eq!(BasicOutboundChannel::queue_depth(), max_messages + 2);
+
+		BasicOutboundChannel::commit();
+		assert_eq!(BasicOutboundChannel::queue_depth(), 2);
+		assert_eq!(queued_nonces(), vec![max_messages + 1, max_messages + 2]);
+		assert!(matches!(
+			last_event(),
+			Event::basic_outbound_channel(crate::outbound::Event::MessagesCommitted(_, 5, 2))
+		));
+
+		BasicOutboundChannel::commit();
+		assert_eq!(BasicOutboundChannel::queue_depth(), 0);
+		assert!(matches!(
+			last_event(),
+			Event::basic_outbound_channel(crate::outbound::Event::MessagesCommitted(_, 2, 0))
+		));
+
+		// Nothing left to commit
+		let num_events = System::events().len();
+		BasicOutboundChannel::commit();
+		assert_eq!(System::events().len(), num_events);
+	})
+}
+
+#[test]
+fn test_commit_respects_payload_budget() {
+	new_tester().execute_with(|| {
+		let target = H160::zero();
+		let who: AccountId = Keyring::Bob.into();
+
+		let payload = vec![1u8; MaxMessagePayloadSize::get()];
+		(0..3).for_each(
+			|_| BasicOutboundChannel::submit(&who, target, &payload).unwrap()
+		);
+
+		// Only two full-size payloads fit in MaxBytesPerCommit
+		BasicOutboundChannel::commit();
+		assert_eq!(queued_nonces(), vec![3]);
+
+		BasicOutboundChannel::commit();
+		assert_eq!(BasicOutboundChannel::queue_depth(), 0);
+	})
+}
+
+#[test]
+fn test_migrates_legacy_message_queue() {
+	new_tester().execute_with(|| {
+		let legacy_queue: Vec<Message> = (1..=2)
+			.map(|nonce| Message { target: H160::zero(), nonce, payload: vec![0, 1, 2] })
+			.collect();
+		migration::put_storage_value(b"BasicOutboundModule", b"MessageQueue", &[], legacy_queue);
+		Nonce::set(2);
+
+		BasicOutboundChannel::migrate_message_queue();
+		assert_eq!(queued_nonces(), vec![1, 2]);
+		assert_eq!(
+			migration::get_storage_value::<Vec<Message>>(b"BasicOutboundModule", b"MessageQueue", &[]),
+			None,
+		);
+
+		// Messages submitted after the upgrade are committed after the migrated ones
+		let who: AccountId = Keyring::Bob.into();
+		assert_ok!(BasicOutboundChannel::submit(&who, H160::zero(), &vec![0, 1, 2]));
+		assert_eq!(queued_nonces(), vec![1, 2, 3]);
+	})
+}
+
+#[test]
+fn test_merkle_commitment_proves_each_message() {
+	new_tester().execute_with(|| {
+		let target = H160::zero();
+		let who: AccountId = Keyring::Bob.into();
+
+		(0..3u8).for_each(
+			|i| BasicOutboundChannel::submit(&who, target, &vec![i; 3]).unwrap()
+		);
+		let messages = queued_messages();
+
+		BasicOutboundChannel::commit();
+		let commitment_hash = match last_event() {
+			Event::basic_outbound_channel(crate::outbound::Event::MessagesCommitted(hash, 3, 0)) => hash,
+			event => panic!("Unexpected event: {:?}", event),
+		};
+
+		let leaves = BasicOutboundChannel::make_message_leaves(&messages);
+		assert_eq!(commitment_hash, merkle_root::<Keccak256>(&leaves));
+
+		for (message, leaf) in messages.iter().zip(leaves) {
+			let proof = BasicOutboundChannel::make_message_proof(&messages, message.nonce).unwrap();
+			assert!(verify_merkle_proof::<Keccak256>(commitment_hash, leaf, &proof));
+		}
+		assert_eq!(BasicOutboundChannel::make_message_proof(&messages, 4), None);
+	})
+}
+
 #[test]
 fn test_submit_exceeds_payload_limit() {
 	new_tester().execute_with(|| {
@@ -190,3 +317,55 @@ fn test_set_principal() {
 		assert_eq!(<Principal<Test>>::get(), alice);
 	});
 }
+
+#[test]
+fn test_message_status_tracks_delivery() {
+	new_tester().execute_with(|| {
+		let target = H160::zero();
+		let who: AccountId = Keyring::Bob.into();
+
+		let max_messages = MaxMessagesPerCommit::get() as u64;
+		(0..max_messages + 1).for_each(
+			|_| BasicOutboundChannel::submit(&who, target, &vec![0, 1, 2]).unwrap()
+		);
+		BasicOutboundChannel::commit();
+
+		assert_eq!(BasicOutboundChannel::message_status(0), MessageStatus::Unknown);
+		assert_eq!(BasicOutboundChannel::message_status(1), MessageStatus::Committed);
+		assert_eq!(BasicOutboundChannel::message_status(max_messages), MessageStatus::Committed);
+		assert_eq!(BasicOutboundChannel::message_status(max_messages + 1), MessageStatus::Queued);
+		assert_eq!(BasicOutboundChannel::message_status(max_messages + 2), MessageStatus::Unknown);
+
+		// Receipts must arrive in nonce order
+		assert_noop!(
+			BasicOutboundChannel::on_delivered(2, true),
+			Error::<Test>::UnexpectedDeliveryNonce,
+		);
+
+		assert_ok!(BasicOutboundChannel::on_delivered(1, true));
+		assert_eq!(BasicOutboundChannel::delivered_nonce(), 1);
+		assert_eq!(BasicOutboundChannel::message_status(1), MessageStatus::Delivered);
+		assert_eq!(
+			last_event(),
+			Event::basic_outbound_channel(crate::outbound::Event::MessageDelivered(1))
+		);
+
+		assert_noop!(
+			BasicOutboundChannel::on_delivered(1, true),
+			Error::<Test>::UnexpectedDeliveryNonce,
+		);
+
+		assert_ok!(BasicOutboundChannel::on_delivered(2, false));
+		assert_eq!(
+			last_event(),
+			Event::basic_outbound_channel(crate::outbound::Event::MessageFailed(2))
+		);
+
+		// Messages that are not committed yet can't have been delivered
+		(3..=max_messages).for_each(|nonce| BasicOutboundChannel::on_delivered(nonce, true).unwrap());
+		assert_noop!(
+			BasicOutboundChannel::on_delivered(max_messages + 1, true),
+			Error::<Test>::UnexpectedDeliveryNonce,
+		);
+	})
+}
@@ -1,22 +1,29 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-	decl_event, decl_module, decl_storage,
-	dispatch::{Parameter, Dispatchable, DispatchResult},
-	traits::{EnsureOrigin, Filter},
-	weights::GetDispatchInfo,
+	decl_error, decl_event, decl_module, decl_storage,
+	dispatch::{Parameter, Dispatchable, DispatchResult, DispatchResultWithPostInfo},
+	traits::{EnsureOrigin, Filter, Get, GetCallMetadata},
+	weights::{GetDispatchInfo, Weight, extract_actual_weight},
+	ensure,
 };
 
 use sp_core::RuntimeDebug;
+use sp_runtime::traits::{Convert, Saturating, TrailingZeroInput};
 
-use frame_system::{self as system};
+use frame_system::{self as system, ensure_signed};
 use sp_core::H160;
-use sp_std::prelude::*;
+use sp_std::{marker::PhantomData, prelude::*};
 
 use artemis_core::MessageDispatch;
 
 use codec::{Encode, Decode};
 
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+
+pub mod migration;
+
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
 pub struct Origin(pub H160);
 
@@ -26,6 +33,55 @@ impl From<H160> for Origin {
 	}
 }
 
+/// Derives the parachain account controlled by an Ethereum account by hashing
+/// its address.
+pub struct HashedEthereumAccount<AccountId>(PhantomData<AccountId>);
+
+impl<AccountId: Decode> Convert<H160, AccountId> for HashedEthereumAccount<AccountId> {
+	fn convert(source: H160) -> AccountId {
+		let hash = (b"ethereum", source).using_encoded(sp_io::hashing::blake2_256);
+		AccountId::decode(&mut TrailingZeroInput::new(&hash[..]))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+}
+
+/// A message whose call failed when dispatched, kept so that it can be retried.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+pub struct FailedMessage<BlockNumber> {
+	/// Ethereum account that sent the message.
+	pub source: H160,
+	/// Encoded call.
+	pub payload: Vec<u8>,
+	/// Block at which the message is discarded if it has not been retried successfully.
+	pub expires_at: BlockNumber,
+}
+
+/// Calls that an Ethereum account may dispatch, identified by the pallet and
+/// function names in the runtime's call metadata.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CallPermission {
+	/// Any call of the pallet.
+	Pallet(Vec<u8>),
+	/// A single call of the pallet. [pallet name, function name]
+	Call(Vec<u8>, Vec<u8>),
+}
+
+/// Storage layout versions of this pallet.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+	/// Calls are dispatched from any Ethereum account that passes the call filter.
+	V1,
+	/// Calls are dispatched only from Ethereum accounts with call permissions.
+	V2,
+}
+
+impl Default for Releases {
+	fn default() -> Self {
+		Releases::V1
+	}
+}
+
 pub struct EnsureEthereumAccount;
 
 impl<OuterOrigin> EnsureOrigin<OuterOrigin> for EnsureEthereumAccount
@@ -50,7 +106,7 @@ pub trait Config: system::Config {
 	type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
 
 	/// The overarching origin type.
-	type Origin: From<Origin>;
+	type Origin: From<Origin> + From<frame_system::RawOrigin<Self::AccountId>>;
 
 	/// Id of the message. Whenever message is passed to the dispatch module, it emits
 	/// event with this id + dispatch result.
@@ -59,6 +115,7 @@ pub trait Config: system::Config {
 	/// The overarching dispatch call type.
 	type Call: Parameter
 		+ GetDispatchInfo
+		+ GetCallMetadata
 		+ Dispatchable<
 			Origin = <Self as Config>::Origin,
 			PostInfo = frame_support::dispatch::PostDispatchInfo,
@@ -67,54 +124,257 @@ pub trait Config: system::Config {
 	/// The pallet will filter all incoming calls right before they're dispatched. If this filter
 	/// rejects the call, special event (`Event::MessageRejected`) is emitted.
 	type CallFilter: Filter<<Self as Config>::Call>;
+
+	/// Calls that are dispatched with a signed origin of the parachain account
+	/// controlled by the Ethereum sender, rather than with an Ethereum origin.
+	/// These calls are not subject to `CallFilter`, but still need call permissions.
+	type SignedCallFilter: Filter<<Self as Config>::Call>;
+
+	/// Derives the parachain account controlled by an Ethereum account.
+	type AccountIdConverter: Convert<H160, Self::AccountId>;
+
+	/// Max weight of a dispatched call. Calls declaring a higher weight are
+	/// not dispatched, and a special event (`Event::MessageOverweight`) is emitted.
+	type MaxMessageWeight: Get<Weight>;
+
+	/// Number of blocks for which messages whose call failed are kept for retrying.
+	type FailedMessageRetention: Get<Self::BlockNumber>;
+
+	/// The origin which may discard failed messages and manage call permissions.
+	type UpdateOrigin: EnsureOrigin<<Self as frame_system::Config>::Origin>;
 }
 
 decl_storage! {
-	trait Store for Module<T: Config> as Dispatch {}
+	trait Store for Module<T: Config> as Dispatch {
+		/// Messages whose call failed, by message id.
+		pub FailedMessages get(fn failed_messages): map hasher(blake2_128_concat) T::MessageId => Option<FailedMessage<T::BlockNumber>>;
+
+		/// Ids of failed messages by the block at which they expire.
+		FailedMessageExpiries: map hasher(twox_64_concat) T::BlockNumber => Vec<T::MessageId>;
+
+		/// Calls that each Ethereum account may dispatch.
+		pub Permissions: double_map hasher(twox_64_concat) H160, hasher(blake2_128_concat) CallPermission => bool;
+
+		/// Storage layout version, used to run migrations.
+		pub StorageVersion get(fn storage_version): Releases;
+	}
+	add_extra_genesis {
+		config(permissions): Vec<(H160, CallPermission)>;
+		build(|config: &GenesisConfig| {
+			StorageVersion::put(Releases::V2);
+			for (source, permission) in config.permissions.iter() {
+				Permissions::insert(source, permission, true);
+			}
+		});
+	}
 }
 
 decl_event! {
     /// Events for the Bridge module.
-	pub enum Event<T> where <T as Config>::MessageId {
+	pub enum Event<T>
+	where
+		<T as Config>::MessageId,
+		BlockNumber = <T as frame_system::Config>::BlockNumber,
+	{
 		/// Message has been dispatched with given result.
 		MessageDispatched(MessageId, DispatchResult),
 		/// Message has been rejected
 		MessageRejected(MessageId),
+		/// Message has been rejected because its source may not dispatch its call.
+		/// [message id, source]
+		MessageNotPermitted(MessageId, H160),
+		/// Message has been rejected because its call weight exceeds `MaxMessageWeight`.
+		/// [message id, call weight]
+		MessageOverweight(MessageId, Weight),
 		/// We have failed to decode a Call from the message.
 		MessageDecodeFailed(MessageId),
+		/// The call of a message failed, and the message is kept for retrying.
+		/// [message id, expiry block]
+		FailedMessageStored(MessageId, BlockNumber),
+		/// A failed message was discarded.
+		/// [message id]
+		FailedMessageDiscarded(MessageId),
+		/// A failed message expired without being retried successfully.
+		/// [message id]
+		FailedMessageExpired(MessageId),
+		/// An Ethereum account was permitted to dispatch calls.
+		/// [source, permission]
+		PermissionGranted(H160, CallPermission),
+		/// A permission of an Ethereum account was revoked.
+		/// [source, permission]
+		PermissionRevoked(H160, CallPermission),
+	}
+}
+
+decl_error! {
+	pub enum Error for Module<T: Config> {
+		/// There is no failed message with the given id.
+		UnknownMessage,
+		/// The call of the message can't be decoded or is not allowed.
+		InvalidCall,
+		/// The source of the message may not dispatch its call.
+		NotPermitted,
+		/// The Ethereum account doesn't have the permission.
+		UnknownPermission,
+		/// The weight of the call of the message exceeds `MaxMessageWeight`.
+		Overweight,
 	}
 }
 
 decl_module! {
 	pub struct Module<T: Config> for enum Call where origin: <T as frame_system::Config>::Origin {
+		type Error = Error<T>;
+
 		fn deposit_event() = default;
+
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let expired = FailedMessageExpiries::<T>::take(now);
+			let num_expired = expired.len() as Weight;
+			for id in expired {
+				if FailedMessages::<T>::take(&id).is_some() {
+					Self::deposit_event(RawEvent::FailedMessageExpired(id));
+				}
+			}
+			T::DbWeight::get().reads_writes(1 + num_expired, 1 + num_expired)
+		}
+
+		/// Dispatch the call of a failed message again. The message is removed
+		/// if the call succeeds.
+		#[weight = T::DbWeight::get().reads_writes(1, 1).saturating_add(T::MaxMessageWeight::get())]
+		pub fn retry_message(origin, id: T::MessageId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let message = FailedMessages::<T>::get(&id).ok_or(Error::<T>::UnknownMessage)?;
+
+			let call = <T as Config>::Call::decode(&mut &message.payload[..])
+				.map_err(|_| Error::<T>::InvalidCall)?;
+			let call_origin = Self::call_origin(message.source, &call)?;
+			ensure!(call.get_dispatch_info().weight <= T::MaxMessageWeight::get(), Error::<T>::Overweight);
+
+			let (weight, result) = Self::dispatch_call(call_origin, call);
+			if result.is_ok() {
+				FailedMessages::<T>::remove(&id);
+			}
+			Self::deposit_event(RawEvent::MessageDispatched(id, result));
+
+			Ok(Some(T::DbWeight::get().reads_writes(1, 1).saturating_add(weight)).into())
+		}
+
+		#[weight = T::DbWeight::get().writes(1)]
+		pub fn discard_message(origin, id: T::MessageId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			FailedMessages::<T>::take(&id).ok_or(Error::<T>::UnknownMessage)?;
+			Self::deposit_event(RawEvent::FailedMessageDiscarded(id));
+			Ok(())
+		}
+
+		#[weight = T::DbWeight::get().writes(1)]
+		pub fn grant_permission(origin, source: H160, permission: CallPermission) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Permissions::insert(source, &permission, true);
+			Self::deposit_event(RawEvent::PermissionGranted(source, permission));
+			Ok(())
+		}
+
+		#[weight = T::DbWeight::get().reads_writes(1, 1)]
+		pub fn revoke_permission(origin, source: H160, permission: CallPermission) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if !Permissions::contains_key(source, &permission) {
+				return Err(Error::<T>::UnknownPermission.into())
+			}
+			Permissions::remove(source, &permission);
+			Self::deposit_event(RawEvent::PermissionRevoked(source, permission));
+			Ok(())
+		}
 	}
 }
 
 pub type MessageIdOf<T> = <T as Config>::MessageId;
 
+impl<T: Config> Module<T> {
+	/// Whether the Ethereum account `source` may dispatch `call`.
+	pub fn is_permitted(source: H160, call: &<T as Config>::Call) -> bool {
+		let metadata = call.get_call_metadata();
+		let pallet = metadata.pallet_name.as_bytes().to_vec();
+		let function = metadata.function_name.as_bytes().to_vec();
+		Permissions::get(source, CallPermission::Pallet(pallet.clone()))
+			|| Permissions::get(source, CallPermission::Call(pallet, function))
+	}
+
+	// Origin to dispatch `call` from the Ethereum account `source` with, if
+	// `source` may dispatch it.
+	fn call_origin(source: H160, call: &<T as Config>::Call) -> Result<<T as Config>::Origin, Error<T>> {
+		let signed = T::SignedCallFilter::filter(call);
+		ensure!(signed || T::CallFilter::filter(call), Error::<T>::InvalidCall);
+		ensure!(Self::is_permitted(source, call), Error::<T>::NotPermitted);
+		if signed {
+			let account = T::AccountIdConverter::convert(source);
+			return Ok(frame_system::RawOrigin::Signed(account).into())
+		}
+		Ok(Origin(source).into())
+	}
+
+	// Dispatch `call`, returning the weight it consumed and its result.
+	fn dispatch_call(origin: <T as Config>::Origin, call: <T as Config>::Call) -> (Weight, DispatchResult) {
+		let info = call.get_dispatch_info();
+		let result = call.dispatch(origin);
+		(extract_actual_weight(&result, &info), result.map(drop).map_err(|e| e.error))
+	}
+
+	fn store_failed_message_weight() -> Weight {
+		T::DbWeight::get().reads_writes(1, 2)
+	}
+
+	fn store_failed_message(id: MessageIdOf<T>, source: H160, payload: &[u8]) {
+		let expires_at = <frame_system::Pallet<T>>::block_number().saturating_add(T::FailedMessageRetention::get());
+		FailedMessages::<T>::insert(&id, FailedMessage {
+			source,
+			payload: payload.to_vec(),
+			expires_at,
+		});
+		FailedMessageExpiries::<T>::append(expires_at, id.clone());
+		Self::deposit_event(RawEvent::FailedMessageStored(id, expires_at));
+	}
+}
+
 impl<T: Config> MessageDispatch<T, MessageIdOf<T>> for Module<T> {
-	fn dispatch(source: H160, id: MessageIdOf<T>, payload: &[u8]) {
+	fn dispatch(source: H160, id: MessageIdOf<T>, payload: &[u8]) -> Weight {
 		let call = match <T as Config>::Call::decode(&mut &payload[..]) {
 			Ok(call) => call,
 			Err(_) => {
 				Self::deposit_event(RawEvent::MessageDecodeFailed(id));
-				return;
+				return 0;
 			}
 		};
 
-		if !T::CallFilter::filter(&call) {
-			Self::deposit_event(RawEvent::MessageRejected(id));
-			return;
+		let call_origin = match Self::call_origin(source, &call) {
+			Ok(call_origin) => call_origin,
+			Err(Error::<T>::NotPermitted) => {
+				Self::deposit_event(RawEvent::MessageNotPermitted(id, source));
+				return 0;
+			},
+			Err(_) => {
+				Self::deposit_event(RawEvent::MessageRejected(id));
+				return 0;
+			},
+		};
+
+		let info = call.get_dispatch_info();
+		if info.weight > T::MaxMessageWeight::get() {
+			Self::deposit_event(RawEvent::MessageOverweight(id, info.weight));
+			return 0;
 		}
 
-		let origin = Origin(source).into();
-		let result = call.dispatch(origin);
+		let (weight, result) = Self::dispatch_call(call_origin, call);
+		Self::deposit_event(RawEvent::MessageDispatched(id.clone(), result));
+		if result.is_err() {
+			Self::store_failed_message(id, source, payload);
+			return weight.saturating_add(Self::store_failed_message_weight());
+		}
+		weight
+	}
 
-		Self::deposit_event(RawEvent::MessageDispatched(
-			id,
-			result.map(drop).map_err(|e| e.error),
-		));
+	fn max_dispatch_weight() -> Weight {
+		T::MaxMessageWeight::get().saturating_add(Self::store_failed_message_weight())
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -128,18 +388,56 @@ impl<T: Config> MessageDispatch<T, MessageIdOf<T>> for Module<T> {
 mod tests {
 	use super::*;
 	use frame_support::{
+		assert_ok, assert_noop,
 		parameter_types,
 		dispatch::DispatchError,
+		traits::{OnInitialize, OnRuntimeUpgrade},
 	};
-	use frame_system::{EventRecord, Phase};
+	use frame_system::{EventRecord, EnsureRoot, Phase};
 	use sp_core::H256;
 	use sp_runtime::{
 		testing::Header,
 		traits::{BlakeTwo256, IdentityLookup},
+		Perbill,
 	};
 
 	use crate as dispatch;
 
+	// An app whose only call fails until it is enabled.
+	mod mock_app {
+		use frame_support::{decl_module, decl_storage, dispatch::{DispatchError, DispatchResult}};
+		use frame_system::ensure_signed;
+
+		pub trait Config: frame_system::Config {}
+
+		decl_storage! {
+			trait Store for Module<T: Config> as MockApp {
+				pub Enabled: bool;
+				pub LastSigner: Option<T::AccountId>;
+			}
+		}
+
+		decl_module! {
+			pub struct Module<T: Config> for enum Call where origin: T::Origin {
+				#[weight = 0]
+				pub fn ping(_origin) -> DispatchResult {
+					if Enabled::get() {
+						Ok(())
+					} else {
+						Err(DispatchError::Other("disabled"))
+					}
+				}
+
+				#[weight = 0]
+				pub fn sign(origin) -> DispatchResult {
+					let who = ensure_signed(origin)?;
+					LastSigner::<T>::put(who);
+					Ok(())
+				}
+			}
+		}
+	}
+
 	type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 	type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -150,7 +448,8 @@ mod tests {
 			UncheckedExtrinsic = UncheckedExtrinsic,
 		{
 			System: frame_system::{Pallet, Call, Storage, Event<T>},
-			Dispatch: dispatch::{Pallet, Call, Storage, Origin, Event<T>},
+			Dispatch: dispatch::{Pallet, Call, Storage, Config, Origin, Event<T>},
+			MockApp: mock_app::{Pallet, Call, Storage},
 		}
 	);
 
@@ -158,6 +457,11 @@ mod tests {
 
 	parameter_types! {
 		pub const BlockHashCount: u64 = 250;
+		pub const MaxMessageWeight: Weight = 1_000_000_000;
+		pub const FailedMessageRetention: u64 = 10;
+		pub MigratedPermissions: Vec<(H160, CallPermission)> = vec![
+			(H160::repeat_byte(8), CallPermission::Pallet(b"MockApp".to_vec())),
+		];
 	}
 
 	impl frame_system::Config for Test {
@@ -191,6 +495,18 @@ mod tests {
 		fn filter(call: &Call) -> bool {
 			match call {
 				Call::System(frame_system::pallet::Call::<Test>::remark(_)) => true,
+				Call::System(frame_system::pallet::Call::<Test>::fill_block(_)) => true,
+				Call::MockApp(_) => true,
+				_ => false
+			}
+		}
+	}
+
+	pub struct SignedCallFilter;
+	impl Filter<Call> for SignedCallFilter {
+		fn filter(call: &Call) -> bool {
+			match call {
+				Call::MockApp(mock_app::Call::<Test>::sign()) => true,
 				_ => false
 			}
 		}
@@ -202,12 +518,25 @@ mod tests {
 		type MessageId = u64;
 		type Call = Call;
 		type CallFilter = CallFilter;
+		type SignedCallFilter = SignedCallFilter;
+		type AccountIdConverter = HashedEthereumAccount<AccountId>;
+		type MaxMessageWeight = MaxMessageWeight;
+		type FailedMessageRetention = FailedMessageRetention;
+		type UpdateOrigin = EnsureRoot<AccountId>;
 	}
 
+	impl mock_app::Config for Test {}
+
 	fn new_test_ext() -> sp_io::TestExternalities {
-		let t = frame_system::GenesisConfig::default()
+		let mut t = frame_system::GenesisConfig::default()
 			.build_storage::<Test>()
 			.unwrap();
+		dispatch::GenesisConfig {
+			permissions: vec![
+				(H160::repeat_byte(7), CallPermission::Pallet(b"System".to_vec())),
+				(H160::repeat_byte(7), CallPermission::Pallet(b"MockApp".to_vec())),
+			],
+		}.assimilate_storage::<Test>(&mut t).unwrap();
 		sp_io::TestExternalities::new(t)
 	}
 
@@ -217,18 +546,27 @@ mod tests {
 			let id = 37;
 			let source = H160::repeat_byte(7);
 
-			let message = Call::System(<frame_system::Call<Test>>::remark(vec![])).encode();
+			let call = Call::System(<frame_system::Call<Test>>::remark(vec![]));
+			let message = call.encode();
 
 			System::set_block_number(1);
-			Dispatch::dispatch(source, id, &message);
+			let weight = Dispatch::dispatch(source, id, &message);
+			assert_eq!(weight, call.get_dispatch_info().weight);
 
 			assert_eq!(
 				System::events(),
-				vec![EventRecord {
-					phase: Phase::Initialization,
-					event: Event::dispatch(crate::Event::<Test>::MessageDispatched(id, Err(DispatchError::BadOrigin))),
-					topics: vec![],
-				}],
+				vec![
+					EventRecord {
+						phase: Phase::Initialization,
+						event: Event::dispatch(crate::Event::<Test>::MessageDispatched(id, Err(DispatchError::BadOrigin))),
+						topics: vec![],
+					},
+					EventRecord {
+						phase: Phase::Initialization,
+						event: Event::dispatch(crate::Event::<Test>::FailedMessageStored(id, 11)),
+						topics: vec![],
+					},
+				],
 			);
 		})
 	}
@@ -277,5 +615,176 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn test_message_overweight() {
+		new_test_ext().execute_with(|| {
+			let id = 37;
+			let source = H160::repeat_byte(7);
+
+			let call = Call::System(<frame_system::Call<Test>>::fill_block(Perbill::from_percent(100)));
+			let call_weight = call.get_dispatch_info().weight;
+			assert!(call_weight > MaxMessageWeight::get());
+
+			System::set_block_number(1);
+			assert_eq!(Dispatch::dispatch(source, id, &call.encode()), 0);
+
+			assert_eq!(
+				System::events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: Event::dispatch(crate::Event::<Test>::MessageOverweight(id, call_weight)),
+					topics: vec![],
+				}],
+			);
+		})
+	}
 
+	fn last_event() -> Event {
+		System::events().pop().expect("Event expected").event
+	}
+
+	#[test]
+	fn test_retry_failed_message() {
+		new_test_ext().execute_with(|| {
+			let id = 37;
+			let source = H160::repeat_byte(7);
+			let message = Call::MockApp(mock_app::Call::<Test>::ping()).encode();
+
+			System::set_block_number(1);
+			Dispatch::dispatch(source, id, &message);
+			assert_eq!(
+				Dispatch::failed_messages(id),
+				Some(FailedMessage { source, payload: message, expires_at: 11 }),
+			);
+
+			// The message is kept while its call keeps failing
+			assert_ok!(Dispatch::retry_message(Origin::signed(1), id));
+			assert!(FailedMessages::<Test>::contains_key(id));
+			assert_eq!(
+				last_event(),
+				Event::dispatch(crate::Event::<Test>::MessageDispatched(id, Err(DispatchError::Other("disabled")))),
+			);
+
+			mock_app::Enabled::put(true);
+			assert_ok!(Dispatch::retry_message(Origin::signed(1), id));
+			assert!(!FailedMessages::<Test>::contains_key(id));
+			assert_eq!(last_event(), Event::dispatch(crate::Event::<Test>::MessageDispatched(id, Ok(()))));
+
+			assert_noop!(Dispatch::retry_message(Origin::signed(1), id), Error::<Test>::UnknownMessage);
+		})
+	}
+
+	#[test]
+	fn test_discard_failed_message() {
+		new_test_ext().execute_with(|| {
+			let id = 37;
+			let message = Call::MockApp(mock_app::Call::<Test>::ping()).encode();
+
+			System::set_block_number(1);
+			Dispatch::dispatch(H160::repeat_byte(7), id, &message);
+
+			assert_noop!(Dispatch::discard_message(Origin::signed(1), id), DispatchError::BadOrigin);
+			assert_ok!(Dispatch::discard_message(Origin::root(), id));
+			assert!(!FailedMessages::<Test>::contains_key(id));
+			assert_eq!(last_event(), Event::dispatch(crate::Event::<Test>::FailedMessageDiscarded(id)));
+		})
+	}
+
+	#[test]
+	fn test_failed_message_expires() {
+		new_test_ext().execute_with(|| {
+			let id = 37;
+			let message = Call::MockApp(mock_app::Call::<Test>::ping()).encode();
+
+			System::set_block_number(1);
+			Dispatch::dispatch(H160::repeat_byte(7), id, &message);
+
+			Dispatch::on_initialize(10);
+			assert!(FailedMessages::<Test>::contains_key(id));
+
+			Dispatch::on_initialize(11);
+			assert!(!FailedMessages::<Test>::contains_key(id));
+			assert_eq!(last_event(), Event::dispatch(crate::Event::<Test>::FailedMessageExpired(id)));
+		})
+	}
+
+	#[test]
+	fn test_message_not_permitted() {
+		new_test_ext().execute_with(|| {
+			let id = 37;
+			let source = H160::repeat_byte(8);
+			let message = Call::MockApp(mock_app::Call::<Test>::ping()).encode();
+
+			System::set_block_number(1);
+			Dispatch::dispatch(source, id, &message);
+			assert_eq!(last_event(), Event::dispatch(crate::Event::<Test>::MessageNotPermitted(id, source)));
+			assert!(!FailedMessages::<Test>::contains_key(id));
+
+			// Permissions can be granted for single calls
+			let permission = CallPermission::Call(b"MockApp".to_vec(), b"ping".to_vec());
+			assert_noop!(
+				Dispatch::grant_permission(Origin::signed(1), source, permission.clone()),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Dispatch::grant_permission(Origin::root(), source, permission.clone()));
+
+			Dispatch::dispatch(source, id, &message);
+			assert_eq!(last_event(), Event::dispatch(crate::Event::<Test>::FailedMessageStored(id, 11)));
+
+			assert_ok!(Dispatch::revoke_permission(Origin::root(), source, permission.clone()));
+			assert_noop!(Dispatch::retry_message(Origin::signed(1), id), Error::<Test>::NotPermitted);
+			assert_noop!(
+				Dispatch::revoke_permission(Origin::root(), source, permission),
+				Error::<Test>::UnknownPermission
+			);
+		})
+	}
+
+	#[test]
+	fn test_grant_call_permissions_migration() {
+		new_test_ext().execute_with(|| {
+			let source = H160::repeat_byte(8);
+			let permission = CallPermission::Pallet(b"MockApp".to_vec());
+
+			// Chains built from a genesis config already have permissions
+			crate::migration::GrantCallPermissions::<Test, MigratedPermissions>::on_runtime_upgrade();
+			assert!(!Permissions::contains_key(source, &permission));
+
+			StorageVersion::kill();
+			crate::migration::GrantCallPermissions::<Test, MigratedPermissions>::on_runtime_upgrade();
+			assert!(Permissions::get(source, &permission));
+			assert_eq!(StorageVersion::get(), Releases::V2);
+
+			// Revoked permissions are not granted again, even once none are left
+			Permissions::remove(source, &permission);
+			Permissions::remove(H160::repeat_byte(7), CallPermission::Pallet(b"System".to_vec()));
+			Permissions::remove(H160::repeat_byte(7), CallPermission::Pallet(b"MockApp".to_vec()));
+			crate::migration::GrantCallPermissions::<Test, MigratedPermissions>::on_runtime_upgrade();
+			assert!(!Permissions::contains_key(source, &permission));
+		})
+	}
+
+	#[test]
+	fn test_dispatch_signed_by_derived_account() {
+		new_test_ext().execute_with(|| {
+			let id = 37;
+			let source = H160::repeat_byte(8);
+			let message = Call::MockApp(mock_app::Call::<Test>::sign()).encode();
+
+			// Signed calls need permissions like any other call
+			System::set_block_number(1);
+			Dispatch::dispatch(source, id, &message);
+			assert_eq!(last_event(), Event::dispatch(crate::Event::<Test>::MessageNotPermitted(id, source)));
+			assert_eq!(mock_app::LastSigner::<Test>::get(), None);
+
+			let permission = CallPermission::Call(b"MockApp".to_vec(), b"sign".to_vec());
+			assert_ok!(Dispatch::grant_permission(Origin::root(), source, permission));
+			Dispatch::dispatch(source, id, &message);
+			assert_eq!(last_event(), Event::dispatch(crate::Event::<Test>::MessageDispatched(id, Ok(()))));
+
+			let account = HashedEthereumAccount::<AccountId>::convert(source);
+			assert_eq!(mock_app::LastSigner::<Test>::get(), Some(account));
+			assert_ne!(account, HashedEthereumAccount::<AccountId>::convert(H160::repeat_byte(7)));
+		})
+	}
 }
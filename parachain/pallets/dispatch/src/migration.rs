@@ -0,0 +1,36 @@
+//! Storage migrations for the dispatch pallet.
+
+use frame_support::{
+	storage::StorageValue,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_core::H160;
+use sp_std::{marker::PhantomData, prelude::*};
+
+use crate::{CallPermission, Config, Permissions, Releases, StorageVersion};
+
+/// Grants the call permissions `P` on chains that dispatched calls from any
+/// Ethereum account before permissions were introduced.
+///
+/// `P` should list the Ethereum apps that were allowed by the runtime's call
+/// filter. The migration runs once, moving `StorageVersion` from `V1` to `V2`,
+/// so permissions revoked afterwards are not granted again and it is safe to
+/// keep it in the runtime after it has run.
+pub struct GrantCallPermissions<T, P>(PhantomData<(T, P)>);
+
+impl<T: Config, P: Get<Vec<(H160, CallPermission)>>> OnRuntimeUpgrade for GrantCallPermissions<T, P> {
+	fn on_runtime_upgrade() -> Weight {
+		if StorageVersion::get() == Releases::V2 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let permissions = P::get();
+		let num_permissions = permissions.len() as Weight;
+		for (source, permission) in permissions {
+			Permissions::insert(source, permission, true);
+		}
+		StorageVersion::put(Releases::V2);
+		T::DbWeight::get().reads_writes(1, 1 + num_permissions)
+	}
+}
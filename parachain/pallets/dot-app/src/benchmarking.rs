@@ -31,7 +31,7 @@ benchmarks! {
 		T::Currency::make_free_balance_be(&caller, balance);
 		T::Currency::make_free_balance_be(&lock_account, 0u32.into());
 
-	}: _(RawOrigin::Signed(caller.clone()), ChannelId::Incentivized, recipient, amount)
+	}: _(RawOrigin::Signed(caller.clone()), ChannelId::INCENTIVIZED, recipient, amount, None)
 	verify {
 		assert!(!balance.is_zero() && !amount.is_zero());
 		assert_eq!(T::Currency::free_balance(&caller), Zero::zero());
@@ -55,7 +55,7 @@ benchmarks! {
 		T::Currency::make_free_balance_be(&caller, balance);
 		T::Currency::make_free_balance_be(&lock_account, 0u32.into());
 
-	}: lock(RawOrigin::Signed(caller.clone()), ChannelId::Incentivized, recipient, amount)
+	}: lock(RawOrigin::Signed(caller.clone()), ChannelId::INCENTIVIZED, recipient, amount, None)
 	verify {
 		assert!(!balance.is_zero() && !amount.is_zero());
 		assert_eq!(T::Currency::free_balance(&caller), balance - amount);
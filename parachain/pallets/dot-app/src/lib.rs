@@ -137,7 +137,7 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		#[pallet::weight(T::WeightInfo::lock())]
 		#[transactional]
-		pub fn lock(origin: OriginFor<T>, channel_id: ChannelId, recipient: H160, amount: BalanceOf<T>) -> DispatchResult {
+		pub fn lock(origin: OriginFor<T>, channel_id: ChannelId, recipient: H160, amount: BalanceOf<T>, max_fee: Option<U256>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			T::Currency::transfer(&who, &Self::account_id(), amount, AllowDeath)?;
@@ -150,7 +150,7 @@ pub mod pallet {
 				amount: amount_wrapped,
 			};
 
-			T::OutboundRouter::submit(channel_id, &who, <Address<T>>::get(), &message.encode())?;
+			T::OutboundRouter::submit(channel_id, &who, <Address<T>>::get(), &message.encode(), max_fee)?;
 			Self::deposit_event(Event::Locked(who.clone(), recipient, amount));
 			Ok(())
 		}
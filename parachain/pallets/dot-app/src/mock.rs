@@ -6,7 +6,7 @@ use frame_support::{
     parameter_types,
 };
 use frame_system as system;
-use sp_core::{H160, H256};
+use sp_core::{H160, H256, U256};
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
@@ -81,8 +81,8 @@ impl artemis_dispatch::Config for Test {
 pub struct MockOutboundRouter<AccountId>(PhantomData<AccountId>);
 
 impl<AccountId> OutboundRouter<AccountId> for MockOutboundRouter<AccountId> {
-    fn submit(channel: ChannelId, _: &AccountId, _: H160, _: &[u8]) -> DispatchResult {
-        if channel == ChannelId::Basic {
+    fn submit(channel: ChannelId, _: &AccountId, _: H160, _: &[u8], _: Option<U256>) -> DispatchResult {
+        if channel == ChannelId::BASIC {
             return Err(DispatchError::Other("some error!"));
         }
 		Ok(())
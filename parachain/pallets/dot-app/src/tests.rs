@@ -26,9 +26,10 @@ fn should_lock() {
 
 		assert_ok!(DOTApp::lock(
 			Origin::signed(sender.clone()),
-			ChannelId::Incentivized,
+			ChannelId::INCENTIVIZED,
 			recipient.clone(),
-			amount));
+			amount,
+			None));
 
 		assert_eq!(Balances::total_balance(&DOTApp::account_id()), amount);
 
@@ -115,9 +116,10 @@ fn should_not_lock_on_add_commitment_failure() {
 		assert_noop!(
 			DOTApp::lock(
 				Origin::signed(sender.clone()),
-				ChannelId::Basic,
+				ChannelId::BASIC,
 				recipient.clone(),
-				amount.into()
+				amount.into(),
+				None
 			),
 			DispatchError::Other("some error!")
 		);
@@ -24,7 +24,7 @@ benchmarks! {
 
 		T::Assets::deposit(AssetId::Token(token), &caller, amount)?;
 
-	}: _(RawOrigin::Signed(caller.clone()), ChannelId::Incentivized, token, recipient, amount)
+	}: _(RawOrigin::Signed(caller.clone()), ChannelId::INCENTIVIZED, token, recipient, amount, None)
 	verify {
 		assert_eq!(T::Assets::balance(AssetId::Token(token), &caller), U256::zero());
 	}
@@ -100,7 +100,7 @@ decl_module! {
 		/// Burn an ERC20 token balance
 		#[weight = T::WeightInfo::burn()]
 		#[transactional]
-		pub fn burn(origin, channel_id: ChannelId, token: H160, recipient: H160, amount: U256) -> DispatchResult {
+		pub fn burn(origin, channel_id: ChannelId, token: H160, recipient: H160, amount: U256, max_fee: Option<U256>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			T::Assets::withdraw(AssetId::Token(token), &who, amount)?;
@@ -112,7 +112,7 @@ decl_module! {
 				amount: amount
 			};
 
-			T::OutboundRouter::submit(channel_id, &who, Address::get(), &message.encode())?;
+			T::OutboundRouter::submit(channel_id, &who, Address::get(), &message.encode(), max_fee)?;
 			Self::deposit_event(RawEvent::Burned(token, who.clone(), recipient, amount));
 
 			Ok(())
@@ -1,7 +1,7 @@
 // Mock runtime
 use sp_std::marker::PhantomData;
 
-use sp_core::{H160, H256};
+use sp_core::{H160, H256, U256};
 use frame_support::{
 	parameter_types,
 	dispatch::{DispatchResult, DispatchError},
@@ -83,8 +83,8 @@ impl artemis_dispatch::Config for Test {
 pub struct MockOutboundRouter<AccountId>(PhantomData<AccountId>);
 
 impl<AccountId> OutboundRouter<AccountId> for MockOutboundRouter<AccountId> {
-	fn submit(channel: ChannelId, _: &AccountId, _: H160, _: &[u8]) -> DispatchResult {
-        if channel == ChannelId::Basic {
+	fn submit(channel: ChannelId, _: &AccountId, _: H160, _: &[u8], _: Option<U256>) -> DispatchResult {
+        if channel == ChannelId::BASIC {
             return Err(DispatchError::Other("some error!"));
         }
 		Ok(())
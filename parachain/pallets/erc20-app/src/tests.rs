@@ -46,10 +46,11 @@ fn burn_should_emit_bridge_event() {
 
 		assert_ok!(ERC20App::burn(
 			Origin::signed(bob.clone()),
-			ChannelId::Incentivized,
+			ChannelId::INCENTIVIZED,
 			token_id,
 			recipient.clone(),
-			20.into()));
+			20.into(),
+			None));
 
 		assert_eq!(
 			Event::erc20_app(RawEvent::Burned(token_id, bob, recipient, 20.into())),
@@ -70,10 +71,11 @@ fn should_not_burn_on_commitment_failure() {
 		assert_noop!(
 			ERC20App::burn(
 				Origin::signed(sender.clone()),
-				ChannelId::Basic,
+				ChannelId::BASIC,
 				token_id,
 				recipient.clone(),
-				20.into()
+				20.into(),
+				None
 			),
 			DispatchError::Other("some error!")
 		);
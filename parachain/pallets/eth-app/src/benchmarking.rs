@@ -23,7 +23,7 @@ benchmarks! {
 
 		T::Asset::deposit(&caller, amount)?;
 
-	}: _(RawOrigin::Signed(caller.clone()), ChannelId::Incentivized, recipient, amount)
+	}: _(RawOrigin::Signed(caller.clone()), ChannelId::INCENTIVIZED, recipient, amount, None)
 	verify {
 		assert_eq!(T::Asset::balance(&caller), U256::zero());
 	}
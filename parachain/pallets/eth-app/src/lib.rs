@@ -99,7 +99,7 @@ decl_module! {
 		// Users should burn their holdings to release funds on the Ethereum side
 		#[weight = T::WeightInfo::burn()]
 		#[transactional]
-		pub fn burn(origin, channel_id: ChannelId, recipient: H160, amount: U256) -> DispatchResult {
+		pub fn burn(origin, channel_id: ChannelId, recipient: H160, amount: U256, max_fee: Option<U256>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			T::Asset::withdraw(&who, amount)?;
@@ -110,7 +110,7 @@ decl_module! {
 				amount: amount
 			};
 
-			T::OutboundRouter::submit(channel_id, &who, Address::get(), &message.encode())?;
+			T::OutboundRouter::submit(channel_id, &who, Address::get(), &message.encode(), max_fee)?;
 			Self::deposit_event(RawEvent::Burned(who.clone(), recipient, amount));
 
 			Ok(())
@@ -43,9 +43,10 @@ fn burn_should_emit_bridge_event() {
 
 		assert_ok!(ETHApp::burn(
 			Origin::signed(bob.clone()),
-			ChannelId::Incentivized,
+			ChannelId::INCENTIVIZED,
 			recipient.clone(),
-			20.into()));
+			20.into(),
+			None));
 
 		assert_eq!(
 			Event::eth_app(RawEvent::Burned(bob, recipient, 20.into())),
@@ -65,9 +66,10 @@ fn should_not_burn_on_commitment_failure() {
 		assert_noop!(
 			ETHApp::burn(
 				Origin::signed(sender.clone()),
-				ChannelId::Basic,
+				ChannelId::BASIC,
 				recipient.clone(),
-				20.into()
+				20.into(),
+				None
 			),
 			DispatchError::Other("some error!")
 		);
@@ -0,0 +1,153 @@
+//! RPC interface for the incentivized outbound channel.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{offchain::OffchainStorage, H256, U256};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+use artemis_channel_rpc::runtime_error;
+
+pub use artemis_incentivized_channel_runtime_api::{
+	IncentivizedOutboundChannelApi as IncentivizedOutboundChannelRuntimeApi, MerkleProof, Message, MessageNonce, MessageStatus,
+};
+
+#[rpc]
+pub trait IncentivizedOutboundChannelApi<BlockHash> {
+	/// Get the messages committed in the commitment with `commitment_hash`.
+	///
+	/// Commitments are read from the offchain database, so the node must run
+	/// with offchain indexing enabled. Returns `None` for unknown commitments.
+	#[rpc(name = "incentivizedOutboundChannel_getCommitment")]
+	fn get_commitment(
+		&self,
+		commitment_hash: H256,
+		at: Option<BlockHash>,
+	) -> Result<Option<Vec<Message>>>;
+
+	/// Get a proof that the message with `nonce` is included in the Merkle
+	/// commitment with `commitment_hash`, using the runtime at block `at`
+	/// (defaults to the best block).
+	#[rpc(name = "incentivizedOutboundChannel_getMerkleProof")]
+	fn get_merkle_proof(
+		&self,
+		commitment_hash: H256,
+		nonce: MessageNonce,
+		at: Option<BlockHash>,
+	) -> Result<Option<MerkleProof>>;
+
+	/// Get the fee currently charged for submitting a message with a payload
+	/// of `payload_len` bytes, at block `at` (defaults to the best block).
+	#[rpc(name = "incentivizedOutboundChannel_quoteFee")]
+	fn quote_fee(
+		&self,
+		payload_len: u32,
+		at: Option<BlockHash>,
+	) -> Result<U256>;
+
+	/// Get the progress of the message with `nonce`, from being queued to
+	/// having its delivery acknowledged by Ethereum, at block `at` (defaults
+	/// to the best block).
+	#[rpc(name = "incentivizedOutboundChannel_getMessageStatus")]
+	fn get_message_status(
+		&self,
+		nonce: MessageNonce,
+		at: Option<BlockHash>,
+	) -> Result<MessageStatus>;
+}
+
+/// Implements the `IncentivizedOutboundChannelApi` RPC trait by reading commitments
+/// from offchain storage.
+pub struct IncentivizedOutboundChannel<C, B, S> {
+	client: Arc<C>,
+	storage: S,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B, S> IncentivizedOutboundChannel<C, B, S> {
+	pub fn new(client: Arc<C>, storage: S) -> Self {
+		Self {
+			client,
+			storage,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, S> IncentivizedOutboundChannel<C, Block, S>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: IncentivizedOutboundChannelRuntimeApi<Block>,
+	S: OffchainStorage,
+{
+	fn read_commitment(
+		&self,
+		commitment_hash: H256,
+		at: &BlockId<Block>,
+	) -> Result<Option<Vec<Message>>> {
+		let key = self.client.runtime_api()
+			.commitment_key(at, commitment_hash)
+			.map_err(|err| runtime_error("Unable to get commitment key.", err))?;
+		artemis_channel_rpc::read_commitment(&self.storage, &key)
+	}
+}
+
+impl<C, Block, S> IncentivizedOutboundChannelApi<<Block as BlockT>::Hash> for IncentivizedOutboundChannel<C, Block, S>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: IncentivizedOutboundChannelRuntimeApi<Block>,
+	S: OffchainStorage + 'static,
+{
+	fn get_commitment(
+		&self,
+		commitment_hash: H256,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<Vec<Message>>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.read_commitment(commitment_hash, &at)
+	}
+
+	fn get_merkle_proof(
+		&self,
+		commitment_hash: H256,
+		nonce: MessageNonce,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<MerkleProof>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let messages = match self.read_commitment(commitment_hash, &at)? {
+			Some(messages) => messages,
+			None => return Ok(None),
+		};
+
+		self.client.runtime_api()
+			.message_proof(&at, messages, nonce)
+			.map_err(|err| runtime_error("Unable to build message proof.", err))
+	}
+
+	fn quote_fee(
+		&self,
+		payload_len: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<U256> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client.runtime_api()
+			.quote_fee(&at, payload_len)
+			.map_err(|err| runtime_error("Unable to quote fee.", err))
+	}
+
+	fn get_message_status(
+		&self,
+		nonce: MessageNonce,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<MessageStatus> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client.runtime_api()
+			.message_status(&at, nonce)
+			.map_err(|err| runtime_error("Unable to get message status.", err))
+	}
+}
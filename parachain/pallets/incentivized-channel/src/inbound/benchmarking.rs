@@ -39,8 +39,8 @@ benchmarks! {
 		let envelope: envelope::Envelope<T> = rlp::decode::<Log>(&message.data)
 			.map(|log| log.try_into().unwrap())
 			.unwrap();
-		Nonce::put(envelope.nonce - 1);
-		SourceChannel::put(envelope.channel);
+		Nonce::insert(envelope.channel, envelope.nonce - 1);
+		SourceChannels::<T>::insert(envelope.channel, SourceChannelInfo::default());
 
 		T::Verifier::initialize_storage(
 			vec![header],
@@ -50,9 +50,9 @@ benchmarks! {
 
 	}: _(RawOrigin::Signed(caller.clone()), message)
 	verify {
-		assert_eq!(envelope.nonce, Nonce::get());
+		assert_eq!(envelope.nonce, Nonce::get(envelope.channel));
 
-		let message_id = MessageId::new(ChannelId::Incentivized, envelope.nonce);
+		let message_id = MessageId::new(ChannelId::INCENTIVIZED, envelope.nonce);
 		if let Some(event) = T::MessageDispatch::successful_dispatch_event(message_id) {
 			assert_last_event::<T>(event);
 		}
@@ -75,6 +75,90 @@ benchmarks! {
 		assert_eq!(RewardFraction::get(), fraction);
 	}
 
+	// Benchmark `claim_rewards` under worst case conditions:
+	// * The source account pays all unclaimed rewards
+	// * The relayer account is created
+	claim_rewards {
+		let caller: T::AccountId = whitelisted_caller();
+		let reward = T::Currency::minimum_balance() * 10u32.into();
+		RelayerRewards::<T>::insert(&caller, reward);
+		TotalRewards::<T>::put(reward);
+		T::Currency::make_free_balance_be(&T::SourceAccount::get(), reward * 2u32.into());
+
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(T::Currency::free_balance(&caller), reward);
+		assert!(!RelayerRewards::<T>::contains_key(&caller));
+	}
+
+	// Benchmark `submit_batch` extrinsic with `m` messages, each of which
+	// is dispatched in order.
+	submit_batch {
+		let m in 1 .. T::MaxMessagesPerBatch::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		let (header, messages) = batch_data();
+		let messages: Vec<Message> = messages.into_iter().take(m as usize).collect();
+		let envelope: envelope::Envelope<T> = rlp::decode::<Log>(&messages[0].data)
+			.map(|log| log.try_into().unwrap())
+			.unwrap();
+		let channel = envelope.channel;
+		SourceChannels::<T>::insert(channel, SourceChannelInfo::default());
+
+		T::Verifier::initialize_storage(
+			vec![header],
+			0.into(),
+			0, // forces all headers to be finalized
+		)?;
+
+	}: _(RawOrigin::Signed(caller.clone()), messages)
+	verify {
+		assert_eq!(m as u64, Nonce::get(channel));
+		assert_last_event::<T>(<T as Config>::Event::from(RawEvent::BatchMessageAccepted(m - 1, m as u64)).into());
+	}
+
+	// Benchmark `register_source_channel` under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	register_source_channel {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let channel = H160::repeat_byte(1);
+
+	}: _(authorized_origin, channel)
+	verify {
+		assert!(SourceChannels::<T>::contains_key(channel));
+	}
+
+	// Benchmark `set_source_channel_sunset` under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	set_source_channel_sunset {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let channel = H160::repeat_byte(1);
+		let sunset: T::BlockNumber = 10u32.into();
+		SourceChannels::<T>::insert(channel, SourceChannelInfo::default());
+
+	}: _(authorized_origin, channel, Some(sunset))
+	verify {
+		assert_eq!(SourceChannels::<T>::get(channel).unwrap().sunset, Some(sunset));
+	}
+
+	set_receipt_channel {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let channel = H160::repeat_byte(1);
+
+	}: _(authorized_origin, Some(channel))
+	verify {
+		assert_eq!(ReceiptChannel::get(), Some(channel));
+	}
+
 	#[extra]
 	submit_eth_mint {
 		let caller: T::AccountId = whitelisted_caller();
@@ -82,8 +166,8 @@ benchmarks! {
 		let envelope: envelope::Envelope<T> = rlp::decode::<Log>(&message.data)
 			.map(|log| log.try_into().unwrap())
 			.unwrap();
-		Nonce::put(envelope.nonce - 1);
-		SourceChannel::put(envelope.channel);
+		Nonce::insert(envelope.channel, envelope.nonce - 1);
+		SourceChannels::<T>::insert(envelope.channel, SourceChannelInfo::default());
 
 		T::Verifier::initialize_storage(
 			vec![header],
@@ -93,9 +177,9 @@ benchmarks! {
 
 	}: submit(RawOrigin::Signed(caller.clone()), message)
 	verify {
-		assert_eq!(envelope.nonce, Nonce::get());
+		assert_eq!(envelope.nonce, Nonce::get(envelope.channel));
 
-		let message_id = MessageId::new(ChannelId::Incentivized, envelope.nonce);
+		let message_id = MessageId::new(ChannelId::INCENTIVIZED, envelope.nonce);
 		if let Some(event) = T::MessageDispatch::successful_dispatch_event(message_id) {
 			assert_last_event::<T>(event);
 		}
@@ -108,8 +192,8 @@ benchmarks! {
 		let envelope: envelope::Envelope<T> = rlp::decode::<Log>(&message.data)
 			.map(|log| log.try_into().unwrap())
 			.unwrap();
-		Nonce::put(envelope.nonce - 1);
-		SourceChannel::put(envelope.channel);
+		Nonce::insert(envelope.channel, envelope.nonce - 1);
+		SourceChannels::<T>::insert(envelope.channel, SourceChannelInfo::default());
 
 		T::Verifier::initialize_storage(
 			vec![header],
@@ -119,9 +203,9 @@ benchmarks! {
 
 	}: submit(RawOrigin::Signed(caller.clone()), message)
 	verify {
-		assert_eq!(envelope.nonce, Nonce::get());
+		assert_eq!(envelope.nonce, Nonce::get(envelope.channel));
 
-		let message_id = MessageId::new(ChannelId::Incentivized, envelope.nonce);
+		let message_id = MessageId::new(ChannelId::INCENTIVIZED, envelope.nonce);
 		if let Some(event) = T::MessageDispatch::successful_dispatch_event(message_id) {
 			assert_last_event::<T>(event);
 		}
@@ -153,6 +237,7 @@ fn eth_mint_data() -> (Header, Message) {
 				hex!("a00000000000000000000000000000000000000000000000000000000000000000").to_vec(),
 				hex!("880000000000000000").to_vec(),
 			],
+			base_fee_per_gas: None,
 		},
 		Message {
 			data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a2300000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
@@ -193,6 +278,7 @@ fn erc20_mint_data() -> (Header, Message) {
 				hex!("a00000000000000000000000000000000000000000000000000000000000000000").to_vec(),
 				hex!("880000000000000000").to_vec(),
 			],
+			base_fee_per_gas: None,
 		},
 		Message {
 			data: hex!("f9015a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b9012000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
@@ -233,6 +319,7 @@ fn dot_unlock_data() -> (Header, Message) {
 				hex!("a00000000000000000000000000000000000000000000000000000000000000000").to_vec(),
 				hex!("880000000000000000").to_vec(),
 			],
+			base_fee_per_gas: None,
 		},
 		Message {
 			data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e830500000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
@@ -248,6 +335,338 @@ fn dot_unlock_data() -> (Header, Message) {
 	)
 }
 
+// Messages with consecutive nonces from the same source channel, all included
+// in one block. The block and its receipts are synthetic: each receipt copies
+// one of the receipts above with the nonce of its channel log replaced.
+fn batch_data() -> (Header, Vec<Message>) {
+	(
+		Header {
+			parent_hash: hex!("1eb95c998f6af053b87e8955d7cecada585b0304dfb5d7283947a5674e6e42f1").into(),
+			timestamp: 1619679100u64.into(),
+			number: 100u64.into(),
+			author: hex!("0000000000000000000000000000000000000000").into(),
+			transactions_root: hex!("8f407c85c74ab696364e8ff77bbdcffeb52a118c1a50dd43d848c6e37407d9e5").into(),
+			ommers_hash: hex!("1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347").into(),
+			extra_data: hex!("").into(),
+			state_root: hex!("2a785e43bdd23e81499a062c1645879e573484a329114c4dacb1762471793116").into(),
+			receipts_root: hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").into(),
+			logs_bloom: (&hex!("00000000000040000000000000000200000000000000400010000000010080000000000000000000000000001000000010000000000000000000000000200080000000000000000400000008002000000000000000008000000000000000000000000000020000000000000000000800200011000400000000000010001000000000020008000000000000000400000000000000000000000000840000000000020000000004000001000000000000000220000000000001000000000200080000000002000000000000000000000000000000000008000000800000200420402010000000000000000000000000000020000000000000000000000000000000")).into(),
+			gas_used: 1758016u64.into(),
+			gas_limit: 6721975u64.into(),
+			difficulty: 0u64.into(),
+			seal: vec![
+				hex!("a00000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				hex!("880000000000000000").to_vec(),
+			],
+			base_fee_per_gas: None,
+		},
+		vec![
+			Message {
+				data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e830500000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 0,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f905e730b905e3f905e0018301a5beb9010000000000000040000000000000000200000000000000000010000000000080000000000000000000000000001000000010000000000000000000000000000000000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000020000000000000000000400000000000000000000000000040000000000000000000004000000000000000000000200000000000001000000000200000000000002000000000000000000000000000000000000000000000000000020400000000000000000000000000000000000000000000000000000000000000000f904d5f9013c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8c00000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000020d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e830500000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9015a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b9012000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 1,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("94b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f9062020b9061cf906190183038d60b9010000000000000040000000000000000200000000000000000010000000000080000000000000000000000000001000000010000000000000000000000000200000000000000000000400000008002000000000000000008000000000000000000000000000020000000000000000000800200011000400000000000010001000000000020008000000000000000400000000000000000000000000840000000000020000000004000001000000000000000200000000000001000000000200000000000002000000000000000000000000000000000008000000800000200420400010000000000000000000000000000020000000000000000000000000000000f9050ef89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a000000000000000000000000000000000000000000000000000000000000003e8f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a00000000000000000000000000000000000000000000000000000000000000000f8b99483428c7db9815f482a39a1715684dcf755021997e1a01e7b27577112ed83d53de87b38aee59ab80d8a9ba4acd90aad6cfee917534c79b880000000000000000000000000f8f7758fbcefd546eaeff7de24aff666b6228e7300000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d00000000000000000000000000000000000000000000000000000000000003e8f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9015a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b9012000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a2300000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 2,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("5dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f904a620b904a2f9049f018305091ab9010000000000000040000000000000000200000000000000400010000000010080000000000000000000000000001000000010000000000000000000000000000080000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000020000000000000000000400000000000000000000000000040000000000000000000004000000000000000000000220000000000001000000000200080000000002000000000000000000000000000000000000000000000000000020402000000000000000000000000000000000000000000000000000000000000000f90394f89994774667629726ec1fabebcec0d9139bd1c8f72a23e1a0caae0f5e72020d428da73a237d1f9bf162e158dda6d4908769b8b60c095b01f4b86000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000000000000000000000000000000000000000000000000002386f26fc10000f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a2300000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e830500000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 3,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeb").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f905e720b905e3f905e0018306aed8b9010000000000000040000000000000000200000000000000000010000000000080000000000000000000000000001000000010000000000000000000000000000000000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000020000000000000000000400000000000000000000000000040000000000000000000004000000000000000000000200000000000001000000000200000000000002000000000000000000000000000000000000000000000000000020400000000000000000000000000000000000000000000000000000000000000000f904d5f9013c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8c00000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000020d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e830500000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9015a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b9012000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000050000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 4,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f9062020b9061cf90619018308967ab9010000000000000040000000000000000200000000000000000010000000000080000000000000000000000000001000000010000000000000000000000000200000000000000000000400000008002000000000000000008000000000000000000000000000020000000000000000000800200011000400000000000010001000000000020008000000000000000400000000000000000000000000840000000000020000000004000001000000000000000200000000000001000000000200000000000002000000000000000000000000000000000008000000800000200420400010000000000000000000000000000020000000000000000000000000000000f9050ef89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a000000000000000000000000000000000000000000000000000000000000003e8f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a00000000000000000000000000000000000000000000000000000000000000000f8b99483428c7db9815f482a39a1715684dcf755021997e1a01e7b27577112ed83d53de87b38aee59ab80d8a9ba4acd90aad6cfee917534c79b880000000000000000000000000f8f7758fbcefd546eaeff7de24aff666b6228e7300000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d00000000000000000000000000000000000000000000000000000000000003e8f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9015a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b9012000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000050000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a2300000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 5,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("36efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8da").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f904a620b904a2f9049f01830a1234b9010000000000000040000000000000000200000000000000400010000000010080000000000000000000000000001000000010000000000000000000000000000080000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000020000000000000000000400000000000000000000000000040000000000000000000004000000000000000000000220000000000001000000000200080000000002000000000000000000000000000000000000000000000000000020402000000000000000000000000000000000000000000000000000000000000000f90394f89994774667629726ec1fabebcec0d9139bd1c8f72a23e1a0caae0f5e72020d428da73a237d1f9bf162e158dda6d4908769b8b60c095b01f4b86000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000000000000000000000000000000000000000000000000002386f26fc10000f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a2300000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e830500000000000000000000000000000000000000000000000000000000000000070000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 6,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("2e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393da").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f905e720b905e3f905e001830bb7f2b9010000000000000040000000000000000200000000000000000010000000000080000000000000000000000000001000000010000000000000000000000000000000000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000020000000000000000000400000000000000000000000000040000000000000000000004000000000000000000000200000000000001000000000200000000000002000000000000000000000000000000000000000000000000000020400000000000000000000000000000000000000000000000000000000000000000f904d5f9013c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8c00000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000020d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e830500000000000000000000000000000000000000000000000000000000000000070000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9015a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b9012000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 7,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("9b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815a").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f9062020b9061cf9061901830d9f94b9010000000000000040000000000000000200000000000000000010000000000080000000000000000000000000001000000010000000000000000000000000200000000000000000000400000008002000000000000000008000000000000000000000000000020000000000000000000800200011000400000000000010001000000000020008000000000000000400000000000000000000000000840000000000020000000004000001000000000000000200000000000001000000000200000000000002000000000000000000000000000000000008000000800000200420400010000000000000000000000000000020000000000000000000000000000000f9050ef89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a000000000000000000000000000000000000000000000000000000000000003e8f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a00000000000000000000000000000000000000000000000000000000000000000f8b99483428c7db9815f482a39a1715684dcf755021997e1a01e7b27577112ed83d53de87b38aee59ab80d8a9ba4acd90aad6cfee917534c79b880000000000000000000000000f8f7758fbcefd546eaeff7de24aff666b6228e7300000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d00000000000000000000000000000000000000000000000000000000000003e8f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9015a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b9012000000000000000000000000083428c7db9815f482a39a1715684dcf75502199700000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a2300000000000000000000000000000000000000000000000000000000000000090000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 8,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("4a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcb").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f904a620b904a2f9049f01830f1b4eb9010000000000000040000000000000000200000000000000400010000000010080000000000000000000000000001000000010000000000000000000000000000080000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000020000000000000000000400000000000000000000000000040000000000000000000004000000000000000000000220000000000001000000000200080000000002000000000000000000000000000000000000000000000000000020402000000000000000000000000000000000000000000000000000000000000000f90394f89994774667629726ec1fabebcec0d9139bd1c8f72a23e1a0caae0f5e72020d428da73a237d1f9bf162e158dda6d4908769b8b60c095b01f4b86000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000000000000000000000000000000000000000000000000002386f26fc10000f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a2300000000000000000000000000000000000000000000000000000000000000090000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000a0000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 9,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1c").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f905e720b905e3f905e0018310c10cb9010000000000000040000000000000000200000000000000000010000000000080000000000000000000000000001000000010000000000000000000000000000000000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000020000000000000000000400000000000000000000000000040000000000000000000004000000000000000000000200000000000001000000000200000000000002000000000000000000000000000000000000000000000000000020400000000000000000000000000000000000000000000000000000000000000000f904d5f9013c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8c00000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000020d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000a0000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9015a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b9012000000000000000000000000083428c7db9815f482a39a1715684dcf755021997000000000000000000000000000000000000000000000000000000000000000b0000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 10,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("1d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2e").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f9062020b9061cf90619018312a8aeb9010000000000000040000000000000000200000000000000000010000000000080000000000000000000000000001000000010000000000000000000000000200000000000000000000400000008002000000000000000008000000000000000000000000000020000000000000000000800200011000400000000000010001000000000020008000000000000000400000000000000000000000000840000000000020000000004000001000000000000000200000000000001000000000200000000000002000000000000000000000000000000000008000000800000200420400010000000000000000000000000000020000000000000000000000000000000f9050ef89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a000000000000000000000000000000000000000000000000000000000000003e8f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a00000000000000000000000000000000000000000000000000000000000000000f8b99483428c7db9815f482a39a1715684dcf755021997e1a01e7b27577112ed83d53de87b38aee59ab80d8a9ba4acd90aad6cfee917534c79b880000000000000000000000000f8f7758fbcefd546eaeff7de24aff666b6228e7300000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d00000000000000000000000000000000000000000000000000000000000003e8f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9015a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b9012000000000000000000000000083428c7db9815f482a39a1715684dcf755021997000000000000000000000000000000000000000000000000000000000000000b0000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000c0000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 11,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("0337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f904a620b904a2f9049f0183142468b9010000000000000040000000000000000200000000000000400010000000010080000000000000000000000000001000000010000000000000000000000000000080000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000020000000000000000000400000000000000000000000000040000000000000000000004000000000000000000000220000000000001000000000200080000000002000000000000000000000000000000000000000000000000000020402000000000000000000000000000000000000000000000000000000000000000f90394f89994774667629726ec1fabebcec0d9139bd1c8f72a23e1a0caae0f5e72020d428da73a237d1f9bf162e158dda6d4908769b8b60c095b01f4b86000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000000000000000000000000000000000000000000000000002386f26fc10000f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000c0000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000d0000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 12,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cb").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f905e720b905e3f905e0018315ca26b9010000000000000040000000000000000200000000000000000010000000000080000000000000000000000000001000000010000000000000000000000000000000000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000020000000000000000000400000000000000000000000000040000000000000000000004000000000000000000000200000000000001000000000200000000000002000000000000000000000000000000000000000000000000000020400000000000000000000000000000000000000000000000000000000000000000f904d5f9013c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8c00000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000020d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305000000000000000000000000000000000000000000000000000000000000000d0000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9015a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b9012000000000000000000000000083428c7db9815f482a39a1715684dcf755021997000000000000000000000000000000000000000000000000000000000000000e0000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 13,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("60f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f9062020b9061cf90619018317b1c8b9010000000000000040000000000000000200000000000000000010000000000080000000000000000000000000001000000010000000000000000000000000200000000000000000000400000008002000000000000000008000000000000000000000000000020000000000000000000800200011000400000000000010001000000000020008000000000000000400000000000000000000000000840000000000020000000004000001000000000000000200000000000001000000000200000000000002000000000000000000000000000000000008000000800000200420400010000000000000000000000000000020000000000000000000000000000000f9050ef89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a000000000000000000000000000000000000000000000000000000000000003e8f89b94f8f7758fbcefd546eaeff7de24aff666b6228e73f863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a000000000000000000000000083428c7db9815f482a39a1715684dcf755021997a00000000000000000000000000000000000000000000000000000000000000000f8b99483428c7db9815f482a39a1715684dcf755021997e1a01e7b27577112ed83d53de87b38aee59ab80d8a9ba4acd90aad6cfee917534c79b880000000000000000000000000f8f7758fbcefd546eaeff7de24aff666b6228e7300000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d00000000000000000000000000000000000000000000000000000000000003e8f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9015a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b9012000000000000000000000000083428c7db9815f482a39a1715684dcf755021997000000000000000000000000000000000000000000000000000000000000000e0000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000006b4201f8f7758fbcefd546eaeff7de24aff666b6228e7389b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27de803000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000f0000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 14,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("8606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f904a620b904a2f9049f0183192d82b9010000000000000040000000000000000200000000000000400010000000010080000000000000000000000000001000000010000000000000000000000000000080000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000020000000000000000000400000000000000000000000000040000000000000000000004000000000000000000000220000000000001000000000200080000000002000000000000000000000000000000000000000000000000000020402000000000000000000000000000000000000000000000000000000000000000f90394f89994774667629726ec1fabebcec0d9139bd1c8f72a23e1a0caae0f5e72020d428da73a237d1f9bf162e158dda6d4908769b8b60c095b01f4b86000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000000000000000000000000000000000000000000000000002386f26fc10000f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000774667629726ec1fabebcec0d9139bd1c8f72a23000000000000000000000000000000000000000000000000000000000000000f0000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057410189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000c16ff2862300000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+			Message {
+				data: hex!("f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e830500000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+				proof: Proof {
+					block_hash: hex!("8100ae90be4fe977782d6bdd24e18d548ee38eeef6a80fbcf004318331a9f1ff").into(),
+					tx_index: 15,
+					data: (
+						vec![
+							hex!("a4156e2dda624517367bc8c3c2afb8200bbe9e61acfe2988f6737a689ecf23f5").to_vec(),
+							hex!("f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd3").to_vec(),
+							hex!("12a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e25").to_vec(),
+						],
+						vec![
+							hex!("f851a0f45b3ec5ea63b2c0ec7d86098ac2ac8af653d46583b931e3b89da0d15b7c7cd380808080808080a0aeaee1aed0dd14172f32187afe2e8be7979d1d7249c35323a73bc3b8992893cd8080808080808080").to_vec(),
+							hex!("f901f180a094b1b8673b66eec26d55a1c1085697cbbcab5323c7c65731d23c0a5c3c9dcb36a05dea8cac7276437c2d53d082793e87842df20d4f6ec4c6deefd2935a027ef738a0c6c31b48aa239c64f6fd539466b09eadbda188f68a122ed90fdd19930db6baeba0dae540025d902c1cba999d8543583bbaf8aa49bb7ce473fc47f4952d9922faf4a036efb467f1fb109bd6c15424c81f7c6e390be513be0dda245ab316298c69b8daa02e70020f2cc7e54b65cab36ac60f3903e2d0e5b88db97c93567a801ed05393daa09b535886a0c00b0435f09d93d43906901c0647955a8ff4e10678c3e7758d815aa04a080781ece43c020c348d19120ebe742fbd440d80f39ea4afa1850b22fadfcba0f4045dba9e3d0ea3bf467bf0a1a37b2cc8c95aa8371444dd107ea2e6db84ad1ca01d398646f37de37f069ec0d08e4f71148bb9dfdada8f79920553bde88f803f2ea00337a1b9acee9afead722e1f47181f99d2fde4b9c3ca59b32280e5956d9fb269a0a1743e0552bcca28a33375b2842aab455e4f0b20edc7810308d0deb36a2f21cba060f9f712bdb2313362da5b8f445ff18d36d6d639281ee7cd21924e3bd3d98b88a08606ceca0434eb4f86a4a0fe7e390ae4547e2ef21302bf064c37ba55708fbc70a012a06310e694f59f829bd811f12e7d96baa23a4d061f8f5a9e0404ae6f999e2580").to_vec(),
+							hex!("f905e720b905e3f905e001831ad340b9010000000000000040000000000000000200000000000000000010000000000080000000000000000000000000001000000010000000000000000000000000000000000000000000000400000008000000000000000000008000000000000000000000000000020000000000000000000800000001000400000000000010000000000000020000000000000000000400000000000000000000000000040000000000000000000004000000000000000000000200000000000001000000000200000000000002000000000000000000000000000000000000000000000000000020400000000000000000000000000000000000000000000000000000000000000000f904d5f9013c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8c00000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000020d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9011c94672a95c8928c8450b594186cf7954ec269626a2df863a0a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098a0000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e8305a000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2b8a00000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f89b94672a95c8928c8450b594186cf7954ec269626a2df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000089b4ab1ef20763630df9743acf155865600daff2a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000de0b6b3a7640000f9013a94eda338e4dc46038493b885327842fd3e301cab39e1a05e9ae1d7c484f74d554a503aa825e823725531d97e784dd9b1aacdb58d1f7076b90100000000000000000000000000b1185ede04202fe62d38f5db72f71e38ff3e830500000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000057400189b4ab1ef20763630df9743acf155865600daff200d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d000064a7b3b6e00d000000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+						],
+					),
+				},
+			},
+		],
+	)
+}
+
 impl_benchmark_test_suite!(
 	IncentivizedInboundChannel,
 	crate::inbound::test::new_tester(Default::default()),
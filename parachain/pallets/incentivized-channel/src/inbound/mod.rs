@@ -1,25 +1,27 @@
 use frame_support::{
 	decl_error, decl_event, decl_module, decl_storage,
-	dispatch::DispatchResult,
+	dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo},
 	traits::{
 		Currency, Get, ExistenceRequirement::KeepAlive,
-		WithdrawReasons, Imbalance,
+		WithdrawReasons,
 		EnsureOrigin,
 	},
-	storage::StorageValue,
+	storage::{StorageValue, migration},
 	log,
 	weights::Weight,
 };
 use frame_system::{self as system, ensure_signed};
-use sp_core::{U256, H160};
+use sp_core::{U256, H160, H256};
 use sp_std::prelude::*;
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::convert::TryFrom;
 use artemis_core::{
-	ChannelId, Message, MessageId,
-	MessageDispatch, Verifier,
+	Channel, ChannelId, InboundChannel, Message, MessageId, MessageNonce,
+	MessageDelivery, MessageDispatch, SourceChannelInfo, Verifier,
 };
 
 use envelope::Envelope;
+use receipt::Receipt;
 
 use sp_runtime::{Perbill, traits::{Zero, Convert}};
 
@@ -30,22 +32,33 @@ mod test;
 
 mod envelope;
 
+mod receipt;
+
 type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
-type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::PositiveImbalance;
 
 /// Weight functions needed for this pallet.
 pub trait WeightInfo {
 	fn submit() -> Weight;
+	fn submit_batch(m: u32) -> Weight;
 	fn set_reward_fraction() -> Weight;
+	fn claim_rewards() -> Weight;
+	fn register_source_channel() -> Weight;
+	fn set_source_channel_sunset() -> Weight;
+	fn set_receipt_channel() -> Weight;
 }
 
 impl WeightInfo for () {
 	fn submit() -> Weight { 0 }
+	fn submit_batch(_m: u32) -> Weight { 0 }
 	fn set_reward_fraction() -> Weight { 0 }
+	fn claim_rewards() -> Weight { 0 }
+	fn register_source_channel() -> Weight { 0 }
+	fn set_source_channel_sunset() -> Weight { 0 }
+	fn set_receipt_channel() -> Weight { 0 }
 }
 
 pub trait Config: system::Config {
-	type Event: From<Event> + Into<<Self as system::Config>::Event>;
+	type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
 
 	/// Verifier module for message verification.
 	type Verifier: Verifier;
@@ -53,9 +66,13 @@ pub trait Config: system::Config {
 	/// Verifier module for message verification.
 	type MessageDispatch: MessageDispatch<Self, MessageId>;
 
+	/// Outbound channel that delivery receipts for its messages are passed to.
+	type MessageDelivery: MessageDelivery;
+
 	type Currency: Currency<Self::AccountId>;
 
-	/// Source of funds to pay relayers
+	/// Source of funds to pay relayers. Relayer rewards are held here
+	/// until they are claimed.
 	type SourceAccount: Get<Self::AccountId>;
 
 	/// Treasury Account
@@ -63,7 +80,14 @@ pub trait Config: system::Config {
 
 	type FeeConverter: Convert<U256, BalanceOf<Self>>;
 
-	/// The origin which may update reward related params
+	/// Max number of verified messages with nonces ahead of the next expected
+	/// nonce that are buffered until the gap is filled. Zero disables buffering.
+	type MaxPendingMessages: Get<u64>;
+
+	/// Max number of messages that can be submitted in a single batch.
+	type MaxMessagesPerBatch: Get<u32>;
+
+	/// The origin which may update reward related params and manage source channels
 	type UpdateOrigin: EnsureOrigin<Self::Origin>;
 
 	/// Weight information for extrinsics in this pallet
@@ -72,15 +96,90 @@ pub trait Config: system::Config {
 
 decl_storage! {
 	trait Store for Module<T: Config> as IncentivizedInboundModule {
-		pub SourceChannel get(fn source_channel) config(): H160;
-		pub Nonce: u64;
+		/// Outbound channels on the Ethereum side that messages are accepted from.
+		pub SourceChannels get(fn source_channels): map hasher(twox_64_concat) H160 => Option<SourceChannelInfo<T::BlockNumber>>;
+
+		/// Nonce of the last message dispatched from each source channel.
+		pub Nonce: map hasher(twox_64_concat) H160 => MessageNonce;
+
 		pub RewardFraction get(fn reward_fraction) config(): Perbill;
 
+		/// Rewards accrued by each relayer that have not been claimed yet.
+		pub RelayerRewards get(fn relayer_rewards): map hasher(blake2_128_concat) T::AccountId => BalanceOf<T>;
+
+		/// Sum of all unclaimed relayer rewards, which the source account holds
+		/// in escrow.
+		pub TotalRewards get(fn total_rewards): BalanceOf<T>;
+
+		/// Treasury shares of message fees that could not be withdrawn from the
+		/// source account yet. Paid down out of later message fees.
+		pub TreasuryShortfall get(fn treasury_shortfall): BalanceOf<T>;
+
+		/// Verified messages waiting for all messages with lower nonces from the
+		/// same source channel to be dispatched, along with the relayer that
+		/// submitted them.
+		pub PendingMessages: double_map hasher(twox_64_concat) H160, hasher(twox_64_concat) MessageNonce => Option<(T::AccountId, Envelope<T>)>;
+
+		/// Inbound channel on the Ethereum side that delivery receipts are accepted from.
+		pub ReceiptChannel get(fn receipt_channel): Option<H160>;
+	}
+	add_extra_genesis {
+		config(source_channels): Vec<H160>;
+		build(|config: &GenesisConfig| {
+			for channel in config.source_channels.iter() {
+				SourceChannels::<T>::insert(channel, SourceChannelInfo::default());
+			}
+		});
 	}
 }
 
 decl_event! {
-	pub enum Event {
+	pub enum Event<T>
+	where
+		AccountId = <T as system::Config>::AccountId,
+		Balance = BalanceOf<T>,
+		BlockNumber = <T as system::Config>::BlockNumber,
+	{
+		/// A message with a valid proof was accepted from a relayer.
+		/// [channel, nonce, source, relayer, block_hash]
+		MessageReceived(ChannelId, MessageNonce, H160, AccountId, H256),
+		/// A relayer's share of a message fee was added to its unclaimed rewards.
+		/// [relayer, amount]
+		RewardAccrued(AccountId, Balance),
+		/// A relayer was paid some of its unclaimed rewards.
+		/// [relayer, amount]
+		RewardsClaimed(AccountId, Balance),
+		/// The source account could not pay all of a relayer's unclaimed rewards.
+		/// The unpaid rewards remain claimable.
+		/// [relayer, unpaid amount]
+		RewardShortfall(AccountId, Balance),
+		/// The remainder of a message fee, along with any earlier shortfall, was
+		/// paid into the treasury.
+		/// [amount]
+		TreasuryCredited(Balance),
+		/// The source account could not pay the treasury share of a message fee
+		/// without dipping into relayer rewards. The unpaid amount is paid out
+		/// of later fees.
+		/// [outstanding amount]
+		TreasuryShortfall(Balance),
+		/// A message arrived ahead of its turn and is buffered until the gap is filled.
+		/// [source_channel, nonce]
+		MessageBuffered(H160, MessageNonce),
+		/// A message in a batch was accepted for dispatch or buffering.
+		/// [index, nonce]
+		BatchMessageAccepted(u32, MessageNonce),
+		/// A message in a batch was rejected.
+		/// [index, error]
+		BatchMessageRejected(u32, DispatchError),
+		/// A source channel was registered.
+		/// [channel]
+		SourceChannelRegistered(H160),
+		/// The sunset block of a source channel was set or cleared.
+		/// [channel, sunset]
+		SourceChannelSunsetSet(H160, Option<BlockNumber>),
+		/// The inbound channel that delivery receipts are accepted from was set or cleared.
+		/// [channel]
+		ReceiptChannelSet(Option<H160>),
 	}
 }
 
@@ -88,10 +187,29 @@ decl_error! {
 	pub enum Error for Module<T: Config> {
 		/// Message came from an invalid outbound channel on the Ethereum side.
 		InvalidSourceChannel,
+		/// Message came from a source channel that has passed its sunset block.
+		SourceChannelSunset,
+		/// Source channel is already registered.
+		SourceChannelAlreadyRegistered,
+		/// Source channel is not registered.
+		UnknownSourceChannel,
 		/// Message has an invalid envelope.
 		InvalidEnvelope,
-		/// Message has an unexpected nonce.
+		/// Message has an unexpected nonce, is too far ahead of the next
+		/// expected nonce, or is already buffered.
 		InvalidNonce,
+		/// Batch contains more than `MaxMessagesPerBatch` messages.
+		BatchTooLarge,
+		/// Message would dispatch the buffered messages of a second source
+		/// channel in the same batch. It can be submitted in another batch.
+		BufferAlreadyDrained,
+		/// The relayer has no unclaimed rewards.
+		NoRewards,
+		/// Receipt came from an inbound channel on the Ethereum side other than
+		/// the receipt channel.
+		InvalidReceiptChannel,
+		/// Receipt has an invalid format.
+		InvalidReceipt,
 	}
 }
 
@@ -102,43 +220,153 @@ decl_module! {
 
 		fn deposit_event() = default;
 
-		#[weight = T::WeightInfo::submit()]
-		pub fn submit(origin, message: Message) -> DispatchResult {
+		fn on_runtime_upgrade() -> Weight {
+			Self::migrate_source_channel()
+		}
+
+		/// Submit a message. The weight of the dispatched calls is refunded
+		/// down to what they actually consumed.
+		#[weight = T::WeightInfo::submit().saturating_add(Module::<T>::max_accept_weight(1))]
+		pub fn submit(origin, message: Message) -> DispatchResultWithPostInfo {
 			let relayer = ensure_signed(origin)?;
-			// submit message to verifier for verification
-			let log = T::Verifier::verify(&message)?;
+			let dispatch_weight = <Self as InboundChannel<T::AccountId>>::submit(&relayer, &message)?;
+			Ok(Some(T::WeightInfo::submit().saturating_add(dispatch_weight)).into())
+		}
 
-			// Decode log into an Envelope
-			let envelope: Envelope<T> = Envelope::try_from(log).map_err(|_| Error::<T>::InvalidEnvelope)?;
+		/// Submit a batch of messages. Each message is verified and accepted
+		/// independently, and the outcome for each one is reported in an event.
+		/// Nonces from each source channel must be strictly increasing across
+		/// the batch, and buffered messages of at most one source channel are
+		/// dispatched by the batch.
+		#[weight = T::WeightInfo::submit_batch(messages.len() as u32)
+			.saturating_add(Module::<T>::max_accept_weight(messages.len() as u64))]
+		pub fn submit_batch(origin, messages: Vec<Message>) -> DispatchResultWithPostInfo {
+			let relayer = ensure_signed(origin)?;
+			if messages.len() > T::MaxMessagesPerBatch::get() as usize {
+				return Err(Error::<T>::BatchTooLarge.into())
+			}
 
-			// Verify that the message was submitted to us from a known
-			// outbound channel on the ethereum side
-			if envelope.channel != SourceChannel::get() {
-				return Err(Error::<T>::InvalidSourceChannel.into())
+			let mut last_nonces: BTreeMap<H160, MessageNonce> = BTreeMap::new();
+			let mut drained_channel: Option<H160> = None;
+			let mut dispatch_weight: Weight = 0;
+			for (index, message) in messages.iter().enumerate() {
+				let result = Self::verify_message(message).and_then(|envelope| {
+					let (channel, nonce) = (envelope.channel, envelope.nonce);
+					if last_nonces.get(&channel).map_or(false, |last| nonce <= *last) {
+						return Err(Error::<T>::InvalidNonce.into())
+					}
+					let drains = Self::unblocks_buffered_messages(channel, nonce);
+					if drains && drained_channel.map_or(false, |drained| drained != channel) {
+						return Err(Error::<T>::BufferAlreadyDrained.into())
+					}
+					Self::accept(relayer.clone(), message.proof.block_hash, envelope)
+						.map(|weight| (channel, nonce, drains, weight))
+				});
+
+				let event = match result {
+					Ok((channel, nonce, drains, weight)) => {
+						last_nonces.insert(channel, nonce);
+						if drains {
+							drained_channel = Some(channel);
+						}
+						dispatch_weight = dispatch_weight.saturating_add(weight);
+						RawEvent::BatchMessageAccepted(index as u32, nonce)
+					},
+					Err(err) => RawEvent::BatchMessageRejected(index as u32, err),
+				};
+				Self::deposit_event(event);
+				dispatch_weight = dispatch_weight.saturating_add(T::DbWeight::get().reads(2));
 			}
 
-			// Verify message nonce
-			Nonce::try_mutate(|nonce| -> DispatchResult {
-				if envelope.nonce != *nonce + 1 {
-					Err(Error::<T>::InvalidNonce.into())
-				} else {
-					*nonce += 1;
-					Ok(())
-				}
-			})?;
+			Ok(Some(T::WeightInfo::submit_batch(messages.len() as u32).saturating_add(dispatch_weight)).into())
+		}
 
-			Self::handle_fee(envelope.fee, &relayer);
+		#[weight = T::WeightInfo::set_reward_fraction()]
+		pub fn set_reward_fraction(origin, fraction: Perbill) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			RewardFraction::set(fraction);
+			Ok(())
+		}
+
+		/// Pay the caller's unclaimed rewards from the source account. If the
+		/// source account can't cover all of them, as much as possible is paid
+		/// and the rest stays claimable.
+		#[weight = T::WeightInfo::claim_rewards()]
+		pub fn claim_rewards(origin) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			let owed = RelayerRewards::<T>::get(&relayer);
+			if owed.is_zero() {
+				return Err(Error::<T>::NoRewards.into())
+			}
 
-			let message_id = MessageId::new(ChannelId::Incentivized, envelope.nonce);
-			T::MessageDispatch::dispatch(envelope.source, message_id, &envelope.payload);
+			let source = T::SourceAccount::get();
+			let available = T::Currency::free_balance(&source).saturating_sub(T::Currency::minimum_balance());
+			let paid = owed.min(available);
+			if !paid.is_zero() {
+				T::Currency::transfer(&source, &relayer, paid, KeepAlive)?;
+				TotalRewards::<T>::mutate(|total| *total = total.saturating_sub(paid));
+				Self::deposit_event(RawEvent::RewardsClaimed(relayer.clone(), paid));
+			}
 
+			let unpaid = owed - paid;
+			if unpaid.is_zero() {
+				RelayerRewards::<T>::remove(&relayer);
+			} else {
+				RelayerRewards::<T>::insert(&relayer, unpaid);
+				Self::deposit_event(RawEvent::RewardShortfall(relayer, unpaid));
+			}
 			Ok(())
 		}
 
-		#[weight = T::WeightInfo::set_reward_fraction()]
-		pub fn set_reward_fraction(origin, fraction: Perbill) -> DispatchResult {
+		#[weight = T::WeightInfo::register_source_channel()]
+		pub fn register_source_channel(origin, channel: H160) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
-			RewardFraction::set(fraction);
+			if SourceChannels::<T>::contains_key(channel) {
+				return Err(Error::<T>::SourceChannelAlreadyRegistered.into())
+			}
+			SourceChannels::<T>::insert(channel, SourceChannelInfo::default());
+			Self::deposit_event(RawEvent::SourceChannelRegistered(channel));
+			Ok(())
+		}
+
+		/// Stop accepting messages from `channel` from block `sunset` onwards,
+		/// or lift a previously set sunset if `sunset` is `None`.
+		#[weight = T::WeightInfo::set_source_channel_sunset()]
+		pub fn set_source_channel_sunset(origin, channel: H160, sunset: Option<T::BlockNumber>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			SourceChannels::<T>::try_mutate(channel, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::UnknownSourceChannel)?;
+				info.sunset = sunset;
+				Ok::<_, DispatchError>(())
+			})?;
+			Self::deposit_event(RawEvent::SourceChannelSunsetSet(channel, sunset));
+			Ok(())
+		}
+
+		/// Submit a receipt emitted by the inbound channel on Ethereum after
+		/// executing a message from the outbound channel. Receipts must be
+		/// submitted in nonce order.
+		///
+		/// Verifying the receipt dominates the cost, so it is charged as a
+		/// message submission.
+		#[weight = T::WeightInfo::submit()]
+		pub fn submit_receipt(origin, message: Message) -> DispatchResult {
+			ensure_signed(origin)?;
+			let log = T::Verifier::verify(&message)?;
+			let receipt = Receipt::try_from(log).map_err(|_| Error::<T>::InvalidReceipt)?;
+			if Self::receipt_channel() != Some(receipt.channel) {
+				return Err(Error::<T>::InvalidReceiptChannel.into())
+			}
+			T::MessageDelivery::on_delivered(receipt.nonce, receipt.success)
+		}
+
+		/// Accept delivery receipts from `channel`, or stop accepting them if
+		/// `channel` is `None`.
+		#[weight = T::WeightInfo::set_receipt_channel()]
+		pub fn set_receipt_channel(origin, channel: Option<H160>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ReceiptChannel::set(channel);
+			Self::deposit_event(RawEvent::ReceiptChannelSet(channel));
 			Ok(())
 		}
 
@@ -146,44 +374,184 @@ decl_module! {
 }
 
 impl<T: Config> Module<T> {
-	/*
-	* Pay the message submission fee into the relayer and treasury account.
-	*
-	* - If the fee is zero, do nothing
-	* - Otherwise, withdraw the fee amount from the DotApp module account, returning a negative imbalance
-	* - Figure out the fraction of the fee amount that should be paid to the relayer
-	* - Pay the relayer if their account exists, returning a positive imbalance.
-	* - Adjust the negative imbalance by offsetting the amount paid to the relayer
-	* - Resolve the negative imbalance by depositing it into the treasury account
-	*/
-	fn handle_fee(amount: BalanceOf<T>, relayer: &T::AccountId) {
-		if amount.is_zero() {
-			return;
+	fn verify_message(message: &Message) -> Result<Envelope<T>, DispatchError> {
+		// submit message to verifier for verification
+		let log = T::Verifier::verify(message)?;
+
+		// Decode log into an Envelope
+		let envelope: Envelope<T> = Envelope::try_from(log).map_err(|_| Error::<T>::InvalidEnvelope)?;
+
+		// Verify that the message was submitted to us from a known
+		// outbound channel on the ethereum side
+		let info = SourceChannels::<T>::get(envelope.channel).ok_or(Error::<T>::InvalidSourceChannel)?;
+		if info.sunset.map_or(false, |sunset| <frame_system::Pallet<T>>::block_number() >= sunset) {
+			return Err(Error::<T>::SourceChannelSunset.into())
+		}
+
+		Ok(envelope)
+	}
+
+	// Verify message nonce, then buffer the message if it's ahead of its turn,
+	// or dispatch it along with the buffered messages that it unblocks.
+	//
+	// Returns the actual weight of accepting this message, including the
+	// messages it dispatches. A buffered message is charged for its dispatch
+	// once it's dispatched.
+	fn accept(relayer: T::AccountId, block_hash: H256, envelope: Envelope<T>) -> Result<Weight, DispatchError> {
+		let next_nonce = Nonce::get(envelope.channel) + 1;
+		let buffer = envelope.nonce > next_nonce;
+		if envelope.nonce < next_nonce
+			|| (buffer && (envelope.nonce - next_nonce > T::MaxPendingMessages::get()
+				|| PendingMessages::<T>::contains_key(envelope.channel, envelope.nonce)))
+		{
+			return Err(Error::<T>::InvalidNonce.into())
 		}
 
-		let imbalance = match T::Currency::withdraw(&T::SourceAccount::get(), amount, WithdrawReasons::TRANSFER, KeepAlive) {
-			Ok(imbalance) => imbalance,
-			Err(err) => {
-				log::error!("Unable to withdraw from source account: {:?}", err);
-				return;
+		Self::deposit_event(RawEvent::MessageReceived(
+			Self::id(),
+			envelope.nonce,
+			envelope.source,
+			relayer.clone(),
+			block_hash,
+		));
+
+		let channel = envelope.channel;
+		if buffer {
+			let nonce = envelope.nonce;
+			PendingMessages::<T>::insert(channel, nonce, (relayer, envelope));
+			Self::deposit_event(RawEvent::MessageBuffered(channel, nonce));
+			Ok(Self::buffered_message_db_weight())
+		} else {
+			let mut weight = Self::dispatch(&relayer, envelope);
+			while let Some((relayer, envelope)) = PendingMessages::<T>::take(channel, Nonce::get(channel) + 1) {
+				weight = weight
+					.saturating_add(Self::dispatch(&relayer, envelope))
+					.saturating_add(Self::drained_message_db_weight());
 			}
+			Ok(weight.saturating_add(T::DbWeight::get().reads(2)))
+		}
+	}
+
+	// Whether a message with `nonce` from `channel` is next in turn and
+	// dispatches buffered messages when accepted.
+	fn unblocks_buffered_messages(channel: H160, nonce: MessageNonce) -> bool {
+		nonce == Nonce::get(channel) + 1 && PendingMessages::<T>::contains_key(channel, nonce + 1)
+	}
+
+	// Max weight of accepting `num_messages` messages in one call. Each of them
+	// may be dispatched, along with the buffered messages of one source channel,
+	// up to MaxPendingMessages.
+	fn max_accept_weight(num_messages: u64) -> Weight {
+		let max_dispatches = T::MaxPendingMessages::get().saturating_add(num_messages);
+		T::MessageDispatch::max_dispatch_weight()
+			.saturating_add(Self::drained_message_db_weight())
+			.saturating_mul(max_dispatches)
+	}
+
+	// Storage accesses for buffering a message: reading the nonce, checking
+	// the buffer and inserting the message.
+	fn buffered_message_db_weight() -> Weight {
+		T::DbWeight::get().reads_writes(2, 1)
+	}
+
+	// Storage accesses for dispatching a buffered message: taking it from the
+	// buffer, updating the nonce, crediting the relayer and paying the treasury
+	// along with any shortfall.
+	fn drained_message_db_weight() -> Weight {
+		T::DbWeight::get().reads_writes(8, 7)
+	}
+
+	fn dispatch(relayer: &T::AccountId, envelope: Envelope<T>) -> Weight {
+		Nonce::insert(envelope.channel, envelope.nonce);
+
+		Self::handle_fee(envelope.fee, relayer);
+
+		let message_id = MessageId::new(Self::id(), envelope.nonce);
+		T::MessageDispatch::dispatch(envelope.source, message_id, &envelope.payload)
+	}
+
+	// Move the single source channel and its nonce from the legacy storage
+	// layout into the per-channel maps.
+	fn migrate_source_channel() -> Weight {
+		let legacy_channel: Option<H160> = migration::take_storage_value(
+			b"IncentivizedInboundModule",
+			b"SourceChannel",
+			&[],
+		);
+		let channel = match legacy_channel {
+			Some(channel) => channel,
+			None => return T::DbWeight::get().reads(1),
 		};
 
+		let nonce: Option<MessageNonce> = migration::take_storage_value(
+			b"IncentivizedInboundModule",
+			b"Nonce",
+			&[],
+		);
+		SourceChannels::<T>::insert(channel, SourceChannelInfo::default());
+		Nonce::insert(channel, nonce.unwrap_or_default());
+
+		T::DbWeight::get().reads_writes(2, 4)
+	}
+
+	// Credit the relayer's share of the fee to its unclaimed rewards, which
+	// stay in the source account until claimed, and pay the rest to the treasury
+	// along with any earlier shortfall. The treasury is only paid out of funds in
+	// the source account beyond the unclaimed rewards.
+	fn handle_fee(amount: BalanceOf<T>, relayer: &T::AccountId) {
+		if amount.is_zero() {
+			return;
+		}
+
 		let reward_fraction: Perbill = RewardFraction::get();
 		let reward_amount = reward_fraction.mul_ceil(amount);
+		if !reward_amount.is_zero() {
+			RelayerRewards::<T>::mutate(relayer, |rewards| *rewards = rewards.saturating_add(reward_amount));
+			TotalRewards::<T>::mutate(|total| *total = total.saturating_add(reward_amount));
+			Self::deposit_event(RawEvent::RewardAccrued(relayer.clone(), reward_amount));
+		}
 
-		let rewarded = T::Currency::deposit_into_existing(relayer, reward_amount)
-			.unwrap_or_else(|_| PositiveImbalanceOf::<T>::zero());
+		let owed = (amount - reward_amount).saturating_add(TreasuryShortfall::<T>::get());
+		if owed.is_zero() {
+			return;
+		}
 
-		let adjusted_imbalance = match imbalance.offset(rewarded).same() {
-			Ok(imbalance) => imbalance,
-			Err(_) => {
-				log::error!("Unable to offset imbalance");
-				return;
+		let source = T::SourceAccount::get();
+		let available = T::Currency::free_balance(&source)
+			.saturating_sub(TotalRewards::<T>::get())
+			.saturating_sub(T::Currency::minimum_balance());
+		let mut paid = owed.min(available);
+		if !paid.is_zero() {
+			match T::Currency::withdraw(&source, paid, WithdrawReasons::TRANSFER, KeepAlive) {
+				Ok(imbalance) => {
+					T::Currency::resolve_creating(&T::TreasuryAccount::get(), imbalance);
+					Self::deposit_event(RawEvent::TreasuryCredited(paid));
+				},
+				Err(err) => {
+					log::error!("Unable to withdraw from source account: {:?}", err);
+					paid = Zero::zero();
+				}
 			}
-		};
+		}
 
-		T::Currency::resolve_creating(&T::TreasuryAccount::get(), adjusted_imbalance);
+		let shortfall = owed - paid;
+		TreasuryShortfall::<T>::put(shortfall);
+		if !shortfall.is_zero() {
+			Self::deposit_event(RawEvent::TreasuryShortfall(shortfall));
+		}
 	}
 
 }
+
+impl<T: Config> Channel for Module<T> {
+	fn id() -> ChannelId {
+		ChannelId::INCENTIVIZED
+	}
+}
+
+impl<T: Config> InboundChannel<T::AccountId> for Module<T> {
+	fn submit(relayer: &T::AccountId, message: &Message) -> Result<Weight, DispatchError> {
+		let envelope = Self::verify_message(message)?;
+		Self::accept(relayer.clone(), message.proof.block_hash, envelope)
+	}
+}
@@ -7,13 +7,14 @@ use frame_support::{
 	parameter_types,
 	dispatch::DispatchError,
 	traits::Currency,
+	weights::GetDispatchInfo,
 };
 use sp_runtime::{
 	Perbill,
 	traits::{Convert, BlakeTwo256, IdentityLookup, IdentifyAccount, Verify}, testing::Header, MultiSignature
 };
 use sp_keyring::AccountKeyring as Keyring;
-use sp_std::{marker::PhantomData, convert::From};
+use sp_std::{marker::PhantomData, convert::{From, TryInto}};
 
 use artemis_core::{MessageDispatch, Message, Proof};
 use artemis_ethereum::{Header as EthereumHeader, Log, U256};
@@ -35,7 +36,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
-		IncentivizedInboundChannel: incentivized_inbound_channel::{Pallet, Call, Storage, Event},
+		IncentivizedInboundChannel: incentivized_inbound_channel::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -108,8 +109,17 @@ impl Verifier for MockVerifier {
 // Mock Dispatch
 pub struct MockMessageDispatch;
 
+pub const MOCK_DISPATCH_WEIGHT: Weight = 1_000;
+pub const MOCK_MAX_DISPATCH_WEIGHT: Weight = 10_000;
+
 impl MessageDispatch<Test, MessageId> for MockMessageDispatch {
-	fn dispatch(_: H160, _: MessageId, _: &[u8]) {}
+	fn dispatch(_: H160, _: MessageId, _: &[u8]) -> Weight {
+		MOCK_DISPATCH_WEIGHT
+	}
+
+	fn max_dispatch_weight() -> Weight {
+		MOCK_MAX_DISPATCH_WEIGHT
+	}
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn successful_dispatch_event(_: MessageId) -> Option<<Test as system::Config>::Event> {
@@ -117,9 +127,23 @@ impl MessageDispatch<Test, MessageId> for MockMessageDispatch {
 	}
 }
 
+// Mock outbound channel that only expects a receipt for nonce 1
+pub struct MockMessageDelivery;
+
+impl MessageDelivery for MockMessageDelivery {
+	fn on_delivered(nonce: MessageNonce, _: bool) -> DispatchResult {
+		if nonce != 1 {
+			return Err(DispatchError::Other("unexpected nonce"))
+		}
+		Ok(())
+	}
+}
+
 parameter_types! {
 	pub SourceAccount: AccountId = Keyring::Eve.into();
 	pub TreasuryAccount: AccountId = Keyring::Dave.into();
+	pub const MaxPendingMessages: u64 = 2;
+	pub const MaxMessagesPerBatch: u32 = 3;
 }
 
 pub struct FeeConverter<T: Config>(PhantomData<T>);
@@ -134,6 +158,9 @@ impl incentivized_inbound_channel::Config for Test {
 	type Event = Event;
 	type Verifier = MockVerifier;
 	type MessageDispatch = MockMessageDispatch;
+	type MessageDelivery = MockMessageDelivery;
+	type MaxPendingMessages = MaxPendingMessages;
+	type MaxMessagesPerBatch = MaxMessagesPerBatch;
 	type Currency = Balances;
 	type SourceAccount = SourceAccount;
 	type TreasuryAccount = TreasuryAccount;
@@ -144,7 +171,7 @@ impl incentivized_inbound_channel::Config for Test {
 
 pub fn new_tester(source_channel: H160) -> sp_io::TestExternalities {
 	new_tester_with_config(incentivized_inbound_channel::GenesisConfig {
-		source_channel,
+		source_channels: vec![source_channel],
 		reward_fraction: Perbill::from_percent(80)
 	})
 }
@@ -191,6 +218,78 @@ const MESSAGE_DATA_1: [u8; 317] = hex!("
 	0000000000000000000000000000000000000000000000000000000000
 ");
 
+// Re-encode the log of `MESSAGE_DATA_0` with a different nonce
+fn source_channel() -> H160 {
+	SOURCE_CHANNEL_ADDR.into()
+}
+
+fn message_with_nonce(nonce: u64) -> Message {
+	message_from_channel(source_channel(), nonce)
+}
+
+fn message_from_channel(channel: H160, nonce: u64) -> Message {
+	let mut log: Log = rlp::decode(&MESSAGE_DATA_0).unwrap();
+	// The channel is the address of the log, and the nonce is the
+	// second word of the ABI-encoded log data
+	log.address = channel;
+	log.data[56..64].copy_from_slice(&nonce.to_be_bytes());
+
+	let mut stream = rlp::RlpStream::new_list(3);
+	stream.append(&log.address);
+	stream.append_list(&log.topics);
+	stream.append(&log.data);
+
+	Message {
+		data: stream.out().to_vec(),
+		proof: Proof {
+			block_hash: Default::default(),
+			tx_index: Default::default(),
+			data: Default::default()
+		},
+	}
+}
+
+// The inbound channel on Ethereum that receipts are accepted from
+const RECEIPT_CHANNEL_ADDR: [u8; 20] = hex!["4b6b7ed1a9ea5b6c3fad0a8e3a0a8d21f52f4a7a"];
+
+// Ethereum Log:
+//   address: 0x4b6b7ed1a9ea5b6c3fad0a8e3a0a8d21f52f4a7a (inbound channel contract)
+//   topics: MessageDispatched(uint64,bool)
+//   data:
+//     nonce: 1
+//     result: true
+const RECEIPT_DATA: [u8; 123] = hex!("
+	f879944b6b7ed1a9ea5b6c3fad0a8e3a0a8d21f52f4a7ae1a0504b093d860dc8
+	27c72a879d052fd8ac6b4c2af80c5f3a634654f172690bf10ab8400000000000
+	0000000000000000000000000000000000000000000000000000010000000000
+	000000000000000000000000000000000000000000000000000001
+");
+
+fn receipt_from_channel(channel: H160, nonce: u64) -> Message {
+	let mut log: Log = rlp::decode(&RECEIPT_DATA).unwrap();
+	// The nonce is the first word of the ABI-encoded log data
+	log.address = channel;
+	log.data[24..32].copy_from_slice(&nonce.to_be_bytes());
+
+	let mut stream = rlp::RlpStream::new_list(3);
+	stream.append(&log.address);
+	stream.append_list(&log.topics);
+	stream.append(&log.data);
+
+	Message {
+		data: stream.out().to_vec(),
+		proof: Proof {
+			block_hash: Default::default(),
+			tx_index: Default::default(),
+			data: Default::default()
+		},
+	}
+}
+
+fn last_event() -> Event {
+	System::events().pop().expect("Event expected").event
+}
+
 #[test]
 fn test_submit_with_invalid_source_channel() {
 	new_tester(H160::zero()).execute_with(|| {
@@ -229,7 +328,7 @@ fn test_submit() {
 			},
 		};
 		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_1));
-		let nonce: u64 = Nonce::get();
+		let nonce: u64 = Nonce::get(source_channel());
 		assert_eq!(nonce, 1);
 
 		// Submit message 2
@@ -242,7 +341,7 @@ fn test_submit() {
 			},
 		};
 		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_2));
-		let nonce: u64 = Nonce::get();
+		let nonce: u64 = Nonce::get(source_channel());
 		assert_eq!(nonce, 2);
 	});
 }
@@ -263,7 +362,7 @@ fn test_submit_with_invalid_nonce() {
 			},
 		};
 		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message.clone()));
-		let nonce: u64 = Nonce::get();
+		let nonce: u64 = Nonce::get(source_channel());
 		assert_eq!(nonce, 1);
 
 		// Submit the same again
@@ -274,6 +373,245 @@ fn test_submit_with_invalid_nonce() {
 	});
 }
 
+#[test]
+fn test_submit_emits_message_received() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		let origin = Origin::signed(relayer.clone());
+
+		let mut message = message_with_nonce(1);
+		message.proof.block_hash = H256::repeat_byte(1);
+		let envelope: Envelope<Test> = rlp::decode::<Log>(&message.data).unwrap().try_into().unwrap();
+
+		assert_ok!(IncentivizedInboundChannel::submit(origin, message));
+		let received = Event::incentivized_inbound_channel(crate::inbound::RawEvent::MessageReceived(
+			ChannelId::INCENTIVIZED,
+			1,
+			envelope.source,
+			relayer,
+			H256::repeat_byte(1),
+		));
+		assert!(System::events().iter().any(|record| record.event == received));
+	});
+}
+
+#[test]
+fn test_register_source_channel() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		let origin = Origin::signed(relayer);
+		let new_channel = H160::repeat_byte(1);
+
+		assert_noop!(
+			IncentivizedInboundChannel::submit(origin.clone(), message_from_channel(new_channel, 1)),
+			Error::<Test>::InvalidSourceChannel
+		);
+		assert_noop!(
+			IncentivizedInboundChannel::register_source_channel(origin.clone(), new_channel),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(IncentivizedInboundChannel::register_source_channel(Origin::root(), new_channel));
+		assert_noop!(
+			IncentivizedInboundChannel::register_source_channel(Origin::root(), new_channel),
+			Error::<Test>::SourceChannelAlreadyRegistered
+		);
+
+		// Each source channel has its own nonce
+		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_from_channel(new_channel, 1)));
+		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_from_channel(new_channel, 3)));
+		assert_eq!(Nonce::get(new_channel), 1);
+		assert_eq!(Nonce::get(source_channel()), 0);
+		assert!(PendingMessages::<Test>::contains_key(new_channel, 3));
+
+		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(1)));
+		assert_eq!(Nonce::get(source_channel()), 1);
+	});
+}
+
+#[test]
+fn test_source_channel_sunset() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		let origin = Origin::signed(relayer);
+
+		assert_noop!(
+			IncentivizedInboundChannel::set_source_channel_sunset(Origin::root(), H160::repeat_byte(1), Some(5)),
+			Error::<Test>::UnknownSourceChannel
+		);
+		assert_ok!(IncentivizedInboundChannel::set_source_channel_sunset(Origin::root(), source_channel(), Some(5)));
+
+		// Messages are accepted until the sunset block
+		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(1)));
+		System::set_block_number(5);
+		assert_noop!(
+			IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(2)),
+			Error::<Test>::SourceChannelSunset
+		);
+
+		assert_ok!(IncentivizedInboundChannel::set_source_channel_sunset(Origin::root(), source_channel(), None));
+		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(2)));
+	});
+}
+
+#[test]
+fn test_migrates_legacy_source_channel() {
+	new_tester_with_config(Default::default()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		migration::put_storage_value(b"IncentivizedInboundModule", b"SourceChannel", &[], source_channel());
+		migration::put_storage_value(b"IncentivizedInboundModule", b"Nonce", &[], 1u64);
+
+		IncentivizedInboundChannel::migrate_source_channel();
+		assert_eq!(SourceChannels::<Test>::get(source_channel()), Some(Default::default()));
+		assert_eq!(Nonce::get(source_channel()), 1);
+		assert_eq!(
+			migration::get_storage_value::<H160>(b"IncentivizedInboundModule", b"SourceChannel", &[]),
+			None,
+		);
+
+		// Messages from the migrated channel are accepted from the migrated nonce
+		assert_ok!(IncentivizedInboundChannel::submit(Origin::signed(relayer), message_with_nonce(2)));
+		assert_eq!(Nonce::get(source_channel()), 2);
+	});
+}
+
+#[test]
+fn test_submit_buffers_out_of_order_messages() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		let origin = Origin::signed(relayer);
+
+		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(3)));
+		assert_eq!(Nonce::get(source_channel()), 0);
+		assert!(PendingMessages::<Test>::contains_key(source_channel(), 3));
+		assert_eq!(last_event(), Event::incentivized_inbound_channel(crate::inbound::RawEvent::MessageBuffered(source_channel(), 3)));
+
+		// The same nonce can't be buffered twice
+		assert_noop!(
+			IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(3)),
+			Error::<Test>::InvalidNonce
+		);
+
+		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(2)));
+		assert_eq!(Nonce::get(source_channel()), 0);
+
+		// Filling the gap dispatches all buffered messages
+		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(1)));
+		assert_eq!(Nonce::get(source_channel()), 3);
+		assert!(!PendingMessages::<Test>::contains_key(source_channel(), 2));
+		assert!(!PendingMessages::<Test>::contains_key(source_channel(), 3));
+	});
+}
+
+#[test]
+fn test_submit_rejects_messages_beyond_buffer() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		let origin = Origin::signed(relayer);
+
+		let max_nonce = 1 + MaxPendingMessages::get();
+		assert_noop!(
+			IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(max_nonce + 1)),
+			Error::<Test>::InvalidNonce
+		);
+		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(max_nonce)));
+	});
+}
+
+#[test]
+fn test_submit_rewards_relayer_of_buffered_message() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+
+		let _ = Balances::deposit_creating(&SourceAccount::get(), 100000000000);
+		let _ = Balances::deposit_creating(&TreasuryAccount::get(), Balances::minimum_balance());
+		let _ = Balances::deposit_creating(&alice, Balances::minimum_balance());
+		let _ = Balances::deposit_creating(&bob, Balances::minimum_balance());
+
+		assert_ok!(IncentivizedInboundChannel::submit(Origin::signed(alice.clone()), message_with_nonce(2)));
+		assert_eq!(Balances::free_balance(&alice), Balances::minimum_balance());
+
+		assert_eq!(IncentivizedInboundChannel::relayer_rewards(&alice), 0);
+
+		// Each relayer is rewarded for its own message once the gap is filled
+		assert_ok!(IncentivizedInboundChannel::submit(Origin::signed(bob.clone()), message_with_nonce(1)));
+		assert_eq!(IncentivizedInboundChannel::relayer_rewards(&alice), 80);
+		assert_eq!(IncentivizedInboundChannel::relayer_rewards(&bob), 80);
+	});
+}
+
+#[test]
+fn test_submit_charges_buffered_messages_when_gap_is_filled() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		let origin = Origin::signed(relayer);
+
+		// The pre-dispatch weight covers draining a full buffer
+		let call = incentivized_inbound_channel::Call::<Test>::submit(message_with_nonce(1));
+		assert_eq!(
+			call.get_dispatch_info().weight,
+			MOCK_MAX_DISPATCH_WEIGHT * (1 + MaxPendingMessages::get()),
+		);
+
+		// A buffered message is charged for its dispatch once it's dispatched
+		let post_info = IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(2)).unwrap();
+		assert_eq!(post_info.actual_weight, Some(IncentivizedInboundChannel::buffered_message_db_weight()));
+		let post_info = IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(3)).unwrap();
+		assert_eq!(post_info.actual_weight, Some(IncentivizedInboundChannel::buffered_message_db_weight()));
+
+		let post_info = IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(1)).unwrap();
+		assert_eq!(post_info.actual_weight, Some(3 * MOCK_DISPATCH_WEIGHT));
+		assert_eq!(Nonce::get(source_channel()), 3);
+
+		// Same when the gap is filled within a batch
+		let messages = vec![message_with_nonce(5), message_with_nonce(4)];
+		let post_info = IncentivizedInboundChannel::submit_batch(origin.clone(), messages).unwrap();
+		assert_eq!(post_info.actual_weight, Some(2 * MOCK_DISPATCH_WEIGHT));
+		assert_eq!(Nonce::get(source_channel()), 5);
+	});
+}
+
+#[test]
+fn test_submit_batch() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		let origin = Origin::signed(relayer);
+
+		let messages = vec![message_with_nonce(1), message_with_nonce(3), message_with_nonce(2)];
+		assert_ok!(IncentivizedInboundChannel::submit_batch(origin.clone(), messages));
+		assert_eq!(Nonce::get(source_channel()), 1);
+		assert!(PendingMessages::<Test>::contains_key(source_channel(), 3));
+
+		// Nonces must increase across the batch
+		let invalid_nonce: DispatchError = Error::<Test>::InvalidNonce.into();
+		let events: Vec<Event> = System::events().into_iter().map(|record| record.event).collect();
+		assert!(events.contains(&Event::incentivized_inbound_channel(crate::inbound::RawEvent::BatchMessageAccepted(0, 1))));
+		assert!(events.contains(&Event::incentivized_inbound_channel(crate::inbound::RawEvent::BatchMessageAccepted(1, 3))));
+		assert_eq!(
+			last_event(),
+			Event::incentivized_inbound_channel(crate::inbound::RawEvent::BatchMessageRejected(2, invalid_nonce))
+		);
+
+		assert_ok!(IncentivizedInboundChannel::submit_batch(origin.clone(), vec![message_with_nonce(2)]));
+		assert_eq!(Nonce::get(source_channel()), 3);
+	});
+}
+
+#[test]
+fn test_submit_batch_too_large() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		let origin = Origin::signed(relayer);
+
+		let messages = (1..=MaxMessagesPerBatch::get() as u64 + 1).map(message_with_nonce).collect();
+		assert_noop!(
+			IncentivizedInboundChannel::submit_batch(origin.clone(), messages),
+			Error::<Test>::BatchTooLarge
+		);
+	});
+}
+
 #[test]
 fn test_handle_fee() {
 	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
@@ -287,7 +625,133 @@ fn test_handle_fee() {
 
 		IncentivizedInboundChannel::handle_fee(fee, &relayer);
 		assert_eq!(Balances::free_balance(&TreasuryAccount::get()), 2000000001);
+		assert_eq!(Balances::free_balance(&SourceAccount::get()), 98000000000);
+		assert_eq!(IncentivizedInboundChannel::relayer_rewards(&relayer), 8000000000);
+
+		let events: Vec<Event> = System::events().into_iter().map(|record| record.event).collect();
+		assert!(events.contains(&Event::incentivized_inbound_channel(
+			crate::inbound::RawEvent::RewardAccrued(relayer.clone(), 8000000000)
+		)));
+		assert_eq!(
+			last_event(),
+			Event::incentivized_inbound_channel(crate::inbound::RawEvent::TreasuryCredited(2000000000))
+		);
+
+		assert_ok!(IncentivizedInboundChannel::claim_rewards(Origin::signed(relayer.clone())));
 		assert_eq!(Balances::free_balance(&relayer), 8000000001);
+		assert_eq!(IncentivizedInboundChannel::relayer_rewards(&relayer), 0);
+		assert_eq!(
+			last_event(),
+			Event::incentivized_inbound_channel(crate::inbound::RawEvent::RewardsClaimed(relayer, 8000000000))
+		);
+	});
+}
+
+#[test]
+fn test_handle_fee_accrues_rewards_for_new_accounts() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+
+		let _ = Balances::deposit_creating(&SourceAccount::get(), 100000000000);
+		let _ = Balances::deposit_creating(&TreasuryAccount::get(), Balances::minimum_balance());
+
+		IncentivizedInboundChannel::handle_fee(10000000000, &relayer);
+		IncentivizedInboundChannel::handle_fee(10000000000, &relayer);
+		assert_eq!(IncentivizedInboundChannel::relayer_rewards(&relayer), 16000000000);
+
+		assert_ok!(IncentivizedInboundChannel::claim_rewards(Origin::signed(relayer.clone())));
+		assert_eq!(Balances::free_balance(&relayer), 16000000000);
+	});
+}
+
+#[test]
+fn test_handle_fee_records_treasury_shortfall() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+
+		// The source account cannot pay the treasury share without being reaped
+		let _ = Balances::deposit_creating(&SourceAccount::get(), 100 + Balances::minimum_balance());
+		let _ = Balances::deposit_creating(&TreasuryAccount::get(), Balances::minimum_balance());
+
+		IncentivizedInboundChannel::handle_fee(1000, &relayer);
+		IncentivizedInboundChannel::handle_fee(1000, &relayer);
+		assert_eq!(IncentivizedInboundChannel::relayer_rewards(&relayer), 1600);
+		assert_eq!(IncentivizedInboundChannel::treasury_shortfall(), 400);
+		assert_eq!(Balances::free_balance(&TreasuryAccount::get()), Balances::minimum_balance());
+		assert_eq!(
+			last_event(),
+			Event::incentivized_inbound_channel(crate::inbound::RawEvent::TreasuryShortfall(400))
+		);
+	});
+}
+
+#[test]
+fn test_handle_fee_settles_treasury_shortfall_without_touching_rewards() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+
+		// The source account only holds the relayer's reward
+		let _ = Balances::deposit_creating(&SourceAccount::get(), 800 + Balances::minimum_balance());
+		let _ = Balances::deposit_creating(&TreasuryAccount::get(), Balances::minimum_balance());
+
+		IncentivizedInboundChannel::handle_fee(1000, &relayer);
+		assert_eq!(IncentivizedInboundChannel::total_rewards(), 800);
+		assert_eq!(IncentivizedInboundChannel::treasury_shortfall(), 200);
+		assert_eq!(Balances::free_balance(&TreasuryAccount::get()), Balances::minimum_balance());
+
+		// The next fee pays the treasury both its share and the shortfall
+		let _ = Balances::deposit_creating(&SourceAccount::get(), 1200);
+		IncentivizedInboundChannel::handle_fee(1000, &relayer);
+		assert_eq!(IncentivizedInboundChannel::total_rewards(), 1600);
+		assert_eq!(IncentivizedInboundChannel::treasury_shortfall(), 0);
+		assert_eq!(Balances::free_balance(&TreasuryAccount::get()), 400 + Balances::minimum_balance());
+		assert_eq!(
+			last_event(),
+			Event::incentivized_inbound_channel(crate::inbound::RawEvent::TreasuryCredited(400))
+		);
+
+		// All rewards are still there to be claimed
+		assert_ok!(IncentivizedInboundChannel::claim_rewards(Origin::signed(relayer.clone())));
+		assert_eq!(Balances::free_balance(&relayer), 1600);
+		assert_eq!(IncentivizedInboundChannel::total_rewards(), 0);
+	});
+}
+
+#[test]
+fn test_claim_rewards_records_shortfall() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		RelayerRewards::<Test>::insert(&relayer, 1000);
+		TotalRewards::<Test>::put(1000);
+
+		// Only 600 can be paid without reaping the source account
+		let _ = Balances::deposit_creating(&SourceAccount::get(), 600 + Balances::minimum_balance());
+
+		assert_ok!(IncentivizedInboundChannel::claim_rewards(Origin::signed(relayer.clone())));
+		assert_eq!(Balances::free_balance(&relayer), 600);
+		assert_eq!(IncentivizedInboundChannel::relayer_rewards(&relayer), 400);
+		assert_eq!(
+			last_event(),
+			Event::incentivized_inbound_channel(crate::inbound::RawEvent::RewardShortfall(relayer.clone(), 400))
+		);
+
+		// The rest can be claimed once the source account is topped up
+		let _ = Balances::deposit_creating(&SourceAccount::get(), 400);
+		assert_ok!(IncentivizedInboundChannel::claim_rewards(Origin::signed(relayer.clone())));
+		assert_eq!(Balances::free_balance(&relayer), 1000);
+		assert!(!RelayerRewards::<Test>::contains_key(&relayer));
+		assert_eq!(IncentivizedInboundChannel::total_rewards(), 0);
+	});
+}
+
+#[test]
+fn test_claim_rewards_fails_without_rewards() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		assert_noop!(
+			IncentivizedInboundChannel::claim_rewards(Origin::signed(relayer)),
+			Error::<Test>::NoRewards
+		);
 	});
 }
 
@@ -304,3 +768,78 @@ fn test_set_reward_fraction_not_authorized() {
 		);
 	});
 }
+
+#[test]
+fn test_submit_batch_drains_buffer_of_one_source_channel() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		let origin = Origin::signed(relayer);
+
+		let other_channel = H160::repeat_byte(7);
+		assert_ok!(IncentivizedInboundChannel::register_source_channel(Origin::root(), other_channel));
+
+		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_with_nonce(2)));
+		assert_ok!(IncentivizedInboundChannel::submit(origin.clone(), message_from_channel(other_channel, 2)));
+
+		let messages = vec![message_with_nonce(1), message_from_channel(other_channel, 1)];
+		assert_ok!(IncentivizedInboundChannel::submit_batch(origin.clone(), messages));
+		assert_eq!(Nonce::get(source_channel()), 2);
+		assert_eq!(Nonce::get(other_channel), 0);
+		let already_drained: DispatchError = Error::<Test>::BufferAlreadyDrained.into();
+		assert_eq!(
+			last_event(),
+			Event::incentivized_inbound_channel(crate::inbound::RawEvent::BatchMessageRejected(1, already_drained))
+		);
+
+		assert_ok!(IncentivizedInboundChannel::submit_batch(origin.clone(), vec![message_from_channel(other_channel, 1)]));
+		assert_eq!(Nonce::get(other_channel), 2);
+	});
+}
+
+#[test]
+fn test_submit_receipt() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		let origin = Origin::signed(relayer);
+		let receipt_channel: H160 = RECEIPT_CHANNEL_ADDR.into();
+
+		// Receipts are rejected until a receipt channel is set
+		assert_noop!(
+			IncentivizedInboundChannel::submit_receipt(origin.clone(), receipt_from_channel(receipt_channel, 1)),
+			Error::<Test>::InvalidReceiptChannel
+		);
+
+		assert_ok!(IncentivizedInboundChannel::set_receipt_channel(Origin::root(), Some(receipt_channel)));
+		assert_eq!(
+			last_event(),
+			Event::incentivized_inbound_channel(crate::inbound::RawEvent::ReceiptChannelSet(Some(receipt_channel)))
+		);
+
+		assert_ok!(IncentivizedInboundChannel::submit_receipt(origin.clone(), receipt_from_channel(receipt_channel, 1)));
+
+		// Errors from the outbound channel are passed on
+		assert_noop!(
+			IncentivizedInboundChannel::submit_receipt(origin.clone(), receipt_from_channel(receipt_channel, 2)),
+			DispatchError::Other("unexpected nonce")
+		);
+		assert_noop!(
+			IncentivizedInboundChannel::submit_receipt(origin.clone(), receipt_from_channel(H160::zero(), 1)),
+			Error::<Test>::InvalidReceiptChannel
+		);
+		assert_noop!(
+			IncentivizedInboundChannel::submit_receipt(origin.clone(), message_with_nonce(1)),
+			Error::<Test>::InvalidReceipt
+		);
+	});
+}
+
+#[test]
+fn test_set_receipt_channel_not_authorized() {
+	new_tester(SOURCE_CHANNEL_ADDR.into()).execute_with(|| {
+		let origin = Origin::signed(Keyring::Bob.into());
+		assert_noop!(
+			IncentivizedInboundChannel::set_receipt_channel(origin, Some(H160::zero())),
+			DispatchError::BadOrigin
+		);
+	});
+}
@@ -17,7 +17,7 @@ benchmarks! {
 
 		for _ in 0 .. m {
 			let payload: Vec<u8> = (0..).take(p as usize).collect();
-			MessageQueue::append(Message {
+			IncentivizedOutboundChannel::<T>::enqueue(Message {
 				target: H160::zero(),
 				nonce: 0u64,
 				fee: U256::zero(),
@@ -29,13 +29,14 @@ benchmarks! {
 
 	}: { IncentivizedOutboundChannel::<T>::on_initialize(block_number) }
 	verify {
-		assert_eq!(MessageQueue::get().len(), 0);
+		// Messages beyond MaxBytesPerCommit stay queued
+		assert!(IncentivizedOutboundChannel::<T>::queue_depth() < m as u64);
 	}
 
 	// Benchmark 'on_initialize` for the best case, i.e. nothing is done
 	// because it's not a commitment interval.
 	on_initialize_non_interval {
-		MessageQueue::append(Message {
+		IncentivizedOutboundChannel::<T>::enqueue(Message {
 			target: H160::zero(),
 			nonce: 0u64,
 			fee: U256::zero(),
@@ -47,32 +48,36 @@ benchmarks! {
 
 	}: { IncentivizedOutboundChannel::<T>::on_initialize(block_number) }
 	verify {
-		assert_eq!(MessageQueue::get().len(), 1);
+		assert_eq!(IncentivizedOutboundChannel::<T>::queue_depth(), 1);
 	}
 
 	// Benchmark 'on_initialize` for the case where it is a commitment interval
 	// but there are no messages in the queue.
 	on_initialize_no_messages {
-		MessageQueue::kill();
+		assert_eq!(IncentivizedOutboundChannel::<T>::queue_depth(), 0);
 
 		let block_number = Interval::<T>::get();
 
 	}: { IncentivizedOutboundChannel::<T>::on_initialize(block_number) }
 
-	// Benchmark `set_fee` under worst case conditions:
+	// Benchmark `set_fee_config` under worst case conditions:
 	// * The origin is authorized, i.e. equals SetFeeOrigin
-	set_fee {
+	set_fee_config {
 		let authorized_origin = match T::SetFeeOrigin::successful_origin().into() {
 			Ok(raw) => raw,
 			Err(_) => return Err("Failed to get raw origin from origin"),
 		};
 
-		let new_fee : U256 = 32000000.into();
-		assert!(Fee::get() != new_fee);
+		let new_curve = FeeCurve {
+			base: 32000000.into(),
+			per_byte: 1000.into(),
+			congestion_multiplier: 4,
+		};
+		assert!(FeeConfig::get() != new_curve);
 
-	}: _(authorized_origin, new_fee)
+	}: _(authorized_origin, new_curve.clone())
 	verify {
-		assert_eq!(Fee::get(), new_fee);
+		assert_eq!(FeeConfig::get(), new_curve);
 	}
 }
 
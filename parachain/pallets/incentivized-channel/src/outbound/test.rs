@@ -12,7 +12,7 @@ use sp_runtime::{
 use sp_keyring::AccountKeyring as Keyring;
 use sp_std::convert::From;
 
-use artemis_core::{AssetId, SingleAsset};
+use artemis_core::{AssetId, SingleAsset, merkle::verify_merkle_proof};
 use artemis_assets::{SingleAssetAdaptor};
 
 use crate::outbound as incentivized_outbound_channel;
@@ -73,6 +73,9 @@ impl artemis_assets::Config for Test {
 parameter_types! {
 	pub const MaxMessagePayloadSize: usize = 128;
 	pub const MaxMessagesPerCommit: usize = 5;
+	pub const MaxBytesPerCommit: usize = 256;
+	pub const OutboundCommitmentMode: CommitmentMode = CommitmentMode::MerkleTree;
+	pub const MaxQueuedMessages: u64 = 8;
 	pub const Ether: AssetId = AssetId::ETH;
 }
 
@@ -83,7 +86,10 @@ impl incentivized_outbound_channel::Config for Test {
 	type Event = Event;
 	type Hashing = Keccak256;
 	type MaxMessagePayloadSize = MaxMessagePayloadSize;
+	type CommitmentMode = OutboundCommitmentMode;
 	type MaxMessagesPerCommit = MaxMessagesPerCommit;
+	type MaxBytesPerCommit = MaxBytesPerCommit;
+	type MaxQueuedMessages = MaxQueuedMessages;
 	type FeeCurrency = SingleAssetAdaptor<Test, Ether>;
 	type SetFeeOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type WeightInfo = ();
@@ -93,7 +99,10 @@ pub fn new_tester() -> sp_io::TestExternalities {
 	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
 
 	let config: incentivized_outbound_channel::GenesisConfig<Test> = incentivized_outbound_channel::GenesisConfig {
-		fee: 100.into(),
+		fee_config: FeeCurve {
+			base: 100.into(),
+			..Default::default()
+		},
 		interval: 1u64
 	};
 	config.assimilate_storage(&mut storage).unwrap();
@@ -104,6 +113,20 @@ pub fn new_tester() -> sp_io::TestExternalities {
 	ext
 }
 
+fn last_event() -> Event {
+	System::events().pop().expect("Event expected").event
+}
+
+fn queued_messages() -> Vec<Message> {
+	(MessageQueueHead::get()..MessageQueueTail::get())
+		.map(|index| MessageQueue::get(index).unwrap())
+		.collect()
+}
+
+fn queued_nonces() -> Vec<u64> {
+	queued_messages().iter().map(|message| message.nonce).collect()
+}
+
 #[test]
 fn test_submit() {
 	new_tester().execute_with(|| {
@@ -113,10 +136,10 @@ fn test_submit() {
 		// Deposit enough money to cover fees
 		FeeCurrency::deposit(&who, 300.into()).unwrap();
 
-		assert_ok!(IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2]));
+		assert_ok!(IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], None));
 		assert_eq!(Nonce::get(), 1);
 
-		assert_ok!(IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2]));
+		assert_ok!(IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], None));
 		assert_eq!(Nonce::get(), 2);
 	});
 }
@@ -130,7 +153,7 @@ fn test_submit_fees_burned() {
 		// Deposit enough money to cover fees
 		FeeCurrency::deposit(&who, 300.into()).unwrap();
 
-		assert_ok!(IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2]));
+		assert_ok!(IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], None));
 
 		assert_eq!(FeeCurrency::balance(&who), 200.into());
 	})
@@ -145,7 +168,7 @@ fn test_submit_not_enough_funds() {
 		FeeCurrency::deposit(&who, 50.into()).unwrap();
 
 		assert_noop!(
-			IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2]),
+			IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], None),
 			Error::<Test>::NoFunds
 		);
 
@@ -161,32 +184,223 @@ fn test_submit_exceeds_queue_limit() {
 		// Deposit enough money to cover fees
 		FeeCurrency::deposit(&who, 1000.into()).unwrap();
 
-		let max_messages = MaxMessagesPerCommit::get();
+		let max_messages = MaxQueuedMessages::get();
 		(0..max_messages).for_each(
-			|_| IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2]).unwrap()
+			|_| IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], None).unwrap()
 		);
 
 		assert_noop!(
-			IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2]),
+			IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], None),
 			Error::<Test>::QueueSizeLimitReached,
 		);
 	})
 }
 
 #[test]
-fn test_set_fee_not_authorized() {
+fn test_commit_carries_excess_messages_into_later_commits() {
+	new_tester().execute_with(|| {
+		let target = H160::zero();
+		let who: AccountId = Keyring::Bob.into();
+
+		// Deposit enough money to cover fees
+		FeeCurrency::deposit(&who, 1000.into()).unwrap();
+
+		let max_messages = MaxMessagesPerCommit::get() as u64;
+		(0..max_messages + 2).for_each(
+			|_| IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], None).unwrap()
+		);
+		assert_eq!(IncentivizedOutboundChannel::queue_depth(), max_messages + 2);
+
+		IncentivizedOutboundChannel::commit();
+		assert_eq!(IncentivizedOutboundChannel::queue_depth(), 2);
+		assert_eq!(queued_nonces(), vec![max_messages + 1, max_messages + 2]);
+		assert!(matches!(
+			last_event(),
+			Event::incentivized_outbound_channel(crate::outbound::Event::MessagesCommitted(_, 5, 2))
+		));
+
+		IncentivizedOutboundChannel::commit();
+		assert_eq!(IncentivizedOutboundChannel::queue_depth(), 0);
+		assert!(matches!(
+			last_event(),
+			Event::incentivized_outbound_channel(crate::outbound::Event::MessagesCommitted(_, 2, 0))
+		));
+
+		// Nothing left to commit
+		let num_events = System::events().len();
+		IncentivizedOutboundChannel::commit();
+		assert_eq!(System::events().len(), num_events);
+	})
+}
+
+#[test]
+fn test_commit_respects_payload_budget() {
+	new_tester().execute_with(|| {
+		let target = H160::zero();
+		let who: AccountId = Keyring::Bob.into();
+
+		// Deposit enough money to cover fees
+		FeeCurrency::deposit(&who, 300.into()).unwrap();
+
+		let payload = vec![1u8; MaxMessagePayloadSize::get()];
+		(0..3).for_each(
+			|_| IncentivizedOutboundChannel::submit(&who, target, &payload, None).unwrap()
+		);
+
+		// Only two full-size payloads fit in MaxBytesPerCommit
+		IncentivizedOutboundChannel::commit();
+		assert_eq!(queued_nonces(), vec![3]);
+
+		IncentivizedOutboundChannel::commit();
+		assert_eq!(IncentivizedOutboundChannel::queue_depth(), 0);
+	})
+}
+
+#[test]
+fn test_migrates_legacy_message_queue() {
+	new_tester().execute_with(|| {
+		let legacy_queue: Vec<Message> = (1..=2)
+			.map(|nonce| Message { target: H160::zero(), nonce, fee: 100.into(), payload: vec![0, 1, 2] })
+			.collect();
+		migration::put_storage_value(b"IncentivizedOutboundModule", b"MessageQueue", &[], legacy_queue);
+		Nonce::set(2);
+
+		IncentivizedOutboundChannel::migrate_message_queue();
+		assert_eq!(queued_nonces(), vec![1, 2]);
+		assert_eq!(
+			migration::get_storage_value::<Vec<Message>>(b"IncentivizedOutboundModule", b"MessageQueue", &[]),
+			None,
+		);
+
+		// Messages submitted after the upgrade are committed after the migrated ones
+		let who: AccountId = Keyring::Bob.into();
+		FeeCurrency::deposit(&who, 100.into()).unwrap();
+		assert_ok!(IncentivizedOutboundChannel::submit(&who, H160::zero(), &vec![0, 1, 2], None));
+		assert_eq!(queued_nonces(), vec![1, 2, 3]);
+	})
+}
+
+#[test]
+fn test_set_fee_config() {
+	new_tester().execute_with(|| {
+		let curve = FeeCurve {
+			base: 1000.into(),
+			per_byte: 10.into(),
+			congestion_multiplier: 2,
+		};
+		assert_ok!(IncentivizedOutboundChannel::set_fee_config(Origin::root(), curve.clone()));
+		assert_eq!(IncentivizedOutboundChannel::fee_config(), curve);
+		assert_eq!(
+			last_event(),
+			Event::incentivized_outbound_channel(crate::outbound::Event::FeeConfigUpdated(curve)),
+		);
+	});
+}
+
+#[test]
+fn test_set_fee_config_not_authorized() {
 	new_tester().execute_with(|| {
 		let bob: AccountId = Keyring::Bob.into();
 		assert_noop!(
-			IncentivizedOutboundChannel::set_fee(
+			IncentivizedOutboundChannel::set_fee_config(
 				Origin::signed(bob),
-				1000.into()
+				FeeCurve::default(),
 			),
 			DispatchError::BadOrigin
 		);
 	});
 }
 
+#[test]
+fn test_fee_grows_with_payload_size_and_queue_depth() {
+	new_tester().execute_with(|| {
+		let target = H160::zero();
+		let who: AccountId = Keyring::Bob.into();
+
+		FeeConfig::put(FeeCurve {
+			base: 100.into(),
+			per_byte: 10.into(),
+			congestion_multiplier: 2,
+		});
+		assert_eq!(IncentivizedOutboundChannel::quote_fee(0), 100.into());
+		assert_eq!(IncentivizedOutboundChannel::quote_fee(3), 130.into());
+
+		// Deposit enough money to cover fees
+		FeeCurrency::deposit(&who, 1000.into()).unwrap();
+
+		// With 1 of MaxQueuedMessages queued, the fee grows by 2 * 1/MaxQueuedMessages
+		assert_ok!(IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], None));
+		let expected = 130 + 130 * 2 / MaxQueuedMessages::get() as u128;
+		assert_eq!(IncentivizedOutboundChannel::quote_fee(3), expected.into());
+
+		assert_ok!(IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], Some(expected.into())));
+		assert_eq!(FeeCurrency::balance(&who), (1000 - 130 - expected).into());
+	});
+}
+
+#[test]
+fn test_submit_fails_if_fee_exceeds_max_fee() {
+	new_tester().execute_with(|| {
+		let target = H160::zero();
+		let who: AccountId = Keyring::Bob.into();
+
+		// Deposit enough money to cover fees
+		FeeCurrency::deposit(&who, 300.into()).unwrap();
+
+		assert_noop!(
+			IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], Some(99.into())),
+			Error::<Test>::FeeTooHigh,
+		);
+		assert_ok!(IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], Some(100.into())));
+	});
+}
+
+#[test]
+fn test_migrates_legacy_fee() {
+	new_tester().execute_with(|| {
+		migration::put_storage_value(b"IncentivizedOutboundModule", b"Fee", &[], U256::from(500));
+
+		IncentivizedOutboundChannel::migrate_fee();
+		assert_eq!(IncentivizedOutboundChannel::fee_config(), FeeCurve { base: 500.into(), ..Default::default() });
+		assert_eq!(IncentivizedOutboundChannel::quote_fee(3), 500.into());
+		assert_eq!(
+			migration::get_storage_value::<U256>(b"IncentivizedOutboundModule", b"Fee", &[]),
+			None,
+		);
+	});
+}
+
+#[test]
+fn test_merkle_commitment_proves_each_message() {
+	new_tester().execute_with(|| {
+		let target = H160::zero();
+		let who: AccountId = Keyring::Bob.into();
+
+		// Deposit enough money to cover fees
+		FeeCurrency::deposit(&who, 300.into()).unwrap();
+
+		(0..3u8).for_each(
+			|i| IncentivizedOutboundChannel::submit(&who, target, &vec![i; 3], None).unwrap()
+		);
+		let messages = queued_messages();
+
+		IncentivizedOutboundChannel::commit();
+		let commitment_hash = match last_event() {
+			Event::incentivized_outbound_channel(crate::outbound::Event::MessagesCommitted(hash, 3, 0)) => hash,
+			event => panic!("Unexpected event: {:?}", event),
+		};
+
+		let leaves = IncentivizedOutboundChannel::make_message_leaves(&messages);
+		assert_eq!(commitment_hash, merkle_root::<Keccak256>(&leaves));
+
+		for (message, leaf) in messages.iter().zip(leaves) {
+			let proof = IncentivizedOutboundChannel::make_message_proof(&messages, message.nonce).unwrap();
+			assert!(verify_merkle_proof::<Keccak256>(commitment_hash, leaf, &proof));
+		}
+		assert_eq!(IncentivizedOutboundChannel::make_message_proof(&messages, 4), None);
+	})
+}
+
 #[test]
 fn test_submit_exceeds_payload_limit() {
 	new_tester().execute_with(|| {
@@ -197,7 +411,7 @@ fn test_submit_exceeds_payload_limit() {
 		let payload: Vec<u8> = (0..).take(max_payload_bytes + 1).collect();
 
 		assert_noop!(
-			IncentivizedOutboundChannel::submit(&who, target, payload.as_slice()),
+			IncentivizedOutboundChannel::submit(&who, target, payload.as_slice(), None),
 			Error::<Test>::PayloadTooLarge,
 		);
 	})
@@ -211,8 +425,63 @@ fn test_submit_fails_on_nonce_overflow() {
 
 		Nonce::set(u64::MAX);
 		assert_noop!(
-			IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2]),
+			IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], None),
 			Error::<Test>::Overflow,
 		);
 	});
 }
+
+#[test]
+fn test_message_status_tracks_delivery() {
+	new_tester().execute_with(|| {
+		let target = H160::zero();
+		let who: AccountId = Keyring::Bob.into();
+
+		// Deposit enough money to cover fees
+		FeeCurrency::deposit(&who, 1000.into()).unwrap();
+
+		let max_messages = MaxMessagesPerCommit::get() as u64;
+		(0..max_messages + 1).for_each(
+			|_| IncentivizedOutboundChannel::submit(&who, target, &vec![0, 1, 2], None).unwrap()
+		);
+		IncentivizedOutboundChannel::commit();
+
+		assert_eq!(IncentivizedOutboundChannel::message_status(0), MessageStatus::Unknown);
+		assert_eq!(IncentivizedOutboundChannel::message_status(1), MessageStatus::Committed);
+		assert_eq!(IncentivizedOutboundChannel::message_status(max_messages), MessageStatus::Committed);
+		assert_eq!(IncentivizedOutboundChannel::message_status(max_messages + 1), MessageStatus::Queued);
+		assert_eq!(IncentivizedOutboundChannel::message_status(max_messages + 2), MessageStatus::Unknown);
+
+		// Receipts must arrive in nonce order
+		assert_noop!(
+			IncentivizedOutboundChannel::on_delivered(2, true),
+			Error::<Test>::UnexpectedDeliveryNonce,
+		);
+
+		assert_ok!(IncentivizedOutboundChannel::on_delivered(1, true));
+		assert_eq!(IncentivizedOutboundChannel::delivered_nonce(), 1);
+		assert_eq!(IncentivizedOutboundChannel::message_status(1), MessageStatus::Delivered);
+		assert_eq!(
+			last_event(),
+			Event::incentivized_outbound_channel(crate::outbound::Event::MessageDelivered(1))
+		);
+
+		assert_noop!(
+			IncentivizedOutboundChannel::on_delivered(1, true),
+			Error::<Test>::UnexpectedDeliveryNonce,
+		);
+
+		assert_ok!(IncentivizedOutboundChannel::on_delivered(2, false));
+		assert_eq!(
+			last_event(),
+			Event::incentivized_outbound_channel(crate::outbound::Event::MessageFailed(2))
+		);
+
+		// Messages that are not committed yet can't have been delivered
+		(3..=max_messages).for_each(|nonce| IncentivizedOutboundChannel::on_delivered(nonce, true).unwrap());
+		assert_noop!(
+			IncentivizedOutboundChannel::on_delivered(max_messages + 1, true),
+			Error::<Test>::UnexpectedDeliveryNonce,
+		);
+	})
+}
@@ -0,0 +1,41 @@
+use hex_literal::hex;
+
+use codec::Decode;
+use crate::{BeaconHeader, ExecutionHeaderUpdate, H256, LightClientUpdate, SyncCommittee};
+
+// SCALE-encoded updates from `tests/fixtures/beacon_updates.json`. See
+// `mock::BeaconFixture` for how they relate to each other.
+
+pub fn genesis_validators_root() -> H256 {
+	hex!("113c5de3731b7bb2b125910b945c7fc8afd67e8b5ecbd79f511eaa9a1b6ef832").into()
+}
+
+pub fn initial_finalized_header() -> BeaconHeader {
+	Decode::decode(&mut &RAW_INITIAL_FINALIZED_HEADER[..]).unwrap()
+}
+
+pub fn initial_sync_committee() -> SyncCommittee {
+	Decode::decode(&mut &RAW_INITIAL_SYNC_COMMITTEE[..]).unwrap()
+}
+
+pub fn sync_committee_update() -> LightClientUpdate {
+	Decode::decode(&mut &RAW_SYNC_COMMITTEE_UPDATE[..]).unwrap()
+}
+
+pub fn finality_update() -> LightClientUpdate {
+	Decode::decode(&mut &RAW_FINALITY_UPDATE[..]).unwrap()
+}
+
+pub fn execution_header_update() -> ExecutionHeaderUpdate {
+	Decode::decode(&mut &RAW_EXECUTION_HEADER_UPDATE[..]).unwrap()
+}
+
+const RAW_INITIAL_FINALIZED_HEADER: [u8; 112] = hex!("404001000000000001000000000000001cbfded636a1a39d70ff887772c99bcc1193b92433fcd0838044380bdec66e5634bfdaf181b16dc8902f66c43839c75622cb8d085907304393ce229d25a13383712c477f61197830dbb154880e5dd931a358a95f6f87794c8a8a8d1c1db7cd46");
+
+const RAW_INITIAL_SYNC_COMMITTEE: [u8; 24626] = hex!("010886267e6eb29a78fd0de842936a2f81a40a6fe22764ee19edb20aa92a856b4850f258a47699d3c28abf91a8706ca4ce8cb4751e5ccdaba62fa437fd2d48ba685a09558145a95ceb15d3ff19fe45aa3ea626b9cdfff6d7fc2aeeba418ae45cabd6a1cb530a6fc3c7f47cecdefbe8ecca0863cedeb140921e9f6d59a60985141f391f8d8bcd7dc33218a227dee06c872cc7878e06852f6d87c363d74d4b927f3dc6dd7b6b57cf6874da90c44663b547bea3ca3fc7e56793fe19c99e3030791f86e9a08066d03d5c5479b87869fbc9aafe4e41191aef12799498929a807863e84c7f1d6a1149e4a67e6159c869376bab452b94e9b4baf6255fca0b8a5f06441744fe7e5a00098227173f6b83ab614f1ff32d6ba3d8f413427537d01bec63790489c4a15f4b139ca99ee93c01619c91c4e9e5e20b51b11921b314469a99e5525ed5599d2c299f921aacca6c809273e2468c5e93c8230f909d8fc965ba0ae9de7d36fb17827827ee4566f648eaa17058143dea5931481bc6cdbd28e2ee95ba2b3b4534a4f8bb45afb41047fc1f93f581515ae44afca05d581401e3d1b341cbb07b62421e9a3844c398fbf6b2ea92a7e1599e2ea9a40ecaec1624ddc4359ad1be8eff06a89d99737ef241ba1f338236016fa2d829313e305359f08be23943eca296b0958ff0918a2ff5e9ee0bf5309c0607d36bf3d9fc1a3cabed0321438d593f577dfabf704a7afec6d47de50ea9cb55c8029bb67a6709d76b116edc34b1e3356b04ab2ef99d2b899d29a49951c12acb635fc6d6cc0e8a26bd730266733495efe6cb3d8ade353fefa28bf855bde87f611e7d471b617f3f78d021c53eff2d8506ba960b9803fb4d83869a4147f2c95e7ca84c98b57d5649a684fec48aea4a83a008c87b6dab9f26bb4fc424f5c668b86c6314d4c4b2cf252569a72700945f831f72adfaaf6bfcc015fad2b828bbc4852925396d7bbb36a2bf0ec48f9125e7035447f333232dd93faa200a1c0eead93c21752c53a9c6dc0375e2c0fe5dea897ce6f8e38309a1f3d7ed48c51e688ee3533da5e00ba5391b6a3da80d83dbbc7ea06cc3275f99a1003283c9df637ed99b044e472f85fed810eeef2aa0935f02fc197c5a4d3ca650a3cc58b181f8063bb775c343df2aae2f43ceb1b2b28a6c974c34ec0ca3eb86bc13bab00f93e361fd1fb4f6ccbab82bb85b6341c2ef1f81d5b1b43378c6ed87a67f79b80df3d9483e57b2f73a1f2bd5c4005e7cf1b7a6cd38cc50bc2af6aeee43ed0b6cde7db9b18a1575e41bbd86b09e03729699008907ebed1c018bdb9657549d089b96144022428e1e53b453bb5bd5d69265b9ac4f8463786d29ec6a9982a11b9f30bf773dd6afa421c2a41e1f82ef1260af5720dc40975bd5f3b1faf011c7d7222987a3c47b9e15475f6634a59075909bfd08f9a708431e195d1c987e42610efaa3d3a304404aaaed9aba18c819c0213b330067d2be687c8e5046104b83553ec91c32fca43d018bb8c101d12541c18ee6533ce4b1d8fb2fef806ddc508420fe7c839542dd1ae2909252f66e39b93c8ae9426e8a0a9df3094a462d6e7ddd813590784773f4e99d9c0229a42fb3e45bc9e0c7ea149717109b1cbb82c9578f04c370336a5c0cef602d369eecac0333f672e03ae68ab7d4484d9645ac16e196435f056a6eb0e0ffc8fdd0573702348763402a7eb2b7652f550d2ae661fb440d356dbd25adc296bd82e2a16b945ccf4ad4c812ce04924357c1ef0351eb24caa1f367ee578347946f87ef26c19bf10065c5796bb80a82d05b0499a0f64b1db4ba67e3111ed5edc1b67f96abddeeaf438683f3af5937a72acbc828274a678d03e2f7e4e093f675a87977ae5ccba8c776ac6488613cc0e0d5793d9105f356a8c98f2be9e0f204e1b1b05508c6f193dc665eeae1991b9f36d61086e7dda6e6b287d9cfc9ad9e46e7355a0dee1a168dbabfb1f63dca9f1618e95489ac1844c1d13c4eb0f19a23354aaf9218d7af8aac91677d54691bd725cd1623afb3afaae6d8c3b01ae0249f7d5a5ade8d882514ac54f8d3538b0f05ec8d8e3d28177ebe4120f0ee1dc3e383f64306247236228bf8abe0a155c19fdbc99167740fd67dba7ea2674ec30093fd66437c0cdf0231a2dd406fb13c0d1b08fcbcb37bc618583bda705f8535efb6773a147950408521186a0317f422dcf7d753fac83b4cc1bc2de85d7b33c39d0f70cfb7ab83b7ec6f841cf22889952e3adc536b2ae622e16c8734f8a3beeeebf934fa5338d45505941ed6de05fe7de015fc82119d1dd2e35e2f559357a710d9fcf20351adcb61b7ee6d5342711f938d7cc9efd4d60e8d3490722f709045251c3e0966505b78e7bc82a1cdb93e98fd2865e0caba6c5dcc6405492f13fbd13e6b7e64edfcf9c6b23d1306bcb57784a5419bee5083255964a20f25b9b6e095ae5dfdb56b6640253d44bdc8f7a088283b33c6e80ce4e684cfd941b4b2885a2a85cf0520034ba28682e18a42741acc8a20ac9dd397750fb33b7df02866b71537ce1cf633a3d1c9c9f12acbe4ab72d5475d3c02c03de7e6b57caf35899d17368530da12ad44d5d5ddeaea31fdb63b996cc1d6b9b52ad6b99bfd26b4e291215d9f8d85bec8c53522cb00800f848169888bffa66492779398f380411182ff6322d95550ea7ae164961dfff4aac1266bf6564734d8c69975f487713af3303f800b818c463172424a4fac54280e11158788205b701b808c9793bd85646b43a885dd6ea8ac2a963a21ac79f6d3ccf1d9ed1faf49d28ae2bb455bf25c940a968f65ffc47bcbecf1c0b5a27f254430f71e9565d33bd497933a056df510372b342cedce8543cc338cca2401c182ca786e0da982dc03069fd8ffd42a7c2c3bf8c0ff4e0bfb34f02a74f8f11d8f62f52b8a4d0e9cad1dc37e9367ecb38ab04b7cde8ba32bb6058303df5d026c42795d78b1ee58d542de56474582ee82ea85c3be6dc1625c84457d482ccde344a2749c311832e6c8b5361d55808da6013b0358efa7ba136e7e7bf864d664765aefba7afd6e66e495815a81d79332e3fc140180089df8119cb6299fda1131cbe66a046bb674f036b3cdd8ad6a87e633bcd9f387bdd51b03479707871472e8b2f9e957ef7b64bf97a23638f09450c2c04f1dea761d68f2982d40ebfb680e2223c77c77f9e2d9e023b4b7e0e53d67ed42753a25967ee81f0a2a04b79b1dca055923ac66d76a1c02d7f11f9655cbdbeb254f92658efc90fea8ce853fc256f00400039bec503a45d797b45d1741bbbef41f41524fca979f96d7ddb107e8f9ec932302049a13a5a8d09c57919cfb756cb3e04ae57dafb1d782bd2e21c39be3351bb508d66f140abfd53f9d424c39183a49b4fab3afa42236cfcb12a85bfde256bf2aeb69c5f3e5eafea99a90db7eef8ea125c67f106bcabf12b4844df447474c2f6e6cb549e50cf7fefca0b1d92882357c3a465e69e76c0f84463c5502db092e09c484462d15a80340c58ca169e3f171ab98b56817ab14faae2683a77eabc76f26b5a0b380ddc488866eab51214d1f4667d43aa343ff8bb7c2862e7be098b80cba172d329374b0d2780defac198f615d26f7f7410940023cb7794171268dfb8bf77aa221115c7b368d68a421f0ae976acf13f8ba4c45ecaea6a756aac053111a5100543355bb98a4c79708c73cc708e27f4b6ab3c3641378f0d7348199fa424adf35c69ce2aa919dfcfc53aa8cbc9a1cf5372309287d51b7102e13a69b34e0ae74d8f4c9cea5c29a50ef5ca30251afd258edde3eb0c81d23350f808738895a9df78d13712ecf06fbdf52248de9ea21cab8b0426dc86587daba75b27ffa868f604219b3672dbe7cefba5276a04d248612c87c033276dfdaca00a2217239db3893f916136f3413e07b08c5e7996588bff5226f5ea839a3c79c98ff07a9dfa807946ae780d7ebfaf53d2e0b817b50d92e4a89708ba3d66d989950c5883efc8eb6fa4402b2f8aee7bf8a22f45e8b488fd499b8b6e1db67ae4fdaac99421fa720745479ffa103e4ea896d74a784a72174faf32273638899f6949f7a7f4f8b9f5543327920a6acde00cad6a8e6f4068e7d1a0f375c6a239d086bbb7c39f3b366e79100febe96541a15e8e046b7dcb4b10af059754f5156dc21c6aeec65d8ce1eeb90d613ba31bb0ef5b5b5e885e19f3501e7938e317f8d914be0b609f27fb8483ab770eaeec259ad7dc5efaa3c47b616ffa93dbe1f6a7b270593e7ead33bd4065da6ba85130defd7c984eed05a11aa1bbddfe28f715871e88d7b321b3f8daf2dbd443a3354cce76d201342b210f15bb950f6b24a98261f523df1ed63f0c3aad808791098e5c30e3f16e5ab3a91cb5c8c4d1ccde99901492cd8dd84a189b3c9b4008631def3dfa95a4409507f9315928432e6b14926bdd3f4d80fa641b45559c6d3fdc7c644540af5477fd24ff7c5642e8c99d1e35f0bda109d29a02d20aa9130bade41b6f3106e06b383e2c02f67477904bc3f95f1b14240db1ffa97779a3b576d5eea9618736f3f401573b12e008a1e0fee8f7fd29a92471aefd94a1d3efe5537d9b4c86b0b7c738c1cdb433d9f23fb4193d71286a39f6e269a50d9a61dafc32c92bd1cb8d14f556ff12bd2976b6ac9493169559cdbd94baa769cd9915867a0aec19590c4e71649c27a0c72c0c8b483812a7328ba286337bda4c4b86737273c0191fe0efdac549d1700d35f008c1adae90a9973ff85c1baf1119023496bac8b32e8ed31d0f6a5474c63c50e87a73c2123e66e0dc19145474c678f3251bd02cd9c5f482d7d730e53c12aff2d2b9bb7ecce306b231efab5c1d6affda7521230139ae863edb109892b4859002f6700bb6ced0d12ba1e00a80fe7b38da592f6992448235cd14329f18c6a7b09dc01eb53ee175599f09110a26ed6a5ce386ab34a03786837e760839e58a7105b1eaf18a122c6d493cacf65ebb04560858a87bb6fa1f4b1b0ffb88ab76f7d95ca98a09049246cce7619c5ae718fd6619e8e935e9733eb05ecb0a98f529220d97927caface8a314cfea289021aab255d696f1a1ee55587ce474fcc9e0ff0e24f086435dab38669dad5672dd59b6702df08f87dde7caa6e887f565b11175e4e1c6dfcca5b5f89d09f5c7d69360af69ffc07cd4179946dad7639e91a1c1e3e66042934ddddfea48e05bbb115c438f3fa506937d6513e0590f6b459e0938fd90531c29b3c3eabcf4fd9180fcee0a340f65cf88cd3753a20e1ad6a20cdddc2763992616b54c7b4bda13f32e0024989ac3f73a852950795e8888267167e8657509351545a77880c1e7e2c6af9ab9237421c80f85f0b656e028c2773d9a67d4f08d76856c2fe1ca08f987a98858631bdfc4b101b37220d4e4561252fdd06f6c2cd535b993c64599919ce16b218a90d5d1211bbe112274c82e7731cee7b616703863711d9705007c009b7fc4df73b58c792133cbb1f07026b7f63a082dbff555872aba504b7d04faefd714b382a43583443883d16a4b6707da909d9cfd081e306932f8cd5872899c3ec0ad2bd6d72a9a06ab3b7ba9397f28244977e586a3facdee930603fd7ff7679774680290004e69bf992fae4a29ae354cdcbc90c02f1c9ebd1264ffdd000ca864c71107341407f63abf3f736ad4351f795b580dedd6f0f337a9c23f438f5203a71827f497a65a54da58e5caec1ddd7a5500f24a54ee9d3b8b61c2d813bd0fa1f16c2effefe21ef9dcc07359b9b8a4ad208c4a46d481760c20ad1922ce72a9397d8e203e69efe74255149538a5473685bdf96af222cdc5ae9373f8d6be53204c9095c0ec7be48bb50c98cc4a4c0ed2ab2db9f0e56337b79ec4456619b0506acf6e725fbcc7c9d5a8d2c69aac71c019a039166ba4371805f7845019ff55c898f87f9b5fecf2f66de01f20ab465df465c82fe4f3bbdd6a7a35728a19ed5b651029edf8a7dec0491c35a075436368f42ac85207e3036009400426cb4efe86239815c0d5f3c00d9b998c3c435ad13d94bcb1fe0deb758068be4fffd14fc8aec01bfac8ab8a9fc9d7843d7218800801fa2a58bab44dd1e8cb5fb69e92fda70b308b5d40502b959985277c67209f4982b69faada8237c26c3699b77a5a6c7e5df87e657333dce21161109eac1c483d722d40d2757f7b29e76553b13d58bcf2a524225b23cdf33e16ee0bf7b7bcaf58ccfed8c50e8dee59ab9035e0f932a34041c6b080cd3d9a5a984a92102be720bfac6d5a69102a6902ffdd1cb60593df1a857c5715c72ae4c041f64691a166080f704728776968aebceca75a2f41dc06c072fc3b8804e9cb15fb9e60db2fc37b9a1599ac4919075bef6625a07324ff563db139f08221c799c91c121a889ca3c8adfdac309a039eea65a3cc5af7a545da1fa97ef8cbcbe350464eb10f2170552e6441a086c872807061dd1e0a32fa3315053375279800642522c973e4e2eb90586535ea72203cf39fe15290421056838e8f00fd884bd9948a3d945b4d5ec44e4c01681284f8a8452ff5070824bae99dfce6801a6f88ba6cce13475fdd0cf55f2fb5a148a248d5f55eaaf74b14b46dc7d858c1bfee3affe2359cecec5dca0fe4afa3e2a4886de1bc08519ec3267a3570c69ada96e835158846026418aad75f31d8f9bbf609686ef491a729544b9d12e3496fe1c6fea030da68e24f2e45c8006528815ff7184eec4414eaa8481e0074521c578e21247b86eef9a7454ee079a513f84c6419f8f3f9ca205964ff56f32c2b1c94b07b52830f55a0e5a7b5c1c0c2a9afe20fcb32d9979667bd9d35d9e224157369041bc2ae5f22e3f0ddd83bf02908d8ed5d7c589c0bfa3a4d021745df7dc2baece8345cc95ba4b1c1941a20719f047f2bef3b839a8c713cdf385608d183de5319bcc95ece9fb7cf7c2a99ba6397bad290c9b15b4ad8b52c904c84732181c1456fec18fcc7ddfaa80560e8299c996474819f45a66cc99267f3f31ba6c57c1f68023bc59d884035c3b3c70eb9544e51e6ce657a8a1a3a0e615995b6e1200613aace9e80cfb0126df13a6d234836f166908db24649aa7451d15ebad0e96bcec0ae515b6c73755bb192434eadcf7eebe1de63c227ada58a0a3f48c5bad1486daa0a43a55e751817c024900820716313ff9e3793e102857a3c5dd8f756beebb7cb615ef6bfa2b80b7a7b31e1e026b010ac5f857764055817b13f8f70691e47079380b665dada72d73298357e50f2160c3534c8f6caaea77bbb2a12bf21e087b3d1d0c8de51905b13cccac14da7278ed67203619b82496439ddb2ca5df7b4ffafdd73f0fa7f77e28a727c572203442bcbe3391b83cbeea81f881ee1b3a0d9521aa9363becee0e8d73356915a2e0ddeaae0f048059e31ffa22a9b1caceee8a91a58d854cde45814b359656b1e8da82655776ad5fc80840254650df9343fc4aab15732988e0734901da054f7faa8d8ef4c341160b215f3ffa77efa2901251a056ec91034385069a0b7a257983b9f6413d19412ac17a441d31ecd48ccb72d143df3cd504c8e0ecdb991b3ae67fc3dd3610e9b4f13ebc295212a8813b00181ef7d29b711472462481800b419aa5b35ae1ee08f537242f50585a7d344dabec8914aed510fc3464b294d9db7be706aa9033dc7463c1178dcc58b43068e56f96a98a3b3c77c5e24809cf889ddc573db346e92ef0b17efa6e15e37d9e84c355f674c81265f2b3db820e63a9604c628e1254bde18ff3f25281094bea24e0eaafc283e8fa7def5d2d94c271a485145956e392155058b3a6925225f7bb3c70e4d32dd6e61c681b702e165b9c299fef52df2cb05af076c87ebbaee4a00c4d28d4f9921043c41869c15d5c9acfbf5773455cb34d16a7ce1900ce941cf5399b309ead7e20bb9264479fbd4964c42fd349bcaacd25bf7b3734d37e50775d4fccb3c893f78fe7c569bdd5d58dfb200a5e10821727d362da80cdbc875d77fd86334226c0c7522ee18a4a59b6ed98a0adb81dbd77072b835e98a3b4b40557abeb612b890d96d40b19d4c43e9349520e7d827c7a2e262de138fbf32909b1a7aebaf43d30a5db2137d06a0011b0da79c78b3ae6fa418edc24cb9a4639112948c97c39d2a052ca1c4f66e11226737d0b61b93cdc1973bf3393f156463e072f565e78b43524a30ac57644f88660acc05d1bf7a0c248cc5858fe2199f5da947994990f42698d090457524a4749a5207535ea6800cad21162e5f58e5f87eec4e79f9f85d9a872f48a3749615de483ac3567be777eb5cd898148b9249dee0ec3ed9559f94e130c849850e1741a78a4d8a147d38bb7c7da50464d07a2423e66507d8aefbc2c065a0d28c3632033cb19fc6664ceea25b3cb362f2e477cf4bf66affe4aa68765060529d0f7df3f251cdd59c1a581d82dd75f5d1a69a6e54a0d0c8a25da730972032901414581fc08c7decc319d8ea45a58a9f8236bddf2f3ef35f8fc796cf4615a4eb8763632de83e8e0dac6f7968ac9906b60725a48bb6e341fa4b8e2107d8df51ab51ad1923b9bc9c12aa774e66d90e0ec4a2f20fad16d81cb5af45d669b46595bfe57df80f513562f291bf0d71ae36be79ce70f44d8405e8b231f189c140df1b0211019d76556c41ee1a857814aeb01d90d040cf148423aa0c06685361e3bd25e3a6881524ad71a65c5aa37496e73377f4d13a8363e44c7520fc52a88280eb7f0bff766bbb3454d5b5256793cf40e1d244293df85f8bed7d6aa564d9eb89668d5a9cd6464480fc0c3f53a8352da4e079c4af4434eaa2c2d2ab181888b62462a231ebb9d9285bffbff7ba8b4f1a845b700f33a1a2e5a2c315b574a6218aaacb65314561ffc7027b1489d1d307dd32d0852ee591c552e10d2d901a6f8a08715390e2b8a45250d23605a22383d23f93f25a85c152247aa89c35a14cce18f51cc51e75790773761b7c2cf8dbbdce0dbba6000739bb76668720100609a29d1f91365134900c3e17e60259247f76cc6515b676e26f0e528fb9f504a9c6b5e22f6c9928d5eedbeffbf1f28fef1f790e47a43bb4fb37aea8dda035c65e8b475611985720e2ed2aff8d0cf0be354afacdada7f0e9984c0bd0a24607149de57f72d5a3f270f9b468c082eef36e225f3ca7900e16e3040be2ae5abfb26b9ce0d7bb4141a4c4582a9e9c3998fca8c3cae799d1a4a226112191bf9b6ec5c979249d999be1ea70ba5412e9818a6cbf71dab45a6849e77c51f923984d057fbb2c3c499e9881f9a6ef58fdc434ca7a3bdbd0477c1533356f4adf77d6438ec792e0947bfca5180efa97c27a4f5c798fd3ad809128f3b2b052d3db098ec871ac5ef1f85d0ce3df86a19c6fc25533247ae93f885cc41b84fdb64b926977b8da7405edb99d8d8aa943a7d904c4253a32ea71a6cfced4063d9d23ce16f84056ae8637a96572b5aa9af1fd62262836b9ad2875a03d5361c5817671eaa7a74573d27cc70c78e8e0a56b144b6b72a22449d81538235f774a0550a10931552cb2c24e35dfe5ede3bcf7b64eb874d59573a090d461ea2293dba9ed4d46ae7709475908464b091474c1924ec08902162f95d235cf8e08a832100eaea4578fd557fe9a1344da3e0eff29ebcb1133a0f9d0766b17860d5b2b51a3e4b7a443d8810b8fb505e4ab8e10d31de7b5c675dcb8192f4c61c59dd1ca2064e14f781bf16fa3feb01427a285407ce45bf5f6ab3944b174c2054064cc1fd533f3b8c8cfa89c3b4b12e900e056bfb4d3782dc296b080d3379b7bf63f1e93968267ed1a0f9cdeb9b60a4f1c7ebedd1491a697cc2e84ae2d462174d39761ac8052eb5b6737847ca74223c4c0553676e0e40cc373030c41d01127abbb75595fc89297b0efa5ba9100bffc087a127ca9dab83d05b5800914337a3ac9f131782beb61a973041a0a9e76e3c59bd73ee128ac0812ae358fb714088d39ea62acfe3c6b96e43da495060acd17b8913bf6869ab15a5229b3365bcbd7432f0f426d5432d52446a55a2caaf6439a289a3f7ae2a6e43309473208a3f24cfa75940f5e8a5d81eb4227521829d0687dfb0891b65a5cb2aba9b74ec9059f4bb9db1d547a5d5ca484c16323d20b77ab33b57b52d35e3ad0544c8a72e02d1c8b2c00aaaeedfb1b66be1e91f70efee105429e6f1b411c64556f87bba91584405980dc94e8d3cc37c0ecb4ea34022a2517f898bb0a1aaa49c83d7585d4ca192bef3b0e8fa0a70efffffbc9bd26e3cc5ca101ec2b01ac55b3eb6a04851fb06e0f6b97ffa23db26b9ed9656ba275164bbf1cd172b2baece70f3a6ea6b23da3197688b6ed8a522a69f7c4150ad23992ce363a3f60015c9dc57398a91da5e24cf7e01cbf00392e3cebe4930e7b5b99f3d8d1cf4b75ef0566d1132c036a6d780254fb49a54087f88e126228ed14b7c76dbc4363ed451a9f3074bd523f6bf6ee65c996eb135ea627d30dbacc063abed2085863ae9bc261564b7e46836ce18388fd6a95017b65318b2b6b2ba6b990e6c51d2257714c73355a58a53eb9b8974be933bb6eac400b23f07b0a057d912c9082be1b4a1f086e7d409ca426710fe3b6bf672c0e5c5273fc5a6382db5151ed3377aefacbe6e18fb8748b964f640fba967e34990f883f8bcc7a72bd1d6f1a41a83615b34f0385f81127afbdd9d917005c24a36bf910ddc47ff063ff78a41b8198958447351c5edcb8e86a3a5959ab12a5802573594b645008344c5d41baff6d8d8fc4b2db8aa226bee0dbcd33120fcf923e34396aafc5f175f7889c829f9311834d84c8714b6de0bcda26fbe9395ee1afe84ba0f84bb345d469dba9dbe4ce8db72cf5cb2833f3f08477463cac15157503f9782abf0ebf1650add41c8a460c510f24c4abb2d318498fb3fd5f228c83d3a72e165d8c83234bc10f615d447823a06c2a04f4062ca34cc3208d8c97d293df5cf35d99342eb1810c4708becac52077a794f96bcad6f350747a3c76afe34b7b664b09a59e2706f9ccfd14628d6bfb01c4117aa3a85a9d92093b47ae2aeca14ea25183b3a627769c3f3d4e74a164f84127f7696bd47abcad2d367e87feaf05dbca52a253bdd930ba929d84371db52f028c6ccee95ad3c674367d7c28ff9bcb4f0517314e9aa4b2fc017dfcc76c02226f5a7f7499d48ad72071c225ba78b1c4c4926e910bd6540971024f5768706149193dc26d77180280b52acc0bdaeef5320d70222b966e022425790064f430a7468da6739899074bbcb0bdc362f7f8186f387a4ada63d0fa61cd1fd76463e1c891c85ccd2bb1e6c2098a8abbd056ecddcf79b0ada480cdd619e6505d808b9afa86e76c746510b2cd343cd33158837be9aaba80f3aae73bd8b0a2246e6a0e66c2361b8feac616c816429e0bb7134bc0c28cc898d0b57b71b3ff890d533f04b5ed33dc07b09400e71dcee50c141069bb75623db242e518cce95ac05701c9a8300fba4cbc0321713f45c96afe1cc684ceb257813ff82635c0d6c13298564d88ee0c9e15814c26a67f835cc8d305555b3124bf5a20c6b2bd8a2a5cc3750987b7929490b43110bdf3d27d0586599ad7dee5cec8d58c768061920642524f33f0bfaa3c878892abc7f0e9d3a00c5b5a34d1aa9df09c9818ecb29a4687277dab6289b77cea73b079fb040216752fd77ddbd8b58b01ea4c05f84ff02ef6c3422a838447321323eeec42e6dba0b92c3dfe98979849638697d3e2b7eae1dadedc6f274ab7c2fe39a9ef28483e0b31312eca5ed05d35ccdff595040d6a7a00670c6f605a6224f539ad868927610136df1ac65e0ec21bf368a70727108504ecfa9f08e7e7970c961dda348872087fefb24a2cfdf5d2bd35d8b2d30dbcc6f6bc7dda94bcadac8c9b3d75eb8dd2d1ad1066e8ed041dcd5ad71dd4c883508062726ae290fdaa4e89b381b9c5f85429cdb86a4432b6f2dc57a73d999ba9d0fcd737491622c2fe0803fc101c4055de085760c7feaaf7921c4c06e4ad0f3e103a13b653de986b316e258a961191cf274afa2b5a494ae625d9991a2ae009fb10c67a604903d589fb615248459678b0f03983c804af74fc9c17beab8dee12be77f3eec981ffcf756857695f0112092809af823142b504ac06ef0320e9a749ccef3dda8160e959a576bf808123ab7a4cbb0deb46153a2c1b789e6e3e3c20d2e05e02a0fd6df00dc327b4965e2998421d0d12458daeddab35795ddcb217e63a836a63e40eb6dadba505c28cb4a46f6041092947bb5498a714ad7099238891206bef1b871273defde302855ebd20afc3737db9d4d8f9e9f1680517136d9691a7e6d1492f0c726aa6e90e884a9636b58fcca74da89df2cece2a27cf2c929a04dc83b32b00cfc140227f5794afa24159c22e2fddef7fb71573cd23cb43452aa7262c62ef7f39369342e6976e06a304040a7f6c7b3e69119807b90a040e0868e6479c6a8c4dfb9fabf2b819a033e07b8f3ee45a65d4aaa7c06b7575f7d6771a8d3e50ea7d5a3ac8fc48bf6d079534a764221b366077f3e90bf47baf1719e27db9eefb246ca16a6d701ede7b42c4c7b292de4014d008864eb5bc43b352fdba2dd9b30f6000e72431348e3913be80fae295132903dc597fac0d92811fb2edb61058552d3a05639a7f0f2aebd25201006c0634d57310ea47876cb7c3d25cee9c1ca38b46dd480f47f90f4442777c3173532137a8c8c779bfc302bd0e7aa2463d08b64ddb6c9cd758cfa1dc12ffa6c901789899067abacadf391038cc200d71e88cf43a52fb3c642458284a658668c4fd7318390142950a8fbbd0eaabb1e4badd7299e0033dbc183331d0922566f538ef946dfd28e4c4101f71fe7b32c69871e011a8b8347d03b6fc9cc1e2a6fd13a9b061a221c208b1ab0b12576c6f8aa9d28d4d595e5cdbffcf0a19aac5e5c247f06ba2b84e1a3f24f0d303d2f5811d24845dde898a362a9e0431dd3d9643068202f88e7486618e1b0d4152aff3f3b228e859db52022fc46b9e8d54aae7a55440eaaef3b685656abc4a2ec5b0e41a9ef382f5be2397f864f49bfd0f2650e56bd44f425efa030b5697409542f25400231f0a0ef887f20161b91bacb7e941f014137b9a4018b189e4f0ce9a9a70c9bebb3657a4125fbbfc54f06b681d4147bd7eab395169a126bc93d3a5a91f8bfc5b0cdd133b84c62b0ca2c87b0c6eb6f6aa218e41819ac4f770f369f160092d5386438d14111c8d73f2a5faa007318403b4767f25ad5816f792ee425dac9b0bd32c6ae3b5b7c8d5987ca304fe59fef2acf5865257b6a5898cf5ddab5cd4374439d23ffe177f085604c32e1da4aa8477521803f55ce19ab71691e861b8fcd23598d7ee36aa596082c521305b6b6973d7961ba7d6cb6654ea727ccaf1a548e5b3acaa5bfd8cd97652fed36c523b9a8c895728ac6567bc0f8d1564fc51eac39322d9118168071e12db050b375486ad093c9391de01e6350b7252651008e1c36a59fc806fb169450fa782d4c05440e386e7ba7372623157fde226efbf39b6d4ba819a1f1fbc901e585098697d5eb0efdac95ed370dd81a1d2852150956b213b3d615f2f94946a4a9f61370b059728dee5635fdb7b9d8323aab3b8fad34b726f2c0701f9ef67d79f5a8f97c4d184208254759002d7834bc8924ebeadd557eae8b2046f40f03d89f7cb4c32b0690e68fef7df4638712b41da29b88dd36a74e160c9075307edb6b4f344401dfeb7f0a6e4593f0bd3b3e58afde32b11b4c4efbe6a9de3f4bb11d85c99c187eb93091534126f6de420e71a8db5ae9a7e884fc5a0899997a0e34f11ecfe5bd05841d47f3cab42f24c07c1b3258a75b244cceab5610ca3e67ef7cc38942106374d05c9e876abc9f153a86461661630d9d3cc71d8559fb179895fde23ab0f769217fbeca7f96106fff1125b14a85c948a50dfb7920b0c2ce550e64013309754cae9b2e2ba6757f9818eddb270c5455bb0d8b15c9bb42f28748fd61f604c241d612875d9d50c0d5bc9e041e182ac422c47f84556e5acecc922e979773b057ae6911b3a2a530518d42d8a20933af2eea0c3f3e43e78f5e7a87dbc69a00cb3f7103328a65d86c55ad20e77a8a87e200a2e81caddc3eca7f6abe340b42ad558a50b0e410af60a6ed1a3a44fbdd616cd42020d16684a1bc20bd6fe44aad1c9a41e6682d3219abf6caa0a10866aacfe63304d7700c94899b2700f05e6c557cf4ff9ed2cc07f5f7c23b6ee5ae04aa98298158c95c20fc6dfa71fe2de41d256f79fc3a398bf3cab61cda63325cd68dbba9adca695c4b482fe0004d7f0bd90ee42c47d65a946f447a459cf36d00d94bb37a97e01f4f4f41b53135fa0f152879a49327147fd49f830ef4ca9cf1a2e7a308b939c168cb6c68f2dae2bf811be8f39d7131dc85ef1c8679148145badd375985f6392a420236d3cb564496dbcb4b3f54a78634a932fd5028e46ea381b370a4e650a807c9ba82f937d514e6f0f26aca75e7599e65356318e7a7cf4907f5550123aae841dab537b2a6fd62cf0acb6413bb8730686bcafc44e666fe571c6e146e38853615ab3f6f26b04f4292651c269fbc0fee5f78fd92283730b962c593caee35ac8225583d998885904189e0d0f93d731c33eaafa294bbcc86d8c9d8eed2fdd21284be7a5241cb5cefeb42fa9a4b39fcfb9cfaeed25b529dab69a1b12591928fc2cf2a1dbb2c3187d55228285c5b9d081016d65a15565801c341322d1a5c6a47697b621958c5e562542ccd18630fc323826ad18756f23104a35b674ad4749a4078512298391e6558244914d1e953eb434cf579ee73f3f9b0860ebac7167ca1260c41e97d4d624a4a095f50df3c1d97bdce39274802b8cae52b49d821e2ef534e95f8f739f164123fc0a18ac23395a60e0579e0a93b314d1936382531610afb0c30c2303891f8546c07693906220124b6f5b3543848aee76723f3ae83d9186abe3841b97cb792dd5d64dace0245d844aadc2f5c4b7ad4b1bd147838f5a56e188db2d9efad37add042460cc2fcedeffdba25affc312ba314fb2761955e1653c8406af2ffb8b835629da37ee44f4565235a3ee2820d2825f4fc392433ecf5bfa0aa1bf980872de267db7d49e123b2a857c5388c5e3b7b376fd843907a2030f8edfe9c8aa5d9a39c255ae38f11f90554ef903ca8336b82f41a7ae02cf380bcb57d6b4ee4abda86bfd33ef49a6e55b2a02a7b5d55dc2f0cd85134336bf7f5b4bca922bdbc2af3c9c25775f13ab98a7278162e50884aea9f2fd7ab00b9757aa69fd8a6f18aaae784eb256587d8de2d29dd3ce2ff445ba2fdd43670c4379baa1844b98a9fdaade83a91c566602377b359cc2f4b9e878f36b21efb5e19ff21b2f77e60399c4f4fb6310246c664faafe7c7d7cca4cbffed98fbda1c73648c13059c99f0e5a2934d712d4853bd1135a235139ecca9f627a4d71c393e01e15c6f90d0194c5b863ada6a99b05fb3b157f9c997e44b0f17aee340cd032bc594fdd509fdffad1bb15803c6595e4feb5281862c1e8b1e5a1dba41b8bca5251c5b696f0f27a0ca736509ab98784407a0f7e6053c7b0c0a2c08f8f56085793136d580503ca9d2a7fcef9fe328b674b6a215f9d7a18f86a3621638b70c40876b2cd250881fd53888d57b1ac29598ebf69a273fe8601c646a8ea34d792b2ab1e22389c42fdf16d8fbb852b9ae09f1eae3adc320617df04ec9f95278da08a4f460d45e532addbe08ead54117f82af5b4f565d02e74bb8778977c404446fd977d0e11fa6dbe01a883cfa7e20f73ddcc8caebe61c15dc5e9ab49fd083c2c4a4f3089a1f648b49c0fdb4e1c182882e7659d426142066a0617f252d97edef1d53f8a9bdfcba39c7562a2d8916cc7f7baf3e6cf816679da1b7ae790fea0daea1da849731ea9b3df2d6459d4d32a43083385a20dde2a0f3fd3cdcb0c37753b1d6a13f6886ac9e67c26dc6b052952ab6e84d3004e2e42ec63c22524157479744377fba6ca89034d61b8a6086b1eee5043c8b1586484e45e60f3378deec5ed8901cafd8a4af09ad1bd4581962f8529c0a47aaefb6cab2d017e26d63d1300ce53c20984e9683a4018f35d323b1faec6bae561e9c41892e13f6688f8def99c888f8361fd073035dbcc1e3ec960fb4f048448f92588e61259402ef8313977e0ef0a116ec14ac83d9a1cc2de4db4e93cdaba7d04d8df02bf2e912b82060899198618f9985877f3e84cf755fd8ad813493ed5087ae04d0f81f6f70de6b2d3b99aad18296547a0bd9ad74d04cc4e55cfc5de877b888188cd3bf49137381b38fa850f4a784da21d4209756b78649f6dd437061cdaa1c17c2f2a4d3e2030c249761ce09c2deb48c7a1e5fff31581fd4862b399f8297d150fac172b2d7ddcc15611b23615bc0b3a37c55f9f73d27681230c649bab660879f84b579c5e485933dccc4f682e64eab38344eec952682ed8e9e39a53e672a1a808489507a38017488a36f9cfa72cdb0f44b0ef95ace131e4dd6698ce320c4621813bc257f332cee8f38e7aa9e1755f91bf2bcb2f5006cdb0af5abb541a8c985ad0092d1e4c07aa032f30bd5c545fb9a67002d0f8257f6f4233ef1437f8b4a4be3f9c880e249e2280c2105c4b064ebac54f5f23b2c92a62cb8e5f875c6a00068ce9cbe2fb0c02e98eaae192478cd0cce5702605f36011479fc019baf803a9eaae14249fef606ee590d700b4fb4dfecce45085e1f3a49bc2c48f1c53139526f5d2d1d6e81b212f44589f66ca2549537844420ce2f1f2f9178449cadcdaa15d8a17aecb4f2b9cac4fd8f8396d03613c0dac9543a7e83fcdfda21ea451cc79de5906feae2119826e4e252304ef755bcde819138d19252f0158c50321e4d0d8ca86e88e81a497e8f0443942454b498a9368faf71f1889b6de917ae7f639c433a67dd738ac9f73866148f19aba11a52f501ebfbce7f39ec0d01d18a598db25fae04b55ac8d0206509ce62ee5ab9b590f71bd6de65fd9fb9a782b96b61bdb8ba61e693790530b0e8bca8f70699a8d3cda9feb88190ea47685675959ae3581909ecdd3f33963e45ca6815fa64a2c55091b806be0128a0e9076ca778df8f1edf0ceab8adef88fbe3525c8d7d91627bed378317f885ee5501a640c80bf963e4cbbb0c1a1ff5a6f1e5cd4abc4008f7eb989bbcc595670977ea2b5a651a6f47e4ab9e16ce5ba54075c60b646962f64f5778bc6bb5fc5d4433d9f6a798cfd11c499812b72db9dbfad50d7705e851c7e1f5db1639ab1ce556f338aaaacbc793b91db374f2a0b5e9462320957ec65f9cea6b450f033e9752f0c4ee1d9cd9230f9a0a28faf8a358762a03d2bfa21c0e7a12c7c2ef08960e25182fae9dd87960a1138178e10465b6de12effdfbe60f52ec096545a92bcbda64fa7bdb16c595c7735b4da4ef1f732283a247ab5fd0494e91b395c932b38a872fba4d2c2b18932b0a694366505d7edde2186b993c58b055a96046deea13c01d05b706e51d870c63c38f645497747097ec8d79390565b6a8e8f0fa488ccb9e5e95fc52c165fa8f56dd59086659dc2ced4bf0aa74b4e256e05fdf95bfa93512b8a8d2da277457306c1cb17f7c1770a94248a0e1c5edb00b3217a4c085c5d470e485ada89a3f9f8457bb1a33b356209eadd9a418f85a4768f8490a816204d95ddb870a4f2aa8fec086108e867acc65c738f0ed7ba98db3e6e58761a44c3c157aa1f20d7b4010cece58872551d416637ecfa07bdb23308a9d9889c39027f9208e87757b14291217611fff3bbca7929926af73c0799191c123cfe6b811d1ec948771a48255b9b814b2f09da0fb1b8298fcb2388deb5eedf97c7e4690fe8b9b1ee6b74c3a9d97c91461e45b7888d93204b315ecbd9b37c7d8be9d536e001a80e38327fa3a27689f13e630e62dc68e7db8a28eec8745e8e994e34549049ead2fd86039f9d1b0d0d12fd722fcbedaa058891aaa2d63f0e62f3595fabc471d3cbd1e2c80ff8457d8e83e403c3c4ab344b67a8aa5d0dc329e215f18fe640f2582aacfbbf3b791012af690fef00f8d5068af7b8eb1919bc12c07238348c06d4363a1cbc9f140d8b58c5da97df9a116edd33309e16371f4da4dcfe3908488cf8c15e2bf4c8ac2f0af52610eafd04742c4f684b36321cfb0a9b692aa4636eec06ae7b88353c948866cfb00edf9cf1b6745aba6a964b817047963a89be539efba58cf89411fdcc52a04140853233e0d2843b2704cb410fd9605288ba6be34b0838928f609b58e9d5d85f9a861893b2f93b89ad58393b8c7d0fbb399a58d9178cf93c30c6936f7792a4b5600cc1029b31bb83d827e1081c074e191adade9244621e2a7f42bd0a040c3461197cfeefc4dc481fb89d2812b40d55fdad9affc9bc4430be56f9d73903edc7ee7ff8f25a9875c4d92d67f241a0a59f3bd465aa5ed8effaf6b8fd446db1607da6b4fb4dee9fe84a674347a248df849e3488a2cc3adf3d51672ea2e98f81ae2102647396ca57c5bf68039e5810bbb3c9d717f7dbe0af9664572c1e0469359e155461fdbab7209396abf22066aad32f98b0eab1f6b0842c0cd892c493634563930aa8752f1a86bbe821296ab78b7a653bc95c611dedff976c5d43c6e4c159168b4a6d7eb7c410bd18c843f7d024a88f77bc485b9e3e28c1289d058665eb68d852e935e00fd9078408f26400f46092254604b77ec23b8cd3b24192c91d9a98d51373629f0aaa12e0aa075a9e9049221c5ea4f24eb213a376308bd6cf7e7d966a5138ac5b921e38eea581d0b23209d0ca0811699308e77f6f9d64fe670838a8c67b2cca8c5f788b971f2abc7011c7653f987a43926869dc82f2040d3d4544c0c1231f4cb16e836246e4cbdcfdb838eb7a5b44e1dee52687526ee514cfbd9fe8e5312d30ed5bc0c7efcc3f31bc0479547528ff4afbd77fd2a7ca91c76f64a911f8c60aee52666538e92cae548939322e7357e0dcc84728acc74df851c5dbb718a85f380bde838a76441f3b476310cac6d599fd396b82e9b2ea8cd6880ce5d3c2d6c37a5f73008c585713a73aa38502785e6b83a4c02db5af7a85637c27976ac6765cd43e05ad91d65ce47605ea1c609e8c099f59a8e47eabc71a3f76259f4ef95bd953dec2af9960aba9f20b5f8a5827d8710762b981285770f65955ae9268cdfd7a7700d8e89640bc57a47e1355291cc606ae28c4d83427504a841b3b9d786588743941ead305a7cde2c3abb5176f662ed1b570b4407a2e7400534d1d5adf46574a8701046f153d0732bc65b6b79945c67b52e947b5e857b6e1ddd28858589647bd679dfab161b956186e2562e117d764e00a31f801b8aa2cf2bcf5a5723912eb597d6a852ead71f4151101c95f901cd38eece2e8729c88b1817fdf135ee06f0bb1105859f4eca4db98143cc0f0f8e45a5ef04d4dacec0529f2f0ccb3585b79a0c9137fe3a45d3c01fefd8d9e8b6e3a9492653c7d5e533ee00ea5c932685954888055415da6753760786e964dd0f4973d563167b03895213a910d4ea597e254343a92058a002ab6eb326ef03f2998ea976628c858b90c8f991a74e821ef9ace50784b416e4674d718ba206d53760ae9c3cc4351642e46a7dbc818f1061c3ad16e222274ca725967bb5ff9183bf5ba4ea916d2aa64f07ebbba9b1d36fc87ac18f7e5ef59e828aba45a82600a905c280c38621baeeb126331fe61bb1bd906e2af27a47c826930592b26aa08f47864ad4b95b1db19cfc141240acb7b5f73dc0a2e44ebaf185befaef5640f62923137ece72763bc5508c01a9bfc5cf179cb5f28350a2346bab7b460122804482ec484ba03b949b923658d694e75bff0637c7a2aa10e66fbe9333e325de19022047d4bbbb8f7af1f20985066668a436ccfb3344961253c6d521ffa0a2a456885116e3fb869d65e326aad70cdcd0c31dda9988ab19683dbd2cb580eb9c75678edd9415cf98aa2de00d07315da4bbc86019c6200446da927545f45451388ff5ccaaf47c2e030658e2ac08be36c7769398563b50fea398ad502e5c53acdb256dc9e7eaf449e962c8119a5a3bb4bd40ffa15c8f865f987cf31497410c8e089cf5fd3ec9ea1b96cc4bc9f0c6cecf806082da0d22fa36cb2b82aa9caebae13385213d2f5101bf005d3c9c176790632ace78a2c9ab347184d5c93f5baf24eeba9e17fc0f543aa63a84c195c264e154635f1613bed21eb558d39db2a1513640f6f249ae91cfe87cae91a9dccc4a49b4b8da2e5068e21fd9662692931b77e84886badc571730cf708d016a594081f06440d499cd7b817a0881dca142c9fd947abfb173b1b5ad713aa7af2550d5a5d1c16f142491bb67dfce13230863a2f7209d38f24db90d30eef8808806865d88efac265ac43cab31109fb5742959c9143a7a32d2bb8802155f96fe37791a19acd1c60a07b23a26584e868db0dfc2b76edfb088a94e343b81426b6fdf62174f516e463c4559dacfd5b7b13703289ccc1be7764239c1141e806d8b839ae82a98e001ebe27ba015c8f89ba44dfeda75c157b29f13244206ea9666f2e2d170295d6d8c18047a9e961cb25e41a3ea92f86f51b546c806070c14c3f3b28d830bc1b47b160714e1f427f921514a187c6455975da47a477aacef16a881f4b23d1a8e86677f7ad2ebfba8c30b482a4e6a07ded39200f73da8088e13131b5e71244fc40a2b3a1d2f0b1d1d914b528994fd4a0cd3596a6cd5b0c388e1136c97c976306cf08c4eadacf609065fcc237930bd714ea674b0bb07b164ad9f19dc8b832f651a921d6e057fa8969066bdae95a2a8bb31d5f300260a78073f34fe25b030cab9aef6677cff84c18129f71d7e98800b90f806102931731786a2738e89b7f918cd4d3ffccddc49489938749385a32437537d13a93233e32fcd68af51290ca2bf27bfbf4859b2ab771e177722e1c0b59c9ac4801271b0c7171025aa3c67656b7eeed92e00deec11f6072bc15b3b69a90135441c441fbbd45ea0f874ac7e9eca12915a660c2da4e985084f03571c568d3a0db9de69863649dce6bea8a85df0b3c72a2dc1fb54ec68b8ab5c5ac29fdfbc16f970d9b86198f042e69b7a8cbbb2a93f90f65f1807c3b49e090809403311906c00bdcb93c6cc29fb6652dc85c934aa78816f51620697ac3cc15b42cb25ee20fd3388654002fb6fb7b2ca6ffb9aae98257ff511fa7a64440b351e93b33b7973cb03b85e5cffb58fb2c608b4e395f4c57986de7404792721f67512fa868d2db1f04a4feb689be690a8b6cadd182f8f0b34dd0d57f8f5dcce4a74c9cb7e7685a66c0f36ffc3cad035e0b6edb6aaf0b4b659e62940a592424c3dcbad78f1b82247a1189e1e65a14390535ef8e519d4a3fae6bd0fa484ec21daa17add483df209954a5f5b0c9eab169caf3c3405fb470eb73311784f1dfdb865cd6751cea761fb67cc3cd6e457f3a46f63a2bbb5b5ca61a0906c1fc1a32ac321e678ac515640367e88b792e6e4da165a5481c3bb3dbc3dc3fcc1f89be5daa25567d6f19b789e9389edd73ba6ccaa3213ad903b341975d6a34653af6cd83dd40d0728572609d910bf0d470fea7994b4a0639c15d0d33f6dadf07b60d0a72684e0259bfcf6cd0326f568c4449fb8642a631e28f01176ed0a95d5bc341b65393fbb8c76e3e73ee89c916dcbdcc83dc0c907c7e07f611a4717e73754bdeeab17f9f2dabecf93f28445a7eb75ef309d679b9efeb29b58a3e8ef84b502d895502b2b3255d7d43cebe0d39d4818db9d14fbd9139637dd10e56464d911b2c0f4aac41b1749b66fe62f47dfb576b3f8e3cef7e71827357d57fb2de64ece54ad71c830a4398324eab3e51d87902a930fdff819e6db0a314da0ffd0309470f7082d8cb5d3dd9a8641e46193352ca191883e047cc2b03c391683296ecbdb20853d58b7d59d6e2933c535a38632922378cb635a57893bd956a329c1c9dceeef1d09758db3e9baeb1773f27e21f40dd2bc4820e4045711aab4a57e167c3393b80bba71b3012c075a7c71c0512cca704b680d89e3374899169fd96ffebc20d2376deb43795618fd5c548213f63e11b78ad1ebdf4303beba07a776bfb0e755d718ad32c274662f81f1c51eb140e9101534ade4bed36415a63664e6a2b3c7e08446ad148a3f337d78f7648589d417db1dbc529f1e3a71242cd02a0ee9f0a9ae246e92027cdc3e94dd0cddf97f17582ba0eb000e258b6eaa713c31d2a3e551fb40682add910ba2849ccc46c2668761b667c17310077a18e3a9d6843d04ace58aa7292cd8b3158128df5bc3efc30da85d1b432ca978a93a91d0cb1d36045dd36429b8152706108cb1f955d7e5a8dff1dda98b332397864795dfe5a4b74b0898868d07b4577b7d8a96b0e6b8b53d7ca48a3b5c0a7b2bb090cb8d10b15acdc6a15c95c8e2c2bc85cccb7dde7e92063560dfe588a3eef4504d5ff6410b4147e9b5eac4e0775db4f7f240789770c1c272c91fb8be6ea3b1bc9dbd8a084d9b0eef3ed9ae901936e0dae0adb7ae1b58a261b765132f88398882b4be4bf08766a7bb6ea98c1b4e7f0b1cec7a2d9078d9f18c2aa4d1e16a360d47438a1d9ff00198b92f89e5cbd1d59f95d7222adcd571de22b05d90210c75be4ca4c228d8045bfde463b45b6277814d0aa294b4b2ff77c7e676d0221c9f1099da76c2660d3ede4538244595529b59a63d2e7f883131c8650785bc3486fdd2771a7754e698c977df5c855818f7fb55b751d7288e968c340a5e896ea66a4237e847ae1e28ec2fa3015813e53ea1fd22794d975f8b8cb19d077f5be29983339b9094d0414edce4f51013ffc282dc31582fa5a4aee7ef3ac02d342a2373eb0340f5d26b21e95af19a603b9cea7ba73fe516cad02454da7f635d06dda9a00a4e02ed599d9901aef471cf9ed53ca81de1548546a6b0a13c6279515dfd777d326d6b1bffeceb88481a75bad8ed86974dfc3b37c9c7d676a7272952fbec4aaeff0b69c61c977685c996894b2cd44ae43e5715091609563e2b0363810de1eb8856539f9b55f778e661d64905da70f4b8d0de5c3a865bf5105c59fd5b16289e39b7475dd2c5c2b6d867e3fb49e1bae9a274189b79eb9afb8841e675ef5c533e058f900178d7708a27c64462f03bbf7464244751f0512b6c1bb43176f22406998ea5119fc79a0568f1047e44013fbc7b782d794f0d75d39a12f45d8db46e850e98f19d8e62a858bd03a62257b304f577b5b4750ab3f744dd663a297927fe8e6c6c32efdbf2165304c77427c0b707ab89475c94f761b28cec85c9e0e9e76d4ca496cc44c9034c8801b9fbb623877af2dad5399554e654ac28cb8c18696fdd1a912354f69dc40bafdeac487585eb020fbe8c79eb5041b7ad4f80587dd222734bbb7562fd5ba9051fd6bca1d7cc209c16a8413a01018193be20ea8439f4049ee9fd87fff95c0f82e5b450fdc62f35dd6b12c65dc575b22e1575b176bc42a9c14257c711d9e3930cb9756d6ff3ce1ca90e0fb937f139233eda99235c3445f609198dde92abcea18d79db54acd415378f9b21e25ff9cb0da84128e4565de9e21dc69ca1345c48ed8d48d8b068b1cf9fd7cfa09a1364a4db7e699a9af6fc653c2b0780a0d483533f3ed896343c188b260fa96a817ddbfa06b0579ef018c92a8f78b30572f796946086175ac23adef36df892d19ee5330e4846d493d916148d3de2fcd7a0878f3d244e499bf0842df5e9aa89593371f5bb00157c8801c400e1037f7ab1383452a1aa4ebff465e1749e869ceb89892d23f4c71293f3c076abf3bae0033143567a3d187d6e7937bf98ca8cd3e168baa1df0c6d1706ae040100d702ff8da280781400626f83a37f73dcde5a4a56c3d1fd0c9a90ffcca2e4c4011bc6b86b50d44567c7acd18585acd327563e5553afa1502836fe088460770e716970df66d91a4030178d817e3884dc57664402e44a342a87cb36b81cf989b3df20baa5750aab4b416a29f95580ae7f014515a8fc44f5c66cad214b7633eddb9eac4224852c7b8473e3e346e95d9bb6a2676ce711e4b9826d459daf92ff94909f9f3c2f69bace313c0a47095b33569c79cac65c9e841359244d300a8d33818a0b62ea42e19d85bb720bf6c2f04240856bd3df72b1260091e6d5d06aaf69fd8d986035274731b134de42452eac35cbbc53864a9758fa97f6b5cb6a19cb0b9459de56b0d378629f7c9c446148095991c404a46bc0d44d79c319a8826c23d7b6b56da8f3a589f08d7c96dccfc37018706d5418733164c01ed5a1b8437f495385dd224aeb386544bc604e6edf2abdef44f7eec2a5eb153990a0680307bc170f8f80ee83d5e47c9cb3346e2f33d9940e7e2d60ccedee68af140eb5bc39eab46e0669fc702ceef709a23a1a74047347fe5d5ebc6380a1f593540f426031dbbc59cc33275cc1589bfcf0d1331102261c21dafd28818a1a93fba983c5708a387f6d454133f575e454c872252b37435b991b612a4fa923214d01149060fc9b89458caccf8ce98656c505ac178e144b6e1aad4c2da05ebbda4edd0e904e31543812fd59a71ab9a6d90cdbbbf4b0094fb7b65a985684bb9bbc0163934a885535b6dd11f7cac92f6bb587000f433a3e9904abf7a3f7566f2c8ab9e79397e8864562cdb3130bfe8d96d5d2ec88e506c1a05cbeee6c38101e591fc93cb67c1dd3a12b2ce9db51969abf4b783dd651e8246af550510b319f960b845d64a6af89ae8c9a17e75612b895c7377939db6271c4d22575b6b8bcd547cc0020cd6244ed3e09d60289495e551fe6f722f484030ac8ecbdaec3ab05535c477582f6a715dd2571542a8f35645ce7f60157a65f4d7bc4e807e9e9e931324835166cf1ad5d5d20404b0eb5e5240a6691516be7cea20eee7b8ce859ecbd52926c40006e95917e9cbe36e2b2b522361cef52709d97f4c8584914f01dd0895ad76e7d1ee8ae4b630a1eea68f01bc195a4e5bdd4fe526786a8a8254213591517006977761e9960d8fb6f84e329d6746380deb89a2d1b034463dd545a07e6a7be4a6bb81b59fce53fe8895c505c8f91a9c4346f631ba56fa107d3b55647863ad72e2243cf28750a1d8a0d1fc538da882d86ab960014d99fa8e11ff991437d4f22709b735ce8a275d802aaa783066a06ced49c510135042f838225441f75babc2e43977a1697d9dd4512b1f8c5853db814a13546f8468b957ee6fafa711d2583019576ebebdc76b9d925a8d306aa131837ff383ff7a93fa0a39baf5d2b6d915411790a15452baddcb5ef5e3afb685f7322c1f0ffce127847c0b3695b7ec75a6023f7fdd46447b05dfa979bdfb0a8e99ce9e63c74916f8e91e2ecd553523afa5e263307a8753c81065f362dd31de9396f4f15366c9a1523847c8246a2cc0bdf24d61554e069ebb843cc578bb7c0e847028c88da1877cc77fa5637efce2e9b8ea2619ca9958dcf030449936dc761211188589b8d764ef8a33f3d8de56b581989f9ec323c866a08b93a5ddb8ac42bf0f48079bb971cf8fe90e0ba58789dc3b293771e9aa2e4657098586b95886e37cc1ffcf2f52a4ad3212c409db759b4419f8fe1ee2eba42b899d4a742ad34e9a5f547c48915ea68ecf7aeeaed20a807a14b6eabd9ea30e6d07f0cfdf5ca1505c066aa3a7cbea2349ea7c896789fafb641315e54749c0a4d824cad970d574da89ec21205b2f20ba14630869ceff0976f16ac11c312f41bcc4456c9a5aa4e0255e56a7071d934b11a702e8f841338de438b10176eed07bbe43ef55a38c5ee9924895c104a734028850e1395d6921fbda335f3d62cd4bd945bfc49824ad208ed641f55f45aef689ef01de47c3b6b435bc9c58cb3d89d7cbbfb5dfa645c01cb3fde1e9726febb1c3710aab3a226cf10eaf084e25e27bce518931f323e88091f47bc72fdb2d33a1a4ad1eb92e2aaa980510c786310457cdcaf2b4f6cb7247d1906053c2ee848332dcab3e61db35121da6ac9e888079a9da767a7d4bf062fa7627b3ab1301b3ff6d730a1d4d0a321159a4ba8b9b8dc5daf515ac512632c549289f1c16c50147a62020f15676cc1f860ab9de44bdfc7cbc277cdab62b2937e4a1b571f45b587f797b13f7e43e682afe556a1efa0189fb0cebf2ad9e7ec264787c266984035b4da55e1d99d9808a834bb7c4a3ae6acd06a03ffd1cc1ecfe4d0da1d8acfed4ea1f4ee89f7d581c3dc603f94376cb3cbc613f32188315aa18ec8773306a62158045a0583e1fbed85aa926c7883404d21ab56f1476396d6146e4a615c22c95a45d3c2cd5c79201714b69b7aeda257b3b4d30c21b68001fb5402c83f06a8f508580a9fbfbae394d27e8ece68ce72727ab478b49ca4a1d36558a195cf67edbc08f1e4e64b274b0084ccef888d5400bbe1d6f6051c21ee01cfab5b765a4447aa786f9a350f66cdc47bad82b265791cb1c5d37872ce55fc35dc01c23fdfa1f6099bc451f6c9058470e6222f20004a963ccfb3b4c3f85a787df0efb82221fe302982b259d1133128ee72355859ee8b5e2af76d8ba2133328459eb4c768dfa9186c81f6536bec46e7acac90b0d01103b3f72139c0ff45665141734c7ee2716a638ce0a86d6fa8d41eb7c849a6da2078f79823cb09e9b5a6ca19341dac7b06f7c3892f0213fe4ef534d0669f5e688314c6edccaca3c6c7038aa3bc4dfbb7c640391d34243de6db499e8e2838b612b474f979b65e39c669072e394afd7ffd5c20c5e9b5a142188fc3b1375bbc076d893df59c8349d110043a304e968c8134a587a232c70f1437ea37758906bf47047499de3fa4ac1fa6fba87f5f97970b334184bab044aa2d62782e7a8cfdb680cae7bfbe6796f573ea28bcd50060610d2cf0f94d0d566839b798c3da491899a5a111dc7f29c8b4873ac7415e21f9939797c6a8975f3ea05af9b2ec0e5bfaaf6521e1a58d06231f77c9659edc2e0fee71123a652d318b3e9fa186a3827611fba7b0d816e0f320642ee3ba78ef82a2e9d2506efdd3a7a8a5b8dea476c3f92bffd0637599f364dcc445752e2073a6fb98ac2d9d6ffe6b5274eb6c8b880ddf331a57655ebc2e5762b27edd4d271695122fb4c324bbc287d28dbc5aff6fa45dc5c682e2b6a45e299374ab49ae93a79496ba5a0e3783946253625ccf9edf84707366cece574c3792f2ea05843a2a666fb7a49055d20c527b24ad15d7549b1a5a3db70a3f467bbd6e0324138c6ff739f8afd887c0f3ba513cb0a866fab27cd4d40d88b30d4e632829c6a8dc623c1900ea4654ed43b0a0a0c323eecc848259882c1c26f7269fca9d17696b513afa6d06942c0caaf16e002e4fc66f5eb9ab22dd9a77d7b35f9b3203a4d718fa5b319b48dd6dd1ec3417133f828df814c085f1675f95218ef21aecf2af9ab8fc4b8954fdb6f1c8af78b8572b4530a36f7c4ba8b35dc19f809bae8d1c431c6e861a6c85548b119598c66d27d52305ac7056dc9d51f707ca11cf20983263957895820b669ae840c611aa1b644e827f9a4416f1d0faf65bf2a6ee30168e4aa273dbbd451686996631c741c40d89fff9191ba340adee8a73f6666dfb417493a98e5c13160708eee3d4901fc4edfdc56924dcc330d3dde872a3ea5ba04334b148a6974988c0dddeda50726ecc6e82bb302d5382f6142b27f583974dcf0239b3e62cc50a83ac7618f5878e2f6ea4fb390c9d8e8e70d266d7e10e0ed079f3016acadd5f150fcf7c9194989417ba0f68067da4bb4f4e60669db602867ab5e3c7818ec92fd1ad64e567060513b298dbc85cd2bcc4d17e5c8887e277a8cfdf624e4f7368e615d813e026e5f3dce3ebfc27723844dc3c5c9c8aaa7ab0192c85e0ed4163137913088b8ea95274969503afe242837aff3b594e8ba671975d3402f8513faf51d9b186a79a6ccb6c0baa4080dbf9e4dc48857c24452129b791ef9f56780b4145443f0ca7b224cfbabf86013cefd83f2ec36ecf2c737c16ae48f6cc95255780f386622e8e8984540c82b3024c7ebc65941980c5e373f6c06025ec0979460a5d2d4281e8ede3d7a202c050e752fcd66d9b1d196bc8bf1ad04a89c4e051b48f38055ea61365cc91cb36205f2f9b876fa4fed1d6c4cedb9dddc0dd24bf50f9c1cd71685cbe2aecd612ec9302b63d49cd3eaeae75f47782311ed570a2dc65d05de4d376e4d0fb5838e320f33486768f20142671d14bbd180de565b916b4fd9af32fa38cef0ea00848b7c16066fafb83dec38554730da02a24e7ea13444a91678ed59034dc7fe5b55b893da6cb782b3c2e01aecbd0da32777c698b1a6ca1ffad92f63ca5e3516907dcf9ad006a38f9be12f2557b597c480c8bb30da8c963ab0a38b1222765808e8c66282ac4cdae88943fa2dcbed8a93b01450784eedcd72f6988054e7ceb9774d861df3699521784670efb6e63e5a6643712d41d85d07bf908c281ed280044828df1a269ba08636c7cc58975c353e9d1147f9202b960c8cd73a99c7a87b4135e6f40820e472bc9913347eb81dcf9dd31ed588e861878f13e16da720f4267de8789871a478b60e4d88251986aec217da112873a56556c0d71222837b70ec12da44aaa91b48f3899814169602b7a82cf2b3fffa7bd93853afba442e3c145efd08dedf0fc47d436dc51cedead57e0ba2bbc473d54f1637b70c371aedef5d465b72ce330f463853d04dac8d13453bdfc8495f8bbf3027a1fc5f50710fc054860749fec630dfdbc8922edf97499963176abfc2df323a5b31a77d33edafdae36c56f43fdca5055c3e6de5dcb0f82c4e558d5289233b1862c99c670aa0f96ed1531131cd8f641288d86636f26bb95fcded9a7fa82b36486f159d13c4430123157e05e1b5a97bb2312517d1daa968cb7523f57f516709f0eb52fb51e523bbff2adb69a8fd0da11c48053a3a21cd9aeef8f237dacd00685bd923fef299e4c6bb7f24ed60219f87996902cb00e742b8642f9761271ce614218893a10e715eff229dfa6b0c2bd825cdde87f4d2de5a28ec84ce83e4302fcd0038c779244dbaf953b13a11812536e23e6e4713a8d8a94b92e47440e1b44407382c50c7ca64db9373908b2b11dd2fbcabf959919794af56fa705f961716172d6402ba7ee72c3bb7ee38950f8dc142bc207a22bd009f5d238828bcecddb0c7ae5cbac5745e84337d54c167edabb065f534f79b535de2337025d703c9401d25ee29372b7a92efbadd7a0c7966c5c31cd6c69a191ea56371ad8a22fafd4c7b195f4860cd8d47c4e9e39990de8262c7315707521853758b676d288f575066191980201b3ca80fb9d05c405c48b1186df293e989128a298b6b3679551df8b76d93c2cad2e6117603acc9f4215811c27b7b0435ea2d9e82fd55fe2909d9a8d8a664a08987457dd6c38d833ce1909f87d1ebff1a6abe507985b5b42ed8c3f3d37bc2e79e194fad3a58ff63b2bc9c9b29337da9ba0a3d7ebd35bb2f71315ca8edeb2ec81f20822d562b04df35a0fb93ad7b2e6a038a9db365b673365cee48095417ed21ed538584f33190f932f3ce4678cef20e2c72aa26c4c6a1cbc4d56685cc503599f8fac1444f5a5a0156b153a805fa6e02579138404d42682bc33faede4c516d3f6a5010b0fb5fe69c95857ae8eb9246be43e864cd107dacf7118f20d982c689bcf0e985c35325fcb8caaba5ee763201c26a38bf262ce2a59ac656c3726b68fcc615c947b1cf74f7edf0f7220c8af793af80409a2d1e8e524a527b56feb9fe29aaf6af601126edaf985045ff73e356574fb93ab629028c515374afd70e63b9dd1fcf31b4667629d294aeabcfdf80011a3e70e6670395c2cfc087717a02593ef9298239205bc8efaadce4c9b1da12cfbfa5b9321921f75276188981cf8e86554270874712114b477a5f071fe78104565339b9fb45145d7e138d037f5a563e396d04ee35caf99c159c2c369cda16ed68badd9db6c9f79931f90b50d404fe70fb7494dbc8c12150c66430d6119de316790d2a86cc9b7984c2e76775e5a6c47a2e681f17429a97935ea841be9a4e6c071e3acab8da0ccf661ee6f9d5641cdcf8a0e68c126f16d4aa34cc891a3c12b51737df5520c2b7862c62e77792fb4fc493d82fa50808e4e3e9b33f284c651b76583a0156cc4a970d7aff1431525eef16ca1bea094c15b9d3458cf64817a672cfbcafe8cb018a20b7e25fe3a47a21852a57541b742e940f159ee7f422b8df51cadf259e40a6c8ff8d29f6315f22cc98d50bc57f06484b1e0f97852b8040c6c05a4c269ae09038f15370564ad5ae429fe515c0691b5b1a853534b4b4f4c8d753d29fd86630533910e970a102624d86080f3db29d21d07d944d842a5ec21f34762402178d8c7ae37db5f50c835e06f61aa55ff4f496e08b3d9684b250adf74215fdef54fc498898d697dc76a35964d852c185b8491ea29817e37c5b1f1edebc428e3bb57cb0b908c8ec6b1e8a796449eab642d924cbba82c31ab92d40464dd0e2ca8cb39e26c630d778ead6a3c6dac8d855794878de1088a33e36a731b56e124fefc5433e84482babecae24c9ac4e8d777806b4a87fe63cfe92dd4c1f6acf370beb7fb34a9305da571c9194972c2b57659775448e10524471e341b4584d15714cd1f1df1a9d682b83dfbd77f7c534af1bc6114dbd5aa09b62a22f5c6e3f5030127157d9828b1f43a9e35eaa472511b253c306b30cd9b58665f34d3e6f2504568c96cc7bcac3c04a822f0b6cb2a3a5c953e88599015a471bfaaff609393e8808748f26341ffbed68ae6f8a058f5a81204493f47697b5e13905fff4df2b23ecbdec0eec540d34bd2b355a3aaaa32a3881e5b66d717dfaf56943a3641304b5949a745dd4a01fcfc8496126403dea0983d62296ee686b3b62088ab5c1a08c09e9591bce9210155c51752e23fb846c20cb6445c1ceee7473a1a893f57fd700587c613ccd1640037b03311e50a9f9dd71802b2cebafd120eff04b09d836c3a85d38ae14b34aa0c8174c79260db64be34c63ca2167228888e812e920bf15eaee46773584b59b78a9cf4df74b0d88f11d21858bf4b72768e38780181480d40d9e5c417ece9d24b64e826dbc398662b00de5d68f87df010d6ce6f7ed12935615ca41dc8dc8d3303a8e6dc1c81072697c9394a67f78951a40675968ad7a49075b2500b952410a2c80226cdb6f066c4df00e4c822ffc1922d02906ac7979dbd7e17e0a529b900727f66e7d276ec85f021c991dfe3bbd7ab7330539bbf6438ebe90938b2d1b9eee533e95d4244b55793a0e882ae27553233e0d6eaac29b263ea9a4b79b901cdbc8447434b3d044dc6cc471f713e2b2c022be11cd549a8a99b13056374643a684d9ed2d4dd168af0f7081a4c627cff77f9f633f92a59fc403d1d9b814ab31a75b6ead4a01a7333b41bb03d4457986c07a29447acea38b3d2e813954416ea06485c5c76b14a9efd58a6b364078942dc4f42b3ec13ea92f2ad6fecee22345d0a8359469239ddbf153f73c099c5f98f312f6ba5b2e6164efe7aad5c84197ef54f2b76faed02df7fa188b280919b9b57c3a8ae2906dbc1b4a5a9c522d90ea3fce48317b7e895a02b2bab7a191ffa741540f959273e4dfe1edf8fadd991963666eac3bca20dd273e6b02afcc0df5cf10127bec7dd1acf66e394868fdd759aea656900496e84f3509e0f88ea9eb396c8c4e15edcefb4d2c565a799fdf2f21c8170fe1681e4e2ba8fea78fb6e1a9cdce9012851f4697847910131ae4273177cb69069f4fba59d144a91808980003a6ad9184c71c7d93486e6b7a62ee18552a7aad4f08ce9e2a7a3bf2ef18623ed7224ae8384add830c01bf37c792767b20aa54c36a90fbbbfba2c3503dd1acb1b5fec2a70922865c2fa10172dd3a44323be18e64ef84c28df3ba3dc2c2e8639a8612d0b54024093d39ddfd37b645e4752366c18d41852951f256ec5224b8a055211c3344dcc647b3ea844b920da99beb8477e1e2d083715626bb23b3740ac116a53d1b3d561e4d6c5e7c2e4774684d0bab9ec31e4784f99022a64b74ef4fa2c5ec5e1500847b61ed411d6cb4e37eba4c628bb3d849adf67fae3399aa881a5a3763fe09e024de2eb018f4d4275ceef5de43d47e321413118714c1aad36029b916e0a52a18704990f5d51578808f4b17f645e9f465921cc9eca821da7dcdc7120c7ce0e71a902331982580014e7fe6309c8e7211a33ca61bc3194cc9d94ca92aa4537301e5f384381bb4c98e45779222dd52d293649a4299d0de97f5344a8a54552393ffc4bb43d7488828c9c3ed69116650ad374ecc5e758bf637edba050ff8e253139f2bc4be3d3392a4653eb3bab5d76c4b6bcb583d915aa248b6da79b95161e7e90228052e27136333b2e5979e304c8f1314123e374823f3cd519cd0a6e8efeef5881d067e83fc158e06446e48042e9ac482be0b2809b6d63f745c78bf30ca719e8db360a46224603b8837cfe5275f3d68f1de6549f01610cf25eb48fb58e2a301ec519d4c7b2badb5fb63909098b6033e85c2bff965afb8f94fd4406bea26f45522cec92b8cb7cf873ed4eba96be3b5074c623c04177ba3ac32f9a996b01b9bfcd94e8e7a7bc9443679a3955a91bd6febe0952bd2ec007e4d4e22e0d963b87f457ffd38389be135127fd6bccb4531d1a67303057f068d96bbb06218fc80020bb700443a35d9597df3625ab11ab092b620669f09843f34fab870b771112c01b4b0e4826ae0d84ff3655a92323078fcb1a6be0a216876c4bc4b3117ca9b273b5b1ef3df190831329fea5b0ae6e506cb7e3df0dd9878fce2a70efe3eccd2530177d15de0e624933d31930920b26836c01e27de5bb923d2acf28404ed153dc807139203caccc60145e8758c49251605355f74b24f18559be23eac70de7bc95569e3b2a8e4ff58f0222b2919ca3ca4b46619cd50247d2b139edc39000e980f145c9ab7b5e29728d58d9805b93ab498ce0a81b36c4d7b33171363cb07bf7e38a6ed1975d9b2bfd059df32aaf437d309809a0c2fbf6af0b840bb6c4622bd233918c3afc234651eb40f34589c356470dd8d289c2dc472047beb1c1d3ea9dfbb2147adfa2a26a81338178546f12eb18ee98cce1ae8dd109ffe5840e850774581365b385069a85fba7296dc71ec761cf12e5337422516a730c7d9d076f2e18e1b593e7ac7466a289b3aa519ba003299b92dc2eafeff60481fa751a39b6ffd1a378d9bef16de4c475975132c21045877ccd98c805fa468fb8a72f0eccfa69fb9ae67bf1cd571d69ba48a7a33e672876a53cb0077224d18a4a67fda1712929d5c50ca23e66ab6f0cc5ed6113fc73e1ccc32fec44e9da9b200a94a6c03016607c446d22307726ea0d86c97663b08eca6dec7d94c99f5bfe03f337dcb9904473e2750b5f5e899eeafe65ceb267a42fd144f6a856c42c75b2784c418b1b066e97777853a22dac90ece8c38b97368922950a7cf6120b635726392882993fc5119ddbc85f4bde36dea7e0aa878cb7baaaaf9ff23291e1e9871e2b5b74b2eac3f2777947b4a1a8192528befbe36b4c3113497249b8599141727e942535e3e6ca32582efb1facff3ce90cbc4756deab85d3ff59a834688d36b08616984a04627cc9e86a017126ee7e31de488ae92523abd73b05d0e9b823186c089bc65e24a3c818189430a73956f073491e849bcfa96b8e2f05e5b2c20d8a7048d867e5866afea66e6c03158d27cbe7ef487c656f01a66c049b4eee7387faecce5882bc77d836ff485160cd78a785fcd7e32efe4c74bf04ba646ed1afb3a73dc526dac9e9018d6758c60bfc44c53952355010bd5e49763f13080b788c4282c763e12167498ed5d62a3ec69c846a61264e2f44f22fa35728881dbfaf1e8cb2fd0bc597e654bf037fe3e91d0b219322597dc901256e5c8bd37aa19eee98c61c3a917e4c2a6b6bc6918c02b538d3d0d8c026964e1c6c904fa09b8d9c546af24a39c855ef8b168d2d00e6ff11188ee82297fd09783a69bc12e6d69f33c5388bd03c6be885c51501f0955f8b936a42bb8f9c1459151139814b82418a773f86d9150f0af73431eee0478a7172e90a8b1ddfccb1ee580093a984fe1aaf7337e70e76233166ca415c9cf3eddb6f8653a22b3063c83fe2d15d194e2dddca17a145aa10f2276e63614bb4745b5be06778006995d7fb00ce00726e0ab8cbcd3eb995002a1ecdf6cf3e47be7abfb203c1de9c89c639ddaa601b8a073f69cf8ca96e19a4b0ccae9bd1d4402a97f4503fbdd9918a4cc7c69066ac1ec2afc555e77a2fd899929da6a0b33ffec7bf0ef03d1eea7f524d46f446413311230434af55fb86b5db6578c048b62ac53aaa9897bccce9e637c2d6d5a2d4582b1fbf70137f78a24ce5874d858c3d385412bc1d16ff0df1b08fdce59c42c975b7a8203fed2e3a0b682b13f9e32906cbb9bd99174f0dd1b87ebf7471d8f7283df06c017b1d39cec4804e1e955ecdf3e66b22adf9b470c8436a11c0dcab32139656944dd66cd7f4e9ac6e256de8f27a0307ab27675f96d53ab97b05edf0fafcf4f9be8ef56bb317024cc4d54052a8ca6226e314e6f4db8a09b6c0a6c6af241d57fee8c131c1a5f9bc90b4f2dcdcbc16531c20a2843d3aa00bd9a7ea2e4652db13552bebb6c3b93cc3e4ba90fc760b7b56960f6ec9c519fc37a12ac467633d479ca7e8ab37e76381486222cc6d41b03c8c311cd08a8db872f2fd993ff93567ae502c5f023437dbdb19a01e67c5a8418c2854b6e8bc9083bb3c6f6537af511c8622a45cd5f8ca654ba54e439237174fc6b792f8fd0a9a2d04af9573922ef642be7507ebd5538ccb212481c1de77abcfa3a3624f4badcb088d588719c0fa36a908428930b3f500b5a87ca118fb6adf6847728be299f935ea76341a5777afb0c95416ad3a8bff3a760dc2c0832eee90389deffdf1da2121c7b4e4");
+
+const RAW_SYNC_COMMITTEE_UPDATE: [u8; 25375] = hex!("a0400100000000000700000000000000085e23d8d5f18c8c2e884b78657005d24de1808617876b5f35a6e2e813aa9999ed9cd6d4ba96f3fac8f1d92e7aa11786f2648a234bb6d387356678787eb17ac8bb1e5dbf4e6254b494e7b2c6c3a2f3165775f563648ddbbad8cf17bde1fbb9d701010898ee9ab88c31c867eae42bbe4ad319d5e33e182292bfbc76682978f2c5fb33dfdd4b3d5cf02b044949460c3b8d89c2f996580e107122186656124b3a26023cea50e94938bd38d230b6762f1f93be2c212c63ebdf82ffaf679c049dc9113f936783367c8696bfe7593d541ec86f1bfdef5ac253939a7d2ce90c6aec6cce77b618a7a88e48d8318692c2e90e89d009c6a98f77bb377f841fcf4fab337a97ba96daaf55ce6ce55238c013d4ab5f1ace8aa7658e779a5b79825cf3c19620e8a2bbe5824e154cda98044aaf0cfb7ff75055d1f75bcb650d6b5175193e6d7b76a579b1eef50dfe98bbdb804422414db037d8feaf095c389270270b241d397a5d36c1aeb868a60a1e97fccfb1f3a367c768cba3a9d09a13c57903c3f703709c03540cdb81a01f9fcbcb67291c881fb007fe3da968c430c8dcc1dbdbc0af4cd8429d27f1a57299d5a2b044fb16235c9550990739a25e8873786055b503b83c238f91dbeac28e1098e7967694747d8df8d4a7e7053dc28000fb714c1a2de40dfa7d47af19b971f511b102a15721b14cd3189c5ea7b3b28206412083b990cbea668712ff953551a8b900dbf268abf341e37fac4b11838a8f831c6ff4531287278aa6b05c633f6a9cc007526063a0a4f5be96326f29733957bd9a34b28dad5d33537d2010e88b77c2ddddda8f118311bf0ee9e9fe260c6f31384d807676e58bebb4200e5968489372bdfb57ae513ebcd1d69d0dd19085a1dd92f9eea7e6488a671cad8b13744021371fe7f267d9ee89ae8bf83ae7dd32a89d97e3481c4085d363bf64ddd05992563f94b32bb7908a9bfdfebad4219d3a81e8017a173fd6308158c89576ea30fbb386ea6e04a6b662aff4247d0f6b97a167a4e2efcac33501d06062b284130ff1c09b85a0c7a15b0ce0c49a79dbd5f11f2e464221f2cfc00265c2329b936ee187c094c97ef57c374258373ef41459a453748c81272c7ac8aebac83df1664a0cdf92b8fb6cedae346cbe4c3894f4e41195dde7f6b130a9fbe406a6c8167ff7476df9a382cfee39bd53a7740c1d1262cee3c5a3728f25d699b280a29f41f41dd6948e624b708cca1d50396146cc08260ea5ad8088e21f618486d2d95eab7d26f5ea436c461bb13dd58d72762819ebfde6b9075999b9b143a47d61e9ba27df94107f02add47bb3f51f8944a25430e544516368b7e7617587eeac2943ef34fc46c996c792afaa642060c1a91c2977a275d4f10268893747d0d0dd913f9d81e88994ad59070064e650e988a27e98ba3e777ea5b8b175f2225f16362869805cd154fda7eb51a89f1c78d19328425b2a2ba30ce7dbd503090a08c1d21ca4add997301db3383bd1e8917e19a691de39fc2f7354d39a12fb975e868cab460ba931c6c17d19233fbbd6e750570a6c2c7d6d0eca749127109e5b8b10950e0f0da713d10be0dbf16e6c58ebaa9748bb944a4852ce988af1f961ab980ffe34de93ffacc1f545b014bda05ea2cd5f22bdd5cb3c640c12081f0e714178f32fc4f6d6ea9bbee626acfc981bfd5c3ade14898580a1005d44956a2140e1223131a46626b0f629d8f1834d9a6f276c0b49a0f6e9387d83eb9d35a33aee68aebff0763a503c2492fcbaa0186a8bfee0a540380320155c8847ba12197dd9225299e763c09fc8f33e87eb1de5ba2053eff377545e03ec8a279b6a8d9512fe17d41f49dac4c82f711a852bfd7fbdf17ec10a4e72153c674488e001e03531438a3a88356c1c9d88ca7063daababf98ea6f32a7ca92dfe7b1606359cd2893d89c3f40261a81b66dbb0f87b86f977da5a1f5963512c832bf425afdfb1a9f283fa27ca3ebec2662e5a6e8acd9b582672b4f046fa19f8897f23d2e389bd6c266db3668bad8a0144e91b2306e6628df0affaa8a6f78ad6f2676f4f864ab5d2bf87d9c3de1f4cd1f33e5d7c36048c0c3eec7846a6c6ad71b21547fccca010b841c87a959774fc9d270b4a15df1e09d1e0ff550fc712014e34636a0778d3872f1ebd634601da4ca4a920256e0ceb73967b4f7cd2b10b7b6d4932b158dd17e58ea27214ebc6564b181d96558286f61dcfb30e1c8543ad2336ec1cf6d352918d91b3856c554283d0b9974becadf6a2bdd8946bc3cfd85097d2a0aa56a2bce0597b7fa3d781b793af615ea1fad9f1558dae44fc0d24c8377a4226e6ae4d98bda3b828b26a768b137adaa9938b23cb0327cb93bd4c062d3d7c915a7bd4f9db1f39944c25ce91d580c72065ab546a96f1ffda2614b5a2bd05f6c3b1326b505e06b8c2e07faf834438859c3a25bc4586c9bc95c6052ebbb773468b3a8c4c9c30bc846b31038f80a44274c53f67b6fcc5ed181f3a15e2502a0cafb8714f112cdcd2529655bc00feac4a1c7535e48b7f456c7060f1e3404e0f98cca71e48816f265d3345c0731a514399c8e2c871b2b9db3b25abe945549de833e7587447ee01f8cb9ea69bead26e4eb3367e5b3b7e3e0fa7582239660343221dd853fcd68fa10c1359b63ff1b8eac9c40f11754e9358a7a5c0b1b0e5f5aee727eb8270b3cf5f8a55b53ae7c7c063beb0bb118f6a6707ca062d8625cab551cc075c5848d6e1e3cecda29973e0c67b9b8c62a18fb7ed2c563f22692b6fc44d907ef56119dff90d6f267e9741312804fd161421ed26d2c1d2a217b38b6fd1aacc25ddd5b7de3f901482c3a1fe67269116007f2e7c959065cea9ecae16a1c7742349a26c28125360cd404dbdf71f0c7c09f1f784b414618d4f14fa59510d0921b7029330929e7d7412eee390f7d487913f43defa5c787b40fbc6c738b3e9101f6887b11ecfe2e7d4b108d082353307d0484d52f97ff2445b560fa5ab005daa31f5a211c97462e3ef98cd79bfdf8ca9934a7d83ef22bc93ef9bd30d1ff95d1217dd02222cb9203fa3e747058f0a60d68ed88d6f26cebd0e587f50d90e6e6b85612db9d98c198ae1f6b21ea1eb73285b956485caaecdc12b7f86087c98dd7b9018fe12890e2df55b1ec77793ee4a412e5611ca78ef9007d6b402b546f8414e062aad50d3d6b80fe115a0919cb8d8dca5a22d58d563972af8b855dfbc0120499be95d0435d25fb472119046f3f16ca3310685e4a1659242a50fac49d3aab8b98604a4e26a5eb6db397eae082cab404876d5733eafefebbe227f1717f62e3bceeaa261dd4f3e3c4fb89d0b6b5595f29546cfaeb4f023e53c0e24819d7bdb8f8710004d42183ee833c860bb221327127676cedbccd7699728ed277d726a88c551b28a5db247c482388b4e1eeb9002c3ea076e0c48efe8892ebb2ead172a880b79ec997eb934de3a51257e55b6b7924743445a3728c95a473346ef1f56b9111cda2c1989b2067af7340c3059c8af68e2368f2c4b4719cbe02f59273692b38cc6ec6a3d6bbf898d10d9f55d7167d8487269d634e9c79de6c3408b63b2cb02c8936ee3e1787f18e291427b2939d519b8eb1fa88fb76c66be20a14535480173719ee4f821ac5c941ed415ba727e9c3b7c81f4b6433673a6ae59e85bb75cf18cb8728f4621eb24d5402ba5e3a5d6d7e7c838d20e74baf8fcded38a45061614b1a08cf2db126093611602cf6a76a3263c920c2a1c1bda43b22222642dc9743b5e607ff00dcf115b29e6011dd8c8046fee313696c864cdc203ade95fa635f711ca8b27fcbae2d7c621ff025b3b2f7c46d4fc0875f9346b0a5a0b795073f03f4e68e40b177ea83aadcccb82fc533e25f6f2906b0dc2e441a78bd8320abeaff1dbac8f9dbe0a3d850b5fb44bc891d6be1eddf589f0b38b2503f583c6551c74dae129aafb8981d56148014e33a87d682b39e3d4d2d4ff54732dddae8181e9afb5646acf03e4334b77b6b343d4ee820682496a914f66aebcc576d7c149c762d54120f91370a9d68582722bc2e91c4bd0472ec5b5804fedcc11a47ff1798a3df4e4525c94a830f605c69602eeaafde3020df969bd4d43eaa2ced40a7e86514fe5ade10fde7ed085eef80bb1403d5f5e64d7511e805c20e995fc8f74454b05d157c7ad2c1bd5ae9709c1081dfac5449751245efc0340d7962d047b642b74166c4a8c880f83ab96975bc133921e820c550daa6ffa3397526f67c72f35cf0c6b787f23fad3f11068fd05bfd89f5ed62dee53b6d2d898578b797ba1d51d49251b5782c68b5505f3cf9aba7618e74718bc9c8421d55c89147791fca52ddabc1ecd277aa1d8fca0b14f16d5db9ee81b6683d1fc44792696f2e565ecc0dea16d2c5798e8910ffa19b1e4f322df80585a6e47a8c0fc2eb885226040433dbe19d9e89dfe404d5903f144a5e52d1b027f51328509738566617a2007f9eacc0a7adb7d7af798a1618c99bb2a234cd00f0e06e11fd731f1c2a5bc476c13b0f1db4fc083e414aeb69f2994e11a2e1fb2b0c8569e79436a000d20a8ffa312cdf636a353db792a6c28d17f6f548395e0228f1042e582f679e899045c7bbfd03bb703c39062e8f87787774d878aab0a3c8ac6fd0387da11d8d1e8263aad9fc1f16fd124aeecd2daa97b595682ed30b839fe9ea46ed3dbf722716e2089e621ab670d0da49ec7f7e8e10f342ef51ac727397838aabe6e55cf4493f5b434c658408fd197855816b15d1048c7c288079c31b10f67ff705909e425de27873c6e9e3c7dccde9fe77a7c9c60e86da73a1bf24b38b9d3b0c73608fec806e9e2ae8218c16a698c42f7ccbe4864697802028193c50e7fe6c34fe7d45276eae03f58521777a77fef2e4d3c7d14628cf5c18de56f1ca95bc13f4d6044d6f9e794dfdf80555e12750994a10bd0e452d263187e946c996061d54c6c34a8521fd50684a4c10773c3ceb8b2147286342f75b67af68da8e28b8375f39321822148fda00a28a87ccce61897a3c70c73a95eb8210082c1655d351dd2c686f01feb1cf8b6be2ce3d43172bccda88501009b1409269978016a790d1eff4224e688d97ceb4269a4828c5531142bd640e583d832b329381049c9ee8c01a2e484edf28f5d2b614c2af18640f63508e0af627ed16d4bb803b662b783b77c145f03030786e7c153a7fdb7046bfa805c0beb8bcba19d8267f53924d9e0d67993020344e3ed04b50c60b7bde0ab0cc894fb70cdc7fa1d6e486e2c1b1ed8673c08e22d7e96e9f5abd1ee9e500bd88893c6e904bc22d2c27eac8a818233e684b5506866817035d62858b348e706b654b4e57ca85a5711ca934a37a031e866b5a7edc78cac735591e9221bae5debbebac864a83ab31baf660c3059350dd41875ea4058d65c71e1b9b10cbf7907ba73767c55c11637385cf7eff7b4a1c2d22dbfd9fd2a5140beaebcddeef79f502ef3e7aea406d76d402d302edfc1ed8f596904a7738571cd48b076e2dcbbb2dbd9f52b8c409e3f0d028aed1b43a84c382e0ac06678a10103a9a501c204c28c81ee149c2cdeed26f31be98dea030c915cd2f8b2ba4136df856c38dba94eb1dc797cbd46e1354c829610f7155562d8f5add76a3721b653521fd15d542679f8962f11ae2081dc02513d628ac00684a5aadfd45310e917192606637862dbae40a5ac699f3bebfd78a4ddaecf7bde8e0d8e118dc83522e0d667294054029b84bc207ddb9edd51de71150bb0ad0478046b99720a157fafe20db74d5bb411bdfa8f99788dad30fa144363d03936da67f63a448498be53473a1edc17ebc805f751451f83cf34db9778e6cdbf616de911be8d81b1bd6fd0a71c2f4a0161a91b19df26e77ee3c746084e21a3c3b980474e65a8967bdf94c251919b481b2abfc1a56c5e9477c4cddf2a2380801eaf7c62d25ca971d50b3c8be6b8829afe021ca836a769b3275f5fe1ffea31efa6cdeb974c9f2fae08c5c6bd09d44f9ab342f114695e7d13e90a81a983b818100a0838710aca77162553f62e6023702c90284c0f64f462ab5e9cb152bc29d943153ae99e8feaf3924bfde8ed8514e8cf42eb97e9ed815a8783c149ce247b234908a16c849120fe920357433d4f7a5e88899b3e9f338b4634a0f59ad9645603108c2afd57e6d9d5dff9a7fa1b0b7fb740712f727960cb68a96b4e21d343922b5561305324f844e065fb0a843991b2670b9e823846ab72d5263e991cfe45958f2c4f828c7d4dea2983da488a93d2c95848398208e46f57952a5902295fb056e39af662cbbff8a610f68236a02d4b7fbe3fc0017ca9653a7aad3eecc034131e9d7a0f1d3b3825692d314a1220b9d5142b30e26ed9da4b3d3d459fd1abba28a95e7ec4274187e29b98a95e147698fa4fde8bf07eb465ff29459f838740999ba270ef5c4b2fbcbdb61e21719a962fafc93d11ffd07857fd51b48e1f344c9189a669e8da05f3551c2720296f7c7edef8514c6c2516288306e63222b7758cb5fc842951027d968354e66da3b866545a6bf5c5458577d3fc2edff7597320908de5d10dc20fc614dc8fdb2fca54584bf2dea0464016d992d97b2d98af1c5da79287cbc42c681557653c0ffd63f67f2c536fcd54d9d2573c336ae681380fd4b100da5b7d486ac1afe30aba74a651b28bc329d25ddd092fd0e68398838672ff96341e8497a529c83e95a6008893b043725ee98a1a7c364e88c81fdd5cb2fa0dd56af68da7b4d42c7c6c0c8511d2d22f7775191caea5fdafb8e42bc5134d9575fe5e25f6b0971ef3b5ec5ba11b9863ff17025f120a6098047f149fdb421d229ae1de3ed3377bafe5288ee5f6946844ce848130f91b62cd34dc0fc815c18da52b742f2a223423e1b389431d69937ea1f8c76e828cac82436bf9a0a0c1894e45ef4c74f7698c8980dd428c9d9ef7a388adac253159c518c4a665c47f9f0e283dc3698d3caac1475f1ad3e970f7d67b9caef7f5b8968ddd853885032d9f32b4b6695e63350cd76c625fd660e1f1b2639f0e72cd96e52c776db667641ee47b16c1b6bed676094240e21a79cfe394258fb1acd02373170f870a03a83458f5627156748a8d4b45e06f33b5dacf0befd1fc388e81c30f61cbcd76b717fbfbf073a0744a53544e2bf3cae3e010b32ff70abc39d74268a8af1f35cda7f731ad97ee6cec2ebe1024ed5a2903a473848cc86282dbc78d316844d3ccd8e5d5363a3668da493f8f41dc4e277fbf000119bf731dfc8ca9a11aaae4fdf46bcb8a10f1379c8d2cef9de79d9653bbdb71d138985046d0335d450672e886429294d02348f707183068278445c35d3f913192c1075652a85a21470b3a8e85a9b1f07d01e37afec12190ddfeadfbc26dd3404018d3cdac4eab216b68755b5086d74205b3bc89568e69fb84626a37885508a2a8ae88a089b0d99ad86bbd6caa60488a6c8233542cb32cc369ecf03e0775eeaf49e7a5da1ab8c158ead8eda86dbab19537b9af0e02e930a94a6c66eedbf1b7df3f410cbc3f5a226644c916811715bd65e52c982a71951dab5b919e2bfb5fc450b347fed5fd16ed2ba21d6cf8431c557652c98fdb22455a2aaf9f5b0a0fd69749a122d8f97c91001a0b2e62036807becb2e6cb863ed72d9de355dfc80840eace9b64d06609d788c973bb0a9c733099ca05de30315049285d60cbd4712387423d93f265eb9889f29199a4287803b3507616c0ae37695e1bf4e211c26c44b75a68c2df81dd534ad7a3266c735b6ebf998f24bc637885e4cf7dba4502d36b03102f47148037ed19f8296c40efb1dc902418e7f245af89a99eddb3a6fc1b65bbd6c0b28005c125b82cc66cb557f928b9c8c4e608600223b84c8a4c499ea1e77b9d53e0ffd49c2f5844e6a3ca42eb2009b5ad0634271a5040f3bc3c66964123cc1ff2bfac93f15549a89ba53ccb759c1cb0fcc74f56f27848e4ecf8c0bc8b4057d55c3eddab8070e2de84492f14dba7064e64cd6355da30cb8be686f7ed59c76b051d078f22356ff826569ae7a3030650c8e1c8558977cbee18e78137d3b5b27823e0bdafb10517f2bb9fab6f7a07ccec3085d427e0f9bdb96d188a551967eaa025c3f1d72cd84675ef4a0fe3993223fec6245796da4fc5c15b82a8d63521f7ea84a811383971811953dbb77c7255c6a508762b15b11c6226e35e9b60c854d46794d6e2a737321ea32599288e59f22df3b011748f130171381639b046b40447b529ddce912ebbc889a934853b24ebf3462a0816259443ce461faf3fbbb967ff1de27626e27cd76f6ac995b8107dbc2d8ec0f15f7214d8d67a04b2e607fb502610b84405cf3f20cd6fa37c3adcd1c2e0334c3040b5e9f482ea2f7d4115dc27e4e37753a6e4cd8eecf1b2d78b5712d449768d4a54ba494819dd94302268b57ec71491e7b23e19a3aa2872a859226b9313d9b9c64e4eb535905008e5d13c214fe9ac684c353e59b72baac93d82a52528ff9d61a8236d2c964549572a63ff29ba4b9f809e0c4a7483c389aefa3b7db03dfe2471b4ea87c8bc5a149892b4998eb43747014af4db36d921299548bccfb4fe99cae26a377137fb6802dc21e4c99652c40477daecdbca6d3b292fa71c86fcd36c06bde3826ab2d0ff18969c8ac9aa582f6f4e3177305a8d082b073bfcfa95b294deec49b1f25a32c0b93a1615f2a438f4155b02cc55cb12df9a8f80a0e1231db2a76ce418cd3f354e796299533bcd788eacd2c7cae8538b306cb31cca8dc7fa9be87a1a90df1223110ab1065fa1bacf8c9b32767528959d7ad19f142624ab3578a431bbcdb24b8477fe1cbd5c15df316340c0f53aac9970f8f90a47b99e304c9b2a6910ade52c7cc1da5e2c327a1a3eeb62182d8cac6899d7bb9cdd414a63c9b5802ece652dde0789b882e4c6d3f423b7dbf683762a27ec8312ccf2e22d71b3d6574edee14de683cabf46a31920e46d5baf351aafe490d6141ad15fd68042383caa9331347b66ee3b371e3f84b8d24f2176d0b2883ee9c0c4f0f6985b834d7f4b7823eafaa2dc40933956cc9a8bf534b2b04e55cd8d132e35933928cf3269bfbfc3f33c7d5eda7da1fa0a277181b61cf9ce4e5f322a8fce81797d0551e2315de09089bee56af3ffd9923046b154ca744fe24aad9561cb6f822281c366f77c68d6e890746221ce75fdbaf6b917b289ec5cbad9ba09657df4c19d080befdaf1984eb8e4a42128f4de7726d3dc84d396b64580cff6c91f36d22c2b342b61c1997fe0f28f87e21e6f98497a14d49b40e5e93a52a86c3c4d1560a7792bbbb2e166b5e9239000e1d7eae9ed3ac29a25f6a773e8ec6983e9334a9e4c91e91bc634c60bbd8f4dcdd76b5d025ebb171c0d2214708a32fb991705a1dd87c8c521e9ab28254b2e9df36f46deaa11a9698ddffcbbd11c81b03fb199174188f73b6ef2d0c8c1e909239722218699a048899afee6470ced876fed850ba6a953a6f06f6cd7264d9dd7c756c61d341c661f1b2a6f5cf1358c4b4b6f0a5ae8b93cf892e96e1901e3019897f1ad7d9e4aba7afbd5fcd5efbc18f9c4312b4e8c813ed3a3344f07487e5e9b75d063bf70f2b5c8a506b6853d403a4c902d8bf409dc8674a354dda476e9035665b04757653d0824060f8e0393fefad880db5b71aef1afeabd6f921e58cc54b1a1d3780977fd733488c3d2aa938cdb727323b9b190403385674e703c9db7cab0539704c80f64dd3b76ab455fce4588426da788dfa109e38d67c41f41c6aa5a1742ca524fcab3606629a3becfa1cfaa3d31b13b12698a0e3a3df4abb0bd2256f899ddf7015a88bb9db1319b6314186c1af1ea4c82f92c933c2963614638710bf396675821615ca1698bc2247573861c376bd9ef988c98e310447ba5aa5cb578e7ade8af755147937a9e1f2451efaf70f6844c9b9cd4e69248114cfd2721afe1bd72af9a2bde556d4c46afb7b2be1e4c81a879c6d7744a5ae156d5a3d9aec092c0b23f324bc71d8c7a426b308a01426bb1d47b78a2b4ad012f03568385303561af3889a14be4713b81eb6bd24e8546d10bf1902f0c05da2eeae60cd8cbf727a94cb65b8b4398c434eaa40fc0615b21e995135b48686637c052e7484f95257e533704b623d90a20aaa977eb7a2e85f0422dd1278e482b0d4feb7b4fc832affa13a6f0f8e580770e270225eb5041f2cb39a4ebbf22a6e3aae9daec8295728bc46ec3306fc9c4522dd65add3af4281fcf9d968fc9da624bbd1cc391611f99021eb2052bb75f2c6090780a05ef0d2218a5d2feaf76163403e659ae640fce6cdeacad41f65bfe257f5100288c65a3a4929a4bce06b90773e7581a9a478906463cac8db847b5bd67756dbeb85613dce78a5a6df7b83fc67b98c2586e7cdfe4571b4dd6ec3270b45f77906f2a4fc2df30c907b5122a5626797e2971329e8db661d59c1de47f02684fef4919f8fcfc8367c477424ee390d75db84921d85e27117dc6478a177b2d50dc46432a1b2f90c008dbabbb921e25d43d3daf89c0896815cb5fb034d6b0f80b2bde55f70b3a3931293a6ce44a55259faf8bea1bffe47fecd2e1df79b18a62d6580f66e4a15dedf066d1c255dee7a27dc6cf133eaacf6f26830e2651743861d170eaff2f3935eb0f52ed5c78129c618d17efb006b18ca4c1e43815da8e0a1903539837dc788d6f0541af7baea92e9e165e59c8eadb00c7e53254bf2cd3b93bf58920d2256ec135df1cc43a1936d867ad4a56937a49872a16c34d54770bafdf0d6066566104fed19a78d5e5805f663b3c0d0fb8fefb9aab4d0d024631cdaf707b19d507a55a7a95287ea18c23f58e29bca20144a05997effd7a3507bc38c6760e5720083bd27b09a803cb6bb490ced007b65faac018fd7d0a6eba1949f9e1a0b1ae1ab1871bb139626d5d30d3a1afb5391b9b2845e05d5c1ce4bedbcbb9eedbce5b086a520a66036ca8bccb69c5b9913ec7b1e4f3b6f571260c770fc188d0e63c023e85f8bdde8b9f67a50a7638f75c57f465e5d9c8163819b6588b9cb3d3ea8d51f8fde941d603b97c79887c9f84d82330b99c0af672f4f5a2b55e1a564437e09d1ec4df584a66548bb1347a7a3c1fb7119c67990b83c5d993c54b306a528690d7078300dff694e107d540fe226f786dc0ecacade961ee36040000459db03f467ed9687b40deeee6403311ab72e08f718dc2045508a336aa94a3c071c1d92bf38b7224beba58cb6db5e77d32186ba4042346596008823ff32d003bf344e56181f259c9a72b0e307242d6f531c92e981e89e34a496ab3983c022e5f6e49cba149355435d99338e7fe1fd3ac328c549910510cb2e71359f42cfb9b475fc1ab795532bd127b0967a60a910e2cb05d0a78cf7835a619ec437cb02763a5f04556d8d287c2450f32bd9744da9ccf12f05b99b75fc61cfddb96ccb6e5051fdb1da7ef983fbcd1ca571cf165865d9b6433f9644dc01eb1c2994ae14e4f57b07b5e81dce6b84dc4020b02cae3ba77a6d8bc91b584ceb37777f65be663cc8877da7df4112f9f3cba9df92587271d6a55987d5b01babf7f9a983851523ef960be82d0388cdd6d2306310d8efa5880c89a163495a162ac6506faec13608305eca03c4c86133ccfa355e82a4e359f8d1ec11e4dbc1c3d3697900573a54a764f9db3ab72880a7b51d394376d7c74f974b575c5bdbfc40912d3ae9b3811d5636d57a794a5a53f4c5efcc6a1f94b2f6bef5b7791183c1d06fc2b98108e25aa59f68a19f6a9340a4cbebe114fcab5fecd75721c78185f46ac584550f683afbd0ad179434662ba59914f6c937e46e73539ad0b9bf1b64a514436a88a6328f927d190b4dbfd46f5ff950ec85b43653ef9836ac9eca62a937c1f6f78ebdd3318ebf2ce7fbfde69c4f7a668bced59283955f1e2fed9bb0c884d74892038340cea098443448f957dfcc7915f1115f6f2fc5a0e3f06c8e853f6b026cbc71742bb90c8c378c24c86620f55d947767798fe71c3c45148e058a816f369bd23081397014c4d2eef008a68826cbcaa1ea8d87a26fdd5160587277480a35136b6335adde2a83be380dccb9188207a933ac74124aa0b7e43091c7ad13a09a2edd6eb5a5a47b88b430d1ae0a4034dcd7754bd8be8e6366edefdb2d3e49d83481aca8ca6db12d9cf2bf3c1c4681a92557a56cf67886ae4964f94df892546c3f710fa227c9f0d248a0c97198bd4e33ff483ae7a9422505b7a94fcf86fc628ce8e260eb5a67b1c3013e48b5ad0d675587a52c5b4a35f77b4f79c1bf90756ed70549d1b719a3e828b9dd90af6a97cbcb449e8b6e3b4f89526c392d000264de7179a5a0f42ce7ff8118580564f5e57d1228660da59145ca2b0acdf157f93c160a286c954dfefb98d1e84e656781bc09e90515a0c19c8ac77392fe16dd212bdec69244436a746769f7ea7bb9ce2f240a8e0ea1fdb344c3b0f5e65335721a72df8b1217022244d89d2ddff41feb4cc9281b2eab785b054e812be10231a3110b544631da46a8ddd2a3d669773671c378529f54ff576b96fd0098deee176fd25036534fe76bc841dfe6d56c98aeb8363fbc74351e983c68d1a87c5794c9280e0a7d2ce0bfc3dc380c9372bda8b23f736fe09177a4db188b10e13030c05c41378b95d728ae60572d04b4e39beaf81c6277f6816b06f8936b3f25d8936da82dbd4ec29b096ed3227ff15b424e07f9db696b968faeb3e121bd058f92a029bb8325743df6837e0c579080d5edfec6db4510f40753a6f7ddb0dc1ec2950f73f74236aa9cc49b6e4808d25bb0ea7c882c343ae217e6441ed10ec2d13f6fad093fe6e7bb5257144ee47cfb986d2a93960f5d095260ccfe8e073412628ead10e4d9dafb2a0a720f34897c8489531944f42234cb0136d4aab2d529ae5299996d4014cd351313fd02ed2b0cd535aad5a654e1cdf008730bc63cdcd9fec07c11f1e863e47b0770f9c34d18e3fca9f7199026478fe0b6f9c3b9bdbcb6b763999b5a8a7458f6a6206246e834f9d724a30af36d595350b41c3ddcd0ac57df3b0425c91d41c21e2a14998376bbffc5049356eb00c58213e86c6c3cbc2c8fe6f85615d0929e966c9a9155381187a77859ee09ae33c0a9eb33ded812163bf1efc39518afa291246cde5e4bb2eedc8f07551b56727fd76c5c0a8ab2b4da8e909c2c15f847c06f807e7fc77d4630531de396ad5b1ffcdad3b86ed37c7b6b876ecdf19b7b22ede3aff358481b078b6638f66b9c4f568eaa1cb12b112135774c5bcff68eb699a342861be86408e1b4083c09916bb081192bb4c86add37f61c0f7a537e07f8ecded32f1f9a17850c3190979762b40d9e4f001a3de353a7b02be80e936e8a955e6f5717f3aebc50bd3a17f819908364e0fc281fbc609875099eb1d0dff5931c9b176faea8ab25680d141e7c37cc0c66c298cf2a21920d5cfc7ddd27f8f40c12726707a6b061bae906aab369c07ba9ec6389086b238a01a07b35713deca81c856cbb647e65d28119b76c03a4567e7869a62887aab5b871696639b2430a87a3ef99e1d27d875ee9218af373d1e8156c69fb3627605b1485599f16ec7dcc49ad29527f7c97ab1953627cdc1e44061eaa745b7f4a9e95ab8ae76a651e4c409b8582360358078aa6e96fea403e55d774ee1e2e1db7cf250ddd3fee66dfb388e887b5dc96c9876c279c16352e8807613d80b63432fad99c205cba5cc028212f755f5b2cee180fcfbd6a25c26b18ddc074a3fc4a082738bbd11096513f0c2ea7733b6d1f1c47376850b58a9442e6008a625777bd83e26d2081318b55e3b170bdeda50958bfbe40d26967adc4e3994586675b64c3d45b315d6c38cfb0077118022610167a23cc64cecd4ec7cad7910fc14cb5efd1b60b1d9a425b22d17a88e89d3a8c77aee27ff93c7ebbf2d68d6fa80585b9c1a838a45d6bcadf460411ac65db03b6ab98a27feeb9bd8e7f24beb9a24c8a0fee16d6a51fe4b694c7401b1e8bcb54cdc3df90dd6a501c466df0e59f293d7fb99309838272ba2380b89cb168f21e40805b52862ea07dcfe173c9b0f83ee216f31c3454683f185f6112a287d72496de8a1a68de1e6a70225f367f0f83a30f06541047688e308c6492ff8886a6d26854074c93b04b8c66eae94eb93a444a8e688f1b7282724cf2e4ad130570c4315479c7b20322fe5712e7bf974dbdaddd94fe57298ff33e056f1603e35a47b602eec28349096d2e02c2c8fd02b177c564d0dcb5bf9673dd395b630ab9770d6924297b8da8096e2c071e2245e9f838e2ec2e39b552a4ca84a038e5dd4fdc899fbf2347c46cc4b7c2f1fad761f11858051275aadf08fc23b08e4d8f47643343754a80d4897b90458fe56971bbb0593896018451bdef8eb45d60dd09eeaedd579dfc8a3bbebd4f5595197aa5270337afeeabec4ea27423cec53c5c62264cfd3c67cbb1e4e3a08cf2239d35695a4d2da8add6db01e648c9f3f850eaf4c7893ef181d9901da3ecc4aee83a05aa7766d02e69ebed3a16f29cca1b450652eee1107602e9ac28dc86413f558a13d5b35b82ba0be994fe96b5b316070cee2a7c26591994531fafca42341ca531f4df53b04c7907fe54e23d411cbe6d2bcc856e96c988e9a552309976c00e0260134a11004eb6b6a5183a55bfb47595d628c8b9586007f8ec236c08675ecda8da36a30bfd6363ddcd9962a3f2c8acd46fa6311a3ca5cf6d575c3e3cf5ccf993e711346ac0997c3622a7e6550f98465533855af3cf99ec5c0e5cc482d0203ff9a0659444d98856e1f6e405c70767774ac5ad7e26c6036cf4784a4a2cb94d31a39f60f1439cbbc49d4a2fc28f9d7a2e28471be5a6eb500909d38b09906db583a660a6ca85b2597c2ed7d7706065f5611822827738889c4eef0ac4a9a784c213c583b66cc178922ac72aea636dfc2c2b04b1edbd9d72bdc15121339fbc46c347580a15ff836bbbb6bdbf3c31b8563f1a0fa4c9f11ce6741b7fd7e9c6487b492dce91e224f8d94b3863a63c86fb94424c5789656ce752651a6db0bfb88f27cbe17cdc5e0d1359098f144ce941827b7a6c0fcee5acaddcffd867142c2a0c6df8c7f1aa861bde624bdafd93aa51b304503ad36c4fc3c8014441039028b5e1274d5228f754650a33d8da85979d94559bf8306e4c7561a44dfef2be6dc1a485274e54a4c121b001fc8b166cb4ed3b0b0a2b7778b223fa46a2584c35e4ca8459103221af27e14e008ae14612b6b91a955564c33813ec69501a022349b0029e33ddfc812a27cbfee48e6ec77b49b0d987fa55d7c9c6bd2ae386c22569daac5c90208afbb897caa2a512995b90d1be6525b5daa6043d031ec6e840df3946cf5f16b3beffff3e870dbb3b0689b75a2d848f8ca0abdafd9a12a9589ec18a9b612987991f4030abeeb195c50f6d631111a634b468ea3671f5dcd3630fbf8519f543b2d8c9113386a8e8b4ca12a96b35451be41ced3307d1e7b5c9bacdd7fbcb579805324d03e83c33b7ec6f9ff83d8ecd178e1480afa91d6382f91aa0d71c42160b47ed7e529f0505a4d4a0ae3a83c33a33c374ffe6924b680b684256c646c440faa225b3dde874113a544c1ae7e019a7abc2d976deccced4d7e94d25e8b4bf1453d1886f8e2aee23ca99082b5a4e477ec3b4f2ac06f37dba8ef63928350b5837a3eeab158b999164929df000c9a18402e21c94c375c1e351c8d07ca3b7470f91d6b93f9730be51f96222306f9ca4097dd89e48a20f02957dccdafe90964a06d5f71ac4b675059a3a94e5bc8126a804d9a5aedccd02be2e45b4e28e841061f12c442841daf63f5f6ed4b15aa95714dd8b39f550a15b230f5e68f8a9fd811c83c476aaeb044ef6d8f2a71a222300530f1dcdaa97a29260477a9cc3247944f83d538ef9ccaae76c1acc4d986aee1512d1ad0c99bac7035d46e1fd041a0ab152a2aea29eaa4d8344a69f3b8d21d884f4102989fff5fb31b82ffdf81e74476b026866ea827d98f69698ab86a92c851bb837a7bf0d0ab6a627c7e08c6bb2df4531ce035770757ce8b566b74e72e3ff01d2b0355caf7c0ef8824d1c7eb49a95dadf80f64f34c6963576f399577e2ff85943972be5f6d0f8603dfa58b2120f6cc3a97d7126adbfb2fe5a7f9e6a58f962f760bf82e495b36d52420eb666a6829aa522355cbb35b500fafc5f24d1573f5f8705ea961ba4ff0c3581650e609686f6c4602a3ca7477cae427c2b6b4344b2c298a2a823eb6a0920fb0d9e7dce9470830134ed98c2b74b6ea38f4dc0631d65bb23c7ac936ebd83f545e4f91c4dfa91fd25c165cf82fc4674f280621599f070511a3a8cec9ea127910b112f1e1612dafa2b4bcef1db4dd5ec10fc8285eebc67f31c92a596ed840c0c2f30bfc52eb67a5298325523e1aca7ac72f592ced6bd16708b51a4d12733768a1f4309417a4c2a728b14008bdab0307f7beb0f70b3bc161fc6482632a2a81ececfed18174a2e3573c404ff98320495c59648a1d45202048860090ed90a42d511cc85995e351bf80cbd0564b1a5a5c6366dc7429378746ce262468d46e2b0bf6778686b70711cba01c82a667c6fa7a42968f222093dd27a3f8c94e0be6e91e4d27a07739b5d54ed4589104c116a23f303d00e7779f040beadce562edfd85dfbd0b1db51be41b20a710e799c6ab188f2ccc7bfec5dde28162701e3bedb0a8a09a561ea162fdb67621557e64590ea374ae57d8d969173d2155f1b3a0ce2c4b4fc5b5497b8f1ccacfcf87f8b51e0757adefdd6c0a746f1423948258086d4c409948cd1fbbb2ee63faefb241cb0b46aae74be74a39893d23dc3a2c46c8547e3e1deedb9271cc58362dfa046cf4da12974cb75a89bc701366a333a62f4c47bdba0b79cfe0e1c046e96bedd49ff83d8d4308b13fd615d11b317fabe55802e63e1b105e230dcac2b9e2c13f07f1abc33edadb9d942871dba1b86ff05421009c88cc739c65771fd4df4f00e1b556bb2c5052ddd366861af8caf49c65be7e5a588ed8294c89285a847dea1c28d127306a2d13d139986be4ddde13ff19383700f88a26d870e52440db68be8af5badce73812e95eca6141f09e531d74824e0fa4f64026a45e1d59d5da041aae0f78b281dfc8360b24db76a8621b71d40de306920d5ae8659f3169664c512dda45cbf8f6d90db62ccda42ef35066890a954725939707321e3a62a37f4ac50805f35f1634d65e5a314a0428cb721c8aa690a2cb8cd378f18f3b062e2bcd0b80abb150698540436903a455a54f8230ea265e57809e9798194c805ac335ea36fa1425801ce7d483b0758909d115c674793b66f7c58259d7c1ec6390ab60afaae04e1e7ebcbf93be38911d4f94c77fd81d728334d58379f733129bd570f1c2521dbe8c622f8f434a2ee06744990841750355e3005a778292097ab82297f04befddb1649b6da915183e6f8e75a83fc97aa5f4ce33617553e47732d75c58827184badf9a91a8a3d79348da889c004d40e701994bd2ad5872cefbb0d6332edcdf8e14d2ad30f750c069301c0d66b5ad70acb061f4f5d4950db1a8c2874f8ff201c3884ce826545fa36a3e3a578c529e33c56d2a81ba5272bd08a47e983e56796b955bc6cf3ddb2a660e5ac7d38305d937970afe52717b5d0f84794b6a63b433475c47953695d9aea84bc4da9f00ac1d0ca66b80268fdad8628c9a402e1c7d4687ed3960b228d79d5247d942893786caa1c673ab111b58a2006ee2c500862f837edd62f6b7bc4730574f9a8e4b89ff1ee6ac0b5ff4f82694feb02974ffb289f2a53f10ec0d1115c3ffab91a88dd3e504d69b650ca359e4af9717c8008fc3bb0d08f6e6f89363e1fa6fbabb186256b0d3bd53cd0dfe9c57aa9061f7d87fdd85cf4b8b2cc8f36bab6884175a490ecfc865223ed185619738abc98d0d8e02e8fb77bfa7efd1c57f78c1d4c6e68250c25af1e2d03a12fa73d105c6a6c85e3204aebcc0013edbfda457937e087e71d2d19b22d5cccca2e3bdc82f1ad99398a8dfe1031676ceb2ebe79ce404100aa80715fd336dda0251c3b935253325109a41340b80f9f1bd19df1ddb1d40c6657a2edf58836a98e1026b17d7723c5e79289cead574f0f1bc55cbefb5946b862c93e49c6a3c004ca01b53d8a176782d8bf21b71c3f6e64390e4569c61a72b5e3b4511edb6c1969414c8d777bcbd074fdf0f902b3dd62896969fddd117140dbf202baf86a15daf6d4c9137a23882044ea9116c30660c8b1f322f9cd3fb49cb0ca2165d98eb013940416a240c0c44e49b27cc2aef523670992906646ee545c1716b90027ec7605f6e3bd3cc28124a51894e1cc431bfd86a875032be3b7f3926c0abd18abc7bfff0c7d6bd32118bc1a0b80adefde09d1077e1fda3e7b3222bbc0197b12b8f353d4f79b8fe82d038cbb3cbfb20df36a5b8ff38d47fdc223c4fefed0b877824609473258b719b1cf6bbd98e532aa965de41285ee8fa7793b02f21d8f5c0b1346c788d08af4157c6dc326872ba6764de634e22757cd69848c66c2fa8bc1ec77a94ff319aba76f8d6f247aa9665fd0078be049e3e504c5f15b474857479116668530c5d2706a18e3cc67862a57ffcfc02376416f29d3b90b1f50223ab24619ad14bc4766db44151dc6ba876c9888fa67cd7c1484e596a30ab22638fa5555fa2439fc3a36e66b23d682b83a2daf7f49714379452cacd4fdb4805f296de98bc6b756ea7785328f2c71995a6846969370aac6a5a1c905179b26dfaa1264e958db28e5e269becab4bbe8b9642ff64c8b4051f97f10784422e0aa2180920dc832d1bd0f8499529ba0e789a180de8bf2de849a37a24cd25042af4ea23249fc7bb514dbe0ccc98fd4c515eca0bd9c58c7d5f235821e6760a1858358ec732ee631c10e69bf8e0c2089e98d1b6d396e6ce1a50be9d51b4a52a039f09c7f335d3c106feb3a2be7f98ffb155a0630e791bf36137e072edeaec39d32ebb722666fb02db3af49fe483654f6574aa361493f5ce11fe855149fe348d59cde4d85cf89c9105be05d6d5efec233f2aeaa910a068e28abe5f6fd1affe735516ec021e5222854f5a602a423c1afecd177dc65e5966e403952ed100984075de0543866a5ded0ca8910d4ed7494a0c3502fdcfba4a312cac7fec363c6a5dba4870b9a65d7069cc6cf50e127fd75ea52aa243d26023feb5e94c68574afc3e9cc7e85b1234fed56f8222e101f503f2cb7b3ce8b1ef39724f9eb6b97d2de324a7a09824f813cf8bac191392c7d29d9d2240d1adf0849262a3edfd118a396c5e4185c86407d743a6120ae0e2f9195a9988bf768b1add640148185ccea71963dd252349b0664c284c2517f5eee3cc933c2b7605a4f0a3092d66c1bf4c3bc85e62e3620343fa20ec6e538a0ee0d0d6d94433c1fbc0976c164e21a3af93846c49c8bc60e7de0a0aa4c9b81a26b08d3cc9083d4487d3cde2702cf55a3e64b04dda83a309815d485e2a081a569065a16322a7452b21d4718938d426c906faf6230746f24897134c29e40b8f394d32cb38a8e99e064162775d61883806fec589b294987c34eea7891fdddc681998bab5dc0a4b7c3571895f0f92df08da9ef29311b53d258d6f1774f60b08622cfda17982e33e2e39af49ecc11c7ace7a7118ea6feb63d9bb7947c8f7baf2da0aafc0136048789c6f4f04525e7f7289372a3a69d552744d9091797cda76b9b94b0fa55c7c44147e402d1682b2eff7a6aa579319322ed8a4f08d1fd03d99956aa909ad96baedec33799d2de1728e22420c3bd77dea7f4bf4253a6cda9b6548271b62f160620b4762a2846639f7b0f1fc97ce340d227562eb8cdcb0244bddb48e962c075b763c36610c460d20039aedc46aed307cad9d205ba9a5ff18b4fde03c4a33a20baf6286acd0ca9d4583a9ccd07acdfd43c3ee3b0dbe9ce16102560d402a54dad3ae7bde6751521e4982f0572d617b878fe8c39da5659f55fa42aa631b1b968f507c66b00c637208529e327e93ba2bc4bb87c3a966e1e2d1a1bfa345765f0d5f8aae8173a7670af21dbe27e3bca29cd74116196879fef5f40b30fe223c5af96e662b856f4d0718b9a517c821511272dddc53d8f59f85060536b1eefbb70415db5df3aa005fe94338bc777ed1e06a19443a68bb7e4e4ea998c8a5aadfbc7bbda186c77313ad7d0f1986e9859667f46c585801028ad22869208e0f036b7728cad85aa07e05e2b3d64ea9ccbb96e9364f795457a05402577a8769a0ef183c9d1fc773341dc6963f7efb4af69bff00fb65201ba1b4657c1eae83e5ae8ed640dab8cc8c45253905a2c03c3babbe5ff38cf97595f51b2aaea4ea192e10fc62a65a57a2dccb7acba69cda2576998bb4d3cb68314daf29818db9a0c7d3a76c9066ad76e0501cf18ee2f923c0355716862ff8312033f8ba9c618c22839be0697800f4224ae5059e711d60f4d5096240bbd11bb317c44a9517e6e4ee06a2bcfc5257aab36c5879a43bbc260ebf784c410445357a6d147ec39527278d6d861d6b2b30dbb8eadc90f4ce61204793b783ac0f23cafef7dfdb37a5b33c4c1a35ad29ccbe40435bd6cd80e574cbcc29a769fc3058f3c1b4c2cf048b9284e685a5228bfa887a49e5c0ebfbe12b60f4dd4f65653101258e54c34950f5198dc8221300e45b0f34e5c87e3825bbff5d0676f2f232ff59eaef7149cae30b6350f01d781963faa258fb8e48a582906ab55669e0098a384a26862f30d96e0dc8598246ecb143fa6dc90e3a2a5e08342784e974d26dce0805120d8b2c66c718dd1578f3748df69ba2400d8ff25271286f1711d55bfbc888b5da0778c29237d7ba4abb38783e8602b8058008f916a7540f71683c521be1cb63b81931bcfa761d083895e20a6fe92244dab29cfd07a02f9ddef4148923914cbc49ef493ee9174952b23a3ff902f2455c0417abebf186d6126ac91e34d30f27bcea97e7e22b8b2e129c6cec99eed28d443341a41dc6fe7074b45e551c07ea55d7d4fd7f1bf3e463b4ec3a2a09df498c44e89505d4654281f76fac37e95750cad254575db739c2bccb7576571f960c63000ad42aeea93d41593d21e6ac21cdb90a885ce700c6e9da47ee9fc66ef53c11400f237e06785dd6734f88cb657c9795d2cecd96611f428b80fb4ea025f3b51891e8cfbac085816a8b6be62ab5a869201a0b8f6e7127fed899824a9f3d9b48fdaf9bf43d28fa1ad2433c50271a7f81e7ac1862fbbf311638a12a566ec15409455f715ea9649980c770b9471a9153e48c32327229902d14727e3b2f768866babbbc4830e5010e79841308092e0486abe167052ba617362964d333ebed274aadaef8dbadd55011e0d05cf1461234934d660e1916c72363925af1ebf271a03a5cf489151feb98eac81b9ade3711c1fff829bcdcdb5743f89843433292cc4933d0a1fc3b4a30fc6f17b370efeff89b08da34b676d636b3f6b47d9c65cce8bfd6e303fffe622a0e1ab54a041167f8de56815311bab8eb43119f04f8dd976b3657be76268f5235b18d0aea49f2893b7d739791e8f44fdc75d53cbfa75c302d4970a55c5c987edff193f1649660cdb06a3435cac62354297b1215edc38ed2ba54d7889ea68c093d8d1badfe296bc494e7e6115f62786fb73974d3e0acf0868a4316d18ae2d9209a1efac1bd249865170f3451d2ea3a4837655f76b755edda174699cb820f0b4df39847bfc8c8252cbb0c62d93aad59340cc50f255e67a2dc724ff6d76cd0f194cda1a0a9f8bf2b95168e6279de3fdb670eef121b2ea1bfa5c51f40bd3b3d5c8ea861dbd8694e3baa90e1c594f2ac85c9f8783b99546e9045b56e65675d4d1a082c7b1bed78e825bdecb58aeb4df8223cd54ba1c5549cf606c52198137989366b2b2fcc5f96740f999ff6391810ebb8da57410a84ba0f17863e22ea25e0f29e94d980622c05f7d7b6f2a9b4e8e232a56d512d9ace26555206f6394d15d21aca62ec621aefa65bfc65df6d518eedbc6134d9439c1d616e14887ac189bf94884e73ee7d0d5a47bd25bcb29f7525ea00d859b9a48b7f355e5f61cda5790d4a0a5f38985bc7e73e1a1bcff00164f54e45e997baba2223a5bad00cdc9edcdaba96faa651b90e9297aee6c7c6bd08987a9c43e501a6118d89193d97fec6d0d05b6df5543a90072955031419e084eaa2ddd62853e0f76d8330282cc614f9a46c3e26d4e8b6b3de37e4a7d40e46ba8244663265028c9ec41b00106b30db276da53340c89e01aa2a5b2c5a151b5df03dcc1cbb358062c2fd3cfcb3b382a79affb9f16a315bf11fd7fd999d141876283b15c6d2c901ec41962018e9ae766bd94b7b9c6c9d5852f7a89d167fd1a15b4c89fb00c75bd0361b832892fd0847a591a4edff318b1856f3160637adcef4a565e02a7038940646af641e8d4ea63de2287d4851015d4348b295018bf5db2e1016cb5e4dd47aba6f62e5a41b80f35493e0e7cc3d4611b6fd56d95823841a4cb69252a639fbc5d68fc22346cd3320215f0c79fa9996bb6b2281a71005b18cca3f41490e54830312036c86143b3bb37228ae5f631712ad8c449f54e1e6080b9cccc2d1f426126808b338ea17b68487c2f75d65df7fba6c6a95758ca6630f21d9b68b9fdbdd9aea38bc0a4accfab22419061a8ba0076278eb8b3d1dc5cda8c4b50053a189c681ccf8af5f05b3e93c60ada585d98bb9f7d58b2943c0f0a90c11c3dc48aae418d8ba8f0a1ad89f65d17b815efe23a92c79e232e75f70361f046bc02a1609bbe70b7df9b76b4e8660433a86a281580d34a30820bb488c5098694370f92266c4460daa5b8551275252baf01adb384788c15519883ffede6a5d07f22bfe37b3374c6c590ef70b4adcc1c5eb4cf1cc05c50eb34929e983cbe2fb78ec405c5a3caab08f1e61b27880d0fb79d9bd611272b9c02d995fecd526aa62045fbd085497ae9edcc5daa43c98c54fe94e09d9b780f3244c3a0e4ec37d2c3979f32fd0ba75d4e3227a6bd45d0a390cd3a0a1fe6ca893e1b16798b7f1d7b464ee6f5e5842723974a8dc42f0e54ffb8a24221f6c4c658c221b1965de1980800434a84f364c99482fe64049ee4abea1bc68b3088ad58dff3eb61d8738967d8c2583493afcf7c4f5be72196c0e3a25e1f0808e7cd28d954d87d006c5c6271e43811183373699a8f9d2974be0004ae574cd7b6e9aa81a411755d4d83903c9a392bc0715d61e5235763442052f74107340843b0bb8bbb8aeaece02e7f4724700f46e72f1111a6ef33620d9eae2c68cfc5bb77458f7be200b7fdd31b60c629766cea3c92dd34b77437c85f7ea3288668c214629df9a694080d6ae665ab8926c1dc9dff424f0e91f9e3eff6df4ed7a80d229059e58fa656a1b683075e77bb154d2abfd6bd1d8ff58a7bbcaffaaab2f1e15b0a3167679e7b6d921f83cd645090483d36a5ab496e1dfa76976da752015580eaff545b2aefd74d2891a1fba510aaec9924ea92775ba89d25ed0b08390a3fb832b6eac271c43515c7eb181fc9c568871519c559ef50b3d352d03d11a174fbbcb0fbaf8c8bc0fd6212ae988d9ed5d9e57d09fd5473e6e552f4e17ee6d2fdc5a2e4b3296ead53c10780d989d092f6c9c8556b4f10e6305cd066156f248a75193f4245ef1effee334a0499704d960cfe7af00cbd9bbe1222f2660a6ac9872771fca6d54f04cd53094853e5716825cc2b2ae175ad3002539d29a56c2b123a5b5585aaff2fff0d2a5dc3f76fa4f68de0a4a2111ceea80380afb5c688283ec5ad8d0104d4c4c788aa39f1be2dff300614df3e0845004de70c4d1efbc6a4dfb7860495ba791af18b7bdd4539abd429512b120f466933174a0cd244da0335b498daafb50a687d66c8ef9e1d84a6c78e8034523426f5d8e4212117631bddce6cfc2eacc2a7d2896d1514a4d3afab1378fa2d46439bcd5a35b1c27a6d38e9ca89a22ecef7f71e873ab38fe61774787302629c6b97308a1b368b88ded8f23150df1597b44f6fe58baca530841142e6dc6bad79d41a5ba13dfd1fb813a6c8b16518671ed266037efde267e1c6d60bbfe3d0bba84c161f935d2a78be137c22a62dab951cd21fc55afad151486089b001942e11973778816368800c2ca9aac0f300a952611e93d6a7c1e243e4f690fe51d2f0b66cf5e5dfc8d64a21bbec74535dc55f7b2f646bd9b3050fd04b5eb363eda5c5da93af75ecf7b5f5472e01ef9d1f6446b9144f5cb572afabfe6ee3ceda031d98890ba5362f6f042b0573f54735c552cf62de712708a70e6e6cc93943fd8192d7a535490992c13882e8270b381900750f3267b1410352a76e81f1ba4fed3bb4032e6366c93e7fac20a8d501fc45540570a37639b7ecfa94d68e612ff98fb8ad222c2cf5193ea47d238f608b3642eaf77ce8e5f841842d950dfee10a8d2cf864b2ace991ed16366f081e19f85633f34d1ee3c6396f08a43fc6b9d23af1c6a261f5aacee1bf75d01437b81d526302b8154ab81c2e112a72d64e40e77f0a1f9d9fc63720e8bc6051b383b4012990d9c7e00b57e0da1d2bd2d881f235d31b794072e7805417fa273122666e0855c75736f5861f624e1bdd3b6480207d0e9bf3e331d30ae035468a17fae3ba07e3663281e5baac8e2d9e3a503ac21ec917f0ef077268b441ed068d9fbf9857f2974db3f9188126c3377f35e242e2d9ede3de93f2a5acaa252799a3a19bdc079ee7629bf5552199f6c44575c39f2e4b7b926f063f6be6b9329b18a6747c769876f713bf77a352b32c76559b009c66f10b67512aefe8a4c71d3d9037b57d15c4e0d93e4b404f2639f4f469565a762a225f6358efb32a93b45acd7cb86bc0f52d9699b7b4086beb2bdffa98cc6bcf06d2e5e932c08b1af09839061aefb686b7db7d844171ab16ee85b1caf6d9616786b2ca4ff762170dc60e21e54933962e608fcf9579364a4e7a964792b35296732bb3f541963e2b83a1a5a99c53cf349d47c9e126558028b286eaa46af2280b514c8e4aa62c6207342b5ed247e05ed58f33e2e30b96422291f280e44c0f89397e3b2901456f6b5e449a4b6dc0062e4f674da6bafdd988c577dd73c97dde707d64d982e3fee3d0f8d2cb890adbb425bf67ba9ca0e1304c5ee54e303ed7ce0d29771a78b8080be4bd1335e815b7a23d832c0fa77c2be78d4b73b2854dc855674a5bf4de39fdc66a46bbc2eee97ecdd7b4e693ed986a3e899c0ecc4327cb536acf8b9d740cf4cb5946f8daae74485accec1119e9475dd87af058893f8f9ccfe67a034195d37a940507a3eb17646b00f6754a53562b2ef27ffb10a497a02555b9fd4b343a06faa4e4478fc382e8ec1981b595c584a046925ee08de409fa646b897654cc30fd6ca7c79ce9be90ad9492adb22f13a65ec4303cb99949720dc250ae60afba7d7a303199a123adea771af2821d1191d35e3e2688d3e3328d55e995bd2ad39bd1b7e80abef3d9b0021d5b7bb6b59a651b76bf1af6e0a920aa76758c8ee1f525b1feb15c58c5248995f09fce4231e3696df9540b34472f756aee3a474e49f55c93803aad92b6d0656e5ce18ccd255d1e427251af4739ff2788ac33ed4b51428a54393ac64a80095f1998f095738d058cd98daa858ad37d54d63a7922011d915bc9197b447a948b18b07de9fd264cccba0cb087c549ee8d971b8694fc18d52907edffce5ee9da5966c9f244f1c4d2503a64736507737eeac8ae97d08690de132c6d65c8fae51cb02b72462c5047c210b14605a6436f763ba70a91f8f8944e2bb127e601eb11a91b8baa32ad315329d3844031e1d4d9c5f866c6e758c0f316aea937e09dceb10ba6f36b279f258f9f78f17998248a3313ff9c8ed341e0c167e243de8c1617834fde1ec516ea961f086f3b92eaf8e26d472b82be95b236af127bcbc8c6e8c622aa8198891dd1360cf3a2670dab96fce71296bb6f0d25bc0270341b0b548bde01bdd69d86bb341bebbe99a786b664b61b6c5767b47c1fec5b8c25a3576b6e3ca0f8dfb91dd5d83addb711dd6b9b7d49eb01cb6eb21804251319d531f35f18638ee64542aee298216abfc1c5b73ebd612e938ab6d69a62428f6f20437ba94ad3fbbbb9eca2290042d4992f747a007a80ff03a56cb57bd8514e0d3fdcd4b1e50f23fc01a0b7df87f882cdab8b03e2b6c07822fd5c3db7d19033b7721799f05de4397c56c08d030fe9529e04869e20f1b91b9a78666d1c8f822f05b088811e3581839abdf6317bb980a49c9ac91350f961ca23ea1e86bbada14a02883b86330ad3637d80f56287ee6776b4471e748420dc52d46600115d7ecadbc43eeb2f9b0d353ede470ca1a0222e84d7f0245f36a15c5c4f78121bdc69bf3cf0091562bd8986b49715e3cfbc956066ea2f4fdb86fadb0b9d5022a46e0cffb2eb42c6152302e16d9e14a6510c7c46250c87bfd69f2a88818ffa8408a2b2823e4a07c3698b2d010e8ea05eadbdb7c1c3172bb3457b0a068fe41e0832e97606947d453e051dd08cf76ca15f160919606ba857099614727d54e1012a8bff372aa660606106b39b6821f472b445ee3b658a60c6a18f17210b8c1be4b4a70d47c4d8cfe3a4f8b8f1c420a08fbba5663b9d94143436d0b23d1a1562dfbcde42f97ef34120ca957259eee57a662837b7d1ef6c340e029ec3b5b2f30757f992fc98a98fc2398639138cfb80aeb5db32509410c65eac7411bd57e8f0bd4ba2d79f0e38a65c5562c4820e0430538f2582549bef6fc081892ab24ae3e8ac22c446b4d951abc8379823aa7d4a5f98775ac65759bd6d65efad856aae70b51f4d7c84744c3b887ed454fd2348a338a1fab9044cd87530219601df75ff41d4abbd983d1e0a2a1b736a6311de5686567783138637f5286a511446a0f8f9fa1841865297a2d3c8e23b532841e3624beafcce0210bb90a107d24c7001a39dbae90d4d669281d65180163f1ea5a727e920cd022bec670085a4d799ec42a891f10d113b9582a91827d1d29cd8deee38058dce2b9886d46c0adc80e9a9e91cc3b0faede3a9eeda71936a12e0ededa7de8eb32d39339c4860d5ad07778ee473b86e73d6380f92d6217d5b4d062eafc9ca566463405da7bf645b3f0e8b69a2c6b90924b22ef5d6452f1463dbcc3164bd52a5e3e80dba89f095dd8fff67274018c4090ccc6029bbb2c52172b0e427647947719462890e927e7c9bc3b8f77a4cbe5e87070d2e0c95e6dcdadd281e462cd1665fe595bea444cf999a53f60b9e3d81662bd344ca38c4527a0d9697620e066441c6f354b596a91f588196baa443de288ced9e07ec1bf27d7b1710e66fa334c35d830df8ecb2ec49f47644c8b19bdf56b02cc1d6f35c96b1edb5adfa62af4c23075841e9dbe59bb4bd039eb294dd5c50f224350375aecc355b91df0670e84860af3130e17c2cb5a4249b3d2abe3311f8f4b3ca4f39a314d880564d571f4a0df593f7d6146b98e01fe8ea04f53c1f3c61e9df0c1858bb84ca05702dece807eb9eff2138a257813adacdda55bb2c63dba672075135ae0133b346b1c5b8ea52b98b642bfbe573ceb4b5d3376c07bb1adbd9b6f1d1bfd36707c4bb310deebb2674cf7728e8e0e03bff39127d20e621535bf1ce337d412fdbada7fc7da8cad2b655acca752e0056f69e8f92bc0105c282eba49b37b882c779d26ffc4cb8c3a3514edd6ac78a0250e5a256154a5a441b079ed1356c94c2c00693308f3d62c7a53624723124585f813f86b22a6633eddd400216150f93d5973aad590bbdfa973a025f54f1b04f8e2de7f04abf30873873d8ac9fb69365bfd81013cea07964b9563597f67732f7c19bfc830c86bf453944d30341f782233f842b6d7a8d2b38d163d0a153ee47b30b4768ac1521aed0eaa23e9eda6b6b1c264d8c8389ff9ab7d536b54681590d8f6c5f7a070946b2c4a8747d3adb2e085011d73e8cf8cbae44d4a77c7b54d7bd56ee93918f1dc0232145abc8ca1823330613c0de39da4bd4cb3a417e7d24d9d958a013acbd8d4e21223cda816e6934874b989f2091be98c84f0d5d513e3e73092e98993cbcbb98a776320f8e992fb7247eca64dae401900fe00c79bd158cbbf64da1aff99325b8c48110130fc81244998863389382ecc9c6868febc6dbad383043652700949a6a0d6cfc45868e0ce022621a22be85c8665bf7c76007ac8c0ce7172f5d60062acfd2b65573dcca3375d624ef9cbbe43c057786523c35bbfb114baf6ff8a990db228d620e8c346c7674268b7f232631d16940c694413c49d97f7c66226efa8b5c045ef529717195c952cade5b2394a199c3115290b084559e524fa07a91c74426a80ba178995dc339da0ada727036d599cf9877e7989be66af76e999e9daa90c3a9fee96b243d4885352a19e48208b57f54db378512f190009e0f2752bcaa1f60caa64e5a859d9e979292ef4eeffd88a57892942fcb41677bd73aca6e79b8b43e8d0c1987c0d4f4108e1a28a870d124049a487402b0282b35ee3a773d325e91ff12d3a42d4a5673402b327c44350d4d4f40ea4841816dd2dab985672baf1ec21b7001a587882b7ec6a8df34657b8f8bce6998cb302a072c66bea035f91f2e4072f316e5718ead0f0c25090f115b09ec8dd67f4a59c8febce370cdb16b6413a5a00b1be9b89043eeedcb714a22e745ceb968e3a3040a4da57b1a863183f6602ba4a29e98aa0e1503b4ce5d86e06c8099bd59ea88505b0b40c1277282fa90d55a008aebab3fb2ebb52eb5d2e642af075b592a0a268de254012fcce9bda5a926b5f902c738cc982efc9bbe5bd1f6399ce4453831fa349e138f5eac46a2632946107b6af346083efdd3eae3227c614da893e706cc18e31d477baff2f37682741c95bc2bd1eeb022fb4989a930a85892fdd52cf0434ad3797ead9575c4280b9e3aad1fd1a3e834d0b0b73340872351da5ba6891cb6ec28b2a24fcf381ac17fe37dadeba2c6712aad12687f566b9d28ff8ca50a1501732f0100d462c67a4cae5de8882769101649a7bd9081cac90eb523c8d2c1af8c311f831d789f3295efdb5240b0a5a1dce33f5054f5722753d984c33922cb5390c149693ba2f6b973afb1b82da8bb6be092f23b01f1bacc5884f85839b72cd97de1a0bd1bb729338934fdcaf25adbb6b4321a06882ebbe4b172f0fd2cf9856a5fb510407c8615fe99b532f5cfa011624583fe7811c36f156044091ed8c99f8cd3ce7f277ae0c5ead581e22ce2866af7daa8dd9b1f992a85b69bfa71a4acbc016cc831d3c4f53c71e7e5573054ea63f95635f914178c3b0faf44d845e41a5fbf04ea72964f4e200fe5cc9a44efaebe241f6073b544c8633b131bcd44e3d2e862a6557a3b25ed92044622f46b6f75b5e49c636e090c337e769e96f4ca9e95532421595e64f5188e4c3a29d7c608f6644eec530fac479cf573b8147775eed6f7b351b35048694aa7e4bc5819b326a35339ba16ac14a4d2d350c2056ab488a1600deb1f09f8af45e06d41a15c323976a90f99b77a10ddcb6adb544456657cb10bdc70839321786e2d6af8e31d3e1004fce9c3ef1939925370dc118521841d90e15c27bfae59c527639c0cde6659e5a824beefa5c63e0ddc910441b7172aa4cd938ce2b6e58491bfdf6a85d64011efcc2622304745a05308963bebff7954f1b280efc126db00cff548bf073f5105fe90587a794c299feb6a70d2f66bde800c583feeeaae3bfaf34730c9270df201f5b59850df6ea781ed5b377e63f08e9ad1274092c778073189d1848be587972589b3a732c70c9a2026c264d025167bcf7eb17bad04b4abe3a5255c5a63607b97cd7fd041fc189149d6d068af2440f3b097de20cdc9d87c2a58edebc303692e7ccc86d4f2d68916216002b0ab70e5199059ce5ee0fc2d8bd36242db3c98de121ae8de2fc5821559cac95dc6288e8b45891994c106540b3f584c50bece2262b0a5f35459cc61584acf265e7ccad63cc66b510129a8354a386806e11656b629142cfc8b4931cb3b9755e2d95d5e0813e520330709d896e6a1a5914ace4a72024821b4e9d5da333ff487f87d70688bd9ab06bfab81466308408ecf98c67029fb2e881c89f3da6963a1d2630b0b6dcac44da2d43487ec2a4e61a99427d3e4662646bb19a5195a973b94c1aee5d99f2562389ee78629318d98dc1258580bbcf2007325a7700ac9f7b910f8a276aa0b03bcc5cdf6b7669147435197a961ad7d5ff61459933f3a759bbafbe2697ac50eff12ac716283cea4c46f037d132a8782d5456c0d16a4af5cb72f0b349270a314aff9d01b686ffe42c0b62fda55e76b4cdf8ed41f00f1d1166fe807053aae9c6ee02bbaad8bb84dad2eb0b393e316cc622a61170aab8f0649fe48e308e7e2ad2f2936180ef35aa771df14342b6f4aaab3af1e0b6a828389ca9f38003e5e375eba5bca61d614fbbf138caa935a2745bf76ae94f4ca15de042460455edccf618346901acd5d9e87a7037abe7d4af88d8d1da8743a2c6f82928846dbd55ac5a73434c2bf20af3d2bc1981a47d817dafd60e4a3959b140fb7c299e8467283a6fd02bb2fd4c8f049cf7f56708217233ac4c11f3967ad5e5e90c77e1885a6849d72e7262400d3070ea6ee89c69f5dc7e8cdcc42a24296fe4c5a355703c5276ea982b2d57f20c8de971fc41b7aa8efa08f8e4471655df5178583c93cad5fb5707322439c50c3ac94d9ca973e9eaebb2d04f37252b5034c9ba71a4601ccdf3cb10b47c113762e9a835fa2896b11f7ffbe3e0f6e4a66fffeecb6d55a860f4da598f23661bba3018a7f376662e1fcb0b6230ac766d97238a7feb8817e4a345319c1975842c53232ba2848123f6b43412fc6acabec2b0947ad381fd829e6ef95b65f70b2f028a806627dc6849a981fb4b01f11b59de19faafa3e9bc7e0f33bf7617deddbfb96bc7370adcafe05370e4ed8028b80100b784799ec668445f9764084f22fdf5f022e5c85b4cf2f2a0a9330136422d00c4520ba08b76fc0e2195c8ad1805d9d034cd7d372ff79b0a7ae591e2fddcd2701e9f1d69e48d633cb810dec79a7ee51e050681fd774efc0a0e6d48982855a3afd4dbfae28ad69976896aff37b5acc56cb6b46e21f5300d7413ef0c0f5c737d220bcc76a6e8b13c2f9d47a8ea3524719bfe2327a4752d4b4c923a4b7beeae8fb65b095a8ca02b715b1ee747a87a72fedab8755e7161db1f430044e65fe3ff3c215f7f3b10e05e19593aaa993b1fa5ecd772d1a3fb4f013a8c20f253c691e7c193be5d18d600372a2bbb9eefccc5b5e98ddcaea70cae4dab880322a71647286a583a3b8b9444ffe61ea6ad8b8e202a498f7da588c9d6274371635f1ca96054e7d0d793650f1f289805798674acd2a415ccb43bee025be1837172ca241f26abab1edbb974a2729d1dc80e3b77fcbbc613bd96bcc7a126bc680941cb43e842c5d4a0de79391996bca76d0261bcf79a491ff0027a9a848c4ac03b4b97c99061086983e9bd12ad12a10b44416079d76fa8e7edf966c82b57ab4a3de96f61a43ba3ac3db493da1d84d1b8fc11b0bdc59f3b7ba6f6311effbd40492961ecd75fa177123a3666f4ec40945aed79d3d045a51b04c6a571c0b22204e5877f9084131885cd5a78ae6035e30b78c3d387fc286e26618aeb5891349256a357b7d5c689d57d7595e70bc6ae7345d2c1a1adff1e168b1c3e7c676d3b5f0d7b856e0c88cd3fe97096e34243a394e0c1f924d8844a213a054d949af19de48039f963716a97ae2ed90d823d5f54adadc83bc0c7211f471bbb41ff93b10da2fed250aea42d97e490e49497ae89deb2d5157a1638791419aa7d9ec6e3e522aa60f98a74804effa56a002f438840092dd43fc8f80bf30edd21ee26ed44a8f649e306c239908a0c8d1c36ff90f408b4fb425a4739675e1c32c49487a86eef0330373e9ac4ec56ed3d4e339cafb4f4156f549ee3a5255c5349e73e2ef45704a8cd1438ff5a07b61d5c04d448d4601b5b54bf245ff7b25dc5297c4969813411cfb4807fb41f6d287e44be656be2e6a8259a27b8ce41b3c7c172f7a6c7f01a89e037464ac6e5f251a103dd1e498af1cc93b83436afe787426c85baf7f87d4606eca760e992e36eb1435408765368a05c62ece34d7eea33f09a94b7c317e3f73670d84e132539663a9e93db0fcff0547baed65fea5311ff47a1d05dff24d483141fc76ac65f4e354e52b6ae3d394f67e5fe752063b79213af3c3a3b40075670422be77358cc5101bbc7967e01cec378d848b00b32407a45b1a7585462fc9909232d84fec2260047b0a0d8cc457a115f462c98f22ae886cec226e77dc7d92c0d265e41195c063dbf07df45e4102945119488c80a91f9cfc3d68b10a08e074af93512cffd6b9a3f8e78788c5eab6133bd906f04c46acc1c8c7dbe5df509e0482bc3b70dfaf4dd8cdbedbe773586186f8c65611f0a8a0cf289ca004b973f6a8d1c1e0aa8c31dfc5453cf6625531f7fa54a4318ba9a01880eac92ee20ba72b617400aed03d52a083a3e5f51c602dbddb410a261a7cc30c79a810cab60f20a277f24aa329775c6805483df6666dadfc060124e06108b9b16d7c3c48ae8c05586e5393f389accd17696beacd5cae0eac89f96ac0ae93f97f51a0bd7f2940724ffb6eb0f4270863842e7f699c9f75ff9051aa20812ce292b0fcf3e9779bec1a5868a8027c272eb39d5214a4a416fdd67c0922e2e7052503a6e2d639118b5b57247869ead31bd26abac9794f71821f7652af105b821aedd8ba8d44f9ded3baad5220bfce53321be1a96b7f86277c52a5116db42055032b03e8212e9a5db9b64e1bc19607a28c7f958549828a7fc7a5bebc0b0555925d38a4af2c409ffd4dab01750ca2bae31ca25a341908c07775908680536cd8c83ef72d1717868e13f52c95a4ff9acced768863a3c870c0811ad5593897ab0a504e647424e22328d9bd86465ad313397266909712d117ef67d3e8f02798e6f8cd02bd8ea2d8111ea4d960eb7b3e824495ed725e420fa81c6816abda7515c6d548b2cfa66c8a00042619093a5cbdffe4efe812aab9d8c734554298ac49ce6a0dbbccedc80f676a42ba67c1480398198353a5c8fd5fa30b659e331d88de4a0039656a43e49890c7b15440afe25a51bacf035322affebeb918e864bf5e823d00854f805ee9d94d3b75cb03495a58e6669901341dfc81f51b7f96077885d3545b036e5f4fe14c41e90f57fb8f7ecb30c89ed05a581a0cf40ad59d9773a529ef008f9077d58aa5fc701f8dc8b8844f6fb7d2ac8ddddcb2545928e624cf6ff609278e32ffd3203e5a398436f04ff76b68d38383f6a67698da224a875f072813e6679e5f256ee244ff2f49c8750ce308ee55ce9c4bea8feb3c7e5e8a0e61ef35ce889a0372d41fb5fb81214ff65a2f1a248757d372517ae7500c1620adfe8380d308aeedf6503cbf5fb7634ada6417b6a6a0920b66b8a6b0ee15d637f64a958b876d978a0517fd3ee003ef0b235da2b4f77debdae003e22cdee162ebc85aff62e482c9223a8f6885f55fb5f1d89fcc37684550d7113c5b968a6b1667826402bdfd8870ad57bc01a783731f25e13ce78b2f0c64ff34b31dab6b34f452d32d6f54db927ff775221b5dcc0023d9dcfbc9f7d5a335d57a08faa563c8ef5049476c13a4e675c433c29f91aaf075002d547f5bb3c20fe372cdad280a0e2a5c77a00e75d4460302e06cc55f4702eca4e4758d3017408dccb1acf4a7b81250346666648e14f6583378a383f22db526eec0e36deed9947f8bca49b5ca945073c1ea389bcc3d8943b6c5adc7b2676e5f19fe9081d60335b2976c7fb86dc3d69a82dff8e82de3297057aa3ccdeace2d5646b2ffbdb3d61773e4b2bf43a8ab760d3946cae373eed151c79cbe8660ea069da04671c3298b36d13239ec19d449d1526d8ea6c8b1c696c2b16367138ffdf00b1c55b08e67e6246bc351d198838c31b7e2453ccbcb1175bad395d1c658cf887075860ee40b1f03e515494cc89615d28c1dcfaa0a36abdd499ea5cfc82eacb60a036aa4e0cf4d81edc78a88594307e7239d401346e40d5d6fbb4a21028d37d79ba9e2cefefe44f56fa4d2eee57e72b500d6b1b1bc4571c85b06580e539f525a61252d76b8b66a9c74195cc73da25372ca2cd63d9534e7211a4e7338c0d1f4091e3460bec0f47193690053171d64bab215ac3a1eecda80bb608e17cb4a815b39fa824fc78c47f19900f5c6d19685b4619dbe4f90f80f0f4b5c7a66315d4828f1e465af7c54deaaaaf023785b508990ef09bb018a29c2f3ff96693898561cc97de3df30b0512db345ef7ae871b0ee67e3ce76cf84dc9e6f36a46cc49cdcb907c332a9b057b5c5adde31d216463064919421eff4a2dd42438767fbbe807b1f31d736c3fdb57db2d0d1b399f767d9a959952eff8a8ffe9d64c347a4eef654b3a0df950631573d683c68c6c484faf1079d70e564ca44dc208e2a37396c44b98f34c3b210799412a8e6a0604c634659923e53af789401e834f1b384cb8bae08265ac7f0361ddf13053645271fd9f11c808546d4d5f2e4470d59b60c9ffd28177a5f0d0041d93e6edca2d9ebcf5a085d27bbfcf3869d582e61e8d236dee76decab29f21961eb4d4395bd658e67c5c167a8783e3af387d2ccb10f93bedb1cae385849d4a2f2d217bd4b11b3db66c2e66490861c68ad7427887ede3a658a29fb7c4bc4f035ea9022d18ac82331967672aa1a44a8783a6285d156788d6ca38417c8b1d6e6a92854b6a5597ce957e73066ed6984481504d74ff0e943cc868427de85adac7c7cff11c32db8acd3cc4c0837b3a8c784d1baede2ddd7cbad438492dc334610f00d197318570780f48eec3a50b62d2194cc0a6edbd73ce965c2ef4c8ffbb7d9fa74aa3ff037f616a35205fec765e998370ceb0f7e76b69b75d3a62e691732d76d7a64482132fcfe5ce6017e8584b97b18ea46aac8008414dd8ed315edfb3ef2e24bd83681e850b273dfbe4cc3cf17be09805c550adae0a0f1bb53a90b4f14040ffe344f5d56679d9e9c24b22d975477c499721f8a007d105064a1a53a1d1f40fe364d14ade476af5563ba209f93cf0125e8987880403896185618a86573dba06917c718d3235688faee6cd4afd4b4984674756c7f37642739b602682373f098c3a21e7887b07a2c9922f58fc5dcd2fd3eb12c8b7698dadfc412e123fdcd69064881ddd97c8388f6406e82a08c6ad35ff1392b187a616292e5f3a427512a5960400100000000000200000000000000d571dea28febcdf4244db2373ade75bf07fa163f67ac8203a90bfd5480971b199c3056ecd299daa019d9121ced683f6555de38ec8744fd680f7da962ae364192ac21d4e163cf8b6d082d26536553bbdc4e9054b292f0f8a3bb6447513734dbdf18924ca04c6c8b77a898ca4c8af2581ac79a42ff13ea5738229e48aa5dfe62ddd3582c2e18277bfee46c843c219c54e093acebfe48aa09f9db504f8cebf8349cabbb3d30f4d67f2bcd999041bdf4026885a50b5aeacc1d5c672e5dcead232606b1a06917c718d3235688faee6cd4afd4b4984674756c7f37642739b602682373f098c3a21e7887b07a2c9922f58fc5dcd2fd3eb12c8b7698dadfc412e123fdcd69064881ddd97c8388f6406e82a08c6ad35ff1392b187a616292e5f3a427512a590101777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777779985c43e5970d7f83381063be5a18bf02d79f9a43e38617f72558188886c5205b53047baf2c483db2f0903e89a26ba7206f9b364b5254124232713de28668bdedb6ca9bb5fb9bebab283fe332a69d51465b0fea98ad5930309d5144a818a4bd3a140010000000000");
+
+const RAW_FINALITY_UPDATE: [u8; 25375] = hex!("806001000000000007000000000000005d9434386807b20b68fb47dfa5fefde316d8cdabce94c31c60e1ccdd5738bd5ed531c6e475b3b4037a4d91e1485349910ed43c61dafb3152e4e815e1382be887c196413fa16f2f556318327691ff6eb902b00137cb1b8a03bb5dbb543fba3f3701010886267e6eb29a78fd0de842936a2f81a40a6fe22764ee19edb20aa92a856b4850f258a47699d3c28abf91a8706ca4ce8cb4751e5ccdaba62fa437fd2d48ba685a09558145a95ceb15d3ff19fe45aa3ea626b9cdfff6d7fc2aeeba418ae45cabd6a1cb530a6fc3c7f47cecdefbe8ecca0863cedeb140921e9f6d59a60985141f391f8d8bcd7dc33218a227dee06c872cc7878e06852f6d87c363d74d4b927f3dc6dd7b6b57cf6874da90c44663b547bea3ca3fc7e56793fe19c99e3030791f86e9a08066d03d5c5479b87869fbc9aafe4e41191aef12799498929a807863e84c7f1d6a1149e4a67e6159c869376bab452b94e9b4baf6255fca0b8a5f06441744fe7e5a00098227173f6b83ab614f1ff32d6ba3d8f413427537d01bec63790489c4a15f4b139ca99ee93c01619c91c4e9e5e20b51b11921b314469a99e5525ed5599d2c299f921aacca6c809273e2468c5e93c8230f909d8fc965ba0ae9de7d36fb17827827ee4566f648eaa17058143dea5931481bc6cdbd28e2ee95ba2b3b4534a4f8bb45afb41047fc1f93f581515ae44afca05d581401e3d1b341cbb07b62421e9a3844c398fbf6b2ea92a7e1599e2ea9a40ecaec1624ddc4359ad1be8eff06a89d99737ef241ba1f338236016fa2d829313e305359f08be23943eca296b0958ff0918a2ff5e9ee0bf5309c0607d36bf3d9fc1a3cabed0321438d593f577dfabf704a7afec6d47de50ea9cb55c8029bb67a6709d76b116edc34b1e3356b04ab2ef99d2b899d29a49951c12acb635fc6d6cc0e8a26bd730266733495efe6cb3d8ade353fefa28bf855bde87f611e7d471b617f3f78d021c53eff2d8506ba960b9803fb4d83869a4147f2c95e7ca84c98b57d5649a684fec48aea4a83a008c87b6dab9f26bb4fc424f5c668b86c6314d4c4b2cf252569a72700945f831f72adfaaf6bfcc015fad2b828bbc4852925396d7bbb36a2bf0ec48f9125e7035447f333232dd93faa200a1c0eead93c21752c53a9c6dc0375e2c0fe5dea897ce6f8e38309a1f3d7ed48c51e688ee3533da5e00ba5391b6a3da80d83dbbc7ea06cc3275f99a1003283c9df637ed99b044e472f85fed810eeef2aa0935f02fc197c5a4d3ca650a3cc58b181f8063bb775c343df2aae2f43ceb1b2b28a6c974c34ec0ca3eb86bc13bab00f93e361fd1fb4f6ccbab82bb85b6341c2ef1f81d5b1b43378c6ed87a67f79b80df3d9483e57b2f73a1f2bd5c4005e7cf1b7a6cd38cc50bc2af6aeee43ed0b6cde7db9b18a1575e41bbd86b09e03729699008907ebed1c018bdb9657549d089b96144022428e1e53b453bb5bd5d69265b9ac4f8463786d29ec6a9982a11b9f30bf773dd6afa421c2a41e1f82ef1260af5720dc40975bd5f3b1faf011c7d7222987a3c47b9e15475f6634a59075909bfd08f9a708431e195d1c987e42610efaa3d3a304404aaaed9aba18c819c0213b330067d2be687c8e5046104b83553ec91c32fca43d018bb8c101d12541c18ee6533ce4b1d8fb2fef806ddc508420fe7c839542dd1ae2909252f66e39b93c8ae9426e8a0a9df3094a462d6e7ddd813590784773f4e99d9c0229a42fb3e45bc9e0c7ea149717109b1cbb82c9578f04c370336a5c0cef602d369eecac0333f672e03ae68ab7d4484d9645ac16e196435f056a6eb0e0ffc8fdd0573702348763402a7eb2b7652f550d2ae661fb440d356dbd25adc296bd82e2a16b945ccf4ad4c812ce04924357c1ef0351eb24caa1f367ee578347946f87ef26c19bf10065c5796bb80a82d05b0499a0f64b1db4ba67e3111ed5edc1b67f96abddeeaf438683f3af5937a72acbc828274a678d03e2f7e4e093f675a87977ae5ccba8c776ac6488613cc0e0d5793d9105f356a8c98f2be9e0f204e1b1b05508c6f193dc665eeae1991b9f36d61086e7dda6e6b287d9cfc9ad9e46e7355a0dee1a168dbabfb1f63dca9f1618e95489ac1844c1d13c4eb0f19a23354aaf9218d7af8aac91677d54691bd725cd1623afb3afaae6d8c3b01ae0249f7d5a5ade8d882514ac54f8d3538b0f05ec8d8e3d28177ebe4120f0ee1dc3e383f64306247236228bf8abe0a155c19fdbc99167740fd67dba7ea2674ec30093fd66437c0cdf0231a2dd406fb13c0d1b08fcbcb37bc618583bda705f8535efb6773a147950408521186a0317f422dcf7d753fac83b4cc1bc2de85d7b33c39d0f70cfb7ab83b7ec6f841cf22889952e3adc536b2ae622e16c8734f8a3beeeebf934fa5338d45505941ed6de05fe7de015fc82119d1dd2e35e2f559357a710d9fcf20351adcb61b7ee6d5342711f938d7cc9efd4d60e8d3490722f709045251c3e0966505b78e7bc82a1cdb93e98fd2865e0caba6c5dcc6405492f13fbd13e6b7e64edfcf9c6b23d1306bcb57784a5419bee5083255964a20f25b9b6e095ae5dfdb56b6640253d44bdc8f7a088283b33c6e80ce4e684cfd941b4b2885a2a85cf0520034ba28682e18a42741acc8a20ac9dd397750fb33b7df02866b71537ce1cf633a3d1c9c9f12acbe4ab72d5475d3c02c03de7e6b57caf35899d17368530da12ad44d5d5ddeaea31fdb63b996cc1d6b9b52ad6b99bfd26b4e291215d9f8d85bec8c53522cb00800f848169888bffa66492779398f380411182ff6322d95550ea7ae164961dfff4aac1266bf6564734d8c69975f487713af3303f800b818c463172424a4fac54280e11158788205b701b808c9793bd85646b43a885dd6ea8ac2a963a21ac79f6d3ccf1d9ed1faf49d28ae2bb455bf25c940a968f65ffc47bcbecf1c0b5a27f254430f71e9565d33bd497933a056df510372b342cedce8543cc338cca2401c182ca786e0da982dc03069fd8ffd42a7c2c3bf8c0ff4e0bfb34f02a74f8f11d8f62f52b8a4d0e9cad1dc37e9367ecb38ab04b7cde8ba32bb6058303df5d026c42795d78b1ee58d542de56474582ee82ea85c3be6dc1625c84457d482ccde344a2749c311832e6c8b5361d55808da6013b0358efa7ba136e7e7bf864d664765aefba7afd6e66e495815a81d79332e3fc140180089df8119cb6299fda1131cbe66a046bb674f036b3cdd8ad6a87e633bcd9f387bdd51b03479707871472e8b2f9e957ef7b64bf97a23638f09450c2c04f1dea761d68f2982d40ebfb680e2223c77c77f9e2d9e023b4b7e0e53d67ed42753a25967ee81f0a2a04b79b1dca055923ac66d76a1c02d7f11f9655cbdbeb254f92658efc90fea8ce853fc256f00400039bec503a45d797b45d1741bbbef41f41524fca979f96d7ddb107e8f9ec932302049a13a5a8d09c57919cfb756cb3e04ae57dafb1d782bd2e21c39be3351bb508d66f140abfd53f9d424c39183a49b4fab3afa42236cfcb12a85bfde256bf2aeb69c5f3e5eafea99a90db7eef8ea125c67f106bcabf12b4844df447474c2f6e6cb549e50cf7fefca0b1d92882357c3a465e69e76c0f84463c5502db092e09c484462d15a80340c58ca169e3f171ab98b56817ab14faae2683a77eabc76f26b5a0b380ddc488866eab51214d1f4667d43aa343ff8bb7c2862e7be098b80cba172d329374b0d2780defac198f615d26f7f7410940023cb7794171268dfb8bf77aa221115c7b368d68a421f0ae976acf13f8ba4c45ecaea6a756aac053111a5100543355bb98a4c79708c73cc708e27f4b6ab3c3641378f0d7348199fa424adf35c69ce2aa919dfcfc53aa8cbc9a1cf5372309287d51b7102e13a69b34e0ae74d8f4c9cea5c29a50ef5ca30251afd258edde3eb0c81d23350f808738895a9df78d13712ecf06fbdf52248de9ea21cab8b0426dc86587daba75b27ffa868f604219b3672dbe7cefba5276a04d248612c87c033276dfdaca00a2217239db3893f916136f3413e07b08c5e7996588bff5226f5ea839a3c79c98ff07a9dfa807946ae780d7ebfaf53d2e0b817b50d92e4a89708ba3d66d989950c5883efc8eb6fa4402b2f8aee7bf8a22f45e8b488fd499b8b6e1db67ae4fdaac99421fa720745479ffa103e4ea896d74a784a72174faf32273638899f6949f7a7f4f8b9f5543327920a6acde00cad6a8e6f4068e7d1a0f375c6a239d086bbb7c39f3b366e79100febe96541a15e8e046b7dcb4b10af059754f5156dc21c6aeec65d8ce1eeb90d613ba31bb0ef5b5b5e885e19f3501e7938e317f8d914be0b609f27fb8483ab770eaeec259ad7dc5efaa3c47b616ffa93dbe1f6a7b270593e7ead33bd4065da6ba85130defd7c984eed05a11aa1bbddfe28f715871e88d7b321b3f8daf2dbd443a3354cce76d201342b210f15bb950f6b24a98261f523df1ed63f0c3aad808791098e5c30e3f16e5ab3a91cb5c8c4d1ccde99901492cd8dd84a189b3c9b4008631def3dfa95a4409507f9315928432e6b14926bdd3f4d80fa641b45559c6d3fdc7c644540af5477fd24ff7c5642e8c99d1e35f0bda109d29a02d20aa9130bade41b6f3106e06b383e2c02f67477904bc3f95f1b14240db1ffa97779a3b576d5eea9618736f3f401573b12e008a1e0fee8f7fd29a92471aefd94a1d3efe5537d9b4c86b0b7c738c1cdb433d9f23fb4193d71286a39f6e269a50d9a61dafc32c92bd1cb8d14f556ff12bd2976b6ac9493169559cdbd94baa769cd9915867a0aec19590c4e71649c27a0c72c0c8b483812a7328ba286337bda4c4b86737273c0191fe0efdac549d1700d35f008c1adae90a9973ff85c1baf1119023496bac8b32e8ed31d0f6a5474c63c50e87a73c2123e66e0dc19145474c678f3251bd02cd9c5f482d7d730e53c12aff2d2b9bb7ecce306b231efab5c1d6affda7521230139ae863edb109892b4859002f6700bb6ced0d12ba1e00a80fe7b38da592f6992448235cd14329f18c6a7b09dc01eb53ee175599f09110a26ed6a5ce386ab34a03786837e760839e58a7105b1eaf18a122c6d493cacf65ebb04560858a87bb6fa1f4b1b0ffb88ab76f7d95ca98a09049246cce7619c5ae718fd6619e8e935e9733eb05ecb0a98f529220d97927caface8a314cfea289021aab255d696f1a1ee55587ce474fcc9e0ff0e24f086435dab38669dad5672dd59b6702df08f87dde7caa6e887f565b11175e4e1c6dfcca5b5f89d09f5c7d69360af69ffc07cd4179946dad7639e91a1c1e3e66042934ddddfea48e05bbb115c438f3fa506937d6513e0590f6b459e0938fd90531c29b3c3eabcf4fd9180fcee0a340f65cf88cd3753a20e1ad6a20cdddc2763992616b54c7b4bda13f32e0024989ac3f73a852950795e8888267167e8657509351545a77880c1e7e2c6af9ab9237421c80f85f0b656e028c2773d9a67d4f08d76856c2fe1ca08f987a98858631bdfc4b101b37220d4e4561252fdd06f6c2cd535b993c64599919ce16b218a90d5d1211bbe112274c82e7731cee7b616703863711d9705007c009b7fc4df73b58c792133cbb1f07026b7f63a082dbff555872aba504b7d04faefd714b382a43583443883d16a4b6707da909d9cfd081e306932f8cd5872899c3ec0ad2bd6d72a9a06ab3b7ba9397f28244977e586a3facdee930603fd7ff7679774680290004e69bf992fae4a29ae354cdcbc90c02f1c9ebd1264ffdd000ca864c71107341407f63abf3f736ad4351f795b580dedd6f0f337a9c23f438f5203a71827f497a65a54da58e5caec1ddd7a5500f24a54ee9d3b8b61c2d813bd0fa1f16c2effefe21ef9dcc07359b9b8a4ad208c4a46d481760c20ad1922ce72a9397d8e203e69efe74255149538a5473685bdf96af222cdc5ae9373f8d6be53204c9095c0ec7be48bb50c98cc4a4c0ed2ab2db9f0e56337b79ec4456619b0506acf6e725fbcc7c9d5a8d2c69aac71c019a039166ba4371805f7845019ff55c898f87f9b5fecf2f66de01f20ab465df465c82fe4f3bbdd6a7a35728a19ed5b651029edf8a7dec0491c35a075436368f42ac85207e3036009400426cb4efe86239815c0d5f3c00d9b998c3c435ad13d94bcb1fe0deb758068be4fffd14fc8aec01bfac8ab8a9fc9d7843d7218800801fa2a58bab44dd1e8cb5fb69e92fda70b308b5d40502b959985277c67209f4982b69faada8237c26c3699b77a5a6c7e5df87e657333dce21161109eac1c483d722d40d2757f7b29e76553b13d58bcf2a524225b23cdf33e16ee0bf7b7bcaf58ccfed8c50e8dee59ab9035e0f932a34041c6b080cd3d9a5a984a92102be720bfac6d5a69102a6902ffdd1cb60593df1a857c5715c72ae4c041f64691a166080f704728776968aebceca75a2f41dc06c072fc3b8804e9cb15fb9e60db2fc37b9a1599ac4919075bef6625a07324ff563db139f08221c799c91c121a889ca3c8adfdac309a039eea65a3cc5af7a545da1fa97ef8cbcbe350464eb10f2170552e6441a086c872807061dd1e0a32fa3315053375279800642522c973e4e2eb90586535ea72203cf39fe15290421056838e8f00fd884bd9948a3d945b4d5ec44e4c01681284f8a8452ff5070824bae99dfce6801a6f88ba6cce13475fdd0cf55f2fb5a148a248d5f55eaaf74b14b46dc7d858c1bfee3affe2359cecec5dca0fe4afa3e2a4886de1bc08519ec3267a3570c69ada96e835158846026418aad75f31d8f9bbf609686ef491a729544b9d12e3496fe1c6fea030da68e24f2e45c8006528815ff7184eec4414eaa8481e0074521c578e21247b86eef9a7454ee079a513f84c6419f8f3f9ca205964ff56f32c2b1c94b07b52830f55a0e5a7b5c1c0c2a9afe20fcb32d9979667bd9d35d9e224157369041bc2ae5f22e3f0ddd83bf02908d8ed5d7c589c0bfa3a4d021745df7dc2baece8345cc95ba4b1c1941a20719f047f2bef3b839a8c713cdf385608d183de5319bcc95ece9fb7cf7c2a99ba6397bad290c9b15b4ad8b52c904c84732181c1456fec18fcc7ddfaa80560e8299c996474819f45a66cc99267f3f31ba6c57c1f68023bc59d884035c3b3c70eb9544e51e6ce657a8a1a3a0e615995b6e1200613aace9e80cfb0126df13a6d234836f166908db24649aa7451d15ebad0e96bcec0ae515b6c73755bb192434eadcf7eebe1de63c227ada58a0a3f48c5bad1486daa0a43a55e751817c024900820716313ff9e3793e102857a3c5dd8f756beebb7cb615ef6bfa2b80b7a7b31e1e026b010ac5f857764055817b13f8f70691e47079380b665dada72d73298357e50f2160c3534c8f6caaea77bbb2a12bf21e087b3d1d0c8de51905b13cccac14da7278ed67203619b82496439ddb2ca5df7b4ffafdd73f0fa7f77e28a727c572203442bcbe3391b83cbeea81f881ee1b3a0d9521aa9363becee0e8d73356915a2e0ddeaae0f048059e31ffa22a9b1caceee8a91a58d854cde45814b359656b1e8da82655776ad5fc80840254650df9343fc4aab15732988e0734901da054f7faa8d8ef4c341160b215f3ffa77efa2901251a056ec91034385069a0b7a257983b9f6413d19412ac17a441d31ecd48ccb72d143df3cd504c8e0ecdb991b3ae67fc3dd3610e9b4f13ebc295212a8813b00181ef7d29b711472462481800b419aa5b35ae1ee08f537242f50585a7d344dabec8914aed510fc3464b294d9db7be706aa9033dc7463c1178dcc58b43068e56f96a98a3b3c77c5e24809cf889ddc573db346e92ef0b17efa6e15e37d9e84c355f674c81265f2b3db820e63a9604c628e1254bde18ff3f25281094bea24e0eaafc283e8fa7def5d2d94c271a485145956e392155058b3a6925225f7bb3c70e4d32dd6e61c681b702e165b9c299fef52df2cb05af076c87ebbaee4a00c4d28d4f9921043c41869c15d5c9acfbf5773455cb34d16a7ce1900ce941cf5399b309ead7e20bb9264479fbd4964c42fd349bcaacd25bf7b3734d37e50775d4fccb3c893f78fe7c569bdd5d58dfb200a5e10821727d362da80cdbc875d77fd86334226c0c7522ee18a4a59b6ed98a0adb81dbd77072b835e98a3b4b40557abeb612b890d96d40b19d4c43e9349520e7d827c7a2e262de138fbf32909b1a7aebaf43d30a5db2137d06a0011b0da79c78b3ae6fa418edc24cb9a4639112948c97c39d2a052ca1c4f66e11226737d0b61b93cdc1973bf3393f156463e072f565e78b43524a30ac57644f88660acc05d1bf7a0c248cc5858fe2199f5da947994990f42698d090457524a4749a5207535ea6800cad21162e5f58e5f87eec4e79f9f85d9a872f48a3749615de483ac3567be777eb5cd898148b9249dee0ec3ed9559f94e130c849850e1741a78a4d8a147d38bb7c7da50464d07a2423e66507d8aefbc2c065a0d28c3632033cb19fc6664ceea25b3cb362f2e477cf4bf66affe4aa68765060529d0f7df3f251cdd59c1a581d82dd75f5d1a69a6e54a0d0c8a25da730972032901414581fc08c7decc319d8ea45a58a9f8236bddf2f3ef35f8fc796cf4615a4eb8763632de83e8e0dac6f7968ac9906b60725a48bb6e341fa4b8e2107d8df51ab51ad1923b9bc9c12aa774e66d90e0ec4a2f20fad16d81cb5af45d669b46595bfe57df80f513562f291bf0d71ae36be79ce70f44d8405e8b231f189c140df1b0211019d76556c41ee1a857814aeb01d90d040cf148423aa0c06685361e3bd25e3a6881524ad71a65c5aa37496e73377f4d13a8363e44c7520fc52a88280eb7f0bff766bbb3454d5b5256793cf40e1d244293df85f8bed7d6aa564d9eb89668d5a9cd6464480fc0c3f53a8352da4e079c4af4434eaa2c2d2ab181888b62462a231ebb9d9285bffbff7ba8b4f1a845b700f33a1a2e5a2c315b574a6218aaacb65314561ffc7027b1489d1d307dd32d0852ee591c552e10d2d901a6f8a08715390e2b8a45250d23605a22383d23f93f25a85c152247aa89c35a14cce18f51cc51e75790773761b7c2cf8dbbdce0dbba6000739bb76668720100609a29d1f91365134900c3e17e60259247f76cc6515b676e26f0e528fb9f504a9c6b5e22f6c9928d5eedbeffbf1f28fef1f790e47a43bb4fb37aea8dda035c65e8b475611985720e2ed2aff8d0cf0be354afacdada7f0e9984c0bd0a24607149de57f72d5a3f270f9b468c082eef36e225f3ca7900e16e3040be2ae5abfb26b9ce0d7bb4141a4c4582a9e9c3998fca8c3cae799d1a4a226112191bf9b6ec5c979249d999be1ea70ba5412e9818a6cbf71dab45a6849e77c51f923984d057fbb2c3c499e9881f9a6ef58fdc434ca7a3bdbd0477c1533356f4adf77d6438ec792e0947bfca5180efa97c27a4f5c798fd3ad809128f3b2b052d3db098ec871ac5ef1f85d0ce3df86a19c6fc25533247ae93f885cc41b84fdb64b926977b8da7405edb99d8d8aa943a7d904c4253a32ea71a6cfced4063d9d23ce16f84056ae8637a96572b5aa9af1fd62262836b9ad2875a03d5361c5817671eaa7a74573d27cc70c78e8e0a56b144b6b72a22449d81538235f774a0550a10931552cb2c24e35dfe5ede3bcf7b64eb874d59573a090d461ea2293dba9ed4d46ae7709475908464b091474c1924ec08902162f95d235cf8e08a832100eaea4578fd557fe9a1344da3e0eff29ebcb1133a0f9d0766b17860d5b2b51a3e4b7a443d8810b8fb505e4ab8e10d31de7b5c675dcb8192f4c61c59dd1ca2064e14f781bf16fa3feb01427a285407ce45bf5f6ab3944b174c2054064cc1fd533f3b8c8cfa89c3b4b12e900e056bfb4d3782dc296b080d3379b7bf63f1e93968267ed1a0f9cdeb9b60a4f1c7ebedd1491a697cc2e84ae2d462174d39761ac8052eb5b6737847ca74223c4c0553676e0e40cc373030c41d01127abbb75595fc89297b0efa5ba9100bffc087a127ca9dab83d05b5800914337a3ac9f131782beb61a973041a0a9e76e3c59bd73ee128ac0812ae358fb714088d39ea62acfe3c6b96e43da495060acd17b8913bf6869ab15a5229b3365bcbd7432f0f426d5432d52446a55a2caaf6439a289a3f7ae2a6e43309473208a3f24cfa75940f5e8a5d81eb4227521829d0687dfb0891b65a5cb2aba9b74ec9059f4bb9db1d547a5d5ca484c16323d20b77ab33b57b52d35e3ad0544c8a72e02d1c8b2c00aaaeedfb1b66be1e91f70efee105429e6f1b411c64556f87bba91584405980dc94e8d3cc37c0ecb4ea34022a2517f898bb0a1aaa49c83d7585d4ca192bef3b0e8fa0a70efffffbc9bd26e3cc5ca101ec2b01ac55b3eb6a04851fb06e0f6b97ffa23db26b9ed9656ba275164bbf1cd172b2baece70f3a6ea6b23da3197688b6ed8a522a69f7c4150ad23992ce363a3f60015c9dc57398a91da5e24cf7e01cbf00392e3cebe4930e7b5b99f3d8d1cf4b75ef0566d1132c036a6d780254fb49a54087f88e126228ed14b7c76dbc4363ed451a9f3074bd523f6bf6ee65c996eb135ea627d30dbacc063abed2085863ae9bc261564b7e46836ce18388fd6a95017b65318b2b6b2ba6b990e6c51d2257714c73355a58a53eb9b8974be933bb6eac400b23f07b0a057d912c9082be1b4a1f086e7d409ca426710fe3b6bf672c0e5c5273fc5a6382db5151ed3377aefacbe6e18fb8748b964f640fba967e34990f883f8bcc7a72bd1d6f1a41a83615b34f0385f81127afbdd9d917005c24a36bf910ddc47ff063ff78a41b8198958447351c5edcb8e86a3a5959ab12a5802573594b645008344c5d41baff6d8d8fc4b2db8aa226bee0dbcd33120fcf923e34396aafc5f175f7889c829f9311834d84c8714b6de0bcda26fbe9395ee1afe84ba0f84bb345d469dba9dbe4ce8db72cf5cb2833f3f08477463cac15157503f9782abf0ebf1650add41c8a460c510f24c4abb2d318498fb3fd5f228c83d3a72e165d8c83234bc10f615d447823a06c2a04f4062ca34cc3208d8c97d293df5cf35d99342eb1810c4708becac52077a794f96bcad6f350747a3c76afe34b7b664b09a59e2706f9ccfd14628d6bfb01c4117aa3a85a9d92093b47ae2aeca14ea25183b3a627769c3f3d4e74a164f84127f7696bd47abcad2d367e87feaf05dbca52a253bdd930ba929d84371db52f028c6ccee95ad3c674367d7c28ff9bcb4f0517314e9aa4b2fc017dfcc76c02226f5a7f7499d48ad72071c225ba78b1c4c4926e910bd6540971024f5768706149193dc26d77180280b52acc0bdaeef5320d70222b966e022425790064f430a7468da6739899074bbcb0bdc362f7f8186f387a4ada63d0fa61cd1fd76463e1c891c85ccd2bb1e6c2098a8abbd056ecddcf79b0ada480cdd619e6505d808b9afa86e76c746510b2cd343cd33158837be9aaba80f3aae73bd8b0a2246e6a0e66c2361b8feac616c816429e0bb7134bc0c28cc898d0b57b71b3ff890d533f04b5ed33dc07b09400e71dcee50c141069bb75623db242e518cce95ac05701c9a8300fba4cbc0321713f45c96afe1cc684ceb257813ff82635c0d6c13298564d88ee0c9e15814c26a67f835cc8d305555b3124bf5a20c6b2bd8a2a5cc3750987b7929490b43110bdf3d27d0586599ad7dee5cec8d58c768061920642524f33f0bfaa3c878892abc7f0e9d3a00c5b5a34d1aa9df09c9818ecb29a4687277dab6289b77cea73b079fb040216752fd77ddbd8b58b01ea4c05f84ff02ef6c3422a838447321323eeec42e6dba0b92c3dfe98979849638697d3e2b7eae1dadedc6f274ab7c2fe39a9ef28483e0b31312eca5ed05d35ccdff595040d6a7a00670c6f605a6224f539ad868927610136df1ac65e0ec21bf368a70727108504ecfa9f08e7e7970c961dda348872087fefb24a2cfdf5d2bd35d8b2d30dbcc6f6bc7dda94bcadac8c9b3d75eb8dd2d1ad1066e8ed041dcd5ad71dd4c883508062726ae290fdaa4e89b381b9c5f85429cdb86a4432b6f2dc57a73d999ba9d0fcd737491622c2fe0803fc101c4055de085760c7feaaf7921c4c06e4ad0f3e103a13b653de986b316e258a961191cf274afa2b5a494ae625d9991a2ae009fb10c67a604903d589fb615248459678b0f03983c804af74fc9c17beab8dee12be77f3eec981ffcf756857695f0112092809af823142b504ac06ef0320e9a749ccef3dda8160e959a576bf808123ab7a4cbb0deb46153a2c1b789e6e3e3c20d2e05e02a0fd6df00dc327b4965e2998421d0d12458daeddab35795ddcb217e63a836a63e40eb6dadba505c28cb4a46f6041092947bb5498a714ad7099238891206bef1b871273defde302855ebd20afc3737db9d4d8f9e9f1680517136d9691a7e6d1492f0c726aa6e90e884a9636b58fcca74da89df2cece2a27cf2c929a04dc83b32b00cfc140227f5794afa24159c22e2fddef7fb71573cd23cb43452aa7262c62ef7f39369342e6976e06a304040a7f6c7b3e69119807b90a040e0868e6479c6a8c4dfb9fabf2b819a033e07b8f3ee45a65d4aaa7c06b7575f7d6771a8d3e50ea7d5a3ac8fc48bf6d079534a764221b366077f3e90bf47baf1719e27db9eefb246ca16a6d701ede7b42c4c7b292de4014d008864eb5bc43b352fdba2dd9b30f6000e72431348e3913be80fae295132903dc597fac0d92811fb2edb61058552d3a05639a7f0f2aebd25201006c0634d57310ea47876cb7c3d25cee9c1ca38b46dd480f47f90f4442777c3173532137a8c8c779bfc302bd0e7aa2463d08b64ddb6c9cd758cfa1dc12ffa6c901789899067abacadf391038cc200d71e88cf43a52fb3c642458284a658668c4fd7318390142950a8fbbd0eaabb1e4badd7299e0033dbc183331d0922566f538ef946dfd28e4c4101f71fe7b32c69871e011a8b8347d03b6fc9cc1e2a6fd13a9b061a221c208b1ab0b12576c6f8aa9d28d4d595e5cdbffcf0a19aac5e5c247f06ba2b84e1a3f24f0d303d2f5811d24845dde898a362a9e0431dd3d9643068202f88e7486618e1b0d4152aff3f3b228e859db52022fc46b9e8d54aae7a55440eaaef3b685656abc4a2ec5b0e41a9ef382f5be2397f864f49bfd0f2650e56bd44f425efa030b5697409542f25400231f0a0ef887f20161b91bacb7e941f014137b9a4018b189e4f0ce9a9a70c9bebb3657a4125fbbfc54f06b681d4147bd7eab395169a126bc93d3a5a91f8bfc5b0cdd133b84c62b0ca2c87b0c6eb6f6aa218e41819ac4f770f369f160092d5386438d14111c8d73f2a5faa007318403b4767f25ad5816f792ee425dac9b0bd32c6ae3b5b7c8d5987ca304fe59fef2acf5865257b6a5898cf5ddab5cd4374439d23ffe177f085604c32e1da4aa8477521803f55ce19ab71691e861b8fcd23598d7ee36aa596082c521305b6b6973d7961ba7d6cb6654ea727ccaf1a548e5b3acaa5bfd8cd97652fed36c523b9a8c895728ac6567bc0f8d1564fc51eac39322d9118168071e12db050b375486ad093c9391de01e6350b7252651008e1c36a59fc806fb169450fa782d4c05440e386e7ba7372623157fde226efbf39b6d4ba819a1f1fbc901e585098697d5eb0efdac95ed370dd81a1d2852150956b213b3d615f2f94946a4a9f61370b059728dee5635fdb7b9d8323aab3b8fad34b726f2c0701f9ef67d79f5a8f97c4d184208254759002d7834bc8924ebeadd557eae8b2046f40f03d89f7cb4c32b0690e68fef7df4638712b41da29b88dd36a74e160c9075307edb6b4f344401dfeb7f0a6e4593f0bd3b3e58afde32b11b4c4efbe6a9de3f4bb11d85c99c187eb93091534126f6de420e71a8db5ae9a7e884fc5a0899997a0e34f11ecfe5bd05841d47f3cab42f24c07c1b3258a75b244cceab5610ca3e67ef7cc38942106374d05c9e876abc9f153a86461661630d9d3cc71d8559fb179895fde23ab0f769217fbeca7f96106fff1125b14a85c948a50dfb7920b0c2ce550e64013309754cae9b2e2ba6757f9818eddb270c5455bb0d8b15c9bb42f28748fd61f604c241d612875d9d50c0d5bc9e041e182ac422c47f84556e5acecc922e979773b057ae6911b3a2a530518d42d8a20933af2eea0c3f3e43e78f5e7a87dbc69a00cb3f7103328a65d86c55ad20e77a8a87e200a2e81caddc3eca7f6abe340b42ad558a50b0e410af60a6ed1a3a44fbdd616cd42020d16684a1bc20bd6fe44aad1c9a41e6682d3219abf6caa0a10866aacfe63304d7700c94899b2700f05e6c557cf4ff9ed2cc07f5f7c23b6ee5ae04aa98298158c95c20fc6dfa71fe2de41d256f79fc3a398bf3cab61cda63325cd68dbba9adca695c4b482fe0004d7f0bd90ee42c47d65a946f447a459cf36d00d94bb37a97e01f4f4f41b53135fa0f152879a49327147fd49f830ef4ca9cf1a2e7a308b939c168cb6c68f2dae2bf811be8f39d7131dc85ef1c8679148145badd375985f6392a420236d3cb564496dbcb4b3f54a78634a932fd5028e46ea381b370a4e650a807c9ba82f937d514e6f0f26aca75e7599e65356318e7a7cf4907f5550123aae841dab537b2a6fd62cf0acb6413bb8730686bcafc44e666fe571c6e146e38853615ab3f6f26b04f4292651c269fbc0fee5f78fd92283730b962c593caee35ac8225583d998885904189e0d0f93d731c33eaafa294bbcc86d8c9d8eed2fdd21284be7a5241cb5cefeb42fa9a4b39fcfb9cfaeed25b529dab69a1b12591928fc2cf2a1dbb2c3187d55228285c5b9d081016d65a15565801c341322d1a5c6a47697b621958c5e562542ccd18630fc323826ad18756f23104a35b674ad4749a4078512298391e6558244914d1e953eb434cf579ee73f3f9b0860ebac7167ca1260c41e97d4d624a4a095f50df3c1d97bdce39274802b8cae52b49d821e2ef534e95f8f739f164123fc0a18ac23395a60e0579e0a93b314d1936382531610afb0c30c2303891f8546c07693906220124b6f5b3543848aee76723f3ae83d9186abe3841b97cb792dd5d64dace0245d844aadc2f5c4b7ad4b1bd147838f5a56e188db2d9efad37add042460cc2fcedeffdba25affc312ba314fb2761955e1653c8406af2ffb8b835629da37ee44f4565235a3ee2820d2825f4fc392433ecf5bfa0aa1bf980872de267db7d49e123b2a857c5388c5e3b7b376fd843907a2030f8edfe9c8aa5d9a39c255ae38f11f90554ef903ca8336b82f41a7ae02cf380bcb57d6b4ee4abda86bfd33ef49a6e55b2a02a7b5d55dc2f0cd85134336bf7f5b4bca922bdbc2af3c9c25775f13ab98a7278162e50884aea9f2fd7ab00b9757aa69fd8a6f18aaae784eb256587d8de2d29dd3ce2ff445ba2fdd43670c4379baa1844b98a9fdaade83a91c566602377b359cc2f4b9e878f36b21efb5e19ff21b2f77e60399c4f4fb6310246c664faafe7c7d7cca4cbffed98fbda1c73648c13059c99f0e5a2934d712d4853bd1135a235139ecca9f627a4d71c393e01e15c6f90d0194c5b863ada6a99b05fb3b157f9c997e44b0f17aee340cd032bc594fdd509fdffad1bb15803c6595e4feb5281862c1e8b1e5a1dba41b8bca5251c5b696f0f27a0ca736509ab98784407a0f7e6053c7b0c0a2c08f8f56085793136d580503ca9d2a7fcef9fe328b674b6a215f9d7a18f86a3621638b70c40876b2cd250881fd53888d57b1ac29598ebf69a273fe8601c646a8ea34d792b2ab1e22389c42fdf16d8fbb852b9ae09f1eae3adc320617df04ec9f95278da08a4f460d45e532addbe08ead54117f82af5b4f565d02e74bb8778977c404446fd977d0e11fa6dbe01a883cfa7e20f73ddcc8caebe61c15dc5e9ab49fd083c2c4a4f3089a1f648b49c0fdb4e1c182882e7659d426142066a0617f252d97edef1d53f8a9bdfcba39c7562a2d8916cc7f7baf3e6cf816679da1b7ae790fea0daea1da849731ea9b3df2d6459d4d32a43083385a20dde2a0f3fd3cdcb0c37753b1d6a13f6886ac9e67c26dc6b052952ab6e84d3004e2e42ec63c22524157479744377fba6ca89034d61b8a6086b1eee5043c8b1586484e45e60f3378deec5ed8901cafd8a4af09ad1bd4581962f8529c0a47aaefb6cab2d017e26d63d1300ce53c20984e9683a4018f35d323b1faec6bae561e9c41892e13f6688f8def99c888f8361fd073035dbcc1e3ec960fb4f048448f92588e61259402ef8313977e0ef0a116ec14ac83d9a1cc2de4db4e93cdaba7d04d8df02bf2e912b82060899198618f9985877f3e84cf755fd8ad813493ed5087ae04d0f81f6f70de6b2d3b99aad18296547a0bd9ad74d04cc4e55cfc5de877b888188cd3bf49137381b38fa850f4a784da21d4209756b78649f6dd437061cdaa1c17c2f2a4d3e2030c249761ce09c2deb48c7a1e5fff31581fd4862b399f8297d150fac172b2d7ddcc15611b23615bc0b3a37c55f9f73d27681230c649bab660879f84b579c5e485933dccc4f682e64eab38344eec952682ed8e9e39a53e672a1a808489507a38017488a36f9cfa72cdb0f44b0ef95ace131e4dd6698ce320c4621813bc257f332cee8f38e7aa9e1755f91bf2bcb2f5006cdb0af5abb541a8c985ad0092d1e4c07aa032f30bd5c545fb9a67002d0f8257f6f4233ef1437f8b4a4be3f9c880e249e2280c2105c4b064ebac54f5f23b2c92a62cb8e5f875c6a00068ce9cbe2fb0c02e98eaae192478cd0cce5702605f36011479fc019baf803a9eaae14249fef606ee590d700b4fb4dfecce45085e1f3a49bc2c48f1c53139526f5d2d1d6e81b212f44589f66ca2549537844420ce2f1f2f9178449cadcdaa15d8a17aecb4f2b9cac4fd8f8396d03613c0dac9543a7e83fcdfda21ea451cc79de5906feae2119826e4e252304ef755bcde819138d19252f0158c50321e4d0d8ca86e88e81a497e8f0443942454b498a9368faf71f1889b6de917ae7f639c433a67dd738ac9f73866148f19aba11a52f501ebfbce7f39ec0d01d18a598db25fae04b55ac8d0206509ce62ee5ab9b590f71bd6de65fd9fb9a782b96b61bdb8ba61e693790530b0e8bca8f70699a8d3cda9feb88190ea47685675959ae3581909ecdd3f33963e45ca6815fa64a2c55091b806be0128a0e9076ca778df8f1edf0ceab8adef88fbe3525c8d7d91627bed378317f885ee5501a640c80bf963e4cbbb0c1a1ff5a6f1e5cd4abc4008f7eb989bbcc595670977ea2b5a651a6f47e4ab9e16ce5ba54075c60b646962f64f5778bc6bb5fc5d4433d9f6a798cfd11c499812b72db9dbfad50d7705e851c7e1f5db1639ab1ce556f338aaaacbc793b91db374f2a0b5e9462320957ec65f9cea6b450f033e9752f0c4ee1d9cd9230f9a0a28faf8a358762a03d2bfa21c0e7a12c7c2ef08960e25182fae9dd87960a1138178e10465b6de12effdfbe60f52ec096545a92bcbda64fa7bdb16c595c7735b4da4ef1f732283a247ab5fd0494e91b395c932b38a872fba4d2c2b18932b0a694366505d7edde2186b993c58b055a96046deea13c01d05b706e51d870c63c38f645497747097ec8d79390565b6a8e8f0fa488ccb9e5e95fc52c165fa8f56dd59086659dc2ced4bf0aa74b4e256e05fdf95bfa93512b8a8d2da277457306c1cb17f7c1770a94248a0e1c5edb00b3217a4c085c5d470e485ada89a3f9f8457bb1a33b356209eadd9a418f85a4768f8490a816204d95ddb870a4f2aa8fec086108e867acc65c738f0ed7ba98db3e6e58761a44c3c157aa1f20d7b4010cece58872551d416637ecfa07bdb23308a9d9889c39027f9208e87757b14291217611fff3bbca7929926af73c0799191c123cfe6b811d1ec948771a48255b9b814b2f09da0fb1b8298fcb2388deb5eedf97c7e4690fe8b9b1ee6b74c3a9d97c91461e45b7888d93204b315ecbd9b37c7d8be9d536e001a80e38327fa3a27689f13e630e62dc68e7db8a28eec8745e8e994e34549049ead2fd86039f9d1b0d0d12fd722fcbedaa058891aaa2d63f0e62f3595fabc471d3cbd1e2c80ff8457d8e83e403c3c4ab344b67a8aa5d0dc329e215f18fe640f2582aacfbbf3b791012af690fef00f8d5068af7b8eb1919bc12c07238348c06d4363a1cbc9f140d8b58c5da97df9a116edd33309e16371f4da4dcfe3908488cf8c15e2bf4c8ac2f0af52610eafd04742c4f684b36321cfb0a9b692aa4636eec06ae7b88353c948866cfb00edf9cf1b6745aba6a964b817047963a89be539efba58cf89411fdcc52a04140853233e0d2843b2704cb410fd9605288ba6be34b0838928f609b58e9d5d85f9a861893b2f93b89ad58393b8c7d0fbb399a58d9178cf93c30c6936f7792a4b5600cc1029b31bb83d827e1081c074e191adade9244621e2a7f42bd0a040c3461197cfeefc4dc481fb89d2812b40d55fdad9affc9bc4430be56f9d73903edc7ee7ff8f25a9875c4d92d67f241a0a59f3bd465aa5ed8effaf6b8fd446db1607da6b4fb4dee9fe84a674347a248df849e3488a2cc3adf3d51672ea2e98f81ae2102647396ca57c5bf68039e5810bbb3c9d717f7dbe0af9664572c1e0469359e155461fdbab7209396abf22066aad32f98b0eab1f6b0842c0cd892c493634563930aa8752f1a86bbe821296ab78b7a653bc95c611dedff976c5d43c6e4c159168b4a6d7eb7c410bd18c843f7d024a88f77bc485b9e3e28c1289d058665eb68d852e935e00fd9078408f26400f46092254604b77ec23b8cd3b24192c91d9a98d51373629f0aaa12e0aa075a9e9049221c5ea4f24eb213a376308bd6cf7e7d966a5138ac5b921e38eea581d0b23209d0ca0811699308e77f6f9d64fe670838a8c67b2cca8c5f788b971f2abc7011c7653f987a43926869dc82f2040d3d4544c0c1231f4cb16e836246e4cbdcfdb838eb7a5b44e1dee52687526ee514cfbd9fe8e5312d30ed5bc0c7efcc3f31bc0479547528ff4afbd77fd2a7ca91c76f64a911f8c60aee52666538e92cae548939322e7357e0dcc84728acc74df851c5dbb718a85f380bde838a76441f3b476310cac6d599fd396b82e9b2ea8cd6880ce5d3c2d6c37a5f73008c585713a73aa38502785e6b83a4c02db5af7a85637c27976ac6765cd43e05ad91d65ce47605ea1c609e8c099f59a8e47eabc71a3f76259f4ef95bd953dec2af9960aba9f20b5f8a5827d8710762b981285770f65955ae9268cdfd7a7700d8e89640bc57a47e1355291cc606ae28c4d83427504a841b3b9d786588743941ead305a7cde2c3abb5176f662ed1b570b4407a2e7400534d1d5adf46574a8701046f153d0732bc65b6b79945c67b52e947b5e857b6e1ddd28858589647bd679dfab161b956186e2562e117d764e00a31f801b8aa2cf2bcf5a5723912eb597d6a852ead71f4151101c95f901cd38eece2e8729c88b1817fdf135ee06f0bb1105859f4eca4db98143cc0f0f8e45a5ef04d4dacec0529f2f0ccb3585b79a0c9137fe3a45d3c01fefd8d9e8b6e3a9492653c7d5e533ee00ea5c932685954888055415da6753760786e964dd0f4973d563167b03895213a910d4ea597e254343a92058a002ab6eb326ef03f2998ea976628c858b90c8f991a74e821ef9ace50784b416e4674d718ba206d53760ae9c3cc4351642e46a7dbc818f1061c3ad16e222274ca725967bb5ff9183bf5ba4ea916d2aa64f07ebbba9b1d36fc87ac18f7e5ef59e828aba45a82600a905c280c38621baeeb126331fe61bb1bd906e2af27a47c826930592b26aa08f47864ad4b95b1db19cfc141240acb7b5f73dc0a2e44ebaf185befaef5640f62923137ece72763bc5508c01a9bfc5cf179cb5f28350a2346bab7b460122804482ec484ba03b949b923658d694e75bff0637c7a2aa10e66fbe9333e325de19022047d4bbbb8f7af1f20985066668a436ccfb3344961253c6d521ffa0a2a456885116e3fb869d65e326aad70cdcd0c31dda9988ab19683dbd2cb580eb9c75678edd9415cf98aa2de00d07315da4bbc86019c6200446da927545f45451388ff5ccaaf47c2e030658e2ac08be36c7769398563b50fea398ad502e5c53acdb256dc9e7eaf449e962c8119a5a3bb4bd40ffa15c8f865f987cf31497410c8e089cf5fd3ec9ea1b96cc4bc9f0c6cecf806082da0d22fa36cb2b82aa9caebae13385213d2f5101bf005d3c9c176790632ace78a2c9ab347184d5c93f5baf24eeba9e17fc0f543aa63a84c195c264e154635f1613bed21eb558d39db2a1513640f6f249ae91cfe87cae91a9dccc4a49b4b8da2e5068e21fd9662692931b77e84886badc571730cf708d016a594081f06440d499cd7b817a0881dca142c9fd947abfb173b1b5ad713aa7af2550d5a5d1c16f142491bb67dfce13230863a2f7209d38f24db90d30eef8808806865d88efac265ac43cab31109fb5742959c9143a7a32d2bb8802155f96fe37791a19acd1c60a07b23a26584e868db0dfc2b76edfb088a94e343b81426b6fdf62174f516e463c4559dacfd5b7b13703289ccc1be7764239c1141e806d8b839ae82a98e001ebe27ba015c8f89ba44dfeda75c157b29f13244206ea9666f2e2d170295d6d8c18047a9e961cb25e41a3ea92f86f51b546c806070c14c3f3b28d830bc1b47b160714e1f427f921514a187c6455975da47a477aacef16a881f4b23d1a8e86677f7ad2ebfba8c30b482a4e6a07ded39200f73da8088e13131b5e71244fc40a2b3a1d2f0b1d1d914b528994fd4a0cd3596a6cd5b0c388e1136c97c976306cf08c4eadacf609065fcc237930bd714ea674b0bb07b164ad9f19dc8b832f651a921d6e057fa8969066bdae95a2a8bb31d5f300260a78073f34fe25b030cab9aef6677cff84c18129f71d7e98800b90f806102931731786a2738e89b7f918cd4d3ffccddc49489938749385a32437537d13a93233e32fcd68af51290ca2bf27bfbf4859b2ab771e177722e1c0b59c9ac4801271b0c7171025aa3c67656b7eeed92e00deec11f6072bc15b3b69a90135441c441fbbd45ea0f874ac7e9eca12915a660c2da4e985084f03571c568d3a0db9de69863649dce6bea8a85df0b3c72a2dc1fb54ec68b8ab5c5ac29fdfbc16f970d9b86198f042e69b7a8cbbb2a93f90f65f1807c3b49e090809403311906c00bdcb93c6cc29fb6652dc85c934aa78816f51620697ac3cc15b42cb25ee20fd3388654002fb6fb7b2ca6ffb9aae98257ff511fa7a64440b351e93b33b7973cb03b85e5cffb58fb2c608b4e395f4c57986de7404792721f67512fa868d2db1f04a4feb689be690a8b6cadd182f8f0b34dd0d57f8f5dcce4a74c9cb7e7685a66c0f36ffc3cad035e0b6edb6aaf0b4b659e62940a592424c3dcbad78f1b82247a1189e1e65a14390535ef8e519d4a3fae6bd0fa484ec21daa17add483df209954a5f5b0c9eab169caf3c3405fb470eb73311784f1dfdb865cd6751cea761fb67cc3cd6e457f3a46f63a2bbb5b5ca61a0906c1fc1a32ac321e678ac515640367e88b792e6e4da165a5481c3bb3dbc3dc3fcc1f89be5daa25567d6f19b789e9389edd73ba6ccaa3213ad903b341975d6a34653af6cd83dd40d0728572609d910bf0d470fea7994b4a0639c15d0d33f6dadf07b60d0a72684e0259bfcf6cd0326f568c4449fb8642a631e28f01176ed0a95d5bc341b65393fbb8c76e3e73ee89c916dcbdcc83dc0c907c7e07f611a4717e73754bdeeab17f9f2dabecf93f28445a7eb75ef309d679b9efeb29b58a3e8ef84b502d895502b2b3255d7d43cebe0d39d4818db9d14fbd9139637dd10e56464d911b2c0f4aac41b1749b66fe62f47dfb576b3f8e3cef7e71827357d57fb2de64ece54ad71c830a4398324eab3e51d87902a930fdff819e6db0a314da0ffd0309470f7082d8cb5d3dd9a8641e46193352ca191883e047cc2b03c391683296ecbdb20853d58b7d59d6e2933c535a38632922378cb635a57893bd956a329c1c9dceeef1d09758db3e9baeb1773f27e21f40dd2bc4820e4045711aab4a57e167c3393b80bba71b3012c075a7c71c0512cca704b680d89e3374899169fd96ffebc20d2376deb43795618fd5c548213f63e11b78ad1ebdf4303beba07a776bfb0e755d718ad32c274662f81f1c51eb140e9101534ade4bed36415a63664e6a2b3c7e08446ad148a3f337d78f7648589d417db1dbc529f1e3a71242cd02a0ee9f0a9ae246e92027cdc3e94dd0cddf97f17582ba0eb000e258b6eaa713c31d2a3e551fb40682add910ba2849ccc46c2668761b667c17310077a18e3a9d6843d04ace58aa7292cd8b3158128df5bc3efc30da85d1b432ca978a93a91d0cb1d36045dd36429b8152706108cb1f955d7e5a8dff1dda98b332397864795dfe5a4b74b0898868d07b4577b7d8a96b0e6b8b53d7ca48a3b5c0a7b2bb090cb8d10b15acdc6a15c95c8e2c2bc85cccb7dde7e92063560dfe588a3eef4504d5ff6410b4147e9b5eac4e0775db4f7f240789770c1c272c91fb8be6ea3b1bc9dbd8a084d9b0eef3ed9ae901936e0dae0adb7ae1b58a261b765132f88398882b4be4bf08766a7bb6ea98c1b4e7f0b1cec7a2d9078d9f18c2aa4d1e16a360d47438a1d9ff00198b92f89e5cbd1d59f95d7222adcd571de22b05d90210c75be4ca4c228d8045bfde463b45b6277814d0aa294b4b2ff77c7e676d0221c9f1099da76c2660d3ede4538244595529b59a63d2e7f883131c8650785bc3486fdd2771a7754e698c977df5c855818f7fb55b751d7288e968c340a5e896ea66a4237e847ae1e28ec2fa3015813e53ea1fd22794d975f8b8cb19d077f5be29983339b9094d0414edce4f51013ffc282dc31582fa5a4aee7ef3ac02d342a2373eb0340f5d26b21e95af19a603b9cea7ba73fe516cad02454da7f635d06dda9a00a4e02ed599d9901aef471cf9ed53ca81de1548546a6b0a13c6279515dfd777d326d6b1bffeceb88481a75bad8ed86974dfc3b37c9c7d676a7272952fbec4aaeff0b69c61c977685c996894b2cd44ae43e5715091609563e2b0363810de1eb8856539f9b55f778e661d64905da70f4b8d0de5c3a865bf5105c59fd5b16289e39b7475dd2c5c2b6d867e3fb49e1bae9a274189b79eb9afb8841e675ef5c533e058f900178d7708a27c64462f03bbf7464244751f0512b6c1bb43176f22406998ea5119fc79a0568f1047e44013fbc7b782d794f0d75d39a12f45d8db46e850e98f19d8e62a858bd03a62257b304f577b5b4750ab3f744dd663a297927fe8e6c6c32efdbf2165304c77427c0b707ab89475c94f761b28cec85c9e0e9e76d4ca496cc44c9034c8801b9fbb623877af2dad5399554e654ac28cb8c18696fdd1a912354f69dc40bafdeac487585eb020fbe8c79eb5041b7ad4f80587dd222734bbb7562fd5ba9051fd6bca1d7cc209c16a8413a01018193be20ea8439f4049ee9fd87fff95c0f82e5b450fdc62f35dd6b12c65dc575b22e1575b176bc42a9c14257c711d9e3930cb9756d6ff3ce1ca90e0fb937f139233eda99235c3445f609198dde92abcea18d79db54acd415378f9b21e25ff9cb0da84128e4565de9e21dc69ca1345c48ed8d48d8b068b1cf9fd7cfa09a1364a4db7e699a9af6fc653c2b0780a0d483533f3ed896343c188b260fa96a817ddbfa06b0579ef018c92a8f78b30572f796946086175ac23adef36df892d19ee5330e4846d493d916148d3de2fcd7a0878f3d244e499bf0842df5e9aa89593371f5bb00157c8801c400e1037f7ab1383452a1aa4ebff465e1749e869ceb89892d23f4c71293f3c076abf3bae0033143567a3d187d6e7937bf98ca8cd3e168baa1df0c6d1706ae040100d702ff8da280781400626f83a37f73dcde5a4a56c3d1fd0c9a90ffcca2e4c4011bc6b86b50d44567c7acd18585acd327563e5553afa1502836fe088460770e716970df66d91a4030178d817e3884dc57664402e44a342a87cb36b81cf989b3df20baa5750aab4b416a29f95580ae7f014515a8fc44f5c66cad214b7633eddb9eac4224852c7b8473e3e346e95d9bb6a2676ce711e4b9826d459daf92ff94909f9f3c2f69bace313c0a47095b33569c79cac65c9e841359244d300a8d33818a0b62ea42e19d85bb720bf6c2f04240856bd3df72b1260091e6d5d06aaf69fd8d986035274731b134de42452eac35cbbc53864a9758fa97f6b5cb6a19cb0b9459de56b0d378629f7c9c446148095991c404a46bc0d44d79c319a8826c23d7b6b56da8f3a589f08d7c96dccfc37018706d5418733164c01ed5a1b8437f495385dd224aeb386544bc604e6edf2abdef44f7eec2a5eb153990a0680307bc170f8f80ee83d5e47c9cb3346e2f33d9940e7e2d60ccedee68af140eb5bc39eab46e0669fc702ceef709a23a1a74047347fe5d5ebc6380a1f593540f426031dbbc59cc33275cc1589bfcf0d1331102261c21dafd28818a1a93fba983c5708a387f6d454133f575e454c872252b37435b991b612a4fa923214d01149060fc9b89458caccf8ce98656c505ac178e144b6e1aad4c2da05ebbda4edd0e904e31543812fd59a71ab9a6d90cdbbbf4b0094fb7b65a985684bb9bbc0163934a885535b6dd11f7cac92f6bb587000f433a3e9904abf7a3f7566f2c8ab9e79397e8864562cdb3130bfe8d96d5d2ec88e506c1a05cbeee6c38101e591fc93cb67c1dd3a12b2ce9db51969abf4b783dd651e8246af550510b319f960b845d64a6af89ae8c9a17e75612b895c7377939db6271c4d22575b6b8bcd547cc0020cd6244ed3e09d60289495e551fe6f722f484030ac8ecbdaec3ab05535c477582f6a715dd2571542a8f35645ce7f60157a65f4d7bc4e807e9e9e931324835166cf1ad5d5d20404b0eb5e5240a6691516be7cea20eee7b8ce859ecbd52926c40006e95917e9cbe36e2b2b522361cef52709d97f4c8584914f01dd0895ad76e7d1ee8ae4b630a1eea68f01bc195a4e5bdd4fe526786a8a8254213591517006977761e9960d8fb6f84e329d6746380deb89a2d1b034463dd545a07e6a7be4a6bb81b59fce53fe8895c505c8f91a9c4346f631ba56fa107d3b55647863ad72e2243cf28750a1d8a0d1fc538da882d86ab960014d99fa8e11ff991437d4f22709b735ce8a275d802aaa783066a06ced49c510135042f838225441f75babc2e43977a1697d9dd4512b1f8c5853db814a13546f8468b957ee6fafa711d2583019576ebebdc76b9d925a8d306aa131837ff383ff7a93fa0a39baf5d2b6d915411790a15452baddcb5ef5e3afb685f7322c1f0ffce127847c0b3695b7ec75a6023f7fdd46447b05dfa979bdfb0a8e99ce9e63c74916f8e91e2ecd553523afa5e263307a8753c81065f362dd31de9396f4f15366c9a1523847c8246a2cc0bdf24d61554e069ebb843cc578bb7c0e847028c88da1877cc77fa5637efce2e9b8ea2619ca9958dcf030449936dc761211188589b8d764ef8a33f3d8de56b581989f9ec323c866a08b93a5ddb8ac42bf0f48079bb971cf8fe90e0ba58789dc3b293771e9aa2e4657098586b95886e37cc1ffcf2f52a4ad3212c409db759b4419f8fe1ee2eba42b899d4a742ad34e9a5f547c48915ea68ecf7aeeaed20a807a14b6eabd9ea30e6d07f0cfdf5ca1505c066aa3a7cbea2349ea7c896789fafb641315e54749c0a4d824cad970d574da89ec21205b2f20ba14630869ceff0976f16ac11c312f41bcc4456c9a5aa4e0255e56a7071d934b11a702e8f841338de438b10176eed07bbe43ef55a38c5ee9924895c104a734028850e1395d6921fbda335f3d62cd4bd945bfc49824ad208ed641f55f45aef689ef01de47c3b6b435bc9c58cb3d89d7cbbfb5dfa645c01cb3fde1e9726febb1c3710aab3a226cf10eaf084e25e27bce518931f323e88091f47bc72fdb2d33a1a4ad1eb92e2aaa980510c786310457cdcaf2b4f6cb7247d1906053c2ee848332dcab3e61db35121da6ac9e888079a9da767a7d4bf062fa7627b3ab1301b3ff6d730a1d4d0a321159a4ba8b9b8dc5daf515ac512632c549289f1c16c50147a62020f15676cc1f860ab9de44bdfc7cbc277cdab62b2937e4a1b571f45b587f797b13f7e43e682afe556a1efa0189fb0cebf2ad9e7ec264787c266984035b4da55e1d99d9808a834bb7c4a3ae6acd06a03ffd1cc1ecfe4d0da1d8acfed4ea1f4ee89f7d581c3dc603f94376cb3cbc613f32188315aa18ec8773306a62158045a0583e1fbed85aa926c7883404d21ab56f1476396d6146e4a615c22c95a45d3c2cd5c79201714b69b7aeda257b3b4d30c21b68001fb5402c83f06a8f508580a9fbfbae394d27e8ece68ce72727ab478b49ca4a1d36558a195cf67edbc08f1e4e64b274b0084ccef888d5400bbe1d6f6051c21ee01cfab5b765a4447aa786f9a350f66cdc47bad82b265791cb1c5d37872ce55fc35dc01c23fdfa1f6099bc451f6c9058470e6222f20004a963ccfb3b4c3f85a787df0efb82221fe302982b259d1133128ee72355859ee8b5e2af76d8ba2133328459eb4c768dfa9186c81f6536bec46e7acac90b0d01103b3f72139c0ff45665141734c7ee2716a638ce0a86d6fa8d41eb7c849a6da2078f79823cb09e9b5a6ca19341dac7b06f7c3892f0213fe4ef534d0669f5e688314c6edccaca3c6c7038aa3bc4dfbb7c640391d34243de6db499e8e2838b612b474f979b65e39c669072e394afd7ffd5c20c5e9b5a142188fc3b1375bbc076d893df59c8349d110043a304e968c8134a587a232c70f1437ea37758906bf47047499de3fa4ac1fa6fba87f5f97970b334184bab044aa2d62782e7a8cfdb680cae7bfbe6796f573ea28bcd50060610d2cf0f94d0d566839b798c3da491899a5a111dc7f29c8b4873ac7415e21f9939797c6a8975f3ea05af9b2ec0e5bfaaf6521e1a58d06231f77c9659edc2e0fee71123a652d318b3e9fa186a3827611fba7b0d816e0f320642ee3ba78ef82a2e9d2506efdd3a7a8a5b8dea476c3f92bffd0637599f364dcc445752e2073a6fb98ac2d9d6ffe6b5274eb6c8b880ddf331a57655ebc2e5762b27edd4d271695122fb4c324bbc287d28dbc5aff6fa45dc5c682e2b6a45e299374ab49ae93a79496ba5a0e3783946253625ccf9edf84707366cece574c3792f2ea05843a2a666fb7a49055d20c527b24ad15d7549b1a5a3db70a3f467bbd6e0324138c6ff739f8afd887c0f3ba513cb0a866fab27cd4d40d88b30d4e632829c6a8dc623c1900ea4654ed43b0a0a0c323eecc848259882c1c26f7269fca9d17696b513afa6d06942c0caaf16e002e4fc66f5eb9ab22dd9a77d7b35f9b3203a4d718fa5b319b48dd6dd1ec3417133f828df814c085f1675f95218ef21aecf2af9ab8fc4b8954fdb6f1c8af78b8572b4530a36f7c4ba8b35dc19f809bae8d1c431c6e861a6c85548b119598c66d27d52305ac7056dc9d51f707ca11cf20983263957895820b669ae840c611aa1b644e827f9a4416f1d0faf65bf2a6ee30168e4aa273dbbd451686996631c741c40d89fff9191ba340adee8a73f6666dfb417493a98e5c13160708eee3d4901fc4edfdc56924dcc330d3dde872a3ea5ba04334b148a6974988c0dddeda50726ecc6e82bb302d5382f6142b27f583974dcf0239b3e62cc50a83ac7618f5878e2f6ea4fb390c9d8e8e70d266d7e10e0ed079f3016acadd5f150fcf7c9194989417ba0f68067da4bb4f4e60669db602867ab5e3c7818ec92fd1ad64e567060513b298dbc85cd2bcc4d17e5c8887e277a8cfdf624e4f7368e615d813e026e5f3dce3ebfc27723844dc3c5c9c8aaa7ab0192c85e0ed4163137913088b8ea95274969503afe242837aff3b594e8ba671975d3402f8513faf51d9b186a79a6ccb6c0baa4080dbf9e4dc48857c24452129b791ef9f56780b4145443f0ca7b224cfbabf86013cefd83f2ec36ecf2c737c16ae48f6cc95255780f386622e8e8984540c82b3024c7ebc65941980c5e373f6c06025ec0979460a5d2d4281e8ede3d7a202c050e752fcd66d9b1d196bc8bf1ad04a89c4e051b48f38055ea61365cc91cb36205f2f9b876fa4fed1d6c4cedb9dddc0dd24bf50f9c1cd71685cbe2aecd612ec9302b63d49cd3eaeae75f47782311ed570a2dc65d05de4d376e4d0fb5838e320f33486768f20142671d14bbd180de565b916b4fd9af32fa38cef0ea00848b7c16066fafb83dec38554730da02a24e7ea13444a91678ed59034dc7fe5b55b893da6cb782b3c2e01aecbd0da32777c698b1a6ca1ffad92f63ca5e3516907dcf9ad006a38f9be12f2557b597c480c8bb30da8c963ab0a38b1222765808e8c66282ac4cdae88943fa2dcbed8a93b01450784eedcd72f6988054e7ceb9774d861df3699521784670efb6e63e5a6643712d41d85d07bf908c281ed280044828df1a269ba08636c7cc58975c353e9d1147f9202b960c8cd73a99c7a87b4135e6f40820e472bc9913347eb81dcf9dd31ed588e861878f13e16da720f4267de8789871a478b60e4d88251986aec217da112873a56556c0d71222837b70ec12da44aaa91b48f3899814169602b7a82cf2b3fffa7bd93853afba442e3c145efd08dedf0fc47d436dc51cedead57e0ba2bbc473d54f1637b70c371aedef5d465b72ce330f463853d04dac8d13453bdfc8495f8bbf3027a1fc5f50710fc054860749fec630dfdbc8922edf97499963176abfc2df323a5b31a77d33edafdae36c56f43fdca5055c3e6de5dcb0f82c4e558d5289233b1862c99c670aa0f96ed1531131cd8f641288d86636f26bb95fcded9a7fa82b36486f159d13c4430123157e05e1b5a97bb2312517d1daa968cb7523f57f516709f0eb52fb51e523bbff2adb69a8fd0da11c48053a3a21cd9aeef8f237dacd00685bd923fef299e4c6bb7f24ed60219f87996902cb00e742b8642f9761271ce614218893a10e715eff229dfa6b0c2bd825cdde87f4d2de5a28ec84ce83e4302fcd0038c779244dbaf953b13a11812536e23e6e4713a8d8a94b92e47440e1b44407382c50c7ca64db9373908b2b11dd2fbcabf959919794af56fa705f961716172d6402ba7ee72c3bb7ee38950f8dc142bc207a22bd009f5d238828bcecddb0c7ae5cbac5745e84337d54c167edabb065f534f79b535de2337025d703c9401d25ee29372b7a92efbadd7a0c7966c5c31cd6c69a191ea56371ad8a22fafd4c7b195f4860cd8d47c4e9e39990de8262c7315707521853758b676d288f575066191980201b3ca80fb9d05c405c48b1186df293e989128a298b6b3679551df8b76d93c2cad2e6117603acc9f4215811c27b7b0435ea2d9e82fd55fe2909d9a8d8a664a08987457dd6c38d833ce1909f87d1ebff1a6abe507985b5b42ed8c3f3d37bc2e79e194fad3a58ff63b2bc9c9b29337da9ba0a3d7ebd35bb2f71315ca8edeb2ec81f20822d562b04df35a0fb93ad7b2e6a038a9db365b673365cee48095417ed21ed538584f33190f932f3ce4678cef20e2c72aa26c4c6a1cbc4d56685cc503599f8fac1444f5a5a0156b153a805fa6e02579138404d42682bc33faede4c516d3f6a5010b0fb5fe69c95857ae8eb9246be43e864cd107dacf7118f20d982c689bcf0e985c35325fcb8caaba5ee763201c26a38bf262ce2a59ac656c3726b68fcc615c947b1cf74f7edf0f7220c8af793af80409a2d1e8e524a527b56feb9fe29aaf6af601126edaf985045ff73e356574fb93ab629028c515374afd70e63b9dd1fcf31b4667629d294aeabcfdf80011a3e70e6670395c2cfc087717a02593ef9298239205bc8efaadce4c9b1da12cfbfa5b9321921f75276188981cf8e86554270874712114b477a5f071fe78104565339b9fb45145d7e138d037f5a563e396d04ee35caf99c159c2c369cda16ed68badd9db6c9f79931f90b50d404fe70fb7494dbc8c12150c66430d6119de316790d2a86cc9b7984c2e76775e5a6c47a2e681f17429a97935ea841be9a4e6c071e3acab8da0ccf661ee6f9d5641cdcf8a0e68c126f16d4aa34cc891a3c12b51737df5520c2b7862c62e77792fb4fc493d82fa50808e4e3e9b33f284c651b76583a0156cc4a970d7aff1431525eef16ca1bea094c15b9d3458cf64817a672cfbcafe8cb018a20b7e25fe3a47a21852a57541b742e940f159ee7f422b8df51cadf259e40a6c8ff8d29f6315f22cc98d50bc57f06484b1e0f97852b8040c6c05a4c269ae09038f15370564ad5ae429fe515c0691b5b1a853534b4b4f4c8d753d29fd86630533910e970a102624d86080f3db29d21d07d944d842a5ec21f34762402178d8c7ae37db5f50c835e06f61aa55ff4f496e08b3d9684b250adf74215fdef54fc498898d697dc76a35964d852c185b8491ea29817e37c5b1f1edebc428e3bb57cb0b908c8ec6b1e8a796449eab642d924cbba82c31ab92d40464dd0e2ca8cb39e26c630d778ead6a3c6dac8d855794878de1088a33e36a731b56e124fefc5433e84482babecae24c9ac4e8d777806b4a87fe63cfe92dd4c1f6acf370beb7fb34a9305da571c9194972c2b57659775448e10524471e341b4584d15714cd1f1df1a9d682b83dfbd77f7c534af1bc6114dbd5aa09b62a22f5c6e3f5030127157d9828b1f43a9e35eaa472511b253c306b30cd9b58665f34d3e6f2504568c96cc7bcac3c04a822f0b6cb2a3a5c953e88599015a471bfaaff609393e8808748f26341ffbed68ae6f8a058f5a81204493f47697b5e13905fff4df2b23ecbdec0eec540d34bd2b355a3aaaa32a3881e5b66d717dfaf56943a3641304b5949a745dd4a01fcfc8496126403dea0983d62296ee686b3b62088ab5c1a08c09e9591bce9210155c51752e23fb846c20cb6445c1ceee7473a1a893f57fd700587c613ccd1640037b03311e50a9f9dd71802b2cebafd120eff04b09d836c3a85d38ae14b34aa0c8174c79260db64be34c63ca2167228888e812e920bf15eaee46773584b59b78a9cf4df74b0d88f11d21858bf4b72768e38780181480d40d9e5c417ece9d24b64e826dbc398662b00de5d68f87df010d6ce6f7ed12935615ca41dc8dc8d3303a8e6dc1c81072697c9394a67f78951a40675968ad7a49075b2500b952410a2c80226cdb6f066c4df00e4c822ffc1922d02906ac7979dbd7e17e0a529b900727f66e7d276ec85f021c991dfe3bbd7ab7330539bbf6438ebe90938b2d1b9eee533e95d4244b55793a0e882ae27553233e0d6eaac29b263ea9a4b79b901cdbc8447434b3d044dc6cc471f713e2b2c022be11cd549a8a99b13056374643a684d9ed2d4dd168af0f7081a4c627cff77f9f633f92a59fc403d1d9b814ab31a75b6ead4a01a7333b41bb03d4457986c07a29447acea38b3d2e813954416ea06485c5c76b14a9efd58a6b364078942dc4f42b3ec13ea92f2ad6fecee22345d0a8359469239ddbf153f73c099c5f98f312f6ba5b2e6164efe7aad5c84197ef54f2b76faed02df7fa188b280919b9b57c3a8ae2906dbc1b4a5a9c522d90ea3fce48317b7e895a02b2bab7a191ffa741540f959273e4dfe1edf8fadd991963666eac3bca20dd273e6b02afcc0df5cf10127bec7dd1acf66e394868fdd759aea656900496e84f3509e0f88ea9eb396c8c4e15edcefb4d2c565a799fdf2f21c8170fe1681e4e2ba8fea78fb6e1a9cdce9012851f4697847910131ae4273177cb69069f4fba59d144a91808980003a6ad9184c71c7d93486e6b7a62ee18552a7aad4f08ce9e2a7a3bf2ef18623ed7224ae8384add830c01bf37c792767b20aa54c36a90fbbbfba2c3503dd1acb1b5fec2a70922865c2fa10172dd3a44323be18e64ef84c28df3ba3dc2c2e8639a8612d0b54024093d39ddfd37b645e4752366c18d41852951f256ec5224b8a055211c3344dcc647b3ea844b920da99beb8477e1e2d083715626bb23b3740ac116a53d1b3d561e4d6c5e7c2e4774684d0bab9ec31e4784f99022a64b74ef4fa2c5ec5e1500847b61ed411d6cb4e37eba4c628bb3d849adf67fae3399aa881a5a3763fe09e024de2eb018f4d4275ceef5de43d47e321413118714c1aad36029b916e0a52a18704990f5d51578808f4b17f645e9f465921cc9eca821da7dcdc7120c7ce0e71a902331982580014e7fe6309c8e7211a33ca61bc3194cc9d94ca92aa4537301e5f384381bb4c98e45779222dd52d293649a4299d0de97f5344a8a54552393ffc4bb43d7488828c9c3ed69116650ad374ecc5e758bf637edba050ff8e253139f2bc4be3d3392a4653eb3bab5d76c4b6bcb583d915aa248b6da79b95161e7e90228052e27136333b2e5979e304c8f1314123e374823f3cd519cd0a6e8efeef5881d067e83fc158e06446e48042e9ac482be0b2809b6d63f745c78bf30ca719e8db360a46224603b8837cfe5275f3d68f1de6549f01610cf25eb48fb58e2a301ec519d4c7b2badb5fb63909098b6033e85c2bff965afb8f94fd4406bea26f45522cec92b8cb7cf873ed4eba96be3b5074c623c04177ba3ac32f9a996b01b9bfcd94e8e7a7bc9443679a3955a91bd6febe0952bd2ec007e4d4e22e0d963b87f457ffd38389be135127fd6bccb4531d1a67303057f068d96bbb06218fc80020bb700443a35d9597df3625ab11ab092b620669f09843f34fab870b771112c01b4b0e4826ae0d84ff3655a92323078fcb1a6be0a216876c4bc4b3117ca9b273b5b1ef3df190831329fea5b0ae6e506cb7e3df0dd9878fce2a70efe3eccd2530177d15de0e624933d31930920b26836c01e27de5bb923d2acf28404ed153dc807139203caccc60145e8758c49251605355f74b24f18559be23eac70de7bc95569e3b2a8e4ff58f0222b2919ca3ca4b46619cd50247d2b139edc39000e980f145c9ab7b5e29728d58d9805b93ab498ce0a81b36c4d7b33171363cb07bf7e38a6ed1975d9b2bfd059df32aaf437d309809a0c2fbf6af0b840bb6c4622bd233918c3afc234651eb40f34589c356470dd8d289c2dc472047beb1c1d3ea9dfbb2147adfa2a26a81338178546f12eb18ee98cce1ae8dd109ffe5840e850774581365b385069a85fba7296dc71ec761cf12e5337422516a730c7d9d076f2e18e1b593e7ac7466a289b3aa519ba003299b92dc2eafeff60481fa751a39b6ffd1a378d9bef16de4c475975132c21045877ccd98c805fa468fb8a72f0eccfa69fb9ae67bf1cd571d69ba48a7a33e672876a53cb0077224d18a4a67fda1712929d5c50ca23e66ab6f0cc5ed6113fc73e1ccc32fec44e9da9b200a94a6c03016607c446d22307726ea0d86c97663b08eca6dec7d94c99f5bfe03f337dcb9904473e2750b5f5e899eeafe65ceb267a42fd144f6a856c42c75b2784c418b1b066e97777853a22dac90ece8c38b97368922950a7cf6120b635726392882993fc5119ddbc85f4bde36dea7e0aa878cb7baaaaf9ff23291e1e9871e2b5b74b2eac3f2777947b4a1a8192528befbe36b4c3113497249b8599141727e942535e3e6ca32582efb1facff3ce90cbc4756deab85d3ff59a834688d36b08616984a04627cc9e86a017126ee7e31de488ae92523abd73b05d0e9b823186c089bc65e24a3c818189430a73956f073491e849bcfa96b8e2f05e5b2c20d8a7048d867e5866afea66e6c03158d27cbe7ef487c656f01a66c049b4eee7387faecce5882bc77d836ff485160cd78a785fcd7e32efe4c74bf04ba646ed1afb3a73dc526dac9e9018d6758c60bfc44c53952355010bd5e49763f13080b788c4282c763e12167498ed5d62a3ec69c846a61264e2f44f22fa35728881dbfaf1e8cb2fd0bc597e654bf037fe3e91d0b219322597dc901256e5c8bd37aa19eee98c61c3a917e4c2a6b6bc6918c02b538d3d0d8c026964e1c6c904fa09b8d9c546af24a39c855ef8b168d2d00e6ff11188ee82297fd09783a69bc12e6d69f33c5388bd03c6be885c51501f0955f8b936a42bb8f9c1459151139814b82418a773f86d9150f0af73431eee0478a7172e90a8b1ddfccb1ee580093a984fe1aaf7337e70e76233166ca415c9cf3eddb6f8653a22b3063c83fe2d15d194e2dddca17a145aa10f2276e63614bb4745b5be06778006995d7fb00ce00726e0ab8cbcd3eb995002a1ecdf6cf3e47be7abfb203c1de9c89c639ddaa601b8a073f69cf8ca96e19a4b0ccae9bd1d4402a97f4503fbdd9918a4cc7c69066ac1ec2afc555e77a2fd899929da6a0b33ffec7bf0ef03d1eea7f524d46f446413311230434af55fb86b5db6578c048b62ac53aaa9897bccce9e637c2d6d5a2d4582b1fbf70137f78a24ce5874d858c3d385412bc1d16ff0df1b08fdce59c42c975b7a8203fed2e3a0b682b13f9e32906cbb9bd99174f0dd1b87ebf7471d8f7283df06c017b1d39cec4804e1e955ecdf3e66b22adf9b470c8436a11c0dcab32139656944dd66cd7f4e9ac6e256de8f27a0307ab27675f96d53ab97b05edf0fafcf4f9be8ef56bb317024cc4d54052a8ca6226e314e6f4db8a09b6c0a6c6af241d57fee8c131c1a5f9bc90b4f2dcdcbc16531c20a2843d3aa00bd9a7ea2e4652db13552bebb6c3b93cc3e4ba90fc760b7b56960f6ec9c519fc37a12ac467633d479ca7e8ab37e76381486222cc6d41b03c8c311cd08a8db872f2fd993ff93567ae502c5f023437dbdb19a01e67c5a8418c2854b6e8bc9083bb3c6f6537af511c8622a45cd5f8ca654ba54e439237174fc6b792f8fd0a9a2d04af9573922ef642be7507ebd5538ccb212481c1de77abcfa3a3624f4badcb088d588719c0fa36a908428930b3f500b5a87ca118fb6adf6847728be299f935ea76341a5777afb0c95416ad3a8bff3a760dc2c0832eee90389deffdf1da2121c7b4e414c8a5249d6c3cba932404c8716390006f4dc29578653d03b8fa6c7f6ed2d7d14a9cbdc5c563c154e6bfe52ec806f4b3066170a2c847c64d23e5f063bf2abd5678f5fc157140af7ca680e54677c443808fc279622b52662d1ad4272e6c88916776a098116359173ac08d3d344a138ef701db2eceac1e43468f59f297892ec8e7af4e33b90b058869d3bccf654d3c21d687cf2a53e24f0f2a689f7cf3384355896d406001000000000004000000000000007480fb6548e56bab499d3d2ae852a74b42908df1aece0f02431ad4e23120cdf23148315fd37400afcf640020ca4c0ef56beb57dc9f88fc9ee7c91e65cac5ed64a029434362d6328daf1f2d9eb7830db299b491907dae12d3dfa9e9105183a85f183a95dd84936f7b43d417a51a1332fe997f571985c8c59f120ad4ea0e4131a896ba743b56aa92bf8e53682f68c876e482521cd1b352fadd6b1933f9863493c072ddbe00672434629dc8bb6d947df4b0bdb8e3e0e78b6d0b1feaafd17317ca9992f5fc157140af7ca680e54677c443808fc279622b52662d1ad4272e6c88916776a098116359173ac08d3d344a138ef701db2eceac1e43468f59f297892ec8e7af4e33b90b058869d3bccf654d3c21d687cf2a53e24f0f2a689f7cf3384355896d010177777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777b884c456d6b7175f9c81e30f2251b9a28bf633ad0447f55505c548f36ddcf6197b32e04382809043da18fbbc2da695880e387540186cf8480195216bd2d14931085e371282bb5eb160c33e08370b06207492f783b4bec019ff323661a4e711168160010000000000");
+
+const RAW_EXECUTION_HEADER_UPDATE: [u8; 1425] = hex!("14600100000000000300000000000000311d049879c0395bb601d26192d211945e26ad6fb4a00eb0efcba643f51d6548aa0fea8d7a903fb71328a131c5bfb9e266041a0d0e0006211759a9e2498ef41b10f6230412ce2ee6551b04b20a11d67e19bbc46d8116abecfaac00be8c8c9dd40148c98505cb5c58e74df8224406082633c10103df25e8b892e86a6cb73d6fce48b531e4d76ac86670aade026b767ecdd4b0a06f57503591607b006b8ebcf7287f62fe1be6a39237316cad241194ceabc6594e184edeadb943ac8ba6f196f4c3d23909a33fbcef10ebd2c721c07f650918183fb56bf1130fe37ee4d834ed761fd8e95f92fb250a28bcc0466ec65eb9d47d601534ca5673b091563f87b0fe6557adda5a69d2d6a85b231fbdcba9e866ab6454a7a3a91065683a48dd3543ae1861dabc875177c8a454f0d4abbd5589ff5f0b73cf270da534c278dad87cf1ac1077abf4a6c0fc84f41b684cdd2f37537724a099df4eeb787c861dc06bd8077e8cf230b698f74f5e406ea3e6f606850ae8340cde5a4f946c3f083fc69c0ac9df28aa9bcd858b32240a2b9966a432a2c50dcf9412f7b62ce8f447f03bab0ad6244e16ce0a64960b4e4b0b12734b3c96ccd0804be66c7096c255075c9d1d65fd9fac57a16f5bfb78c7ff0184ef317cbe9552494bb276f4331ce769fc178330c4bb8cc21a94e826af6e548bf2068ca639b48160f47ac2186c7555e6bd9ac0be112bda8e1ea16ccd9efc6a6e230e633b38b31e28dbd5ea1b34da3717261c0b29ab446fd15424610e1a4bc0aeef6053f9049f53c2bc94289533dc7248f17c5e0c783f4939f2baf904a172d9bd7105698e55f78b7df2cee9af11ab471db1b9aa4a973bfeb7c7ee5d6cb3a7dcec03e96a8e7abc64664ebea8e5c39bfe2275b7538a26d99215a2ae45c2582015957fc9f711ed3e33dcb80e2402663ab2b8bc0215f5b7c8ff5a63ff02dcf618daed79dcdd9866a85ebede42e33f4f190a87b9dc92a8c4b745fc749bf2ef2c7235b49da2552c3c7602bc00f12a9e4aacc8633a751f12c2c0bb7aeb7311111111111111111111111111111111111111118df446fe7b2e6e892a7229be9d1eb6939eefca70fb9cb765ab75524ead3f57d3fd5e397a84884641f53c496804f24b5276cbb8c5c9cfc2342246be8e3ce5ad020104000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004ecc87072b72de6b4b61d82d64ca394f81d29cbb78e61763137ce02e5e1e8a72f214ed000000000080c3c901000000004e61bc000000000073c922630000000018626561636f6e00863ba10100000000000000000000000000000000000000000000000000000014f0e90dbb3ad840536889bd6ff381e6985c28f67f7b9165dde3af49feed6d05f02495ab1fe00bab52d96c9466b7bcce27863178825608449376f7e8bba6d4580102ca6ed2ae59e5db867c9f76338558913a7519c2fc326f7cadc3c93e54e8234d104a21a008644c81a1246ce007d765fcaa9df6b1376475447e184f31872462332c4f1070ce6e0bbb840bcdb1d8edad0736d0e5538775473b40e5465a0db6f8c5a4f845a83825bf4bc2a4e134d7909386cbe2311a134836520a5f6869d00b938d926cf87d6ea4d547e623cce5514296be16f2bdfd8a1905ae89e4ef7fa1e01bedd8");
@@ -0,0 +1,75 @@
+//! VerifierBeacon pallet benchmarking
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, whitelisted_caller, impl_benchmark_test_suite};
+
+#[allow(unused_imports)]
+use crate::Module as VerifierBeacon;
+
+mod data;
+
+// Initialize storage as the genesis config of the mock runtime does
+fn initialize_storage<T: Config>() {
+	GenesisValidatorsRoot::put(data::genesis_validators_root());
+	VerifierBeacon::<T>::store_finalized_header(&data::initial_finalized_header());
+	CurrentSyncCommittee::put(data::initial_sync_committee());
+}
+
+benchmarks! {
+	// Benchmark `import_finality_update` extrinsic under worst case conditions:
+	// * The update finalizes a header in the next sync committee period, so the
+	//   sync committees rotate.
+	// * The update proves the sync committee of the period after that.
+	// * The signature is verified against the next sync committee.
+	// * Storing the finalized header prunes the oldest finalized header.
+	import_finality_update {
+		let caller: T::AccountId = whitelisted_caller();
+
+		initialize_storage::<T>();
+		VerifierBeacon::<T>::process_finality_update(&data::sync_committee_update())?;
+
+		// Make sure a header is pruned regardless of MaxFinalizedHeadersToKeep
+		let next_index = FinalizedBeaconHeadersIndex::get();
+		if !FinalizedBeaconHeaderRoots::contains_key(next_index) {
+			FinalizedBeaconHeaderRoots::insert(next_index, H256::repeat_byte(1));
+		}
+
+		let update = data::finality_update();
+		let finalized_slot = update.finalized_header.slot;
+
+	}: _(RawOrigin::Signed(caller.clone()), update)
+	verify {
+		assert_eq!(LatestFinalizedHeader::get().1, finalized_slot);
+	}
+
+	// Benchmark `import_execution_header` extrinsic under worst case conditions:
+	// * The beacon block is proven to be an ancestor of a finalized header.
+	// * Storing the execution header prunes the oldest execution header.
+	import_execution_header {
+		let caller: T::AccountId = whitelisted_caller();
+
+		initialize_storage::<T>();
+		VerifierBeacon::<T>::process_finality_update(&data::sync_committee_update())?;
+		VerifierBeacon::<T>::process_finality_update(&data::finality_update())?;
+
+		// Make sure a header is pruned regardless of MaxExecutionHeadersToKeep
+		ExecutionHeaderHashes::insert(ExecutionHeadersIndex::get(), H256::repeat_byte(1));
+
+		let update = data::execution_header_update();
+		let block_hash = update.execution_header.block_hash;
+
+	}: _(RawOrigin::Signed(caller.clone()), update)
+	verify {
+		assert!(ExecutionHeaders::contains_key(block_hash));
+	}
+}
+
+impl_benchmark_test_suite!(
+	VerifierBeacon,
+	crate::mock::new_tester(&crate::mock::beacon_fixture()),
+	crate::mock::Test,
+);
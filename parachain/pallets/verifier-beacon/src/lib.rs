@@ -0,0 +1,539 @@
+//! # Ethereum Beacon Chain Verifier
+//!
+//! The verifier module verifies `Message` objects by verifying the existence
+//! of their corresponding Ethereum log in an execution block that has been
+//! finalized by the Ethereum proof-of-stake beacon chain.
+//!
+//! The module follows the beacon chain using the Altair light client protocol.
+//! Relayers submit `import_finality_update` extrinsics containing a header
+//! attested by the current sync committee, the finalized header it commits to,
+//! and, once per sync committee period, the next sync committee. The aggregated
+//! BLS signature of the participating sync committee members is checked against
+//! the sync committee in storage, so imports don't depend on any trusted relayer.
+//!
+//! Execution payload headers are imported with `import_execution_header`. The
+//! payload header is proven against the body of a beacon block, which must
+//! either be a stored finalized header or be proven to be one of its ancestors
+//! through the `block_roots` vector of the finalized state. Only the receipts
+//! root and a few identifying fields of each execution header are kept. The most
+//! recent `MaxFinalizedHeadersToKeep` beacon headers and `MaxExecutionHeadersToKeep`
+//! execution headers are kept, older ones are pruned.
+//!
+//! ## Usage
+//!
+//! This module implements the `Verifier` interface, with the same receipt inclusion
+//! rules as the Ethash light client in `pallet-verifier-lightclient`. Channels switch
+//! to this verifier by setting their `Verifier` type to this module. Message proofs
+//! then reference execution block hashes.
+//!
+#![allow(unused_variables)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_system::{self as system, ensure_signed};
+use frame_support::{
+	decl_module, decl_storage, decl_event, decl_error, ensure, log,
+	dispatch::{DispatchError, DispatchResult},
+	traits::Get, weights::Weight, transactional,
+};
+use sp_std::prelude::*;
+
+use artemis_core::{Message, Verifier, Proof};
+use artemis_ethereum::{Header as EthereumHeader, Log, Receipt, H256, U256, mpt};
+
+pub mod ssz;
+pub mod types;
+
+pub use types::{
+	AncestryProof, BeaconHeader, ExecutionHeaderState, ExecutionHeaderUpdate,
+	ExecutionPayloadHeader, Fork, ForkSchedule, ForkVersion, LightClientUpdate, NextSyncCommitteeUpdate,
+	PublicKey, Signature, SyncAggregate, SyncCommittee,
+};
+use types::{SLOTS_PER_EPOCH, SLOTS_PER_HISTORICAL_ROOT, SYNC_COMMITTEE_SIZE, sync_committee_period};
+
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// Generalized index of `finalized_checkpoint.root` in `BeaconState`.
+const FINALIZED_ROOT_INDEX: u64 = 105;
+/// Generalized index of `next_sync_committee` in `BeaconState`.
+const NEXT_SYNC_COMMITTEE_INDEX: u64 = 55;
+/// Generalized index of `execution_payload` in `BeaconBlockBody`.
+const EXECUTION_PAYLOAD_INDEX: u64 = 25;
+/// Generalized index of `block_roots` in `BeaconState`.
+const BLOCK_ROOTS_INDEX: u64 = 37;
+
+/// Domain type of sync committee signatures.
+const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [7, 0, 0, 0];
+
+/// Weight functions needed for this pallet.
+pub trait WeightInfo {
+	fn import_finality_update() -> Weight;
+	fn import_execution_header() -> Weight;
+}
+
+impl WeightInfo for () {
+	fn import_finality_update() -> Weight { 0 }
+	fn import_execution_header() -> Weight { 0 }
+}
+
+pub trait Config: system::Config {
+	type Event: From<Event> + Into<<Self as system::Config>::Event>;
+	/// Forks of the beacon chain. Sync committees sign under the version of
+	/// the fork that is active at the signed slot.
+	type ForkSchedule: Get<ForkSchedule>;
+	/// Number of finalized beacon headers to keep before pruning
+	type MaxFinalizedHeadersToKeep: Get<u32>;
+	/// Number of execution headers to keep before pruning
+	type MaxExecutionHeadersToKeep: Get<u32>;
+	/// Weight information for extrinsics in this pallet
+	type WeightInfo: WeightInfo;
+}
+
+decl_storage! {
+	trait Store for Module<T: Config> as VerifierBeacon {
+		/// Root of the genesis validator set, used to compute signature domains.
+		pub GenesisValidatorsRoot get(fn genesis_validators_root) config(): H256;
+		/// Root and slot of the latest finalized beacon header.
+		pub LatestFinalizedHeader get(fn latest_finalized_header): (H256, u64);
+		/// Map of finalized beacon headers by block root.
+		FinalizedBeaconHeaders: map hasher(identity) H256 => Option<BeaconHeader>;
+		/// Ring buffer of finalized beacon header roots, used for pruning.
+		FinalizedBeaconHeaderRoots: map hasher(twox_64_concat) u32 => Option<H256>;
+		/// Next position in `FinalizedBeaconHeaderRoots`.
+		FinalizedBeaconHeadersIndex: u32;
+		/// Sync committee of the period of the latest finalized header.
+		CurrentSyncCommittee: SyncCommittee;
+		/// Sync committee of the period after the latest finalized header, if known.
+		NextSyncCommittee: Option<SyncCommittee>;
+		/// Map of imported execution headers by block hash.
+		ExecutionHeaders: map hasher(identity) H256 => Option<ExecutionHeaderState>;
+		/// Ring buffer of execution header hashes, used for pruning.
+		ExecutionHeaderHashes: map hasher(twox_64_concat) u32 => Option<H256>;
+		/// Next position in `ExecutionHeaderHashes`.
+		ExecutionHeadersIndex: u32;
+	}
+
+	add_extra_genesis {
+		config(initial_finalized_header): BeaconHeader;
+		config(initial_sync_committee): SyncCommittee;
+
+		build(|config| {
+			Module::<T>::store_finalized_header(&config.initial_finalized_header);
+			CurrentSyncCommittee::put(config.initial_sync_committee.clone());
+		})
+	}
+}
+
+decl_event!(
+	pub enum Event {
+		/// A finalized beacon header has been imported. [block root, slot]
+		BeaconHeaderImported(H256, u64),
+		/// The current sync committee has rotated to the next one. [period]
+		SyncCommitteeUpdated(u64),
+		/// An execution header has been imported. [block hash, block number]
+		ExecutionHeaderImported(H256, u64),
+	}
+);
+
+decl_error! {
+	pub enum Error for Module<T: Config> {
+		/// Sync committee bits don't form a bitvector of the sync committee size.
+		InvalidSyncCommitteeBits,
+		/// Fewer than two thirds of the sync committee signed the update.
+		InsufficientSyncCommitteeParticipants,
+		/// Slots of the update are out of order.
+		InvalidUpdateSlot,
+		/// Update is signed in a period for which the sync committee isn't known.
+		InvalidSyncCommitteePeriod,
+		/// Update neither advances finality nor provides the next sync committee.
+		StaleUpdate,
+		/// Finalized header could not be proven against the attested header.
+		InvalidFinalityProof,
+		/// Next sync committee is missing, conflicts with the stored one or
+		/// could not be proven against the attested header.
+		InvalidSyncCommitteeUpdate,
+		/// A sync committee public key is not a valid BLS public key.
+		InvalidSyncCommitteePublicKey,
+		/// Sync committee signature is malformed or doesn't match the attested header.
+		InvalidSignature,
+		/// No fork in the fork schedule is active at the signed slot.
+		UnknownForkVersion,
+		/// Beacon header referenced by an execution header update isn't finalized.
+		HeaderNotFinalized,
+		/// Beacon header could not be proven to be an ancestor of a finalized header.
+		InvalidAncestryProof,
+		/// Execution header could not be proven against its beacon block.
+		InvalidExecutionHeaderProof,
+		/// Execution header has already been imported.
+		DuplicateExecutionHeader,
+		/// Execution header referenced in inclusion proof doesn't exist, e.g.
+		/// because it's pruned or hasn't been imported.
+		MissingHeader,
+		/// Proof could not be applied / verified.
+		InvalidProof,
+		/// This should never be returned - indicates a bug
+		Unknown,
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Config> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
+		fn deposit_event() = default;
+
+		/// Import a finalized beacon header, signed by the sync committee.
+		///
+		/// The update must either finalize a newer header than the latest finalized
+		/// header, or provide the next sync committee if it isn't known yet. When
+		/// the finalized header moves into the next sync committee period, the
+		/// update must provide the sync committee of the period after that.
+		#[weight = T::WeightInfo::import_finality_update()]
+		#[transactional]
+		pub fn import_finality_update(origin, update: LightClientUpdate) -> DispatchResult {
+			let _sender = ensure_signed(origin)?;
+
+			log::trace!(
+				target: "import_finality_update",
+				"Received update finalizing slot {}. Starting validation",
+				update.finalized_header.slot,
+			);
+
+			Self::process_finality_update(&update)
+		}
+
+		/// Import an execution payload header from a finalized beacon block.
+		#[weight = T::WeightInfo::import_execution_header()]
+		#[transactional]
+		pub fn import_execution_header(origin, update: ExecutionHeaderUpdate) -> DispatchResult {
+			let _sender = ensure_signed(origin)?;
+
+			log::trace!(
+				target: "import_execution_header",
+				"Received execution header {}. Starting validation",
+				update.execution_header.block_number,
+			);
+
+			Self::process_execution_header_update(&update)
+		}
+	}
+}
+
+impl<T: Config> Module<T> {
+	fn process_finality_update(update: &LightClientUpdate) -> DispatchResult {
+		let participants = Self::count_participants(&update.sync_aggregate.sync_committee_bits)?;
+		ensure!(
+			participants * 3 >= SYNC_COMMITTEE_SIZE * 2,
+			Error::<T>::InsufficientSyncCommitteeParticipants,
+		);
+
+		ensure!(
+			update.signature_slot > update.attested_header.slot
+			&& update.attested_header.slot >= update.finalized_header.slot,
+			Error::<T>::InvalidUpdateSlot,
+		);
+
+		let (_, latest_finalized_slot) = LatestFinalizedHeader::get();
+		let store_period = sync_committee_period(latest_finalized_slot);
+		let signature_period = sync_committee_period(update.signature_slot);
+		let finalized_period = sync_committee_period(update.finalized_header.slot);
+		let next_sync_committee = NextSyncCommittee::get();
+
+		match next_sync_committee {
+			Some(_) => ensure!(
+				signature_period == store_period || signature_period == store_period + 1,
+				Error::<T>::InvalidSyncCommitteePeriod,
+			),
+			None => ensure!(
+				signature_period == store_period && finalized_period == store_period,
+				Error::<T>::InvalidSyncCommitteePeriod,
+			),
+		}
+
+		let provides_next_sync_committee = next_sync_committee.is_none()
+			&& update.next_sync_committee_update.is_some();
+		ensure!(
+			update.finalized_header.slot > latest_finalized_slot || provides_next_sync_committee,
+			Error::<T>::StaleUpdate,
+		);
+
+		let finalized_block_root = ssz::beacon_header_root(&update.finalized_header);
+		ensure!(
+			ssz::verify_merkle_branch(
+				finalized_block_root,
+				&update.finality_branch,
+				FINALIZED_ROOT_INDEX,
+				update.attested_header.state_root,
+			),
+			Error::<T>::InvalidFinalityProof,
+		);
+
+		let rotates_sync_committee = finalized_period == store_period + 1;
+		if let Some(committee_update) = &update.next_sync_committee_update {
+			// The proven committee is the successor of the committee of the
+			// attested period, which must be the period we're moving into.
+			ensure!(
+				sync_committee_period(update.attested_header.slot) == finalized_period,
+				Error::<T>::InvalidSyncCommitteeUpdate,
+			);
+			if let Some(stored) = next_sync_committee.as_ref().filter(|_| !rotates_sync_committee) {
+				ensure!(
+					*stored == committee_update.next_sync_committee,
+					Error::<T>::InvalidSyncCommitteeUpdate,
+				);
+			}
+			ensure!(
+				ssz::verify_merkle_branch(
+					ssz::sync_committee_root(&committee_update.next_sync_committee),
+					&committee_update.next_sync_committee_branch,
+					NEXT_SYNC_COMMITTEE_INDEX,
+					update.attested_header.state_root,
+				),
+				Error::<T>::InvalidSyncCommitteeUpdate,
+			);
+		} else {
+			ensure!(!rotates_sync_committee, Error::<T>::InvalidSyncCommitteeUpdate);
+		}
+
+		let sync_committee = if signature_period == store_period {
+			CurrentSyncCommittee::get()
+		} else {
+			next_sync_committee.clone().ok_or(Error::<T>::Unknown)?
+		};
+		Self::verify_sync_committee_signature(
+			&update.sync_aggregate,
+			&sync_committee,
+			&update.attested_header,
+			update.signature_slot,
+		)?;
+
+		let proven_sync_committee = update.next_sync_committee_update
+			.as_ref()
+			.map(|committee_update| committee_update.next_sync_committee.clone());
+		if rotates_sync_committee {
+			CurrentSyncCommittee::put(next_sync_committee.ok_or(Error::<T>::Unknown)?);
+			NextSyncCommittee::set(proven_sync_committee);
+			Self::deposit_event(Event::SyncCommitteeUpdated(finalized_period));
+		} else if next_sync_committee.is_none() {
+			NextSyncCommittee::set(proven_sync_committee);
+		}
+
+		if update.finalized_header.slot > latest_finalized_slot {
+			Self::store_finalized_header(&update.finalized_header);
+			Self::deposit_event(Event::BeaconHeaderImported(
+				finalized_block_root,
+				update.finalized_header.slot,
+			));
+		}
+
+		Ok(())
+	}
+
+	fn process_execution_header_update(update: &ExecutionHeaderUpdate) -> DispatchResult {
+		let execution_header = &update.execution_header;
+		ensure!(
+			!ExecutionHeaders::contains_key(execution_header.block_hash),
+			Error::<T>::DuplicateExecutionHeader,
+		);
+
+		let block_root = ssz::beacon_header_root(&update.header);
+		match &update.ancestry_proof {
+			None => ensure!(
+				FinalizedBeaconHeaders::contains_key(block_root),
+				Error::<T>::HeaderNotFinalized,
+			),
+			Some(ancestry_proof) => {
+				let finalized_header = FinalizedBeaconHeaders::get(ancestry_proof.finalized_block_root)
+					.ok_or(Error::<T>::HeaderNotFinalized)?;
+				// `block_roots` only covers the last SLOTS_PER_HISTORICAL_ROOT slots
+				ensure!(
+					update.header.slot < finalized_header.slot
+					&& finalized_header.slot - update.header.slot <= SLOTS_PER_HISTORICAL_ROOT,
+					Error::<T>::InvalidAncestryProof,
+				);
+				let gindex = BLOCK_ROOTS_INDEX * SLOTS_PER_HISTORICAL_ROOT
+					+ update.header.slot % SLOTS_PER_HISTORICAL_ROOT;
+				ensure!(
+					ssz::verify_merkle_branch(
+						block_root,
+						&ancestry_proof.header_branch,
+						gindex,
+						finalized_header.state_root,
+					),
+					Error::<T>::InvalidAncestryProof,
+				);
+			}
+		}
+
+		ensure!(
+			ssz::verify_merkle_branch(
+				ssz::execution_payload_header_root(execution_header),
+				&update.execution_branch,
+				EXECUTION_PAYLOAD_INDEX,
+				update.header.body_root,
+			),
+			Error::<T>::InvalidExecutionHeaderProof,
+		);
+
+		Self::store_execution_header(ExecutionHeaderState {
+			beacon_block_root: block_root,
+			beacon_slot: update.header.slot,
+			block_hash: execution_header.block_hash,
+			block_number: execution_header.block_number,
+			receipts_root: execution_header.receipts_root,
+		});
+		Self::deposit_event(Event::ExecutionHeaderImported(
+			execution_header.block_hash,
+			execution_header.block_number,
+		));
+
+		Ok(())
+	}
+
+	// Count the set bits in a sync committee bitvector
+	fn count_participants(sync_committee_bits: &[u8]) -> Result<usize, DispatchError> {
+		ensure!(
+			sync_committee_bits.len() == SYNC_COMMITTEE_SIZE / 8,
+			Error::<T>::InvalidSyncCommitteeBits,
+		);
+		Ok(sync_committee_bits.iter().map(|byte| byte.count_ones() as usize).sum())
+	}
+
+	// Verify the aggregate signature of the participating sync committee members
+	// over `header`, made at `signature_slot`
+	fn verify_sync_committee_signature(
+		sync_aggregate: &SyncAggregate,
+		sync_committee: &SyncCommittee,
+		header: &BeaconHeader,
+		signature_slot: u64,
+	) -> DispatchResult {
+		ensure!(
+			sync_committee.pubkeys.len() == SYNC_COMMITTEE_SIZE,
+			Error::<T>::Unknown,
+		);
+
+		let pubkeys = sync_committee.pubkeys
+			.iter()
+			.enumerate()
+			.filter(|(i, _)| (sync_aggregate.sync_committee_bits[i / 8] >> (i % 8)) & 1 == 1)
+			.map(|(_, pubkey)| milagro_bls::PublicKey::from_bytes(&pubkey.0))
+			.collect::<Result<Vec<_>, _>>()
+			.map_err(|_| Error::<T>::InvalidSyncCommitteePublicKey)?;
+
+		let signature = milagro_bls::Signature::from_bytes(&sync_aggregate.sync_committee_signature.0)
+			.map_err(|_| Error::<T>::InvalidSignature)?;
+		let aggregate_signature = milagro_bls::AggregateSignature::from_signature(&signature);
+
+		// The signature is made over the slot before `signature_slot`
+		let domain = ssz::compute_domain(
+			DOMAIN_SYNC_COMMITTEE,
+			Self::fork_version(signature_slot.saturating_sub(1))?,
+			GenesisValidatorsRoot::get(),
+		);
+		let signing_root = ssz::signing_root(ssz::beacon_header_root(header), domain);
+
+		ensure!(
+			aggregate_signature.fast_aggregate_verify(
+				signing_root.as_bytes(),
+				&pubkeys.iter().collect::<Vec<_>>(),
+			),
+			Error::<T>::InvalidSignature,
+		);
+
+		Ok(())
+	}
+
+	// Version of the fork that is active at `slot`
+	fn fork_version(slot: u64) -> Result<ForkVersion, DispatchError> {
+		T::ForkSchedule::get()
+			.version(slot / SLOTS_PER_EPOCH)
+			.ok_or(Error::<T>::UnknownForkVersion.into())
+	}
+
+	// Store a finalized beacon header as the latest finalized header, pruning
+	// the oldest header if more than MaxFinalizedHeadersToKeep are stored
+	fn store_finalized_header(header: &BeaconHeader) {
+		let block_root = ssz::beacon_header_root(header);
+		let index = FinalizedBeaconHeadersIndex::get();
+		if let Some(pruned_root) = FinalizedBeaconHeaderRoots::get(index) {
+			FinalizedBeaconHeaders::remove(pruned_root);
+		}
+
+		FinalizedBeaconHeaders::insert(block_root, header);
+		FinalizedBeaconHeaderRoots::insert(index, block_root);
+		FinalizedBeaconHeadersIndex::put((index + 1) % T::MaxFinalizedHeadersToKeep::get().max(1));
+		LatestFinalizedHeader::put((block_root, header.slot));
+	}
+
+	// Store an execution header, pruning the oldest header if more than
+	// MaxExecutionHeadersToKeep are stored
+	fn store_execution_header(header: ExecutionHeaderState) {
+		let index = ExecutionHeadersIndex::get();
+		if let Some(pruned_hash) = ExecutionHeaderHashes::get(index) {
+			ExecutionHeaders::remove(pruned_hash);
+		}
+
+		ExecutionHeaderHashes::insert(index, header.block_hash);
+		ExecutionHeadersIndex::put((index + 1) % T::MaxExecutionHeadersToKeep::get().max(1));
+		ExecutionHeaders::insert(header.block_hash, header);
+	}
+
+	// Verifies that the receipt encoded in proof.data is included
+	// in the execution block given by proof.block_hash.
+	fn verify_receipt_inclusion(proof: &Proof) -> Result<Receipt, DispatchError> {
+		let header = ExecutionHeaders::get(proof.block_hash)
+			.ok_or(Error::<T>::MissingHeader)?;
+
+		let (root, data) = mpt::verify_proof(&rlp::encode(&proof.tx_index), &proof.data.1)
+			.map_err(|_| Error::<T>::InvalidProof)?;
+		ensure!(root == header.receipts_root, Error::<T>::InvalidProof);
+
+		rlp::decode(&data).map_err(|_| Error::<T>::InvalidProof.into())
+	}
+}
+
+impl<T: Config> Verifier for Module<T> {
+
+	/// Verify a message by verifying the existence of the corresponding
+	/// Ethereum log in an execution block. Returns the log if successful.
+	fn verify(message: &Message) -> Result<Log, DispatchError> {
+		let receipt = Self::verify_receipt_inclusion(&message.proof)?;
+
+		let log: Log = rlp::decode(&message.data)
+			.map_err(|_| Error::<T>::InvalidProof)?;
+
+		if !receipt.contains_log(&log) {
+			return Err(Error::<T>::InvalidProof.into());
+		}
+
+		Ok(log)
+	}
+
+	/// Store the given Ethereum headers as trusted execution headers without
+	/// performing validation. Difficulty and finality parameters are ignored.
+	///
+	/// NOTE: This should only be used to initialize empty storage.
+	fn initialize_storage(
+		headers: Vec<EthereumHeader>,
+		initial_difficulty: U256,
+		descendants_until_final: u8,
+	) -> Result<(), &'static str> {
+		ensure!(!headers.is_empty(), "Need at least one header");
+
+		for header in headers.iter() {
+			Self::store_execution_header(ExecutionHeaderState {
+				block_hash: header.compute_hash(),
+				block_number: header.number,
+				receipts_root: header.receipts_root,
+				..Default::default()
+			});
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,212 @@
+//! SSZ merkleization of the beacon chain containers verified by this pallet.
+//!
+//! See https://github.com/ethereum/consensus-specs/blob/dev/ssz/simple-serialize.md#merkleization
+
+use sp_io::hashing::sha2_256;
+use sp_std::prelude::*;
+
+use artemis_ethereum::{H256, U256};
+
+use crate::types::{BeaconHeader, ExecutionPayloadHeader, ForkVersion, PublicKey, SyncCommittee};
+
+type Chunk = [u8; 32];
+
+/// Max size of `ExecutionPayloadHeader::extra_data`.
+const MAX_EXTRA_DATA_BYTES: usize = 32;
+
+fn hash_pair(left: &Chunk, right: &Chunk) -> Chunk {
+	let mut data = [0u8; 64];
+	data[..32].copy_from_slice(left);
+	data[32..].copy_from_slice(right);
+	sha2_256(&data)
+}
+
+fn pack_u64(value: u64) -> Chunk {
+	let mut chunk = [0u8; 32];
+	chunk[..8].copy_from_slice(&value.to_le_bytes());
+	chunk
+}
+
+fn pack_u256(value: &U256) -> Chunk {
+	let mut chunk = [0u8; 32];
+	value.to_little_endian(&mut chunk);
+	chunk
+}
+
+fn pack_bytes(bytes: &[u8]) -> Vec<Chunk> {
+	bytes
+		.chunks(32)
+		.map(|part| {
+			let mut chunk = [0u8; 32];
+			chunk[..part.len()].copy_from_slice(part);
+			chunk
+		})
+		.collect()
+}
+
+/// Merkleize `chunks`, padded with zero chunks to the next power of two of `limit`.
+fn merkleize(chunks: &[Chunk], limit: usize) -> Chunk {
+	let depth = limit.max(chunks.len()).max(1).next_power_of_two().trailing_zeros();
+	let mut layer = chunks.to_vec();
+	let mut zero_hash = [0u8; 32];
+	for _ in 0..depth {
+		if layer.len() % 2 == 1 {
+			layer.push(zero_hash);
+		}
+		layer = layer.chunks(2).map(|pair| hash_pair(&pair[0], &pair[1])).collect();
+		zero_hash = hash_pair(&zero_hash, &zero_hash);
+	}
+	layer.first().copied().unwrap_or(zero_hash)
+}
+
+fn mix_in_length(root: &Chunk, length: usize) -> Chunk {
+	hash_pair(root, &pack_u64(length as u64))
+}
+
+fn pubkey_root(pubkey: &PublicKey) -> Chunk {
+	merkleize(&pack_bytes(&pubkey.0), 2)
+}
+
+pub fn beacon_header_root(header: &BeaconHeader) -> H256 {
+	merkleize(&[
+		pack_u64(header.slot),
+		pack_u64(header.proposer_index),
+		header.parent_root.0,
+		header.state_root.0,
+		header.body_root.0,
+	], 5).into()
+}
+
+pub fn sync_committee_root(committee: &SyncCommittee) -> H256 {
+	let pubkeys: Vec<Chunk> = committee.pubkeys.iter().map(pubkey_root).collect();
+	merkleize(&[
+		merkleize(&pubkeys, pubkeys.len()),
+		pubkey_root(&committee.aggregate_pubkey),
+	], 2).into()
+}
+
+pub fn execution_payload_header_root(header: &ExecutionPayloadHeader) -> H256 {
+	let mut fee_recipient = [0u8; 32];
+	fee_recipient[..20].copy_from_slice(header.fee_recipient.as_bytes());
+	let extra_data_root = mix_in_length(
+		&merkleize(&pack_bytes(&header.extra_data), (MAX_EXTRA_DATA_BYTES + 31) / 32),
+		header.extra_data.len(),
+	);
+
+	let mut fields = vec![
+		header.parent_hash.0,
+		fee_recipient,
+		header.state_root.0,
+		header.receipts_root.0,
+		merkleize(&pack_bytes(&header.logs_bloom), 256 / 32),
+		header.prev_randao.0,
+		pack_u64(header.block_number),
+		pack_u64(header.gas_limit),
+		pack_u64(header.gas_used),
+		pack_u64(header.timestamp),
+		extra_data_root,
+		pack_u256(&header.base_fee_per_gas),
+		header.block_hash.0,
+		header.transactions_root.0,
+	];
+	if let Some(withdrawals_root) = header.withdrawals_root {
+		fields.push(withdrawals_root.0);
+	}
+	merkleize(&fields, fields.len()).into()
+}
+
+/// Compute a signature domain (`compute_domain` in the consensus specs).
+pub fn compute_domain(
+	domain_type: [u8; 4],
+	fork_version: ForkVersion,
+	genesis_validators_root: H256,
+) -> H256 {
+	let mut version = [0u8; 32];
+	version[..4].copy_from_slice(&fork_version);
+	let fork_data_root = merkleize(&[version, genesis_validators_root.0], 2);
+
+	let mut domain = [0u8; 32];
+	domain[..4].copy_from_slice(&domain_type);
+	domain[4..].copy_from_slice(&fork_data_root[..28]);
+	domain.into()
+}
+
+/// Compute the root that is signed for `object_root` in `domain`.
+pub fn signing_root(object_root: H256, domain: H256) -> H256 {
+	merkleize(&[object_root.0, domain.0], 2).into()
+}
+
+/// Check that `leaf` is at generalized index `gindex` in the tree with root `root`.
+pub fn verify_merkle_branch(leaf: H256, branch: &[H256], gindex: u64, root: H256) -> bool {
+	if gindex == 0 {
+		return false;
+	}
+	let depth = 63 - gindex.leading_zeros() as usize;
+	if branch.len() != depth {
+		return false;
+	}
+
+	let mut value = leaf.0;
+	for (i, node) in branch.iter().enumerate() {
+		value = if (gindex >> i) & 1 == 1 {
+			hash_pair(&node.0, &value)
+		} else {
+			hash_pair(&value, &node.0)
+		};
+	}
+	value == root.0
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use hex_literal::hex;
+
+	#[test]
+	fn merkleize_pads_to_limit() {
+		let zero = [0u8; 32];
+		let zero_1 = hash_pair(&zero, &zero);
+		let zero_2 = hash_pair(&zero_1, &zero_1);
+
+		assert_eq!(merkleize(&[], 1), zero);
+		assert_eq!(merkleize(&[], 4), zero_2);
+		assert_eq!(merkleize(&[[1u8; 32]], 1), [1u8; 32]);
+		assert_eq!(
+			merkleize(&[[1u8; 32], [2u8; 32], [3u8; 32]], 3),
+			hash_pair(&hash_pair(&[1u8; 32], &[2u8; 32]), &hash_pair(&[3u8; 32], &zero)),
+		);
+	}
+
+	#[test]
+	fn compute_domain_matches_spec() {
+		// DOMAIN_SYNC_COMMITTEE on mainnet at Bellatrix
+		let genesis_validators_root: H256 =
+			hex!("4b363db94e286120d76eb905340fdd4e54bfe9f06bf33ff6cf5ad27f511bfe95").into();
+		let domain = compute_domain([7, 0, 0, 0], [2, 0, 0, 0], genesis_validators_root);
+		assert_eq!(
+			domain,
+			hex!("070000004a26c58b08add8089b75caa540848881a8d4f0af0be83417a85c0f45").into(),
+		);
+		assert_ne!(
+			domain,
+			compute_domain([7, 0, 0, 0], [3, 0, 0, 0], genesis_validators_root),
+		);
+	}
+
+	#[test]
+	fn verify_merkle_branch_checks_index_and_depth() {
+		let leaves: Vec<H256> = (0u8..4).map(|i| H256::repeat_byte(i + 1)).collect();
+		let left = hash_pair(&leaves[0].0, &leaves[1].0);
+		let right = hash_pair(&leaves[2].0, &leaves[3].0);
+		let root: H256 = hash_pair(&left, &right).into();
+
+		// Leaf 2 has generalized index 4 + 2 = 6
+		let branch = vec![leaves[3], left.into()];
+		assert!(verify_merkle_branch(leaves[2], &branch, 6, root));
+		assert!(!verify_merkle_branch(leaves[2], &branch, 7, root));
+		assert!(!verify_merkle_branch(leaves[2], &branch, 12, root));
+		assert!(!verify_merkle_branch(leaves[3], &branch, 6, root));
+		assert!(!verify_merkle_branch(leaves[2], &branch, 0, root));
+	}
+}
@@ -0,0 +1,313 @@
+use artemis_core::{Verifier as VerifierConfig};
+use crate::mock::{
+	beacon_fixture, log_payload, message_with_receipt_proof, new_tester,
+	receipt_root_and_proof, AccountId, Origin, System, Test, Verifier,
+	Event as TestEvent,
+};
+
+use crate::sp_api_hidden_includes_decl_storage::hidden_include::{StorageMap, StorageValue};
+use frame_support::{assert_err, assert_ok};
+use sp_keyring::AccountKeyring as Keyring;
+use crate::{
+	CurrentSyncCommittee, Error, Event, EthereumHeader, ExecutionHeaders, FinalizedBeaconHeaders,
+	Fork, ForkSchedule, LatestFinalizedHeader, Log, NextSyncCommittee, ssz::beacon_header_root,
+	types::{SLOTS_PER_EPOCH, sync_committee_period},
+};
+
+fn verifier_events() -> Vec<Event> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			TestEvent::verifier(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+fn relayer() -> Origin {
+	let ferdie: AccountId = Keyring::Ferdie.into();
+	Origin::signed(ferdie)
+}
+
+#[test]
+fn it_imports_next_sync_committee() {
+	let fixture = beacon_fixture();
+	new_tester(&fixture).execute_with(|| {
+		let update = fixture.sync_committee_update.clone();
+		let finalized_root = beacon_header_root(&update.finalized_header);
+		let next_sync_committee = update.next_sync_committee_update.clone().unwrap().next_sync_committee;
+
+		assert_eq!(NextSyncCommittee::get(), None);
+		assert_ok!(Verifier::import_finality_update(relayer(), update.clone()));
+
+		assert_eq!(NextSyncCommittee::get(), Some(next_sync_committee));
+		assert_eq!(CurrentSyncCommittee::get(), fixture.initial_sync_committee);
+		assert_eq!(LatestFinalizedHeader::get(), (finalized_root, update.finalized_header.slot));
+		assert_eq!(FinalizedBeaconHeaders::get(finalized_root), Some(update.finalized_header.clone()));
+		assert_eq!(
+			verifier_events(),
+			vec![Event::BeaconHeaderImported(finalized_root, update.finalized_header.slot)],
+		);
+	});
+}
+
+#[test]
+fn it_rotates_sync_committees_when_finalizing_next_period() {
+	let fixture = beacon_fixture();
+	new_tester(&fixture).execute_with(|| {
+		let next_sync_committee = fixture.sync_committee_update
+			.next_sync_committee_update.clone().unwrap().next_sync_committee;
+		let update = fixture.finality_update.clone();
+		let finalized_root = beacon_header_root(&update.finalized_header);
+
+		assert_ok!(Verifier::import_finality_update(relayer(), fixture.sync_committee_update.clone()));
+		assert_ok!(Verifier::import_finality_update(relayer(), update.clone()));
+
+		assert_eq!(CurrentSyncCommittee::get(), next_sync_committee);
+		assert_eq!(NextSyncCommittee::get(), Some(fixture.initial_sync_committee.clone()));
+		assert_eq!(LatestFinalizedHeader::get(), (finalized_root, update.finalized_header.slot));
+		assert_eq!(
+			verifier_events()[1..],
+			[
+				Event::SyncCommitteeUpdated(11),
+				Event::BeaconHeaderImported(finalized_root, update.finalized_header.slot),
+			],
+		);
+
+		// Only MaxFinalizedHeadersToKeep headers are kept
+		let initial_root = beacon_header_root(&fixture.initial_finalized_header);
+		assert!(!FinalizedBeaconHeaders::contains_key(initial_root));
+	});
+}
+
+#[test]
+fn it_rejects_update_signed_by_unknown_sync_committee() {
+	let fixture = beacon_fixture();
+	new_tester(&fixture).execute_with(|| {
+		assert_err!(
+			Verifier::import_finality_update(relayer(), fixture.finality_update.clone()),
+			Error::<Test>::InvalidSyncCommitteePeriod,
+		);
+	});
+}
+
+#[test]
+fn it_rejects_stale_update() {
+	let fixture = beacon_fixture();
+	new_tester(&fixture).execute_with(|| {
+		let update = fixture.sync_committee_update.clone();
+		assert_ok!(Verifier::import_finality_update(relayer(), update.clone()));
+		assert_err!(
+			Verifier::import_finality_update(relayer(), update),
+			Error::<Test>::StaleUpdate,
+		);
+	});
+}
+
+#[test]
+fn it_rejects_update_with_insufficient_participation() {
+	let fixture = beacon_fixture();
+	new_tester(&fixture).execute_with(|| {
+		let mut update = fixture.sync_committee_update.clone();
+		// 341 of 512 participants is just below the two thirds supermajority
+		update.sync_aggregate.sync_committee_bits = vec![0; 64];
+		for i in 0..341 {
+			update.sync_aggregate.sync_committee_bits[i / 8] |= 1 << (i % 8);
+		}
+		assert_err!(
+			Verifier::import_finality_update(relayer(), update.clone()),
+			Error::<Test>::InsufficientSyncCommitteeParticipants,
+		);
+
+		update.sync_aggregate.sync_committee_bits.pop();
+		assert_err!(
+			Verifier::import_finality_update(relayer(), update),
+			Error::<Test>::InvalidSyncCommitteeBits,
+		);
+	});
+}
+
+#[test]
+fn it_rejects_update_with_invalid_signature() {
+	let fixture = beacon_fixture();
+	new_tester(&fixture).execute_with(|| {
+		let mut update = fixture.sync_committee_update.clone();
+		update.attested_header.proposer_index += 1;
+		assert_err!(
+			Verifier::import_finality_update(relayer(), update),
+			Error::<Test>::InvalidSignature,
+		);
+
+		// Signature doesn't match the set of participants
+		let mut update = fixture.sync_committee_update.clone();
+		update.sync_aggregate.sync_committee_bits[63] ^= 0x80;
+		assert_err!(
+			Verifier::import_finality_update(relayer(), update),
+			Error::<Test>::InvalidSignature,
+		);
+	});
+}
+
+#[test]
+fn it_verifies_signature_with_fork_version_at_signature_slot() {
+	let fixture = beacon_fixture();
+	new_tester(&fixture).execute_with(|| {
+		// Signed under the second fork, but the third fork is active from
+		// epoch 2600 onwards
+		let mut update = fixture.sync_committee_update.clone();
+		update.signature_slot = 2600 * SLOTS_PER_EPOCH + 1;
+		assert_eq!(sync_committee_period(update.signature_slot), 10);
+		assert_err!(
+			Verifier::import_finality_update(relayer(), update),
+			Error::<Test>::InvalidSignature,
+		);
+
+		// The signature is checked against the slot before the signature slot
+		let mut update = fixture.sync_committee_update.clone();
+		update.signature_slot = 2600 * SLOTS_PER_EPOCH;
+		assert_ok!(Verifier::import_finality_update(relayer(), update));
+
+		// The finality update is signed under the third fork
+		assert_ok!(Verifier::import_finality_update(relayer(), fixture.finality_update.clone()));
+	});
+}
+
+#[test]
+fn fork_schedule_returns_version_of_latest_active_fork() {
+	let schedule = ForkSchedule::mainnet();
+	assert_eq!(schedule.version(0), Some([0, 0, 0, 0]));
+	assert_eq!(schedule.version(74239), Some([0, 0, 0, 0]));
+	assert_eq!(schedule.version(74240), Some([1, 0, 0, 0]));
+	assert_eq!(schedule.version(200000), Some([3, 0, 0, 0]));
+
+	let schedule = ForkSchedule { forks: &[Fork { version: [1, 0, 0, 0], epoch: 10 }] };
+	assert_eq!(schedule.version(9), None);
+}
+
+#[test]
+fn it_rejects_update_with_invalid_proofs() {
+	let fixture = beacon_fixture();
+	new_tester(&fixture).execute_with(|| {
+		let mut update = fixture.sync_committee_update.clone();
+		update.finalized_header.slot += 1;
+		assert_err!(
+			Verifier::import_finality_update(relayer(), update),
+			Error::<Test>::InvalidFinalityProof,
+		);
+
+		let mut update = fixture.sync_committee_update.clone();
+		update.next_sync_committee_update.as_mut().unwrap().next_sync_committee.pubkeys.swap(0, 1);
+		assert_err!(
+			Verifier::import_finality_update(relayer(), update),
+			Error::<Test>::InvalidSyncCommitteeUpdate,
+		);
+
+		// Moving into the next period requires the committee of the period after
+		assert_ok!(Verifier::import_finality_update(relayer(), fixture.sync_committee_update.clone()));
+		let mut update = fixture.finality_update.clone();
+		update.next_sync_committee_update = None;
+		assert_err!(
+			Verifier::import_finality_update(relayer(), update),
+			Error::<Test>::InvalidSyncCommitteeUpdate,
+		);
+	});
+}
+
+#[test]
+fn it_imports_execution_header_and_verifies_messages() {
+	let fixture = beacon_fixture();
+	new_tester(&fixture).execute_with(|| {
+		let update = fixture.execution_header_update.clone();
+		let block_hash = update.execution_header.block_hash;
+		let (receipts_root, receipt_proof) = receipt_root_and_proof();
+		assert_eq!(update.execution_header.receipts_root, receipts_root);
+
+		let message = message_with_receipt_proof(log_payload(), block_hash, receipt_proof);
+		assert_err!(
+			Verifier::verify(&message),
+			Error::<Test>::MissingHeader,
+		);
+
+		assert_ok!(Verifier::import_finality_update(relayer(), fixture.sync_committee_update.clone()));
+		assert_ok!(Verifier::import_finality_update(relayer(), fixture.finality_update.clone()));
+		assert_ok!(Verifier::import_execution_header(relayer(), update.clone()));
+
+		let stored_header = ExecutionHeaders::get(block_hash).unwrap();
+		assert_eq!(stored_header.beacon_block_root, beacon_header_root(&update.header));
+		assert_eq!(stored_header.block_number, update.execution_header.block_number);
+		assert_eq!(
+			verifier_events().last(),
+			Some(&Event::ExecutionHeaderImported(block_hash, update.execution_header.block_number)),
+		);
+
+		let log: Log = rlp::decode(&message.data).unwrap();
+		assert_eq!(Verifier::verify(&message), Ok(log));
+
+		let mut invalid_message = message.clone();
+		invalid_message.proof.tx_index = 4;
+		assert_err!(
+			Verifier::verify(&invalid_message),
+			Error::<Test>::InvalidProof,
+		);
+
+		assert_err!(
+			Verifier::import_execution_header(relayer(), update),
+			Error::<Test>::DuplicateExecutionHeader,
+		);
+	});
+}
+
+#[test]
+fn it_rejects_execution_header_without_finalized_ancestor() {
+	let fixture = beacon_fixture();
+	new_tester(&fixture).execute_with(|| {
+		let update = fixture.execution_header_update.clone();
+		assert_err!(
+			Verifier::import_execution_header(relayer(), update.clone()),
+			Error::<Test>::HeaderNotFinalized,
+		);
+
+		assert_ok!(Verifier::import_finality_update(relayer(), fixture.sync_committee_update.clone()));
+		assert_ok!(Verifier::import_finality_update(relayer(), fixture.finality_update.clone()));
+
+		let mut invalid_update = update.clone();
+		invalid_update.ancestry_proof = None;
+		assert_err!(
+			Verifier::import_execution_header(relayer(), invalid_update),
+			Error::<Test>::HeaderNotFinalized,
+		);
+
+		let mut invalid_update = update.clone();
+		invalid_update.header.slot += 1;
+		assert_err!(
+			Verifier::import_execution_header(relayer(), invalid_update),
+			Error::<Test>::InvalidAncestryProof,
+		);
+
+		let mut invalid_update = update;
+		invalid_update.execution_header.gas_used += 1;
+		assert_err!(
+			Verifier::import_execution_header(relayer(), invalid_update),
+			Error::<Test>::InvalidExecutionHeaderProof,
+		);
+	});
+}
+
+#[test]
+fn it_prunes_execution_headers() {
+	let fixture = beacon_fixture();
+	new_tester(&fixture).execute_with(|| {
+		let headers: Vec<EthereumHeader> = (0..3)
+			.map(|number| EthereumHeader { number, ..Default::default() })
+			.collect();
+		assert_ok!(Verifier::initialize_storage(headers.clone(), 0.into(), 0));
+
+		// Only MaxExecutionHeadersToKeep headers are kept
+		assert!(!ExecutionHeaders::contains_key(headers[0].compute_hash()));
+		for header in headers[1..].iter() {
+			let stored_header = ExecutionHeaders::get(header.compute_hash()).unwrap();
+			assert_eq!(stored_header.block_number, header.number);
+			assert_eq!(stored_header.receipts_root, header.receipts_root);
+		}
+	});
+}
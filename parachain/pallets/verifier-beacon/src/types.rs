@@ -0,0 +1,203 @@
+//! Beacon chain types used by the verifier. Field layouts follow the
+//! consensus specs for the Altair light client protocol and the
+//! Bellatrix/Capella execution payload header.
+
+use codec::{Encode, Decode};
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use artemis_ethereum::{H160, H256, U256};
+
+pub const SYNC_COMMITTEE_SIZE: usize = 512;
+pub const SLOTS_PER_EPOCH: u64 = 32;
+pub const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: u64 = 256;
+pub const SLOTS_PER_HISTORICAL_ROOT: u64 = 8192;
+
+pub const PUBKEY_SIZE: usize = 48;
+pub const SIGNATURE_SIZE: usize = 96;
+
+/// Fork version, e.g. `[2, 0, 0, 0]` for Bellatrix on mainnet.
+pub type ForkVersion = [u8; 4];
+
+/// A beacon chain fork and the epoch it activates at.
+#[derive(Clone, Copy, PartialEq, RuntimeDebug)]
+pub struct Fork {
+	pub version: ForkVersion,
+	pub epoch: u64,
+}
+
+// Fork versions and epochs of mainnet can be found here:
+// https://github.com/ethereum/consensus-specs/blob/v1.3.0/configs/mainnet.yaml
+const MAINNET_FORKS: [Fork; 4] = [
+	Fork { version: [0, 0, 0, 0], epoch: 0 },
+	Fork { version: [1, 0, 0, 0], epoch: 74240 },
+	Fork { version: [2, 0, 0, 0], epoch: 144896 },
+	Fork { version: [3, 0, 0, 0], epoch: 194048 },
+];
+
+/// Describes when beacon chain forks activated. Signature domains depend on
+/// the fork version, so these values are network-specific.
+#[derive(PartialEq, RuntimeDebug)]
+pub struct ForkSchedule {
+	// Forks ordered by ascending epoch
+	pub forks: &'static [Fork],
+}
+
+impl ForkSchedule {
+
+	pub const fn mainnet() -> Self {
+		ForkSchedule { forks: &MAINNET_FORKS }
+	}
+
+	/// Version of the latest fork active at `epoch`, if any.
+	pub fn version(&self, epoch: u64) -> Option<ForkVersion> {
+		self.forks.iter().rev().find(|fork| epoch >= fork.epoch).map(|fork| fork.version)
+	}
+}
+
+/// Compute the sync committee period of `slot`.
+pub fn sync_committee_period(slot: u64) -> u64 {
+	slot / SLOTS_PER_EPOCH / EPOCHS_PER_SYNC_COMMITTEE_PERIOD
+}
+
+macro_rules! impl_fixed_bytes {
+	($name:ident, $size:expr) => {
+		#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+		pub struct $name(pub [u8; $size]);
+
+		impl Default for $name {
+			fn default() -> Self {
+				$name([0; $size])
+			}
+		}
+
+		#[cfg(feature = "std")]
+		impl Serialize for $name {
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				sp_core::bytes::serialize(&self.0, serializer)
+			}
+		}
+
+		#[cfg(feature = "std")]
+		impl<'de> Deserialize<'de> for $name {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				let bytes = sp_core::bytes::deserialize(deserializer)?;
+				if bytes.len() != $size {
+					return Err(serde::de::Error::custom(format!("Expected {} bytes", $size)));
+				}
+				let mut value = [0; $size];
+				value.copy_from_slice(&bytes);
+				Ok($name(value))
+			}
+		}
+	};
+}
+
+impl_fixed_bytes!(PublicKey, PUBKEY_SIZE);
+impl_fixed_bytes!(Signature, SIGNATURE_SIZE);
+
+/// A beacon block header.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BeaconHeader {
+	pub slot: u64,
+	pub proposer_index: u64,
+	pub parent_root: H256,
+	pub state_root: H256,
+	pub body_root: H256,
+}
+
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SyncCommittee {
+	pub pubkeys: Vec<PublicKey>,
+	pub aggregate_pubkey: PublicKey,
+}
+
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SyncAggregate {
+	/// Bitvector of participating sync committee members.
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub sync_committee_bits: Vec<u8>,
+	pub sync_committee_signature: Signature,
+}
+
+/// The next sync committee and its Merkle proof against an attested state root.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct NextSyncCommitteeUpdate {
+	pub next_sync_committee: SyncCommittee,
+	pub next_sync_committee_branch: Vec<H256>,
+}
+
+/// A header attested by the sync committee, the finalized header it commits
+/// to and, optionally, the next sync committee.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct LightClientUpdate {
+	pub attested_header: BeaconHeader,
+	pub next_sync_committee_update: Option<NextSyncCommitteeUpdate>,
+	pub finalized_header: BeaconHeader,
+	pub finality_branch: Vec<H256>,
+	pub sync_aggregate: SyncAggregate,
+	pub signature_slot: u64,
+}
+
+/// Execution payload header. `withdrawals_root` is only present from Capella.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ExecutionPayloadHeader {
+	pub parent_hash: H256,
+	pub fee_recipient: H160,
+	pub state_root: H256,
+	pub receipts_root: H256,
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub logs_bloom: Vec<u8>,
+	pub prev_randao: H256,
+	pub block_number: u64,
+	pub gas_limit: u64,
+	pub gas_used: u64,
+	pub timestamp: u64,
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub extra_data: Vec<u8>,
+	pub base_fee_per_gas: U256,
+	pub block_hash: H256,
+	pub transactions_root: H256,
+	pub withdrawals_root: Option<H256>,
+}
+
+/// Proof that a beacon block is an ancestor of a finalized block, via the
+/// `block_roots` vector in the finalized block's state.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AncestryProof {
+	pub header_branch: Vec<H256>,
+	pub finalized_block_root: H256,
+}
+
+/// An execution payload header and the proofs linking it to a finalized beacon block.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ExecutionHeaderUpdate {
+	/// Beacon block containing the execution payload.
+	pub header: BeaconHeader,
+	/// Required unless `header` itself is a stored finalized header.
+	pub ancestry_proof: Option<AncestryProof>,
+	pub execution_header: ExecutionPayloadHeader,
+	/// Proof of `execution_header` against `header.body_root`.
+	pub execution_branch: Vec<H256>,
+}
+
+/// Execution header as it is stored in the runtime storage.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct ExecutionHeaderState {
+	pub beacon_block_root: H256,
+	pub beacon_slot: u64,
+	pub block_hash: H256,
+	pub block_number: u64,
+	pub receipts_root: H256,
+}
@@ -0,0 +1,83 @@
+//! RPC interface for the Ethereum light client verifier.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+use artemis_core::Proof;
+use artemis_ethereum::Receipt;
+
+pub use pallet_verifier_lightclient_runtime_api::{
+	ReceiptVerificationError, VerifierApi as VerifierRuntimeApi,
+};
+
+#[rpc]
+pub trait VerifierApi<BlockHash> {
+	/// Dry-run a receipt proof against the light client state at block `at`
+	/// (defaults to the best block).
+	///
+	/// `proof` holds the RLP-encoded trie nodes from the receipts root down
+	/// to the receipt at `tx_index` in the Ethereum block `block_hash`.
+	/// Returns the decoded receipt or the reason verification failed.
+	#[rpc(name = "verifier_verifyReceipt")]
+	fn verify_receipt(
+		&self,
+		block_hash: H256,
+		tx_index: u32,
+		proof: Vec<Bytes>,
+		at: Option<BlockHash>,
+	) -> Result<std::result::Result<Receipt, ReceiptVerificationError>>;
+}
+
+/// Error code for failures to call into the runtime.
+const RUNTIME_ERROR: i64 = 1;
+
+/// Implements the `VerifierApi` RPC trait for the light client verifier.
+pub struct Verifier<C, B> {
+	client: Arc<C>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> Verifier<C, B> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block> VerifierApi<<Block as BlockT>::Hash> for Verifier<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: VerifierRuntimeApi<Block>,
+{
+	fn verify_receipt(
+		&self,
+		block_hash: H256,
+		tx_index: u32,
+		proof: Vec<Bytes>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<std::result::Result<Receipt, ReceiptVerificationError>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let proof = Proof {
+			block_hash,
+			tx_index,
+			data: (Vec::new(), proof.into_iter().map(|node| node.to_vec()).collect()),
+		};
+
+		api.verify_receipt(&at, proof).map_err(|err| RpcError {
+			code: ErrorCode::ServerError(RUNTIME_ERROR),
+			message: "Unable to verify receipt.".into(),
+			data: Some(format!("{:?}", err).into()),
+		})
+	}
+}
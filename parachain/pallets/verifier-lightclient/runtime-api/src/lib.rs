@@ -0,0 +1,17 @@
+//! Runtime API definition for the Ethereum light client verifier.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use artemis_core::Proof;
+
+pub use artemis_ethereum::Receipt;
+pub use verifier_lightclient::ReceiptVerificationError;
+
+sp_api::decl_runtime_apis! {
+	pub trait VerifierApi {
+		/// Verify that the receipt in `proof` is included in a finalized
+		/// Ethereum block known to the light client. Returns the decoded
+		/// receipt, including all of its logs, or the reason verification failed.
+		fn verify_receipt(proof: Proof) -> Result<Receipt, ReceiptVerificationError>;
+	}
+}
@@ -16,7 +16,7 @@ mod data;
 /// `data::headers_11963025_to_11963069()[RESERVED_FOR_PRUNING]` is specially
 /// chosen to be a sibling of the previous header. Indices 0 to RESERVED_FOR_PRUNING - 1
 /// contain strictly increasing block numbers.
-const RESERVED_FOR_PRUNING: usize = HEADERS_TO_PRUNE_IN_SINGLE_IMPORT as usize;
+const RESERVED_FOR_PRUNING: usize = MAX_HEADERS_TO_PRUNE_IN_SINGLE_IMPORT as usize;
 
 fn get_best_block() -> (EthereumHeaderId, U256) {
 	BestBlock::get()
@@ -48,14 +48,15 @@ benchmarks! {
 	// * Import will set a new finalized header.
 	// * Import will iterate over the max value of DescendantsUntilFinalized headers
 	//   in the chain.
-	// * Import will prune HEADERS_TO_PRUNE_IN_SINGLE_IMPORT headers.
+	// * Import will prune MAX_HEADERS_TO_PRUNE_IN_SINGLE_IMPORT headers.
 	// * Pruned headers will come from distinct block numbers so that we have the max
 	//   number of HeaderByNumber::take calls.
 	// * The last pruned header will have siblings that we don't prune and have to
 	//   re-insert using HeadersByNumber::insert.
 	import_header {
 		let caller: T::AccountId = whitelisted_caller();
-		let descendants_until_final = T::DescendantsUntilFinalized::get();
+		let descendants_until_final = MAX_DESCENDANTS_UNTIL_FINALIZED;
+		DescendantsUntilFinalized::put(descendants_until_final);
 
 		let next_finalized_idx = RESERVED_FOR_PRUNING + 1;
 		let next_tip_idx = next_finalized_idx + descendants_until_final as usize;
@@ -103,14 +104,15 @@ benchmarks! {
 	// * Import will *not* set a new finalized header because its sibling was imported first.
 	// * Import will iterate over the max value of DescendantsUntilFinalized headers
 	//   in the chain.
-	// * Import will prune HEADERS_TO_PRUNE_IN_SINGLE_IMPORT headers.
+	// * Import will prune MAX_HEADERS_TO_PRUNE_IN_SINGLE_IMPORT headers.
 	// * Pruned headers will come from distinct block numbers so that we have the max
 	//   number of HeaderByNumber::take calls.
 	// * The last pruned header will have siblings that we don't prune and have to
 	//   re-insert using HeadersByNumber::insert.
 	import_header_not_new_finalized_with_max_prune {
 		let caller: T::AccountId = whitelisted_caller();
-		let descendants_until_final = T::DescendantsUntilFinalized::get();
+		let descendants_until_final = MAX_DESCENDANTS_UNTIL_FINALIZED;
+		DescendantsUntilFinalized::put(descendants_until_final);
 
 		let finalized_idx = RESERVED_FOR_PRUNING + 1;
 		let next_tip_idx = finalized_idx + descendants_until_final as usize;
@@ -166,7 +168,8 @@ benchmarks! {
 	// * Import will prune a single old header with no siblings.
 	import_header_new_finalized_with_single_prune {
 		let caller: T::AccountId = whitelisted_caller();
-		let descendants_until_final = T::DescendantsUntilFinalized::get();
+		let descendants_until_final = MAX_DESCENDANTS_UNTIL_FINALIZED;
+		DescendantsUntilFinalized::put(descendants_until_final);
 
 		let finalized_idx = RESERVED_FOR_PRUNING + 1;
 		let next_tip_idx = finalized_idx + descendants_until_final as usize;
@@ -214,7 +217,8 @@ benchmarks! {
 	// * Import will prune a single old header with no siblings.
 	import_header_not_new_finalized_with_single_prune {
 		let caller: T::AccountId = whitelisted_caller();
-		let descendants_until_final = T::DescendantsUntilFinalized::get();
+		let descendants_until_final = MAX_DESCENDANTS_UNTIL_FINALIZED;
+		DescendantsUntilFinalized::put(descendants_until_final);
 
 		let finalized_idx = RESERVED_FOR_PRUNING + 1;
 		let next_tip_idx = finalized_idx + descendants_until_final as usize;
@@ -258,6 +262,135 @@ benchmarks! {
 			oldest_header.number + 1,
 		);
 	}
+
+	// Benchmark `import_headers` extrinsic with a batch of `n` consecutive headers.
+	// Each header in the batch sets a new best block and, once the chain is long
+	// enough, a new finalized header.
+	import_headers {
+		let n in 1 .. MAX_HEADERS_IN_SINGLE_IMPORT;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let descendants_until_final = MAX_DESCENDANTS_UNTIL_FINALIZED;
+		DescendantsUntilFinalized::put(descendants_until_final);
+
+		// Headers from index 11 (block 11963035) onwards have proofs in the test data
+		let first_idx = 11;
+		let headers = data::headers_11963025_to_11963069();
+		let batch: Vec<_> = headers[first_idx..first_idx + n as usize]
+			.iter()
+			.map(|header| {
+				let proof = data::header_proof(header.compute_hash()).unwrap();
+				(header.clone(), proof)
+			})
+			.collect();
+
+		VerifierLightclient::<T>::initialize_storage(
+			headers[0..first_idx].to_vec(),
+			U256::zero(),
+			descendants_until_final,
+		)?;
+
+	}: _(RawOrigin::Signed(caller.clone()), batch)
+	verify {
+		// Check that the best header is the last header in the batch
+		let best = &headers[first_idx + n as usize - 1];
+		assert_eq!(
+			get_best_block().0,
+			EthereumHeaderId {
+				number: best.number,
+				hash: best.compute_hash(),
+			},
+		);
+	}
+
+	// Benchmark `set_descendants_until_finalized` under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	// * The value decreases from MAX_DESCENDANTS_UNTIL_FINALIZED to 0, so
+	//   MAX_DESCENDANTS_UNTIL_FINALIZED headers are finalized at once.
+	set_descendants_until_finalized {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+
+		let descendants_until_final = MAX_DESCENDANTS_UNTIL_FINALIZED;
+		DescendantsUntilFinalized::put(descendants_until_final);
+
+		// Skip the headers reserved for pruning, which aren't a single chain
+		let first_idx = RESERVED_FOR_PRUNING + 1;
+		let tip_idx = first_idx + descendants_until_final as usize;
+		let headers = data::headers_11963025_to_11963069();
+		VerifierLightclient::<T>::initialize_storage(
+			headers[first_idx..=tip_idx].to_vec(),
+			U256::zero(),
+			descendants_until_final,
+		)?;
+
+		let new_descendants: u8 = 0;
+
+	}: _(authorized_origin, new_descendants)
+	verify {
+		assert_eq!(DescendantsUntilFinalized::get(), new_descendants);
+
+		let tip = &headers[tip_idx];
+		assert_eq!(
+			FinalizedBlock::get(),
+			EthereumHeaderId {
+				number: tip.number,
+				hash: tip.compute_hash(),
+			},
+		);
+	}
+
+	// Benchmark `set_finalized_headers_to_keep` under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	set_finalized_headers_to_keep {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+
+		let new_headers_to_keep: u64 = FinalizedHeadersToKeep::get() / 2;
+
+	}: _(authorized_origin, new_headers_to_keep)
+	verify {
+		assert_eq!(FinalizedHeadersToKeep::get(), new_headers_to_keep);
+	}
+
+	// Benchmark `set_headers_to_prune_in_single_import` under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	set_headers_to_prune_in_single_import {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+
+		let new_headers_to_prune: u64 = 1;
+		assert!(HeadersToPruneInSingleImport::get() != new_headers_to_prune);
+
+	}: _(authorized_origin, new_headers_to_prune)
+	verify {
+		assert_eq!(HeadersToPruneInSingleImport::get(), new_headers_to_prune);
+	}
+
+	// Benchmark `append_dag_merkle_roots` under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	// * Every appended root is written to storage
+	append_dag_merkle_roots {
+		let n in 1 .. MAX_DAG_MERKLE_ROOTS_IN_SINGLE_APPEND;
+
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+
+		let first_epoch = NextDagEpoch::get();
+		let roots: Vec<H128> = (0..n).map(|i| H128::repeat_byte(i as u8)).collect();
+
+	}: _(authorized_origin, first_epoch, roots)
+	verify {
+		assert_eq!(NextDagEpoch::get(), first_epoch + n as u64);
+	}
 }
 
 impl_benchmark_test_suite!(
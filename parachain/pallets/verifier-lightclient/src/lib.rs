@@ -7,9 +7,21 @@
 //!
 //! This module relies on the relayer service which submits `import_header`
 //! extrinsics, in order, as new blocks in the Ethereum network are authored.
-//! It stores the most recent `FINALIZED_HEADERS_TO_KEEP` + `DescendantsUntilFinalized`
+//! It stores the most recent `FinalizedHeadersToKeep` + `DescendantsUntilFinalized`
 //! headers and prunes older headers. This means verification will only succeed
-//! for messages from *finalized* blocks no older than `FINALIZED_HEADERS_TO_KEEP`.
+//! for messages from *finalized* blocks no older than `FinalizedHeadersToKeep`.
+//!
+//! The finality depth (`DescendantsUntilFinalized`) and the pruning parameters
+//! (`FinalizedHeadersToKeep`, `HeadersToPruneInSingleImport`) are kept in storage
+//! and can be changed by `UpdateOrigin`. Growing the retained window only takes
+//! effect for headers that have not been pruned yet, and shrinking it prunes the
+//! excess headers gradually over subsequent imports.
+//!
+//! Ethash proofs are checked against the Merkle root of the DAG of the header's
+//! epoch. Roots up to `DAGS_MERKLE_ROOTS` are built into the runtime. Roots of
+//! later epochs are appended to storage by `UpdateOrigin` with
+//! `append_dag_merkle_roots`, so the verifier keeps working across epochs
+//! without a runtime upgrade.
 //!
 //! ## Usage
 //!
@@ -23,17 +35,21 @@ use frame_system::{self as system, ensure_signed};
 use frame_support::{
 	decl_module, decl_storage, decl_event, decl_error, ensure, log,
 	dispatch::{DispatchError, DispatchResult},
-	traits::Get, weights::Weight,
+	traits::{EnsureOrigin, Get}, weights::Weight, transactional,
 };
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
 use codec::{Encode, Decode};
 
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
 use artemis_core::{Message, Verifier, Proof};
 use artemis_ethereum::{
-	HeaderId as EthereumHeaderId, Log, Receipt, H256, U256,
+	HeaderId as EthereumHeaderId, Log, Receipt, H128, H256, U256,
 	difficulty::calc_difficulty,
-	ethashproof::{DoubleNodeWithMerkleProof as EthashProofData, EthashProver},
+	eip1559,
+	ethashproof::{DoubleNodeWithMerkleProof as EthashProofData, EthashProver, EPOCH_LENGTH},
 };
 pub use artemis_ethereum::{
 	Header as EthereumHeader, difficulty::DifficultyConfig as EthereumDifficultyConfig,
@@ -41,16 +57,28 @@ pub use artemis_ethereum::{
 
 mod benchmarking;
 
+pub mod migration;
+
 #[cfg(test)]
 mod mock;
 
 #[cfg(test)]
 mod tests;
 
-/// Max number of finalized headers to keep.
-const FINALIZED_HEADERS_TO_KEEP: u64 = 50_000;
-/// Max number of headers we're pruning in single import call.
-const HEADERS_TO_PRUNE_IN_SINGLE_IMPORT: u64 = 8;
+/// Default number of finalized headers to keep.
+const DEFAULT_FINALIZED_HEADERS_TO_KEEP: u64 = 50_000;
+/// Max number of headers we're pruning in single import call. Import weights
+/// are benchmarked against this value, so `HeadersToPruneInSingleImport`
+/// cannot exceed it.
+const MAX_HEADERS_TO_PRUNE_IN_SINGLE_IMPORT: u64 = 8;
+/// Max number of descendants a header needs before it is finalized. Import
+/// weights are benchmarked against this value, so `DescendantsUntilFinalized`
+/// cannot exceed it.
+const MAX_DESCENDANTS_UNTIL_FINALIZED: u8 = 10;
+/// Max number of headers that can be imported in a single `import_headers` call.
+const MAX_HEADERS_IN_SINGLE_IMPORT: u32 = 32;
+/// Max number of DAG Merkle roots that can be appended in a single call.
+const MAX_DAG_MERKLE_ROOTS_IN_SINGLE_APPEND: u32 = 64;
 
 /// Ethereum block header as it is stored in the runtime storage.
 #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug)]
@@ -67,6 +95,21 @@ pub struct StoredHeader<Submitter> {
 	pub finalized: bool,
 }
 
+/// Storage layout versions of this pallet.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+	/// Stored headers have no base fee.
+	V1,
+	/// Stored headers have an optional EIP-1559 base fee.
+	V2,
+}
+
+impl Default for Releases {
+	fn default() -> Self {
+		Releases::V1
+	}
+}
+
 /// Blocks range that we want to prune.
 #[derive(Clone, Encode, Decode, Default, PartialEq, RuntimeDebug)]
 struct PruningRange {
@@ -78,31 +121,60 @@ struct PruningRange {
 	pub oldest_block_to_keep: u64,
 }
 
+/// Reason why a receipt proof could not be verified.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ReceiptVerificationError {
+	/// Header referenced in the proof doesn't exist, e.g. because it's
+	/// pruned or older than genesis.
+	MissingHeader,
+	/// Header referenced in the proof is not final yet.
+	HeaderNotFinalized,
+	/// Proof nodes are malformed or don't link up to a single root.
+	InvalidProof,
+	/// Proof root doesn't match the receipts root of the header.
+	ReceiptsRootMismatch,
+	/// Proven value is not a valid RLP-encoded receipt.
+	InvalidReceipt,
+}
+
 /// Weight functions needed for this pallet.
 pub trait WeightInfo {
 	fn import_header() -> Weight;
+	fn import_headers(num_headers: u32) -> Weight;
 	fn import_header_not_new_finalized_with_max_prune() -> Weight;
 	fn import_header_new_finalized_with_single_prune() -> Weight;
 	fn import_header_not_new_finalized_with_single_prune() -> Weight;
+	fn set_descendants_until_finalized() -> Weight;
+	fn set_finalized_headers_to_keep() -> Weight;
+	fn set_headers_to_prune_in_single_import() -> Weight;
+	fn append_dag_merkle_roots(num_roots: u32) -> Weight;
 }
 
 impl WeightInfo for () {
 	fn import_header() -> Weight { 0 }
+	fn import_headers(_: u32) -> Weight { 0 }
 	fn import_header_not_new_finalized_with_max_prune() -> Weight { 0 }
 	fn import_header_new_finalized_with_single_prune() -> Weight { 0 }
 	fn import_header_not_new_finalized_with_single_prune() -> Weight { 0 }
+	fn set_descendants_until_finalized() -> Weight { 0 }
+	fn set_finalized_headers_to_keep() -> Weight { 0 }
+	fn set_headers_to_prune_in_single_import() -> Weight { 0 }
+	fn append_dag_merkle_roots(_: u32) -> Weight { 0 }
 }
 
 pub trait Config: system::Config {
 	type Event: From<Event> + Into<<Self as system::Config>::Event>;
-	/// The number of descendants, in the highest difficulty chain, a block
-	/// needs to have in order to be considered final.
-	type DescendantsUntilFinalized: Get<u8>;
 	/// Ethereum network parameters for header difficulty
 	type DifficultyConfig: Get<EthereumDifficultyConfig>;
 	/// Determines whether Ethash PoW is verified for headers
 	/// NOTE: Should only be false for dev
 	type VerifyPoW: Get<bool>;
+	/// Block number on which London (EIP-1559) rules activated
+	type LondonForkBlock: Get<u64>;
+	/// The origin which may update finality and pruning parameters and
+	/// append DAG Merkle roots
+	type UpdateOrigin: EnsureOrigin<Self::Origin>;
 	/// Weight information for extrinsics in this pallet
 	type WeightInfo: WeightInfo;
 }
@@ -119,6 +191,21 @@ decl_storage! {
 		Headers: map hasher(identity) H256 => Option<StoredHeader<T::AccountId>>;
 		/// Map of imported header hashes by number.
 		HeadersByNumber: map hasher(blake2_128_concat) u64 => Option<Vec<H256>>;
+		/// The number of descendants, in the highest difficulty chain, a block
+		/// needs to have in order to be considered final.
+		pub DescendantsUntilFinalized get(fn descendants_until_finalized) config(): u8;
+		/// The number of finalized headers to keep before pruning.
+		pub FinalizedHeadersToKeep get(fn finalized_headers_to_keep) config(): u64 = DEFAULT_FINALIZED_HEADERS_TO_KEEP;
+		/// Max number of headers to prune per imported header.
+		pub HeadersToPruneInSingleImport get(fn headers_to_prune_in_single_import) config(): u64 = MAX_HEADERS_TO_PRUNE_IN_SINGLE_IMPORT;
+		/// Storage layout version, used to run migrations.
+		pub StorageVersion get(fn storage_version): Releases;
+		/// Hash of the last header handled by the stored header migration.
+		HeadersMigrationCursor: Option<H256>;
+		/// DAG Merkle roots by epoch, for epochs after those in `DAGS_MERKLE_ROOTS`.
+		DagMerkleRoots: map hasher(twox_64_concat) u64 => Option<H128>;
+		/// First epoch without a known DAG Merkle root.
+		pub NextDagEpoch get(fn next_dag_epoch): u64 = EthashProver::dag_merkle_roots_end_epoch();
 	}
 
 	add_extra_genesis {
@@ -128,6 +215,13 @@ decl_storage! {
 		build(|config| {
 			let initial_header = &config.initial_header;
 
+			assert!(
+				config.descendants_until_finalized <= MAX_DESCENDANTS_UNTIL_FINALIZED,
+				"DescendantsUntilFinalized exceeds MAX_DESCENDANTS_UNTIL_FINALIZED",
+			);
+
+			StorageVersion::put(Releases::V2);
+
 			Module::<T>::initialize_storage(
 				vec![initial_header.clone()],
 				config.initial_difficulty,
@@ -143,8 +237,19 @@ decl_storage! {
 }
 
 decl_event!(
-	/// This module has no events
 	pub enum Event {
+		/// A header has been imported. [header]
+		HeaderImported(EthereumHeaderId),
+		/// The best chain has switched to another fork.
+		/// [old best header, new best header, common ancestor, depth]
+		///
+		/// `depth` is the number of headers on the old best chain that are
+		/// no longer part of the best chain.
+		Reorganized(EthereumHeaderId, EthereumHeaderId, EthereumHeaderId, u64),
+		/// A header has been finalized. [header]
+		HeaderFinalized(EthereumHeaderId),
+		/// DAG Merkle roots have been appended. [first epoch, number of roots]
+		DagMerkleRootsAppended(u64, u32),
 	}
 );
 
@@ -167,6 +272,16 @@ decl_error! {
 		InvalidHeader,
 		/// Proof could not be applied / verified.
 		InvalidProof,
+		/// Batch of headers is empty or exceeds the maximum batch size.
+		InvalidBatchSize,
+		/// Parameter value is outside of its allowed range.
+		InvalidParameter,
+		/// DAG Merkle roots don't start at the first epoch without a known root.
+		UnexpectedDagEpoch,
+		/// Stored headers are still being migrated to the current storage layout.
+		MigrationInProgress,
+		/// DAG Merkle root of the header's epoch has not been appended yet.
+		MissingDagMerkleRoot,
 		/// This should never be returned - indicates a bug
 		Unknown,
 	}
@@ -178,14 +293,24 @@ decl_module! {
 
 		fn deposit_event() = default;
 
+		// Migrate stored headers to the current layout, a few per block.
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			if StorageVersion::get() == Releases::V1 {
+				migration::migrate_stored_headers::<T>()
+			} else {
+				T::DbWeight::get().reads(1)
+			}
+		}
+
 		/// Import a single Ethereum PoW header.
 		///
 		/// Note that this extrinsic has a very high weight. The weight is affected by the
-		/// value of `DescendantsUntilFinalized`. Regenerate weights if it changes.
+		/// value of `DescendantsUntilFinalized`, so it is benchmarked at
+		/// `MAX_DESCENDANTS_UNTIL_FINALIZED`.
 		///
 		/// The largest contributors to the worst case weight, in decreasing order, are:
 		/// - Pruning: max 2 writes per pruned header + 2 writes to finalize pruning state.
-		///   Up to `HEADERS_TO_PRUNE_IN_SINGLE_IMPORT` can be pruned in one call.
+		///   Up to `HeadersToPruneInSingleImport` can be pruned in one call.
 		/// - Ethash validation: this cost is pure CPU. EthashProver checks a merkle proof
 		///   for each DAG node selected in the "hashimoto"-loop.
 		/// - Iterating over ancestors: min `DescendantsUntilFinalized` reads to find the
@@ -232,12 +357,145 @@ decl_module! {
 
 			Ok(())
 		}
+
+		/// Import an ordered batch of Ethereum PoW headers, each with its Ethash proof.
+		///
+		/// Headers are validated and stored in order, so a header's parent may be an
+		/// earlier header in the same batch. Finalization and pruning happen once, after
+		/// the last header is stored. The batch is imported atomically: if any header
+		/// fails validation, none of the headers are imported.
+		///
+		/// Up to `HeadersToPruneInSingleImport` headers are pruned per imported
+		/// header, so pruning keeps pace with imports made through `import_header`.
+		#[weight = T::WeightInfo::import_headers(headers.len() as u32)]
+		#[transactional]
+		pub fn import_headers(origin, headers: Vec<(EthereumHeader, Vec<EthashProofData>)>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let num_headers = headers.len() as u32;
+			ensure!(
+				num_headers > 0 && num_headers <= MAX_HEADERS_IN_SINGLE_IMPORT,
+				Error::<T>::InvalidBatchSize,
+			);
+
+			log::trace!(
+				target: "import_headers",
+				"Received batch of {} headers. Starting validation and import",
+				num_headers,
+			);
+
+			let mut best_block_id = None;
+			for (header, proof) in headers.iter() {
+				if let Err(err) = Self::validate_header_to_import(header, proof) {
+					log::trace!(
+						target: "import_headers",
+						"Validation for header {} returned error. Skipping import of batch",
+						header.number,
+					);
+					return Err(err);
+				}
+
+				if let Some(new_best_block_id) = Self::insert_validated_header(&sender, header)? {
+					best_block_id = Some(new_best_block_id);
+				}
+			}
+
+			if let Some(best_block_id) = best_block_id {
+				Self::finalize_and_prune(
+					&best_block_id,
+					HeadersToPruneInSingleImport::get().saturating_mul(num_headers.into()),
+				)?;
+			}
+
+			log::trace!(
+				target: "import_headers",
+				"Import of {} headers succeeded!",
+				num_headers,
+			);
+
+			Ok(())
+		}
+
+		/// Set the number of descendants a header needs in the highest difficulty
+		/// chain before it is considered final. Must be at most
+		/// `MAX_DESCENDANTS_UNTIL_FINALIZED`.
+		///
+		/// Headers that are already final stay final when this increases. When it
+		/// decreases, the best chain is finalized up to the new depth immediately.
+		#[weight = T::WeightInfo::set_descendants_until_finalized()]
+		#[transactional]
+		pub fn set_descendants_until_finalized(origin, descendants: u8) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				descendants <= MAX_DESCENDANTS_UNTIL_FINALIZED,
+				Error::<T>::InvalidParameter,
+			);
+			Self::ensure_migrated()?;
+			DescendantsUntilFinalized::set(descendants);
+			Self::finalize(&BestBlock::get().0)?;
+			Ok(())
+		}
+
+		/// Set the number of finalized headers to keep before pruning.
+		///
+		/// Pruning cannot be reverted, so increasing this value only retains headers
+		/// that haven't been pruned yet. Decreasing it prunes the excess headers over
+		/// subsequent imports, at most `HeadersToPruneInSingleImport` per header.
+		#[weight = T::WeightInfo::set_finalized_headers_to_keep()]
+		pub fn set_finalized_headers_to_keep(origin, headers_to_keep: u64) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(headers_to_keep > 0, Error::<T>::InvalidParameter);
+			FinalizedHeadersToKeep::set(headers_to_keep);
+			Ok(())
+		}
+
+		/// Set the max number of headers to prune per imported header. Must be between
+		/// 1 and `MAX_HEADERS_TO_PRUNE_IN_SINGLE_IMPORT`.
+		#[weight = T::WeightInfo::set_headers_to_prune_in_single_import()]
+		pub fn set_headers_to_prune_in_single_import(origin, headers_to_prune: u64) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				headers_to_prune > 0 && headers_to_prune <= MAX_HEADERS_TO_PRUNE_IN_SINGLE_IMPORT,
+				Error::<T>::InvalidParameter,
+			);
+			HeadersToPruneInSingleImport::set(headers_to_prune);
+			Ok(())
+		}
+
+		/// Append the DAG Merkle roots of consecutive epochs, starting at `first_epoch`.
+		///
+		/// `first_epoch` must be the first epoch without a known root, i.e. `NextDagEpoch`,
+		/// so that roots are never overwritten and a repeated call fails instead of
+		/// appending the same roots twice. At most `MAX_DAG_MERKLE_ROOTS_IN_SINGLE_APPEND`
+		/// roots can be appended per call.
+		#[weight = T::WeightInfo::append_dag_merkle_roots(roots.len() as u32)]
+		pub fn append_dag_merkle_roots(origin, first_epoch: u64, roots: Vec<H128>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let num_roots = roots.len() as u32;
+			ensure!(
+				num_roots > 0 && num_roots <= MAX_DAG_MERKLE_ROOTS_IN_SINGLE_APPEND,
+				Error::<T>::InvalidBatchSize,
+			);
+			ensure!(first_epoch == NextDagEpoch::get(), Error::<T>::UnexpectedDagEpoch);
+
+			for (epoch, root) in (first_epoch..).zip(roots) {
+				DagMerkleRoots::insert(epoch, root);
+			}
+			NextDagEpoch::put(first_epoch + num_roots as u64);
+
+			Self::deposit_event(Event::DagMerkleRootsAppended(first_epoch, num_roots));
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Module<T> {
 	// Validate an Ethereum header for import
 	fn validate_header_to_import(header: &EthereumHeader, proof: &[EthashProofData]) -> DispatchResult {
+		Self::ensure_migrated()?;
+
 		let hash = header.compute_hash();
 		ensure!(
 			!Headers::<T>::contains_key(hash),
@@ -278,15 +536,31 @@ impl<T: Config> Module<T> {
 		// See YellowPaper formula (50) in section 4.3.4
 		ensure!(
 			header.gas_used <= header.gas_limit
-			&& header.gas_limit < parent.gas_limit * 1025 / 1024
-			&& header.gas_limit > parent.gas_limit * 1023 / 1024
-			&& header.gas_limit >= 5000.into()
 			&& header.timestamp > parent.timestamp
 			&& header.number == parent.number + 1
 			&& header.extra_data.len() <= 32,
 			Error::<T>::InvalidHeader,
 		);
 
+		let london_fork_block = T::LondonForkBlock::get();
+		if header.number >= london_fork_block {
+			// See EIP-1559 gas limit elasticity and base fee rules
+			ensure!(
+				eip1559::verify_gas_limit(london_fork_block, &parent, header)
+				&& header.base_fee_per_gas.is_some()
+				&& header.base_fee_per_gas == eip1559::calc_base_fee(london_fork_block, &parent),
+				Error::<T>::InvalidHeader,
+			);
+		} else {
+			ensure!(
+				header.gas_limit < parent.gas_limit * 1025 / 1024
+				&& header.gas_limit > parent.gas_limit * 1023 / 1024
+				&& header.gas_limit >= 5000.into()
+				&& header.base_fee_per_gas.is_none(),
+				Error::<T>::InvalidHeader,
+			);
+		}
+
 		let difficulty_config = T::DifficultyConfig::get();
 		let header_difficulty = calc_difficulty(&difficulty_config, header.timestamp, &parent)
 			.map_err(|_| Error::<T>::InvalidHeader)?;
@@ -297,11 +571,14 @@ impl<T: Config> Module<T> {
 
 		let header_mix_hash = header.mix_hash().ok_or(Error::<T>::InvalidHeader)?;
 		let header_nonce = header.nonce().ok_or(Error::<T>::InvalidHeader)?;
-		let (mix_hash, result) = EthashProver::new().hashimoto_merkle(
+		let dag_merkle_root = Self::dag_merkle_root(header.number / EPOCH_LENGTH)
+			.ok_or(Error::<T>::MissingDagMerkleRoot)?;
+		let (mix_hash, result) = EthashProver::new().hashimoto_merkle_with_dag_root(
 			header.compute_partial_hash(),
 			header_nonce,
 			header.number,
 			proof,
+			dag_merkle_root,
 		).map_err(|_| Error::<T>::InvalidHeader)?;
 		ensure!(
 			mix_hash == header_mix_hash
@@ -312,8 +589,26 @@ impl<T: Config> Module<T> {
 		Ok(())
 	}
 
+	/// Merkle root of the DAG for `epoch`, either built into the runtime or
+	/// appended with `append_dag_merkle_roots`.
+	pub fn dag_merkle_root(epoch: u64) -> Option<H128> {
+		EthashProver::dag_merkle_root(epoch).or_else(|| DagMerkleRoots::get(epoch))
+	}
+
 	// Import a new, validated Ethereum header
 	fn import_validated_header(sender: &T::AccountId, header: &EthereumHeader) -> DispatchResult {
+		if let Some(best_block_id) = Self::insert_validated_header(sender, header)? {
+			Self::finalize_and_prune(&best_block_id, HeadersToPruneInSingleImport::get())?;
+		}
+		Ok(())
+	}
+
+	// Store a new, validated Ethereum header and track it as the best block
+	// if it has the highest total difficulty. Returns the new best block, if any.
+	fn insert_validated_header(
+		sender: &T::AccountId,
+		header: &EthereumHeader,
+	) -> Result<Option<EthereumHeaderId>, DispatchError> {
 		let hash = header.compute_hash();
 		let stored_parent_header = Headers::<T>::get(header.parent_hash)
 			.ok_or(Error::<T>::MissingParentHeader)?;
@@ -341,45 +636,102 @@ impl<T: Config> Module<T> {
 			HeadersByNumber::insert(header.number, vec![hash]);
 		}
 
+		let header_id = EthereumHeaderId {
+			number: header.number,
+			hash,
+		};
+		Self::deposit_event(Event::HeaderImported(header_id));
+
 		// Maybe track new highest difficulty chain
-		let (_, highest_difficulty) = BestBlock::get();
+		let (prev_best_block_id, highest_difficulty) = BestBlock::get();
 		if total_difficulty > highest_difficulty || (!T::VerifyPoW::get() && total_difficulty == U256::zero()) {
-			let best_block_id = EthereumHeaderId {
-				number: header.number,
-				hash,
-			};
+			let best_block_id = header_id;
 			BestBlock::put((best_block_id, total_difficulty));
 
-			// Finalize blocks if possible
-			let finalized_block_id = FinalizedBlock::get();
-			let new_finalized_block_id = Self::get_best_finalized_header(
-				&best_block_id,
-				&finalized_block_id,
-			)?;
-			if new_finalized_block_id != finalized_block_id {
-				FinalizedBlock::put(new_finalized_block_id);
-				Headers::<T>::mutate(new_finalized_block_id.hash, |option| -> DispatchResult {
+			// The new best block doesn't extend the previous best chain,
+			// so the previous best block may now be on a side fork
+			if header.parent_hash != prev_best_block_id.hash {
+				let common_ancestor = Self::find_common_ancestor(&prev_best_block_id, &best_block_id)?;
+				if common_ancestor != prev_best_block_id {
+					Self::deposit_event(Event::Reorganized(
+						prev_best_block_id,
+						best_block_id,
+						common_ancestor,
+						prev_best_block_id.number.saturating_sub(common_ancestor.number),
+					));
+				}
+			}
+
+			return Ok(Some(best_block_id));
+		}
+
+		Ok(None)
+	}
+
+	// Finalize the newest block that has enough descendants in the chain
+	// ending at `best_block_id`, then prune up to `max_headers_to_prune`
+	// headers that are older than the retained window.
+	fn finalize_and_prune(best_block_id: &EthereumHeaderId, max_headers_to_prune: u64) -> DispatchResult {
+		// Finalize blocks if possible
+		let new_finalized_block_id = Self::finalize(best_block_id)?;
+
+		// Clean up old headers
+		let pruning_range = BlocksToPrune::get();
+		let new_pruning_range = Self::prune_header_range(
+			&pruning_range,
+			max_headers_to_prune,
+			new_finalized_block_id.number.saturating_sub(FinalizedHeadersToKeep::get()),
+		);
+		if new_pruning_range != pruning_range {
+			BlocksToPrune::put(new_pruning_range);
+		}
+
+		Ok(())
+	}
+
+	// Stored headers can only be read once they're in the current layout.
+	fn ensure_migrated() -> DispatchResult {
+		ensure!(
+			StorageVersion::get() == Releases::V2,
+			Error::<T>::MigrationInProgress,
+		);
+		Ok(())
+	}
+
+	// Finalize the latest block that can be finalized based on the given
+	// highest difficulty chain, along with every header between it and the
+	// previously finalized block. Returns the (possibly unchanged) finalized block.
+	fn finalize(best_block_id: &EthereumHeaderId) -> Result<EthereumHeaderId, DispatchError> {
+		let finalized_block_id = FinalizedBlock::get();
+		let new_finalized_block_id = Self::get_best_finalized_header(
+			best_block_id,
+			&finalized_block_id,
+		)?;
+		if new_finalized_block_id == finalized_block_id {
+			return Ok(finalized_block_id);
+		}
+
+		FinalizedBlock::put(new_finalized_block_id);
+		let mut next_hash = new_finalized_block_id.hash;
+		loop {
+			let (number, parent_hash) = Headers::<T>::try_mutate(
+				next_hash,
+				|option| -> Result<(u64, H256), DispatchError> {
 					if let Some(header) = option {
 						header.finalized = true;
-						return Ok(());
+						return Ok((header.header.number, header.header.parent_hash));
 					}
 					Err(Error::<T>::Unknown.into())
-				})?;
-			}
-
-			// Clean up old headers
-			let pruning_range = BlocksToPrune::get();
-			let new_pruning_range = Self::prune_header_range(
-				&pruning_range,
-				HEADERS_TO_PRUNE_IN_SINGLE_IMPORT,
-				new_finalized_block_id.number.saturating_sub(FINALIZED_HEADERS_TO_KEEP),
-			);
-			if new_pruning_range != pruning_range {
-				BlocksToPrune::put(new_pruning_range);
+				},
+			)?;
+			if number <= finalized_block_id.number + 1 {
+				break;
 			}
+			next_hash = parent_hash;
 		}
+		Self::deposit_event(Event::HeaderFinalized(new_finalized_block_id));
 
-		Ok(())
+		Ok(new_finalized_block_id)
 	}
 
 	// Return the latest block that can be finalized based on the given
@@ -388,7 +740,7 @@ impl<T: Config> Module<T> {
 		best_block_id: &EthereumHeaderId,
 		finalized_block_id: &EthereumHeaderId,
 	) -> Result<EthereumHeaderId, DispatchError> {
-		let required_descendants = T::DescendantsUntilFinalized::get() as usize;
+		let required_descendants = DescendantsUntilFinalized::get() as usize;
 		let maybe_newly_finalized_ancestor = ancestry::<T>(best_block_id.hash)
 			.enumerate()
 			.find_map(|(i, pair)| if i < required_descendants { None } else { Some(pair) });
@@ -403,6 +755,11 @@ impl<T: Config> Module<T> {
 						number: header.number,
 					});
 				}
+				// Finality can't be reverted, so if DescendantsUntilFinalized has
+				// grown, keep the current finalized block until the chain catches up.
+				if header.number < finalized_block_id.number {
+					return Ok(finalized_block_id.clone());
+				}
 				if hash != finalized_block_id.hash {
 					return Err(Error::<T>::Unknown.into());
 				}
@@ -412,6 +769,37 @@ impl<T: Config> Module<T> {
 		}
 	}
 
+	// Return the most recent header that is an ancestor of (or equal to) both
+	// given headers. Both headers must descend from the latest finalized block,
+	// so iteration is bounded by the number of unfinalized headers. Fails with
+	// `MissingParentHeader` if an ancestor isn't stored before they meet.
+	fn find_common_ancestor(
+		left_id: &EthereumHeaderId,
+		right_id: &EthereumHeaderId,
+	) -> Result<EthereumHeaderId, DispatchError> {
+		let mut left_ancestry = ancestry::<T>(left_id.hash).map(|(hash, header)| (hash, header.number));
+		let mut right_ancestry = ancestry::<T>(right_id.hash).map(|(hash, header)| (hash, header.number));
+		let mut left = left_ancestry.next();
+		let mut right = right_ancestry.next();
+
+		while let (Some((left_hash, left_number)), Some((right_hash, right_number))) = (left, right) {
+			if left_hash == right_hash {
+				return Ok(EthereumHeaderId {
+					number: left_number,
+					hash: left_hash,
+				});
+			}
+			if left_number >= right_number {
+				left = left_ancestry.next();
+			}
+			if right_number >= left_number {
+				right = right_ancestry.next();
+			}
+		}
+
+		Err(Error::<T>::MissingParentHeader.into())
+	}
+
 	// Remove old headers, from oldest to newest, in the provided range
 	// (adjusted to `prune_end` if newer). Only up to `max_headers_to_prune`
 	// will be removed.
@@ -464,15 +852,32 @@ impl<T: Config> Module<T> {
 	// in the block given by proof.block_hash. Inclusion is only
 	// recognized if the block has been finalized.
 	fn verify_receipt_inclusion(proof: &Proof) -> Result<Receipt, DispatchError> {
+		Self::verify_receipt(proof).map_err(|err| match err {
+			ReceiptVerificationError::MissingHeader => Error::<T>::MissingHeader,
+			ReceiptVerificationError::HeaderNotFinalized => Error::<T>::HeaderNotFinalized,
+			_ => Error::<T>::InvalidProof,
+		}.into())
+	}
+
+	/// Verify that the receipt in `proof` is included in the finalized block
+	/// given by `proof.block_hash`, returning the decoded receipt or the
+	/// reason verification failed. Used by the `VerifierApi` runtime API
+	/// to dry-run proofs.
+	pub fn verify_receipt(proof: &Proof) -> Result<Receipt, ReceiptVerificationError> {
 		let stored_header = Headers::<T>::get(proof.block_hash)
-			.ok_or(Error::<T>::MissingHeader)?;
+			.ok_or(ReceiptVerificationError::MissingHeader)?;
 
-		ensure!(stored_header.finalized, Error::<T>::HeaderNotFinalized);
+		if !stored_header.finalized {
+			return Err(ReceiptVerificationError::HeaderNotFinalized);
+		}
 
-		let receipt = stored_header.header.check_receipt_proof(&proof.data.1)
-			.ok_or(Error::<T>::InvalidProof)?;
+		let (root, data) = stored_header.header.apply_merkle_proof(&rlp::encode(&proof.tx_index), &proof.data.1)
+			.ok_or(ReceiptVerificationError::InvalidProof)?;
+		if root != stored_header.header.receipts_root {
+			return Err(ReceiptVerificationError::ReceiptsRootMismatch);
+		}
 
-		Ok(receipt)
+		rlp::decode(&data).map_err(|_| ReceiptVerificationError::InvalidReceipt)
 	}
 }
 
@@ -491,6 +896,8 @@ impl<T: Config> Verifier for Module<T> {
 	/// Verify a message by verifying the existence of the corresponding
 	/// Ethereum log in a block. Returns the log if successful.
 	fn verify(message: &Message) -> Result<Log, DispatchError> {
+		Self::ensure_migrated()?;
+
 		let receipt = Self::verify_receipt_inclusion(&message.proof)?;
 
 		let log: Log = rlp::decode(&message.data)
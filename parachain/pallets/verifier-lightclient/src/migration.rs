@@ -0,0 +1,148 @@
+//! Storage migrations for the verifier-lightclient pallet.
+
+use codec::Decode;
+use frame_support::{
+	log,
+	storage::{unhashed, StoragePrefixedMap, StorageValue},
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+use artemis_ethereum::{Address, Bloom, H256, U256};
+
+use crate::{
+	Config, DescendantsUntilFinalized, EthereumHeader, Headers, HeadersMigrationCursor,
+	Releases, StorageVersion, StoredHeader, MAX_DESCENDANTS_UNTIL_FINALIZED,
+};
+
+/// Initializes the storage-backed finality depth on chains that used the
+/// `DescendantsUntilFinalized` config constant before it was moved to storage.
+///
+/// `D` should be the value of the old constant, capped at
+/// `MAX_DESCENDANTS_UNTIL_FINALIZED`. The migration only writes when
+/// no value is stored yet, so it is safe to keep it in the runtime after it has run.
+/// `FinalizedHeadersToKeep` and `HeadersToPruneInSingleImport` fall back to the
+/// previous hardcoded values when unset and need no migration.
+pub struct InitializeDescendantsUntilFinalized<T, D>(PhantomData<(T, D)>);
+
+impl<T: Config, D: Get<u8>> OnRuntimeUpgrade for InitializeDescendantsUntilFinalized<T, D> {
+	fn on_runtime_upgrade() -> Weight {
+		if DescendantsUntilFinalized::exists() {
+			return T::DbWeight::get().reads(1);
+		}
+
+		DescendantsUntilFinalized::put(D::get().min(MAX_DESCENDANTS_UNTIL_FINALIZED));
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
+
+/// Ethereum header as it was stored before `base_fee_per_gas` was added.
+#[derive(Decode)]
+struct HeaderV1 {
+	parent_hash: H256,
+	timestamp: u64,
+	number: u64,
+	author: Address,
+	transactions_root: H256,
+	ommers_hash: H256,
+	extra_data: Vec<u8>,
+	state_root: H256,
+	receipts_root: H256,
+	logs_bloom: Bloom,
+	gas_used: U256,
+	gas_limit: U256,
+	difficulty: U256,
+	seal: Vec<Vec<u8>>,
+}
+
+#[derive(Decode)]
+struct StoredHeaderV1<Submitter> {
+	submitter: Option<Submitter>,
+	header: HeaderV1,
+	total_difficulty: U256,
+	finalized: bool,
+}
+
+impl<Submitter> From<StoredHeaderV1<Submitter>> for StoredHeader<Submitter> {
+	fn from(old: StoredHeaderV1<Submitter>) -> Self {
+		let header = old.header;
+		StoredHeader {
+			submitter: old.submitter,
+			header: EthereumHeader {
+				parent_hash: header.parent_hash,
+				timestamp: header.timestamp,
+				number: header.number,
+				author: header.author,
+				transactions_root: header.transactions_root,
+				ommers_hash: header.ommers_hash,
+				extra_data: header.extra_data,
+				state_root: header.state_root,
+				receipts_root: header.receipts_root,
+				logs_bloom: header.logs_bloom,
+				gas_used: header.gas_used,
+				gas_limit: header.gas_limit,
+				difficulty: header.difficulty,
+				seal: header.seal,
+				base_fee_per_gas: None,
+			},
+			total_difficulty: old.total_difficulty,
+			finalized: old.finalized,
+		}
+	}
+}
+
+/// Max number of stored headers re-encoded per block by [`migrate_stored_headers`].
+pub const MAX_HEADERS_TO_MIGRATE_PER_BLOCK: u32 = 64;
+
+/// Re-encodes stored headers with the `base_fee_per_gas` field added for
+/// London support. All stored headers predate London, so the base fee is
+/// left empty.
+///
+/// Called from `on_initialize` while `StorageVersion` is `V1`. Each call
+/// re-encodes up to `MAX_HEADERS_TO_MIGRATE_PER_BLOCK` headers, continuing
+/// after the last header handled by the previous call, so the migration is
+/// spread over as many blocks as needed. `StorageVersion` is set to `V2` once
+/// every header has been handled. Headers already in the new layout are skipped.
+pub fn migrate_stored_headers<T: Config>() -> Weight {
+	let prefix = Headers::<T>::final_prefix();
+	let mut previous_key = match HeadersMigrationCursor::get() {
+		Some(hash) => [&prefix[..], hash.as_bytes()].concat(),
+		None => prefix.to_vec(),
+	};
+
+	let mut reads: Weight = 2;
+	let mut writes: Weight = 1;
+	for _ in 0..MAX_HEADERS_TO_MIGRATE_PER_BLOCK {
+		let key = match sp_io::storage::next_key(&previous_key) {
+			Some(key) if key.starts_with(&prefix) => key,
+			_ => {
+				HeadersMigrationCursor::kill();
+				StorageVersion::put(Releases::V2);
+				return T::DbWeight::get().reads_writes(reads, writes + 1);
+			}
+		};
+
+		reads += 1;
+		if let Some(value) = unhashed::get_raw(&key) {
+			if StoredHeader::<T::AccountId>::decode(&mut &value[..]).is_err() {
+				match StoredHeaderV1::<T::AccountId>::decode(&mut &value[..]) {
+					Ok(old) => {
+						unhashed::put(&key, &StoredHeader::<T::AccountId>::from(old));
+						writes += 1;
+					}
+					Err(_) => log::error!(
+						target: "verifier-lightclient",
+						"Failed to decode stored header at {:?}",
+						key,
+					),
+				}
+			}
+		}
+		previous_key = key;
+	}
+
+	// Headers are stored under the identity hash of their block hash
+	HeadersMigrationCursor::put(H256::from_slice(&previous_key[prefix.len()..]));
+	T::DbWeight::get().reads_writes(reads, writes)
+}
@@ -22,11 +22,7 @@ parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 }
 
-pub const MAINNET_DIFFICULTY_CONFIG: EthereumDifficultyConfig = EthereumDifficultyConfig {
-	byzantium_fork_block: 4370000,
-	constantinople_fork_block: 7280000,
-	muir_glacier_fork_block: 9200000,
-};
+pub const MAINNET_DIFFICULTY_CONFIG: EthereumDifficultyConfig = EthereumDifficultyConfig::mainnet();
 
 pub mod mock_verifier {
 
@@ -73,16 +69,17 @@ pub mod mock_verifier {
 	}
 
 	parameter_types! {
-		pub const DescendantsUntilFinalized: u8 = 2;
 		pub const DifficultyConfig: EthereumDifficultyConfig = MAINNET_DIFFICULTY_CONFIG;
+		pub const LondonForkBlock: u64 = 12_965_000;
 		pub const VerifyPoW: bool = false;
 	}
 
 	impl verifier::Config for Test {
 		type Event = Event;
-		type DescendantsUntilFinalized = DescendantsUntilFinalized;
 		type DifficultyConfig = DifficultyConfig;
 		type VerifyPoW = VerifyPoW;
+		type LondonForkBlock = LondonForkBlock;
+		type UpdateOrigin = frame_system::EnsureRoot<Self::AccountId>;
 		type WeightInfo = ();
 	}
 }
@@ -132,16 +129,17 @@ pub mod mock_verifier_with_pow {
 	}
 
 	parameter_types! {
-		pub const DescendantsUntilFinalized: u8 = 2;
 		pub const DifficultyConfig: EthereumDifficultyConfig = MAINNET_DIFFICULTY_CONFIG;
+		pub const LondonForkBlock: u64 = 12_965_000;
 		pub const VerifyPoW: bool = true;
 	}
 
 	impl verifier::Config for Test {
 		type Event = Event;
-		type DescendantsUntilFinalized = DescendantsUntilFinalized;
 		type DifficultyConfig = DifficultyConfig;
 		type VerifyPoW = VerifyPoW;
+		type LondonForkBlock = LondonForkBlock;
+		type UpdateOrigin = frame_system::EnsureRoot<Self::AccountId>;
 		type WeightInfo = ();
 	}
 }
@@ -188,7 +186,7 @@ pub fn message_with_receipt_proof(payload: Vec<u8>, block_hash: H256, proof_data
 		data: payload,
 		proof: Proof {
 			block_hash,
-			tx_index: 0,
+			tx_index: 5,
 			data: proof_data,
 		},
 	}
@@ -222,6 +220,8 @@ pub fn new_tester<T: crate::Config>() -> sp_io::TestExternalities {
 	new_tester_with_config::<T>(crate::GenesisConfig {
 		initial_header: genesis_ethereum_header(),
 		initial_difficulty: 0.into(),
+		descendants_until_finalized: 2,
+		..Default::default()
 	})
 }
 
@@ -230,7 +230,7 @@ pub fn new_tester_with_config<T: crate::Config>(config: crate::GenesisConfig) ->
 
 	config.assimilate_storage::<T>(&mut storage).unwrap();
 
-	let ext: sp_io::TestExternalities = storage.into();
-	//ext.execute_with(|| <frame_system::Module<T>>::set_block_number();
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| <frame_system::Pallet<T>>::set_block_number(1u32.into()));
 	ext
 }
@@ -1,7 +1,7 @@
 use artemis_core::{Verifier as VerifierConfig};
 use crate::mock::{
 	child_of_genesis_ethereum_header, child_of_header,
-	genesis_ethereum_block_hash, log_payload,
+	genesis_ethereum_block_hash, genesis_ethereum_header, log_payload,
 	message_with_receipt_proof, receipt_root_and_proof,
 	AccountId, new_tester, new_tester_with_config,
 	ethereum_header_from_file, ethereum_header_proof_from_file
@@ -13,17 +13,48 @@ use crate::mock::mock_verifier::{
 	Verifier,
 	Test,
 	Origin,
+	System,
+	Event as TestEvent,
 };
 
-use crate::sp_api_hidden_includes_decl_storage::hidden_include::{StorageMap, StorageValue};
-use frame_support::{assert_err, assert_ok};
+use crate::sp_api_hidden_includes_decl_storage::hidden_include::{
+	IterableStorageMap, StorageMap, StorageValue,
+};
+use codec::Encode;
+use hex_literal::hex;
+use frame_support::{
+	assert_err, assert_ok, parameter_types, storage::unhashed,
+	traits::{OnInitialize, OnRuntimeUpgrade},
+};
 use sp_keyring::AccountKeyring as Keyring;
 use sp_runtime::DispatchError;
 use crate::{
-	BestBlock, Error, EthereumHeader, FinalizedBlock, GenesisConfig, Headers,
-	HeadersByNumber, PruningRange,
+	BestBlock, BlocksToPrune, DescendantsUntilFinalized, Error, Event,
+	EthashProver, EthereumHeader, EthereumHeaderId, FinalizedBlock, FinalizedHeadersToKeep,
+	GenesisConfig, H128, Headers, HeadersByNumber, HeadersToPruneInSingleImport, Log,
+	NextDagEpoch, PruningRange, ReceiptVerificationError, Releases, StorageVersion,
+	EPOCH_LENGTH, MAX_DAG_MERKLE_ROOTS_IN_SINGLE_APPEND, MAX_DESCENDANTS_UNTIL_FINALIZED,
+	MAX_HEADERS_IN_SINGLE_IMPORT, MAX_HEADERS_TO_PRUNE_IN_SINGLE_IMPORT,
+	migration::{InitializeDescendantsUntilFinalized, MAX_HEADERS_TO_MIGRATE_PER_BLOCK},
 };
 
+fn header_id(header: &EthereumHeader) -> EthereumHeaderId {
+	EthereumHeaderId {
+		number: header.number,
+		hash: header.compute_hash(),
+	}
+}
+
+fn verifier_events() -> Vec<Event> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			TestEvent::verifier(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
 #[test]
 fn it_tracks_highest_difficulty_ethereum_chain() {
 	new_tester::<Test>().execute_with(|| {
@@ -144,6 +175,77 @@ fn it_tracks_only_one_finalized_ethereum_fork() {
 	});
 }
 
+#[test]
+fn it_emits_events_for_imports_reorgs_and_finalization() {
+	new_tester::<Test>().execute_with(|| {
+		let genesis_id = header_id(&Default::default());
+		let block1 = child_of_genesis_ethereum_header();
+		let block2 = child_of_header(&block1);
+		let block3 = child_of_header(&block2);
+		let mut block4 = child_of_genesis_ethereum_header();
+		block4.difficulty = 2.into();
+
+		// Import order: B1, B4, B2, B3
+		//   B0
+		//   |  \
+		//   B1  B4
+		//   |
+		//   B2
+		//   |
+		//   B3
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		for header in vec![&block1, &block4, &block2, &block3].into_iter() {
+			assert_ok!(Verifier::import_header(
+				Origin::signed(ferdie.clone()),
+				header.clone(),
+				Default::default(),
+			));
+		}
+
+		assert_eq!(
+			verifier_events(),
+			vec![
+				Event::HeaderImported(header_id(&block1)),
+				// B4 has more difficulty than B1
+				Event::HeaderImported(header_id(&block4)),
+				Event::Reorganized(header_id(&block1), header_id(&block4), genesis_id, 1),
+				// B2 has the same total difficulty as B4, so B4 remains the best block
+				Event::HeaderImported(header_id(&block2)),
+				// B3 switches back to the B1 fork and finalizes B1
+				// (relies on DescendantsUntilFinalized = 2)
+				Event::HeaderImported(header_id(&block3)),
+				Event::Reorganized(header_id(&block4), header_id(&block3), genesis_id, 1),
+				Event::HeaderFinalized(header_id(&block1)),
+			],
+		);
+	});
+}
+
+#[test]
+fn it_does_not_emit_reorg_event_when_extending_best_chain() {
+	new_tester::<Test>().execute_with(|| {
+		let block1 = child_of_genesis_ethereum_header();
+		let block2 = child_of_header(&block1);
+
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		for header in vec![&block1, &block2].into_iter() {
+			assert_ok!(Verifier::import_header(
+				Origin::signed(ferdie.clone()),
+				header.clone(),
+				Default::default(),
+			));
+		}
+
+		assert_eq!(
+			verifier_events(),
+			vec![
+				Event::HeaderImported(header_id(&block1)),
+				Event::HeaderImported(header_id(&block2)),
+			],
+		);
+	});
+}
+
 #[test]
 fn it_prunes_ethereum_headers_correctly() {
 	new_tester::<Test>().execute_with(|| {
@@ -222,6 +324,338 @@ fn it_prunes_ethereum_headers_correctly() {
 	});
 }
 
+#[test]
+fn it_updates_parameters_only_with_update_origin() {
+	new_tester::<Test>().execute_with(|| {
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		assert_err!(
+			Verifier::set_descendants_until_finalized(Origin::signed(ferdie.clone()), 5),
+			DispatchError::BadOrigin,
+		);
+		assert_err!(
+			Verifier::set_finalized_headers_to_keep(Origin::signed(ferdie.clone()), 5),
+			DispatchError::BadOrigin,
+		);
+		assert_err!(
+			Verifier::set_headers_to_prune_in_single_import(Origin::signed(ferdie.clone()), 5),
+			DispatchError::BadOrigin,
+		);
+
+		assert_ok!(Verifier::set_descendants_until_finalized(Origin::root(), 5));
+		assert_ok!(Verifier::set_finalized_headers_to_keep(Origin::root(), 100));
+		assert_ok!(Verifier::set_headers_to_prune_in_single_import(Origin::root(), 1));
+		assert_eq!(DescendantsUntilFinalized::get(), 5);
+		assert_eq!(FinalizedHeadersToKeep::get(), 100);
+		assert_eq!(HeadersToPruneInSingleImport::get(), 1);
+	});
+}
+
+#[test]
+fn it_rejects_invalid_finality_and_pruning_parameters() {
+	new_tester::<Test>().execute_with(|| {
+		assert_err!(
+			Verifier::set_descendants_until_finalized(
+				Origin::root(),
+				MAX_DESCENDANTS_UNTIL_FINALIZED + 1,
+			),
+			Error::<Test>::InvalidParameter,
+		);
+		assert_err!(
+			Verifier::set_finalized_headers_to_keep(Origin::root(), 0),
+			Error::<Test>::InvalidParameter,
+		);
+		assert_err!(
+			Verifier::set_headers_to_prune_in_single_import(Origin::root(), 0),
+			Error::<Test>::InvalidParameter,
+		);
+		assert_err!(
+			Verifier::set_headers_to_prune_in_single_import(
+				Origin::root(),
+				MAX_HEADERS_TO_PRUNE_IN_SINGLE_IMPORT + 1,
+			),
+			Error::<Test>::InvalidParameter,
+		);
+	});
+}
+
+#[test]
+fn it_appends_dag_merkle_roots_after_last_static_epoch() {
+	new_tester::<Test>().execute_with(|| {
+		let end_epoch = EthashProver::dag_merkle_roots_end_epoch();
+		let roots = vec![H128::repeat_byte(1), H128::repeat_byte(2)];
+		assert_eq!(NextDagEpoch::get(), end_epoch);
+		assert_eq!(Verifier::dag_merkle_root(end_epoch - 1), EthashProver::dag_merkle_root(end_epoch - 1));
+		assert!(Verifier::dag_merkle_root(end_epoch - 1).is_some());
+		assert_eq!(Verifier::dag_merkle_root(end_epoch), None);
+
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		assert_err!(
+			Verifier::append_dag_merkle_roots(Origin::signed(ferdie), end_epoch, roots.clone()),
+			DispatchError::BadOrigin,
+		);
+
+		assert_ok!(Verifier::append_dag_merkle_roots(Origin::root(), end_epoch, roots.clone()));
+		assert_eq!(Verifier::dag_merkle_root(end_epoch), Some(roots[0]));
+		assert_eq!(Verifier::dag_merkle_root(end_epoch + 1), Some(roots[1]));
+		assert_eq!(Verifier::dag_merkle_root(end_epoch + 2), None);
+		assert_eq!(NextDagEpoch::get(), end_epoch + 2);
+		assert_eq!(
+			verifier_events(),
+			vec![Event::DagMerkleRootsAppended(end_epoch, 2)],
+		);
+
+		// Roots are never overwritten
+		assert_err!(
+			Verifier::append_dag_merkle_roots(Origin::root(), end_epoch, roots),
+			Error::<Test>::UnexpectedDagEpoch,
+		);
+	});
+}
+
+#[test]
+fn it_rejects_invalid_dag_merkle_root_appends() {
+	new_tester::<Test>().execute_with(|| {
+		let end_epoch = EthashProver::dag_merkle_roots_end_epoch();
+		assert_err!(
+			Verifier::append_dag_merkle_roots(Origin::root(), end_epoch + 1, vec![H128::zero()]),
+			Error::<Test>::UnexpectedDagEpoch,
+		);
+		assert_err!(
+			Verifier::append_dag_merkle_roots(Origin::root(), end_epoch - 1, vec![H128::zero()]),
+			Error::<Test>::UnexpectedDagEpoch,
+		);
+		assert_err!(
+			Verifier::append_dag_merkle_roots(Origin::root(), end_epoch, Vec::new()),
+			Error::<Test>::InvalidBatchSize,
+		);
+		assert_err!(
+			Verifier::append_dag_merkle_roots(
+				Origin::root(),
+				end_epoch,
+				vec![H128::zero(); MAX_DAG_MERKLE_ROOTS_IN_SINGLE_APPEND as usize + 1],
+			),
+			Error::<Test>::InvalidBatchSize,
+		);
+		assert_eq!(Verifier::dag_merkle_root(end_epoch), None);
+	});
+}
+
+#[test]
+fn it_keeps_finalized_header_when_descendants_until_finalized_changes() {
+	new_tester::<Test>().execute_with(|| {
+		let block1 = child_of_genesis_ethereum_header();
+		let block2 = child_of_header(&block1);
+		let block3 = child_of_header(&block2);
+		let block4 = child_of_header(&block3);
+		let block5 = child_of_header(&block4);
+
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		for header in vec![block1.clone(), block2, block3].into_iter() {
+			assert_ok!(Verifier::import_header(
+				Origin::signed(ferdie.clone()),
+				header,
+				Default::default(),
+			));
+		}
+		assert_eq!(FinalizedBlock::get(), header_id(&block1));
+
+		// B1 stays final even though the header 4 blocks behind B4 is older
+		assert_ok!(Verifier::set_descendants_until_finalized(Origin::root(), 4));
+		assert_ok!(Verifier::import_header(
+			Origin::signed(ferdie.clone()),
+			block4.clone(),
+			Default::default(),
+		));
+		assert_eq!(FinalizedBlock::get(), header_id(&block1));
+
+		assert_ok!(Verifier::set_descendants_until_finalized(Origin::root(), 1));
+		assert_eq!(FinalizedBlock::get(), header_id(&block3));
+		assert_ok!(Verifier::import_header(
+			Origin::signed(ferdie.clone()),
+			block5,
+			Default::default(),
+		));
+		assert_eq!(FinalizedBlock::get(), header_id(&block4));
+	});
+}
+
+#[test]
+fn it_finalizes_skipped_headers_when_descendants_until_finalized_decreases() {
+	new_tester::<Test>().execute_with(|| {
+		assert_ok!(Verifier::set_descendants_until_finalized(Origin::root(), 4));
+
+		let mut headers = vec![];
+		let mut parent = genesis_ethereum_header();
+		for _ in 0..5 {
+			let header = child_of_header(&parent);
+			headers.push(header.clone());
+			parent = header;
+		}
+
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		for header in headers.iter() {
+			assert_ok!(Verifier::import_header(
+				Origin::signed(ferdie.clone()),
+				header.clone(),
+				Default::default(),
+			));
+		}
+		assert_eq!(FinalizedBlock::get(), header_id(&headers[0]));
+
+		assert_ok!(Verifier::set_descendants_until_finalized(Origin::root(), 1));
+		assert_eq!(FinalizedBlock::get(), header_id(&headers[3]));
+		for header in headers[..4].iter() {
+			assert!(Headers::<Test>::get(header.compute_hash()).unwrap().finalized);
+		}
+		assert!(!Headers::<Test>::get(headers[4].compute_hash()).unwrap().finalized);
+		assert_eq!(
+			verifier_events().last(),
+			Some(&Event::HeaderFinalized(header_id(&headers[3]))),
+		);
+	});
+}
+
+#[test]
+fn it_prunes_gradually_when_finalized_headers_to_keep_shrinks() {
+	new_tester::<Test>().execute_with(|| {
+		let mut headers = vec![child_of_genesis_ethereum_header()];
+		for _ in 0..6 {
+			headers.push(child_of_header(headers.last().unwrap()));
+		}
+
+		// Import B1 to B5. Nothing is pruned with the default window.
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		for header in headers[0..5].iter() {
+			assert_ok!(Verifier::import_header(
+				Origin::signed(ferdie.clone()),
+				header.clone(),
+				Default::default(),
+			));
+		}
+		assert!(Headers::<Test>::contains_key(genesis_ethereum_block_hash()));
+
+		// Relies on DescendantsUntilFinalized = 2, so importing B6 finalizes B4
+		// and makes B0 to B2 prunable. Only one header is pruned per import.
+		assert_ok!(Verifier::set_finalized_headers_to_keep(Origin::root(), 1));
+		assert_ok!(Verifier::set_headers_to_prune_in_single_import(Origin::root(), 1));
+		assert_ok!(Verifier::import_header(
+			Origin::signed(ferdie.clone()),
+			headers[5].clone(),
+			Default::default(),
+		));
+		assert!(!Headers::<Test>::contains_key(genesis_ethereum_block_hash()));
+		assert!(Headers::<Test>::contains_key(headers[0].compute_hash()));
+		assert_eq!(
+			BlocksToPrune::get(),
+			PruningRange { oldest_unpruned_block: 1, oldest_block_to_keep: 3 },
+		);
+
+		assert_ok!(Verifier::import_header(
+			Origin::signed(ferdie.clone()),
+			headers[6].clone(),
+			Default::default(),
+		));
+		assert!(!Headers::<Test>::contains_key(headers[0].compute_hash()));
+		assert!(Headers::<Test>::contains_key(headers[1].compute_hash()));
+		assert_eq!(
+			BlocksToPrune::get(),
+			PruningRange { oldest_unpruned_block: 2, oldest_block_to_keep: 4 },
+		);
+	});
+}
+
+#[test]
+fn it_initializes_descendants_until_finalized_only_once() {
+	parameter_types! {
+		pub const OldDescendantsUntilFinalized: u8 = 3;
+		pub const OtherDescendantsUntilFinalized: u8 = 4;
+	}
+
+	new_tester::<Test>().execute_with(|| {
+		DescendantsUntilFinalized::kill();
+
+		InitializeDescendantsUntilFinalized::<Test, OldDescendantsUntilFinalized>::on_runtime_upgrade();
+		assert_eq!(DescendantsUntilFinalized::get(), 3);
+
+		InitializeDescendantsUntilFinalized::<Test, OtherDescendantsUntilFinalized>::on_runtime_upgrade();
+		assert_eq!(DescendantsUntilFinalized::get(), 3);
+	});
+}
+
+#[test]
+fn it_migrates_stored_headers_to_include_base_fee_over_several_blocks() {
+	new_tester::<Test>().execute_with(|| {
+		assert_eq!(StorageVersion::get(), Releases::V2);
+
+		let mut headers = vec![];
+		let mut parent = genesis_ethereum_header();
+		for _ in 0..MAX_HEADERS_TO_MIGRATE_PER_BLOCK + 10 {
+			let header = child_of_header(&parent);
+			headers.push(header.clone());
+			parent = header;
+		}
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		assert_ok!(Verifier::import_headers(
+			Origin::signed(ferdie.clone()),
+			headers[..MAX_HEADERS_IN_SINGLE_IMPORT as usize]
+				.iter()
+				.map(|header| (header.clone(), Default::default()))
+				.collect(),
+		));
+		for header in headers[MAX_HEADERS_IN_SINGLE_IMPORT as usize..].iter() {
+			assert_ok!(Verifier::import_header(
+				Origin::signed(ferdie.clone()),
+				header.clone(),
+				Default::default(),
+			));
+		}
+
+		// Re-encode every stored header without the base fee, which is the
+		// `None` byte just before `total_difficulty` and `finalized`.
+		let stored_headers: Vec<_> = Headers::<Test>::iter().collect();
+		assert!(stored_headers.len() > MAX_HEADERS_TO_MIGRATE_PER_BLOCK as usize);
+		for (hash, stored_header) in stored_headers.iter() {
+			let mut encoded = stored_header.encode();
+			let base_fee_index = encoded.len() - 32 - 1 - 1;
+			assert_eq!(encoded.remove(base_fee_index), 0);
+			unhashed::put_raw(&Headers::<Test>::hashed_key_for(hash), &encoded);
+		}
+		StorageVersion::kill();
+
+		let next_header = child_of_header(&parent);
+		assert_err!(
+			Verifier::import_header(
+				Origin::signed(ferdie.clone()),
+				next_header.clone(),
+				Default::default(),
+			),
+			Error::<Test>::MigrationInProgress,
+		);
+
+		// The first block migrates some of the headers
+		Verifier::on_initialize(1);
+		assert_eq!(StorageVersion::get(), Releases::V1);
+		let migrated = stored_headers
+			.iter()
+			.filter(|(hash, stored_header)| Headers::<Test>::get(hash).as_ref() == Some(stored_header))
+			.count();
+		assert_eq!(migrated, MAX_HEADERS_TO_MIGRATE_PER_BLOCK as usize);
+
+		// The second block migrates the rest
+		Verifier::on_initialize(2);
+		assert_eq!(StorageVersion::get(), Releases::V2);
+		for (hash, stored_header) in stored_headers.iter() {
+			assert_eq!(Headers::<Test>::get(hash).as_ref(), Some(stored_header));
+		}
+
+		assert_ok!(Verifier::import_header(
+			Origin::signed(ferdie),
+			next_header,
+			Default::default(),
+		));
+	});
+}
+
 #[test]
 fn it_imports_ethereum_header_only_once() {
 	new_tester::<Test>().execute_with(|| {
@@ -275,11 +709,133 @@ fn it_rejects_ethereum_header_before_parent() {
 	});
 }
 
+#[test]
+fn it_imports_batch_of_ethereum_headers() {
+	new_tester::<Test>().execute_with(|| {
+		let block1 = child_of_genesis_ethereum_header();
+		let block2 = child_of_header(&block1);
+		let block3 = child_of_header(&block2);
+
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		assert_ok!(Verifier::import_headers(
+			Origin::signed(ferdie.clone()),
+			vec![
+				(block1.clone(), Default::default()),
+				(block2.clone(), Default::default()),
+				(block3.clone(), Default::default()),
+			],
+		));
+
+		for header in vec![&block1, &block2, &block3].into_iter() {
+			assert!(Headers::<Test>::contains_key(header.compute_hash()));
+		}
+		// Relies on DescendantsUntilFinalized = 2
+		assert_eq!(FinalizedBlock::get(), header_id(&block1));
+		assert!(Headers::<Test>::get(block1.compute_hash()).unwrap().finalized);
+		assert_eq!(BestBlock::get().0, header_id(&block3));
+
+		let events = verifier_events();
+		assert_eq!(
+			events,
+			vec![
+				Event::HeaderImported(header_id(&block1)),
+				Event::HeaderImported(header_id(&block2)),
+				Event::HeaderImported(header_id(&block3)),
+				Event::HeaderFinalized(header_id(&block1)),
+			],
+		);
+	});
+}
+
+#[test]
+fn it_finalizes_every_header_when_batch_moves_finality_by_several_headers() {
+	new_tester::<Test>().execute_with(|| {
+		let mut headers = vec![];
+		let mut parent = genesis_ethereum_header();
+		for _ in 0..6 {
+			let header = child_of_header(&parent);
+			headers.push(header.clone());
+			parent = header;
+		}
+
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		assert_ok!(Verifier::import_headers(
+			Origin::signed(ferdie),
+			headers.iter().map(|header| (header.clone(), Default::default())).collect(),
+		));
+
+		// Relies on DescendantsUntilFinalized = 2
+		assert_eq!(FinalizedBlock::get(), header_id(&headers[3]));
+		for header in headers[..4].iter() {
+			assert!(Headers::<Test>::get(header.compute_hash()).unwrap().finalized);
+		}
+		for header in headers[4..].iter() {
+			assert!(!Headers::<Test>::get(header.compute_hash()).unwrap().finalized);
+		}
+		assert_eq!(
+			verifier_events().last(),
+			Some(&Event::HeaderFinalized(header_id(&headers[3]))),
+		);
+	});
+}
+
+#[test]
+fn it_rejects_empty_or_oversized_batch_of_ethereum_headers() {
+	new_tester::<Test>().execute_with(|| {
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		assert_err!(
+			Verifier::import_headers(Origin::signed(ferdie.clone()), vec![]),
+			Error::<Test>::InvalidBatchSize,
+		);
+
+		let mut headers = vec![];
+		let mut parent: EthereumHeader = Default::default();
+		for _ in 0..=MAX_HEADERS_IN_SINGLE_IMPORT {
+			let header = child_of_header(&parent);
+			headers.push((header.clone(), Default::default()));
+			parent = header;
+		}
+		assert_err!(
+			Verifier::import_headers(Origin::signed(ferdie.clone()), headers),
+			Error::<Test>::InvalidBatchSize,
+		);
+	});
+}
+
+#[test]
+fn it_imports_no_headers_if_any_header_in_batch_is_invalid() {
+	new_tester::<Test>().execute_with(|| {
+		let block1 = child_of_genesis_ethereum_header();
+		let block2 = child_of_header(&block1);
+		let mut orphan: EthereumHeader = Default::default();
+		orphan.parent_hash = block2.compute_hash();
+		orphan.number = 5;
+
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		assert_err!(
+			Verifier::import_headers(
+				Origin::signed(ferdie.clone()),
+				vec![
+					(block1.clone(), Default::default()),
+					(orphan, Default::default()),
+				],
+			),
+			Error::<Test>::MissingParentHeader,
+		);
+
+		assert!(!Headers::<Test>::contains_key(block1.compute_hash()));
+		assert_eq!(BestBlock::get().0, header_id(&Default::default()));
+		assert!(verifier_events().is_empty());
+	});
+}
+
 #[test]
 fn it_validates_proof_of_work() {
 	new_tester_with_config::<mock_verifier_with_pow::Test>(GenesisConfig {
 			initial_header: ethereum_header_from_file(11090290, ""),
 			initial_difficulty: 0.into(),
+			descendants_until_finalized: 2,
+			..Default::default()
 	}).execute_with(|| {
 		let header1 = ethereum_header_from_file(11090291, "");
 		let header1_proof = ethereum_header_proof_from_file(11090291, "");
@@ -307,6 +863,8 @@ fn it_rejects_ethereum_header_with_low_difficulty() {
 	new_tester_with_config::<mock_verifier_with_pow::Test>(GenesisConfig {
 		initial_header: ethereum_header_from_file(11090291, ""),
 		initial_difficulty: 0.into(),
+		descendants_until_finalized: 2,
+		..Default::default()
 	}).execute_with(|| {
 		let header = ethereum_header_from_file(11090292, "_low_difficulty");
 		let header_proof = ethereum_header_proof_from_file(11090292, "_low_difficulty");
@@ -322,6 +880,59 @@ fn it_rejects_ethereum_header_with_low_difficulty() {
 	});
 }
 
+#[test]
+fn it_validates_proof_of_work_past_static_dag_epochs_after_appending_root() {
+	// The first header of epoch 512 doesn't have a DAG Merkle root in DAGS_MERKLE_ROOTS.
+	// Real headers can't be generated offline, so these headers and the DAG nodes in the
+	// proof are synthetic. The proof verifies against `dag_merkle_root`, but the header
+	// can't meet its difficulty target, so import still fails after the root is appended.
+	new_tester_with_config::<mock_verifier_with_pow::Test>(GenesisConfig {
+		initial_header: ethereum_header_from_file(15359999, "_synthetic"),
+		initial_difficulty: 0.into(),
+		descendants_until_finalized: 2,
+		..Default::default()
+	}).execute_with(|| {
+		let header = ethereum_header_from_file(15360000, "_synthetic");
+		let header_proof = ethereum_header_proof_from_file(15360000, "_synthetic");
+		let dag_merkle_root: H128 = hex!("a5a041ac95d6b507a5ae4deb3555bb5b").into();
+		let epoch = EthashProver::dag_merkle_roots_end_epoch();
+		assert_eq!(header.number / EPOCH_LENGTH, epoch);
+
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		assert_err!(
+			mock_verifier_with_pow::Verifier::import_header(
+				mock_verifier_with_pow::Origin::signed(ferdie.clone()),
+				header.clone(),
+				header_proof.clone(),
+			),
+			Error::<mock_verifier_with_pow::Test>::MissingDagMerkleRoot,
+		);
+
+		assert_ok!(mock_verifier_with_pow::Verifier::append_dag_merkle_roots(
+			mock_verifier_with_pow::Origin::root(),
+			epoch,
+			vec![dag_merkle_root],
+		));
+
+		let (mix_hash, _) = EthashProver::new().hashimoto_merkle_with_dag_root(
+			header.compute_partial_hash(),
+			header.nonce().unwrap(),
+			header.number,
+			&header_proof,
+			mock_verifier_with_pow::Verifier::dag_merkle_root(epoch).unwrap(),
+		).unwrap();
+		assert_eq!(mix_hash, header.mix_hash().unwrap());
+		assert_err!(
+			mock_verifier_with_pow::Verifier::import_header(
+				mock_verifier_with_pow::Origin::signed(ferdie),
+				header,
+				header_proof,
+			),
+			Error::<mock_verifier_with_pow::Test>::InvalidHeader,
+		);
+	});
+}
+
 #[test]
 fn it_confirms_receipt_inclusion_in_finalized_header() {
 	let (receipts_root, receipt_proof) = receipt_root_and_proof();
@@ -332,6 +943,8 @@ fn it_confirms_receipt_inclusion_in_finalized_header() {
 	new_tester_with_config::<Test>(GenesisConfig {
 		initial_header: finalized_header,
 		initial_difficulty: 0.into(),
+		descendants_until_finalized: 2,
+		..Default::default()
 	}).execute_with(|| {
 		assert_ok!(Verifier::verify(
 			&message_with_receipt_proof(log_payload(), finalized_header_hash, receipt_proof),
@@ -362,6 +975,8 @@ fn it_denies_receipt_inclusion_for_invalid_log() {
 	new_tester_with_config::<Test>(GenesisConfig {
 		initial_header: finalized_header,
 		initial_difficulty: 0.into(),
+		descendants_until_finalized: 2,
+		..Default::default()
 	}).execute_with(|| {
 		// Invalid log payload
 		assert_err!(
@@ -383,6 +998,79 @@ fn it_denies_receipt_inclusion_for_invalid_log() {
 	})
 }
 
+#[test]
+fn it_returns_decoded_receipt_for_valid_proof() {
+	let (receipts_root, receipt_proof) = receipt_root_and_proof();
+	let mut finalized_header: EthereumHeader = Default::default();
+	finalized_header.receipts_root = receipts_root;
+	let finalized_header_hash = finalized_header.compute_hash();
+
+	new_tester_with_config::<Test>(GenesisConfig {
+		initial_header: finalized_header,
+		initial_difficulty: 0.into(),
+		descendants_until_finalized: 2,
+		..Default::default()
+	}).execute_with(|| {
+		let message = message_with_receipt_proof(log_payload(), finalized_header_hash, receipt_proof);
+		let receipt = Verifier::verify_receipt(&message.proof).unwrap();
+
+		let log: Log = rlp::decode(&log_payload()).unwrap();
+		assert_eq!(receipt.logs.len(), 6);
+		assert!(receipt.contains_log(&log));
+	});
+}
+
+#[test]
+fn it_returns_reason_for_failed_receipt_verification() {
+	new_tester::<Test>().execute_with(|| {
+		let (receipts_root, receipt_proof) = receipt_root_and_proof();
+		let mut block1 = child_of_genesis_ethereum_header();
+		block1.receipts_root = receipts_root;
+		let block1_hash = block1.compute_hash();
+
+		let message = message_with_receipt_proof(log_payload(), block1_hash, receipt_proof.clone());
+		assert_eq!(
+			Verifier::verify_receipt(&message.proof),
+			Err(ReceiptVerificationError::MissingHeader),
+		);
+
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		assert_ok!(Verifier::import_header(
+			Origin::signed(ferdie.clone()),
+			block1,
+			Default::default(),
+		));
+		assert_eq!(
+			Verifier::verify_receipt(&message.proof),
+			Err(ReceiptVerificationError::HeaderNotFinalized),
+		);
+
+		// Genesis is finalized but has a different receipts root
+		let message = message_with_receipt_proof(
+			log_payload(),
+			genesis_ethereum_block_hash(),
+			receipt_proof.clone(),
+		);
+		assert_eq!(
+			Verifier::verify_receipt(&message.proof),
+			Err(ReceiptVerificationError::ReceiptsRootMismatch),
+		);
+
+		// Proof with an intermediate node missing doesn't link up
+		let mut broken_proof = receipt_proof;
+		broken_proof.1.remove(1);
+		let message = message_with_receipt_proof(
+			log_payload(),
+			genesis_ethereum_block_hash(),
+			broken_proof,
+		);
+		assert_eq!(
+			Verifier::verify_receipt(&message.proof),
+			Err(ReceiptVerificationError::InvalidProof),
+		);
+	});
+}
+
 #[test]
 fn it_denies_receipt_inclusion_for_invalid_header() {
 	new_tester::<Test>().execute_with(|| {
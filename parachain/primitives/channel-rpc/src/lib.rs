@@ -0,0 +1,40 @@
+//! Helpers shared by the RPC interfaces of the outbound channels.
+
+use std::fmt::Debug;
+
+use codec::Decode;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use sp_core::offchain::OffchainStorage;
+
+/// Error code for failures to call into the runtime.
+pub const RUNTIME_ERROR: i64 = 1;
+/// Error code for commitments that can't be decoded.
+pub const DECODE_ERROR: i64 = 2;
+
+/// Convert the error of a runtime API call into an RPC error with `message`.
+pub fn runtime_error(message: &str, err: impl Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(RUNTIME_ERROR),
+		message: message.into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+/// Read the messages of the commitment indexed under `key` from offchain
+/// storage. Returns `None` if no commitment is indexed under `key`.
+pub fn read_commitment<S, Message>(storage: &S, key: &[u8]) -> Result<Option<Vec<Message>>>
+where
+	S: OffchainStorage,
+	Message: Decode,
+{
+	match storage.get(sp_offchain::STORAGE_PREFIX, key) {
+		Some(encoded) => Vec::<Message>::decode(&mut encoded.as_slice())
+			.map(Some)
+			.map_err(|err| RpcError {
+				code: ErrorCode::ServerError(DECODE_ERROR),
+				message: "Unable to decode commitment.".into(),
+				data: Some(format!("{:?}", err).into()),
+			}),
+		None => Ok(None),
+	}
+}
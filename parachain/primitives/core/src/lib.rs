@@ -6,7 +6,10 @@
 #![allow(unused_variables)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	weights::Weight,
+};
 use frame_system::Config;
 use sp_core::H160;
 use sp_std::prelude::*;
@@ -14,16 +17,21 @@ use artemis_ethereum::{Header, Log, U256};
 
 pub mod types;
 pub mod assets;
+pub mod merkle;
 
 pub use types::{
 	Message,
 	Proof,
 	ChannelId,
+	CommitmentMode,
 	MessageId,
 	MessageNonce,
+	MessageStatus,
+	SourceChannelInfo,
 };
 
 pub use assets::{AssetId, MultiAsset, SingleAsset};
+pub use merkle::MerkleProof;
 /// A trait for verifying messages.
 ///
 /// This trait should be implemented by runtime modules that wish to provide message verification functionality.
@@ -37,8 +45,86 @@ pub trait Verifier {
 }
 
 /// Outbound submission for applications
+///
+/// `max_fee` caps the fee the submitter is willing to pay on channels that
+/// charge one. Submission fails rather than paying a higher fee.
 pub trait OutboundRouter<AccountId> {
-	fn submit(channel_id: ChannelId, who: &AccountId, target: H160, payload: &[u8]) -> DispatchResult;
+	fn submit(channel_id: ChannelId, who: &AccountId, target: H160, payload: &[u8], max_fee: Option<U256>) -> DispatchResult;
+}
+
+/// A channel registered in the runtime under a [`ChannelId`].
+pub trait Channel {
+	fn id() -> ChannelId;
+}
+
+/// A channel that applications can submit messages for Ethereum to.
+pub trait OutboundChannel<AccountId>: Channel {
+	fn submit(who: &AccountId, target: H160, payload: &[u8], max_fee: Option<U256>) -> DispatchResult;
+}
+
+/// Outbound channels keyed by [`ChannelId`].
+///
+/// Implemented for tuples of [`OutboundChannel`]s, so that a runtime can
+/// register any set of channels.
+pub trait OutboundChannelRegistry<AccountId> {
+	/// Submit a message to the channel registered under `channel_id`, or
+	/// return `None` if there is no such channel.
+	fn submit(channel_id: ChannelId, who: &AccountId, target: H160, payload: &[u8], max_fee: Option<U256>) -> Option<DispatchResult>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+#[tuple_types_custom_trait_bound(OutboundChannel<AccountId>)]
+impl<AccountId> OutboundChannelRegistry<AccountId> for Tuple {
+	fn submit(channel_id: ChannelId, who: &AccountId, target: H160, payload: &[u8], max_fee: Option<U256>) -> Option<DispatchResult> {
+		for_tuples!( #(
+			if <Tuple as Channel>::id() == channel_id {
+				return Some(<Tuple as OutboundChannel<AccountId>>::submit(who, target, payload, max_fee));
+			}
+		)* );
+		None
+	}
+}
+
+/// A channel that relayers submit messages from Ethereum to.
+pub trait InboundChannel<AccountId>: Channel {
+	/// Verify and accept `message`, returning the weight consumed by
+	/// dispatching it and any messages it unblocks.
+	fn submit(relayer: &AccountId, message: &Message) -> Result<Weight, DispatchError>;
+}
+
+/// Inbound channels keyed by [`ChannelId`].
+///
+/// Implemented for tuples of [`InboundChannel`]s, like [`OutboundChannelRegistry`].
+pub trait InboundChannelRegistry<AccountId> {
+	/// Submit a message to the channel registered under `channel_id`, or
+	/// return `None` if there is no such channel.
+	fn submit(channel_id: ChannelId, relayer: &AccountId, message: &Message) -> Option<Result<Weight, DispatchError>>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+#[tuple_types_custom_trait_bound(InboundChannel<AccountId>)]
+impl<AccountId> InboundChannelRegistry<AccountId> for Tuple {
+	fn submit(channel_id: ChannelId, relayer: &AccountId, message: &Message) -> Option<Result<Weight, DispatchError>> {
+		for_tuples!( #(
+			if <Tuple as Channel>::id() == channel_id {
+				return Some(<Tuple as InboundChannel<AccountId>>::submit(relayer, message));
+			}
+		)* );
+		None
+	}
+}
+
+/// Handles receipts for messages that an outbound channel sent to Ethereum.
+pub trait MessageDelivery {
+	/// Record that the message with `nonce` was executed on Ethereum,
+	/// successfully or not. Receipts must arrive in nonce order.
+	fn on_delivered(nonce: MessageNonce, success: bool) -> DispatchResult;
+}
+
+impl MessageDelivery for () {
+	fn on_delivered(_: MessageNonce, _: bool) -> DispatchResult {
+		Ok(())
+	}
 }
 
 /// Add a message to a commitment
@@ -48,7 +134,66 @@ pub trait MessageCommitment {
 
 /// Dispatch a message
 pub trait MessageDispatch<T: Config, MessageId> {
-	fn dispatch(source: H160, id: MessageId, payload: &[u8]);
+	/// Dispatch the call encoded in `payload`, returning the weight it consumed.
+	fn dispatch(source: H160, id: MessageId, payload: &[u8]) -> Weight;
+	/// Max weight that dispatching a single message can consume.
+	fn max_dispatch_weight() -> Weight;
 	#[cfg(feature = "runtime-benchmarks")]
 	fn successful_dispatch_event(id: MessageId) -> Option<<T as Config>::Event>;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	struct First;
+	struct Second;
+
+	impl Channel for First {
+		fn id() -> ChannelId { ChannelId(7) }
+	}
+
+	impl OutboundChannel<u64> for First {
+		fn submit(_: &u64, _: H160, _: &[u8], _: Option<U256>) -> DispatchResult { Ok(()) }
+	}
+
+	impl Channel for Second {
+		fn id() -> ChannelId { ChannelId(9) }
+	}
+
+	impl OutboundChannel<u64> for Second {
+		fn submit(_: &u64, _: H160, _: &[u8], _: Option<U256>) -> DispatchResult { Err(DispatchError::Other("second")) }
+	}
+
+	impl InboundChannel<u64> for First {
+		fn submit(_: &u64, _: &Message) -> Result<Weight, DispatchError> { Ok(5) }
+	}
+
+	impl InboundChannel<u64> for Second {
+		fn submit(_: &u64, _: &Message) -> Result<Weight, DispatchError> { Err(DispatchError::Other("second")) }
+	}
+
+	#[test]
+	fn registry_routes_by_channel_id() {
+		type Channels = (First, Second);
+		let submit = |channel_id| <Channels as OutboundChannelRegistry<u64>>::submit(channel_id, &1, H160::zero(), &[], None);
+
+		assert_eq!(submit(ChannelId(7)), Some(Ok(())));
+		assert_eq!(submit(ChannelId(9)), Some(Err(DispatchError::Other("second"))));
+		assert_eq!(submit(ChannelId::BASIC), None);
+	}
+
+	#[test]
+	fn inbound_registry_routes_by_channel_id() {
+		type Channels = (First, Second);
+		let message = Message {
+			data: vec![],
+			proof: Proof { block_hash: Default::default(), tx_index: 0, data: (vec![], vec![]) },
+		};
+		let submit = |channel_id| <Channels as InboundChannelRegistry<u64>>::submit(channel_id, &1, &message);
+
+		assert_eq!(submit(ChannelId(7)), Some(Ok(5)));
+		assert_eq!(submit(ChannelId(9)), Some(Err(DispatchError::Other("second"))));
+		assert_eq!(submit(ChannelId::BASIC), None);
+	}
+}
@@ -0,0 +1,160 @@
+//! Binary Merkle trees over message commitments
+//!
+//! Trees are built bottom-up from an ordered list of leaves. Each parent is
+//! `hash(left ++ right)`, and the last node of a layer with an odd number of
+//! nodes is promoted to the next layer unchanged. This matches
+//! `MerkleProof.verifyMerkleLeafAtPosition` in the Ethereum contracts.
+
+use codec::{Encode, Decode};
+use sp_core::{RuntimeDebug, H256};
+use sp_runtime::traits::Hash;
+use sp_std::prelude::*;
+
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+
+/// Proof that a leaf is included in a Merkle tree.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MerkleProof {
+	/// Position of the leaf in the tree, starting at 0.
+	pub leaf_index: u64,
+	/// Number of leaves in the tree.
+	pub num_leaves: u64,
+	/// Sibling hashes, ordered from the leaf to the root.
+	pub items: Vec<H256>,
+}
+
+fn hash_pair<H: Hash<Output = H256>>(left: &H256, right: &H256) -> H256 {
+	H::hash(&[left.as_bytes(), right.as_bytes()].concat())
+}
+
+fn next_layer<H: Hash<Output = H256>>(layer: &[H256]) -> Vec<H256> {
+	layer
+		.chunks(2)
+		.map(|pair| match pair {
+			[left, right] => hash_pair::<H>(left, right),
+			[single] => *single,
+			_ => unreachable!(),
+		})
+		.collect()
+}
+
+/// Compute the root of the tree with `leaves`. The root of an empty tree is zero.
+pub fn merkle_root<H: Hash<Output = H256>>(leaves: &[H256]) -> H256 {
+	let mut layer = leaves.to_vec();
+	while layer.len() > 1 {
+		layer = next_layer::<H>(&layer);
+	}
+	layer.first().copied().unwrap_or_default()
+}
+
+/// Build a proof for the leaf at `leaf_index`.
+pub fn merkle_proof<H: Hash<Output = H256>>(leaves: &[H256], leaf_index: usize) -> Option<MerkleProof> {
+	if leaf_index >= leaves.len() {
+		return None;
+	}
+
+	let mut items = Vec::new();
+	let mut layer = leaves.to_vec();
+	let mut position = leaf_index;
+	while layer.len() > 1 {
+		let sibling = position ^ 1;
+		if sibling < layer.len() {
+			items.push(layer[sibling]);
+		}
+		layer = next_layer::<H>(&layer);
+		position /= 2;
+	}
+
+	Some(MerkleProof {
+		leaf_index: leaf_index as u64,
+		num_leaves: leaves.len() as u64,
+		items,
+	})
+}
+
+/// Check that `leaf` is included in the tree with `root` according to `proof`.
+pub fn verify_merkle_proof<H: Hash<Output = H256>>(root: H256, leaf: H256, proof: &MerkleProof) -> bool {
+	let mut position = proof.leaf_index;
+	let mut width = proof.num_leaves;
+	if position >= width {
+		return false;
+	}
+
+	let mut items = proof.items.iter();
+	let mut computed = leaf;
+	while width > 1 {
+		let is_left = position % 2 == 0;
+		// The rightmost node of a layer with an odd width has no sibling
+		if !(is_left && position + 1 == width) {
+			let item = match items.next() {
+				Some(item) => item,
+				None => return false,
+			};
+			computed = if is_left {
+				hash_pair::<H>(&computed, item)
+			} else {
+				hash_pair::<H>(item, &computed)
+			};
+		}
+		position /= 2;
+		width = (width + 1) / 2;
+	}
+
+	items.next().is_none() && computed == root
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::traits::Keccak256;
+
+	fn leaves(count: u8) -> Vec<H256> {
+		(0..count).map(|i| Keccak256::hash(&[i])).collect()
+	}
+
+	#[test]
+	fn root_of_small_trees() {
+		let leaves = leaves(3);
+		assert_eq!(merkle_root::<Keccak256>(&[]), H256::zero());
+		assert_eq!(merkle_root::<Keccak256>(&leaves[..1]), leaves[0]);
+		assert_eq!(
+			merkle_root::<Keccak256>(&leaves),
+			hash_pair::<Keccak256>(&hash_pair::<Keccak256>(&leaves[0], &leaves[1]), &leaves[2]),
+		);
+	}
+
+	#[test]
+	fn proofs_verify_for_every_leaf() {
+		for count in 1..=9 {
+			let leaves = leaves(count);
+			let root = merkle_root::<Keccak256>(&leaves);
+			for (index, leaf) in leaves.iter().enumerate() {
+				let proof = merkle_proof::<Keccak256>(&leaves, index).unwrap();
+				assert!(verify_merkle_proof::<Keccak256>(root, *leaf, &proof));
+				assert!(!verify_merkle_proof::<Keccak256>(root, H256::repeat_byte(1), &proof));
+			}
+			assert_eq!(merkle_proof::<Keccak256>(&leaves, count as usize), None);
+		}
+	}
+
+	#[test]
+	fn rejects_malformed_proofs() {
+		let leaves = leaves(5);
+		let root = merkle_root::<Keccak256>(&leaves);
+		let proof = merkle_proof::<Keccak256>(&leaves, 2).unwrap();
+
+		let mut wrong_index = proof.clone();
+		wrong_index.leaf_index = 3;
+		assert!(!verify_merkle_proof::<Keccak256>(root, leaves[2], &wrong_index));
+
+		let mut extra_item = proof.clone();
+		extra_item.items.push(H256::zero());
+		assert!(!verify_merkle_proof::<Keccak256>(root, leaves[2], &extra_item));
+
+		let mut missing_item = proof;
+		missing_item.items.pop();
+		assert!(!verify_merkle_proof::<Keccak256>(root, leaves[2], &missing_item));
+	}
+}
@@ -4,9 +4,11 @@ use frame_support::RuntimeDebug;
 use sp_std::vec::Vec;
 use sp_core::H256;
 use sp_runtime::DigestItem;
-use enum_iterator::IntoEnumIterator;
 use codec::{Encode, Decode};
 
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
 pub struct MessageId {
 	pub channel_id: ChannelId,
@@ -23,10 +25,50 @@ impl MessageId {
 
 pub type MessageNonce = u64;
 
-#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, IntoEnumIterator, RuntimeDebug)]
-pub enum ChannelId {
-	Basic,
-	Incentivized
+/// Id under which a channel is registered in the runtime.
+///
+/// Ids are assigned by the runtime, so new kinds of channels can be added
+/// without changes to this type. The encoding is the same as that of the
+/// former `Basic`/`Incentivized` enum.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug)]
+pub struct ChannelId(pub u8);
+
+impl ChannelId {
+	pub const BASIC: ChannelId = ChannelId(0);
+	pub const INCENTIVIZED: ChannelId = ChannelId(1);
+}
+
+/// An outbound channel contract on Ethereum that an inbound channel accepts
+/// messages from.
+#[derive(Encode, Decode, Copy, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct SourceChannelInfo<BlockNumber> {
+	/// Block from which messages from the channel are no longer accepted.
+	pub sunset: Option<BlockNumber>,
+}
+
+/// Progress of a message sent over an outbound channel.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum MessageStatus {
+	/// No message has been submitted with this nonce.
+	Unknown,
+	/// The message is waiting to be committed.
+	Queued,
+	/// The message has been committed and is waiting for a delivery receipt.
+	Committed,
+	/// A receipt for the message has arrived from Ethereum. Whether the message
+	/// was executed successfully is reported in the outbound channel's events.
+	Delivered,
+}
+
+/// How an outbound channel commits to a batch of messages.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum CommitmentMode {
+	/// Hash of the ABI-encoded array of all messages in the batch.
+	Batch,
+	/// Root of a Merkle tree with the hashes of the ABI-encoded messages as
+	/// leaves, so that messages can be verified one at a time.
+	MerkleTree,
 }
 
 /// A message relayed from Ethereum.
@@ -7,70 +7,134 @@ const DIFFICULTY_BOUND_DIVISOR: u32 = 11;  // right-shifts equivalent to divisio
 const EXP_DIFFICULTY_PERIOD: u64 = 100000;
 const MINIMUM_DIFFICULTY: u32 = 131072;
 
-#[derive(PartialEq, RuntimeDebug)]
+#[derive(Clone, Copy, PartialEq, RuntimeDebug)]
 pub enum BombDelay {
 	// See https://eips.ethereum.org/EIPS/eip-649
 	Byzantium = 3000000,
 	// See https://eips.ethereum.org/EIPS/eip-1234
 	Constantinople = 5000000,
-	// // See https://eips.ethereum.org/EIPS/eip-2384
+	// See https://eips.ethereum.org/EIPS/eip-2384
 	MuirGlacier = 9000000,
+	// See https://eips.ethereum.org/EIPS/eip-3554
+	London = 9700000,
+	// See https://eips.ethereum.org/EIPS/eip-4345
+	ArrowGlacier = 10700000,
+	// See https://eips.ethereum.org/EIPS/eip-5133
+	GrayGlacier = 11400000,
+}
+
+/// Formula used to adjust difficulty relative to the parent block.
+#[derive(Clone, Copy, PartialEq, RuntimeDebug)]
+pub enum DifficultyRules {
+	// Uncle-aware adjustment from EIP-100, used by every fork since Byzantium
+	Byzantium,
+}
+
+/// A hard fork that affects difficulty calculations.
+#[derive(Clone, Copy, PartialEq, RuntimeDebug)]
+pub struct DifficultyFork {
+	// Block number on which the fork activated
+	pub fork_block: u64,
+	// Difficulty bomb delay from this block onwards
+	pub bomb_delay: BombDelay,
+	// Difficulty adjustment from this block onwards
+	pub rules: DifficultyRules,
+}
+
+impl DifficultyFork {
+	pub const fn new(fork_block: u64, bomb_delay: BombDelay) -> Self {
+		DifficultyFork {
+			fork_block,
+			bomb_delay,
+			rules: DifficultyRules::Byzantium,
+		}
+	}
 }
 
+// Correct block numbers for mainnet and various testnets can be found here:
+// https://github.com/ethereum/go-ethereum/blob/v1.10.21/params/config.go
+const MAINNET_FORKS: [DifficultyFork; 6] = [
+	DifficultyFork::new(4370000, BombDelay::Byzantium),
+	DifficultyFork::new(7280000, BombDelay::Constantinople),
+	DifficultyFork::new(9200000, BombDelay::MuirGlacier),
+	DifficultyFork::new(12965000, BombDelay::London),
+	DifficultyFork::new(13773000, BombDelay::ArrowGlacier),
+	DifficultyFork::new(15050000, BombDelay::GrayGlacier),
+];
+
+const ROPSTEN_FORKS: [DifficultyFork; 4] = [
+	DifficultyFork::new(1700000, BombDelay::Byzantium),
+	DifficultyFork::new(4230000, BombDelay::Constantinople),
+	DifficultyFork::new(7117117, BombDelay::MuirGlacier),
+	DifficultyFork::new(10499401, BombDelay::London),
+];
+
+const POW_TESTNET_FORKS: [DifficultyFork; 3] = [
+	DifficultyFork::new(0, BombDelay::Byzantium),
+	DifficultyFork::new(0, BombDelay::Constantinople),
+	DifficultyFork::new(5062605, BombDelay::London),
+];
+
 /// Describes when hard forks occurred that affect difficulty calculations. These
 /// values are network-specific.
 #[derive(PartialEq, RuntimeDebug)]
 pub struct DifficultyConfig {
-	// Block number on which Byzantium (EIP-649) rules activated
-	pub byzantium_fork_block: u64,
-	// Block number on which Constantinople (EIP-1234) rules activated
-	pub constantinople_fork_block: u64,
-	// Block number on which MuirGlacier (EIP-2384) activated
-	pub muir_glacier_fork_block: u64,
+	// Forks ordered by ascending block number
+	pub forks: &'static [DifficultyFork],
 }
 
 impl DifficultyConfig {
 
-	// Correct block numbers for mainnet and various testnets can be found here:
-	// https://github.com/ethereum/go-ethereum/blob/498458b4102c0d32d7453035a115e6b9df5e485d/params/config.go#L55-L258
-	pub fn mainnet() -> Self {
-		DifficultyConfig {
-			byzantium_fork_block: 4370000,
-			constantinople_fork_block: 7280000,
-			muir_glacier_fork_block: 9200000,
-		}
+	pub const fn mainnet() -> Self {
+		DifficultyConfig { forks: &MAINNET_FORKS }
 	}
 
-	pub fn ropsten() -> Self {
-		DifficultyConfig {
-			byzantium_fork_block: 1700000,
-			constantinople_fork_block: 4230000,
-			muir_glacier_fork_block: 7117117,
-		}
+	pub const fn ropsten() -> Self {
+		DifficultyConfig { forks: &ROPSTEN_FORKS }
+	}
+
+	/// Fork schedule of a synthetic proof-of-work test network, which
+	/// activates the Byzantium and Constantinople rules from genesis and
+	/// London at Goerli's fork block. Goerli itself uses Clique proof of
+	/// authority, so its headers can't be verified with any preset. Only meant
+	/// for local test chains; no runtime uses it.
+	pub const fn pow_testnet() -> Self {
+		DifficultyConfig { forks: &POW_TESTNET_FORKS }
+	}
+
+	/// The latest fork active at `block_number`, if any.
+	pub fn fork(&self, block_number: u64) -> Option<&DifficultyFork> {
+		self.forks.iter().rev().find(|fork| block_number >= fork.fork_block)
 	}
 
 	pub fn bomb_delay(&self, block_number: u64) -> Option<BombDelay> {
-		if block_number >= self.muir_glacier_fork_block {
-			return Some(BombDelay::MuirGlacier);
-		} else if block_number >= self.constantinople_fork_block {
-			return Some(BombDelay::Constantinople);
-		} else if block_number >= self.byzantium_fork_block {
-			return Some(BombDelay::Byzantium);
-		}
-		None
+		self.fork(block_number).map(|fork| fork.bomb_delay)
 	}
 }
 
-/// This difficulty calculation follows Byzantium rules (https://eips.ethereum.org/EIPS/eip-649)
-/// and shouldn't be used to calculate difficulty prior to the Byzantium fork.
+/// Calculate the difficulty of the child of `parent` using the rules and bomb delay
+/// of the fork active at the child's block number. Difficulty can't be calculated
+/// for blocks prior to Byzantium.
 pub fn calc_difficulty(
 	config: &DifficultyConfig,
 	time: u64,
 	parent: &Header,
 ) -> Result<U256, &'static str> {
-	let bomb_delay = config.bomb_delay(parent.number + 1)
+	let fork = config.fork(parent.number + 1)
 		.ok_or("Cannot calculate difficulty for block number prior to Byzantium")?;
 
+	match fork.rules {
+		DifficultyRules::Byzantium => calc_byzantium_difficulty(fork.bomb_delay, time, parent),
+	}
+}
+
+/// This difficulty calculation follows Byzantium rules (https://eips.ethereum.org/EIPS/eip-649)
+/// and shouldn't be used to calculate difficulty prior to the Byzantium fork.
+fn calc_byzantium_difficulty(
+	bomb_delay: BombDelay,
+	time: u64,
+	parent: &Header,
+) -> Result<U256, &'static str> {
 	let block_time_div_9: i64 = time.checked_sub(parent.timestamp)
 		.ok_or("Invalid block time")
 		.and_then(|x| {
@@ -187,7 +251,7 @@ mod tests {
 				parent.ommers_hash = test_case.parent_uncles;
 
 				let difficulty = calc_difficulty(&$config, test_case.current_timestamp, &parent);
-				if $config.byzantium_fork_block > test_case.current_block_number {
+				if $config.fork(test_case.current_block_number).is_none() {
 					assert_eq!(
 						difficulty,
 						Err("Cannot calculate difficulty for block number prior to Byzantium"),
@@ -208,33 +272,26 @@ mod tests {
 		};
 	}
 
+	fn all_blocks_are(bomb_delay: BombDelay) -> DifficultyConfig {
+		let forks = vec![DifficultyFork::new(0, bomb_delay)];
+		DifficultyConfig { forks: Box::leak(forks.into_boxed_slice()) }
+	}
+
 	#[test]
 	fn byzantium_difficulty_calc_is_correct() {
-		let all_blocks_are_byzantium = DifficultyConfig {
-			byzantium_fork_block: 0,
-			constantinople_fork_block: u64::max_value(),
-			muir_glacier_fork_block: u64::max_value(),
-		};
+		let all_blocks_are_byzantium = all_blocks_are(BombDelay::Byzantium);
 		test_difficulty!("difficultyByzantium.json", all_blocks_are_byzantium);
 	}
 
 	#[test]
 	fn constantinople_difficulty_calc_is_correct() {
-		let all_blocks_are_constantinople = DifficultyConfig {
-			byzantium_fork_block: 0,
-			constantinople_fork_block: 0,
-			muir_glacier_fork_block: u64::max_value(),
-		};
+		let all_blocks_are_constantinople = all_blocks_are(BombDelay::Constantinople);
 		test_difficulty!("difficultyConstantinople.json", all_blocks_are_constantinople);
 	}
 
 	#[test]
 	fn muir_glacier_difficulty_calc_is_correct() {
-		let all_blocks_are_muir_glacier = DifficultyConfig {
-			byzantium_fork_block: 0,
-			constantinople_fork_block: 0,
-			muir_glacier_fork_block: 0,
-		};
+		let all_blocks_are_muir_glacier = all_blocks_are(BombDelay::MuirGlacier);
 		test_difficulty!("difficultyEIP2384.json", all_blocks_are_muir_glacier);
 		test_difficulty!("difficultyEIP2384_random.json", all_blocks_are_muir_glacier);
 		test_difficulty!("difficultyEIP2384_random_to20M.json", all_blocks_are_muir_glacier);
@@ -251,4 +308,54 @@ mod tests {
 		let ropsten_config = DifficultyConfig::ropsten();
 		test_difficulty!("difficultyRopsten.json", ropsten_config);
 	}
+
+	#[test]
+	fn bomb_delay_follows_fork_table() {
+		let mainnet_config = DifficultyConfig::mainnet();
+		assert_eq!(mainnet_config.bomb_delay(4369999), None);
+		assert_eq!(mainnet_config.bomb_delay(4370000), Some(BombDelay::Byzantium));
+		assert_eq!(mainnet_config.bomb_delay(9200000), Some(BombDelay::MuirGlacier));
+		assert_eq!(mainnet_config.bomb_delay(12965000), Some(BombDelay::London));
+		assert_eq!(mainnet_config.bomb_delay(13773000), Some(BombDelay::ArrowGlacier));
+		assert_eq!(mainnet_config.bomb_delay(15050000), Some(BombDelay::GrayGlacier));
+
+		let ropsten_config = DifficultyConfig::ropsten();
+		assert_eq!(ropsten_config.bomb_delay(10499401), Some(BombDelay::London));
+		assert_eq!(ropsten_config.bomb_delay(u64::max_value()), Some(BombDelay::London));
+
+		let testnet_config = DifficultyConfig::pow_testnet();
+		assert_eq!(testnet_config.bomb_delay(0), Some(BombDelay::Constantinople));
+		assert_eq!(testnet_config.bomb_delay(5062605), Some(BombDelay::London));
+	}
+
+	#[test]
+	fn later_bomb_delays_push_back_exponential_component() {
+		// Block time of 18-26s with ommers leaves the parent difficulty unchanged
+		// apart from the exponential component.
+		let mut parent: Header = Default::default();
+		parent.number = 15049999;
+		parent.timestamp = 1000;
+		parent.difficulty = 12_000_000_000_000_000u64.into();
+		let time = parent.timestamp + 18;
+
+		// Gray Glacier: (15049999 - 11399999) / 100000 - 2 = 34
+		assert_eq!(
+			calc_difficulty(&DifficultyConfig::mainnet(), time, &parent),
+			Ok(parent.difficulty + U256::from(2).pow(34.into())),
+		);
+
+		// Arrow Glacier: (15049999 - 10699999) / 100000 - 2 = 41
+		parent.number = 15049998;
+		assert_eq!(
+			calc_difficulty(&DifficultyConfig::mainnet(), time, &parent),
+			Ok(parent.difficulty + U256::from(2).pow(41.into())),
+		);
+
+		// Muir Glacier only: (15049999 - 8999999) / 100000 - 2 = 58
+		parent.number = 15049999;
+		assert_eq!(
+			calc_difficulty(&all_blocks_are(BombDelay::MuirGlacier), time, &parent),
+			Ok(parent.difficulty + U256::from(2).pow(58.into())),
+		);
+	}
 }
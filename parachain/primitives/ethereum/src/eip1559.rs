@@ -0,0 +1,131 @@
+use crate::header::Header;
+use ethereum_types::U256;
+
+/// Base fee of the first London block.
+pub const INITIAL_BASE_FEE: u64 = 1_000_000_000;
+/// Bounds the amount the base fee can change between blocks.
+pub const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+/// Bounds the maximum gas limit an EIP-1559 block may have.
+pub const ELASTICITY_MULTIPLIER: u64 = 2;
+
+const GAS_LIMIT_BOUND_DIVISOR: u64 = 1024;
+const MIN_GAS_LIMIT: u64 = 5000;
+
+/// Gas limit of `parent` as seen by EIP-1559 rules. On the fork block the parent's
+/// gas limit is scaled up by the elasticity multiplier so the gas target stays the same.
+pub fn parent_gas_limit(london_fork_block: u64, parent: &Header) -> U256 {
+	if parent.number < london_fork_block {
+		parent.gas_limit.saturating_mul(ELASTICITY_MULTIPLIER.into())
+	} else {
+		parent.gas_limit
+	}
+}
+
+/// Check that the gas limit of a London block is within the bounds allowed
+/// relative to its parent.
+pub fn verify_gas_limit(london_fork_block: u64, parent: &Header, header: &Header) -> bool {
+	let parent_gas_limit = parent_gas_limit(london_fork_block, parent);
+	let diff = if parent_gas_limit > header.gas_limit {
+		parent_gas_limit - header.gas_limit
+	} else {
+		header.gas_limit - parent_gas_limit
+	};
+
+	diff < parent_gas_limit / GAS_LIMIT_BOUND_DIVISOR
+		&& header.gas_limit >= MIN_GAS_LIMIT.into()
+}
+
+/// Calculate the expected base fee of the child of `parent`. Returns `None` if
+/// `parent` is a London block without a base fee.
+pub fn calc_base_fee(london_fork_block: u64, parent: &Header) -> Option<U256> {
+	if parent.number < london_fork_block {
+		return Some(INITIAL_BASE_FEE.into());
+	}
+
+	let parent_base_fee = parent.base_fee_per_gas?;
+	let parent_gas_target = parent.gas_limit / ELASTICITY_MULTIPLIER;
+	if parent_gas_target.is_zero() {
+		return None;
+	}
+
+	if parent.gas_used == parent_gas_target {
+		Some(parent_base_fee)
+	} else if parent.gas_used > parent_gas_target {
+		let gas_used_delta = parent.gas_used - parent_gas_target;
+		let base_fee_delta = (parent_base_fee.saturating_mul(gas_used_delta)
+			/ parent_gas_target
+			/ BASE_FEE_MAX_CHANGE_DENOMINATOR)
+			.max(U256::one());
+		Some(parent_base_fee.saturating_add(base_fee_delta))
+	} else {
+		let gas_used_delta = parent_gas_target - parent.gas_used;
+		let base_fee_delta = parent_base_fee.saturating_mul(gas_used_delta)
+			/ parent_gas_target
+			/ BASE_FEE_MAX_CHANGE_DENOMINATOR;
+		Some(parent_base_fee.saturating_sub(base_fee_delta))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	const FORK_BLOCK: u64 = 100;
+
+	fn make_header(number: u64, gas_limit: u64, gas_used: u64, base_fee: Option<u64>) -> Header {
+		Header {
+			number,
+			gas_limit: gas_limit.into(),
+			gas_used: gas_used.into(),
+			base_fee_per_gas: base_fee.map(Into::into),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn calc_base_fee_follows_parent_gas_usage() {
+		// Test vectors from go-ethereum's consensus/misc/eip1559_test.go
+		let cases = vec![
+			(10_000_000, 1_000_000_000),
+			(9_000_000, 987_500_000),
+			(11_000_000, 1_012_500_000),
+		];
+		for (gas_used, expected) in cases {
+			let parent = make_header(FORK_BLOCK, 20_000_000, gas_used, Some(INITIAL_BASE_FEE));
+			assert_eq!(calc_base_fee(FORK_BLOCK, &parent), Some(expected.into()));
+		}
+	}
+
+	#[test]
+	fn calc_base_fee_on_fork_block() {
+		let parent = make_header(FORK_BLOCK - 1, 10_000_000, 9_000_000, None);
+		assert_eq!(calc_base_fee(FORK_BLOCK, &parent), Some(INITIAL_BASE_FEE.into()));
+
+		let parent = make_header(FORK_BLOCK, 10_000_000, 9_000_000, None);
+		assert_eq!(calc_base_fee(FORK_BLOCK, &parent), None);
+	}
+
+	#[test]
+	fn calc_base_fee_increases_by_at_least_one() {
+		let parent = make_header(FORK_BLOCK, 20_000_000, 10_000_001, Some(7));
+		assert_eq!(calc_base_fee(FORK_BLOCK, &parent), Some(8.into()));
+	}
+
+	#[test]
+	fn verify_gas_limit_applies_elasticity_on_fork_block() {
+		let pre_london = make_header(FORK_BLOCK - 1, 10_000_000, 0, None);
+		let mut header = make_header(FORK_BLOCK, 20_000_000, 0, Some(INITIAL_BASE_FEE));
+		assert!(verify_gas_limit(FORK_BLOCK, &pre_london, &header));
+		header.gas_limit = 10_000_000.into();
+		assert!(!verify_gas_limit(FORK_BLOCK, &pre_london, &header));
+
+		let london = make_header(FORK_BLOCK, 20_000_000, 0, Some(INITIAL_BASE_FEE));
+		header.gas_limit = (20_000_000 + 20_000_000 / 1024 - 1).into();
+		assert!(verify_gas_limit(FORK_BLOCK, &london, &header));
+		header.gas_limit = (20_000_000 + 20_000_000 / 1024).into();
+		assert!(!verify_gas_limit(FORK_BLOCK, &london, &header));
+		header.gas_limit = (20_000_000 - 20_000_000 / 1024).into();
+		assert!(!verify_gas_limit(FORK_BLOCK, &london, &header));
+	}
+}
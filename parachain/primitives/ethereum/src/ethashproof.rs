@@ -10,7 +10,7 @@ pub use crate::ethashdata::{DAGS_MERKLE_ROOTS, DAGS_START_EPOCH};
 
 /// Ethash Params. See https://eth.wiki/en/concepts/ethash/ethash
 /// Blocks per epoch
-const EPOCH_LENGTH: u64 = 30000;
+pub const EPOCH_LENGTH: u64 = 30000;
 /// Width of mix 
 const MIX_BYTES: usize = 128;
 /// Hash length in bytes
@@ -130,7 +130,7 @@ impl EthashCache {
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Error {
-    // Epoch doesn't map to the range in DAGS_MERKLE_ROOTS
+    // No DAG Merkle root is known for the epoch
     EpochOutOfRange,
     // The merkle proof could not be verified
     InvalidMerkleProof,
@@ -156,18 +156,41 @@ impl EthashProver {
         }
     }
 
-    fn dag_merkle_root(&self, epoch: u64) -> Option<H128> {
-        DAGS_MERKLE_ROOTS.get((epoch - DAGS_START_EPOCH) as usize)
+    /// Merkle root of the DAG for `epoch` from DAGS_MERKLE_ROOTS, if the table covers it.
+    pub fn dag_merkle_root(epoch: u64) -> Option<H128> {
+        let index = epoch.checked_sub(DAGS_START_EPOCH)?;
+        DAGS_MERKLE_ROOTS.get(index as usize)
             .map(|x| H128::from(x))
     }
 
-    // Adapted fro https://github.com/near/rainbow-bridge/blob/3fcdfbc6c0011f0e1507956a81c820616fb963b4/contracts/near/eth-client/src/lib.rs#L363
+    /// First epoch after the range covered by DAGS_MERKLE_ROOTS.
+    pub fn dag_merkle_roots_end_epoch() -> u64 {
+        DAGS_START_EPOCH + DAGS_MERKLE_ROOTS.len() as u64
+    }
+
     pub fn hashimoto_merkle(
         &self,
         header_hash: H256,
         nonce: H64,
         header_number: u64,
         nodes: &[DoubleNodeWithMerkleProof],
+    ) -> Result<(H256, H256), Error> {
+        let merkle_root = Self::dag_merkle_root(header_number / EPOCH_LENGTH)
+            .ok_or(Error::EpochOutOfRange)?;
+        self.hashimoto_merkle_with_dag_root(header_hash, nonce, header_number, nodes, merkle_root)
+    }
+
+    /// Like `hashimoto_merkle`, but checks the DAG nodes against the given Merkle
+    /// root rather than the one in DAGS_MERKLE_ROOTS. The caller is responsible
+    /// for providing the root of the DAG for the epoch of `header_number`.
+    // Adapted fro https://github.com/near/rainbow-bridge/blob/3fcdfbc6c0011f0e1507956a81c820616fb963b4/contracts/near/eth-client/src/lib.rs#L363
+    pub fn hashimoto_merkle_with_dag_root(
+        &self,
+        header_hash: H256,
+        nonce: H64,
+        header_number: u64,
+        nodes: &[DoubleNodeWithMerkleProof],
+        merkle_root: H128,
     ) -> Result<(H256, H256), Error> {
         // Check that we have the expected number of nodes with proofs
         const MIXHASHES: usize = MIX_BYTES / HASH_BYTES;
@@ -176,8 +199,6 @@ impl EthashProver {
         }
     
         let epoch = header_number / EPOCH_LENGTH;
-        // Reuse single Merkle root across all the proofs
-        let merkle_root = self.dag_merkle_root(epoch).ok_or(Error::EpochOutOfRange)?;
         let full_size = ethash::get_full_size(epoch as usize);
 
         // Boxed index since ethash::hashimoto gets Fn, but not FnMut
@@ -369,6 +390,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dag_merkle_root_is_none_after_last_static_epoch() {
+        let end_epoch = EthashProver::dag_merkle_roots_end_epoch();
+        assert_eq!(end_epoch, DAGS_START_EPOCH + 512);
+        assert_eq!(
+            EthashProver::dag_merkle_root(end_epoch - 1),
+            Some(H128::from(&DAGS_MERKLE_ROOTS[511])),
+        );
+        assert_eq!(EthashProver::dag_merkle_root(end_epoch), None);
+    }
+
+    #[test]
+    fn hashimoto_merkle_with_dag_root_checks_given_root() {
+        let block_with_proofs = BlockWithProofs::from_file(&fixture_path("3.json"));
+        let header_partial_hash: H256 = hex!("481f55e00fd23652cb45ffba86a08b8d497f3b18cc2c0f14cbeb178b4c386e10").into();
+        let header_number: u64 = 3;
+        let header_nonce: H64 = hex!("2e9344e0cbde83ce").into();
+        let proofs = block_with_proofs
+            .to_double_node_with_merkle_proof_vec(DoubleNodeWithMerkleProof::from_values);
+        let prover = EthashProver::new();
+        let dag_root = EthashProver::dag_merkle_root(0).unwrap();
+
+        assert_eq!(
+            prover.hashimoto_merkle_with_dag_root(header_partial_hash, header_nonce, header_number, &proofs, dag_root),
+            prover.hashimoto_merkle(header_partial_hash, header_nonce, header_number, &proofs),
+        );
+        assert_eq!(
+            prover.hashimoto_merkle_with_dag_root(header_partial_hash, header_nonce, header_number, &proofs, H128::zero()),
+            Err(Error::InvalidMerkleProof),
+        );
+    }
+
     extern crate wasm_bindgen_test;
 
     use wasm_bindgen_test::*;
@@ -1,14 +1,15 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod difficulty;
+pub mod eip1559;
 pub mod ethashdata;
 pub mod ethashproof;
 pub mod header;
 pub mod log;
 pub mod receipt;
-mod mpt;
+pub mod mpt;
 
-pub use ethereum_types::{Address, H64, H160, H256, U256};
+pub use ethereum_types::{Address, H64, H128, H160, H256, U256};
 
 pub use header::{Bloom, Header, HeaderId};
 pub use log::Log;
@@ -2,7 +2,11 @@ use codec::{Encode, Decode};
 use sp_std::prelude::*;
 use ethereum_types::{H160, H256};
 
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
 #[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Log {
 	pub address: H160,
 	pub topics: Vec<H256>,
@@ -1,75 +1,210 @@
 //! Helper types to work with Ethereum's Merkle Patricia Trie nodes
+//! and to verify inclusion proofs against a trie root.
 
-use sp_std::convert::TryFrom;
+use sp_io::hashing::keccak_256;
 use sp_std::prelude::*;
 use ethereum_types::H256;
 
-pub trait Node {
-    fn contains_hash(&self, hash: H256) -> bool;
+/// Reason why a Merkle Patricia Trie proof failed verification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofError {
+    /// The proof contains no nodes.
+    EmptyProof,
+    /// A node is not valid RLP or not a valid trie node.
+    InvalidNode,
+    /// A node's hash doesn't match the reference held by its parent.
+    HashMismatch,
+    /// The proof ends before the key is resolved.
+    MissingNode,
+    /// The key isn't in the trie, i.e. its path diverges from the proven path.
+    KeyNotFound,
+    /// The proof contains nodes that are not on the path of the key.
+    UnusedNodes,
 }
 
-impl TryFrom<&[u8]> for Box<dyn Node> {
-    type Error = rlp::DecoderError;
+/// Verify an inclusion proof for `key` and return the root of the trie it
+/// commits to together with the value stored at `key`.
+///
+/// `proof` holds the RLP-encoded nodes on the path of `key`, ordered from the
+/// root to the node holding the value. Nodes whose encoding is shorter than
+/// 32 bytes are embedded in their parent and must not be listed separately.
+/// The caller is expected to compare the returned root against a trusted one.
+pub fn verify_proof(key: &[u8], proof: &[Vec<u8>]) -> Result<(H256, Vec<u8>), ProofError> {
+    let mut nodes = proof.iter();
+    let root_node = nodes.next().ok_or(ProofError::EmptyProof)?;
+    let root: H256 = keccak_256(root_node).into();
 
-    fn try_from(bytes: &[u8]) -> Result<Box<dyn Node>, Self::Error> {
-        let rlp = rlp::Rlp::new(bytes);
-        match rlp.item_count()? {
-            2 => {
-                let node: ShortNode = rlp.as_val()?;
-                Ok(Box::new(node))
+    let key_nibbles = to_nibbles(key);
+    let mut path = key_nibbles.as_slice();
+    let mut node_bytes = root_node.clone();
+
+    loop {
+        let child = match decode_node(&node_bytes)? {
+            DecodedNode::Full(node) => match path.split_first() {
+                Some((&nibble, rest)) => {
+                    path = rest;
+                    node.children[nibble as usize].clone().ok_or(ProofError::KeyNotFound)?
+                }
+                None => return finish(nodes, root, node.value.ok_or(ProofError::KeyNotFound)?),
+            },
+            DecodedNode::Short(node) => {
+                let (node_path, is_leaf) = node.decode_path().ok_or(ProofError::InvalidNode)?;
+                if !path.starts_with(&node_path) {
+                    return Err(ProofError::KeyNotFound);
+                }
+                path = &path[node_path.len()..];
+
+                if is_leaf {
+                    if !path.is_empty() {
+                        return Err(ProofError::KeyNotFound);
+                    }
+                    return finish(nodes, root, node.value);
+                }
+                node.child()
             }
-            17 => {
-                let node: FullNode = rlp.as_val()?;
-                Ok(Box::new(node))
+        };
+
+        node_bytes = match child {
+            NodeRef::Hash(hash) => {
+                let next = nodes.next().ok_or(ProofError::MissingNode)?;
+                if H256::from(keccak_256(next)) != hash {
+                    return Err(ProofError::HashMismatch);
+                }
+                next.clone()
             }
-            _ => Err(rlp::DecoderError::Custom("Invalid number of list elements"))
+            NodeRef::Inline(bytes) => bytes,
+        };
+    }
+}
+
+fn finish<'a>(
+    mut remaining: impl Iterator<Item = &'a Vec<u8>>,
+    root: H256,
+    value: Vec<u8>,
+) -> Result<(H256, Vec<u8>), ProofError> {
+    if remaining.next().is_some() {
+        return Err(ProofError::UnusedNodes);
+    }
+    Ok((root, value))
+}
+
+/// Split each byte of `key` into two nibbles, high nibble first.
+fn to_nibbles(key: &[u8]) -> Vec<u8> {
+    let mut nibbles = Vec::with_capacity(key.len() * 2);
+    for byte in key {
+        nibbles.push(byte >> 4);
+        nibbles.push(byte & 0x0f);
+    }
+    nibbles
+}
+
+enum DecodedNode {
+    Full(FullNode),
+    Short(ShortNode),
+}
+
+fn decode_node(bytes: &[u8]) -> Result<DecodedNode, ProofError> {
+    let rlp = rlp::Rlp::new(bytes);
+    let node = match rlp.item_count() {
+        Ok(2) => rlp.as_val().map(DecodedNode::Short),
+        Ok(17) => rlp.as_val().map(DecodedNode::Full),
+        _ => return Err(ProofError::InvalidNode),
+    };
+    node.map_err(|_| ProofError::InvalidNode)
+}
+
+/// Reference from a trie node to one of its children. Children whose RLP
+/// encoding is at least 32 bytes long are referenced by the keccak hash of
+/// the encoding, smaller children are embedded in their parent.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NodeRef {
+    /// Hash of the RLP-encoded child.
+    Hash(H256),
+    /// The RLP-encoded child itself.
+    Inline(Vec<u8>),
+}
+
+impl NodeRef {
+    /// Decode a child reference, returning `None` for an empty child.
+    fn decode(rlp: &rlp::Rlp) -> Result<Option<Self>, rlp::DecoderError> {
+        if rlp.is_list() {
+            return Ok(Some(NodeRef::Inline(rlp.as_raw().to_vec())));
+        }
+
+        let v: Vec<u8> = rlp.as_val()?;
+        match v.len() {
+            0 => Ok(None),
+            32 => Ok(Some(NodeRef::Hash(H256::from_slice(&v)))),
+            _ => Err(rlp::DecoderError::Custom("Expected 32-byte hash, embedded node or empty child"))
         }
     }
 }
 
-/// Intermediate trie node with children (refers to node with same name in Geth).
-/// This struct only handles the proof representation, i.e. a child is either empty
-/// or a 32-byte hash of its subtree.
+/// Branch node with 16 children and an optional value for a key that ends
+/// at this node (refers to node with same name in Geth).
 pub struct FullNode {
-    pub children: Vec<Option<H256>>
+    pub children: Vec<Option<NodeRef>>,
+    pub value: Option<Vec<u8>>,
 }
 
 impl rlp::Decodable for FullNode {
     fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
-        let children: Vec<Option<H256>> = rlp.iter()
-            .map(|item| {
-                let v: Vec<u8> = item.as_val()?;
-                match v.len() {
-                    0 => Ok(None),
-                    32 => {
-                        let mut bytes = [0u8; 32];
-                        bytes.copy_from_slice(&v);
-                        Ok(Some(bytes.into()))
-                    }
-                    _ => Err(rlp::DecoderError::Custom("Expected 32-byte hash or empty child"))
-                }
-            })
+        if rlp.item_count()? != 17 {
+            return Err(rlp::DecoderError::Custom("Expected 17 list elements"));
+        }
+
+        let children: Vec<Option<NodeRef>> = rlp.iter()
+            .take(16)
+            .map(|item| NodeRef::decode(&item))
             .collect::<Result<_, rlp::DecoderError>>()?;
 
-        Ok(Self { children })
-    }
-}
+        let value: Vec<u8> = rlp.val_at(16)?;
+        let value = if value.is_empty() { None } else { Some(value) };
 
-impl Node for FullNode {
-    fn contains_hash(&self, hash: H256) -> bool {
-        self.children.iter().find(|&h| Some(hash) == *h).is_some()
+        Ok(Self { children, value })
     }
 }
 
-/// Trie node where `value` is either the RLP-encoded item we're
-/// proving or an intermediate hash (refers to node with same name in Geth)
-/// Proof verification should return `value`. `key` is an implementation
-/// detail of the trie.
+/// Leaf or extension node (refers to node with same name in Geth).
+/// `key` is the hex-prefix encoded path of the node. For a leaf, `value`
+/// is the RLP-encoded item we're proving. For an extension, `value` is
+/// either the 32-byte hash of the child or, for a child shorter than
+/// 32 bytes, the RLP-encoded child itself.
 pub struct ShortNode {
 	pub key: Vec<u8>,
 	pub value: Vec<u8>,
 }
 
+impl ShortNode {
+    /// Decode the hex-prefix encoded path into nibbles. Returns the nibbles
+    /// and whether this node is a leaf.
+    pub fn decode_path(&self) -> Option<(Vec<u8>, bool)> {
+        let (&first, rest) = self.key.split_first()?;
+        let flag = first >> 4;
+        let is_leaf = flag & 0x2 != 0;
+        let is_odd = flag & 0x1 != 0;
+        if flag > 3 || (!is_odd && first & 0x0f != 0) {
+            return None;
+        }
+
+        let mut nibbles = Vec::with_capacity(rest.len() * 2 + 1);
+        if is_odd {
+            nibbles.push(first & 0x0f);
+        }
+        nibbles.extend(to_nibbles(rest));
+        Some((nibbles, is_leaf))
+    }
+
+    /// Reference to the child of an extension node.
+    fn child(&self) -> NodeRef {
+        if self.value.len() == 32 {
+            NodeRef::Hash(H256::from_slice(&self.value))
+        } else {
+            NodeRef::Inline(self.value.clone())
+        }
+    }
+}
+
 impl rlp::Decodable for ShortNode {
     fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
         let mut iter = rlp.iter();
@@ -80,6 +215,7 @@ impl rlp::Decodable for ShortNode {
         };
 
         let value: Vec<u8> = match iter.next() {
+            Some(data) if data.is_list() => data.as_raw().to_vec(),
             Some(data) => data.as_val()?,
             None => return Err(rlp::DecoderError::Custom("Expected value bytes"))
         };
@@ -88,17 +224,13 @@ impl rlp::Decodable for ShortNode {
     }
 }
 
-impl Node for ShortNode {
-    fn contains_hash(&self, hash: H256) -> bool {
-        self.value == hash.0
-    }
-}
-
 #[cfg(test)]
 mod tests {
 
     use super::*;
     use hex_literal::hex;
+    use rand::{Rng, SeedableRng, rngs::StdRng};
+    use std::collections::BTreeMap;
 
     const RAW_PROOF: [&[u8]; 3] = [
         &hex!("f90131a0b5ba404eb5a6a88e56579f4d37ef9813b5ad7f86f0823ff3b407ac5a6bb465eca0398ead2655e78e03c127ce22c5830e90f18b1601ec055f938336c084feb915a9a026d322c26e46c50942c1aabde50e36df5cde572aed650ce73ea3182c6e90a02ca00600a356135f4db1db0d9842264cdff2652676f881669e91e316c0b6dd783011a0837f1deb4075336da320388c1edfffc56c448a43f4a5ba031300d32a7b509fc5a01c3ac82fd65b4aba7f9afaf604d9c82ec7e2deb573a091ae235751bc5c0c288da05d454159d9071b0f68b6e0503d290f23ac7602c1db0c569dee4605d8f5298f09a00bbed10350ec954448df795f6fd46e3faefc800ede061b3840eedc6e2b07a74da0acb02d26a3650f2064c14a435fdf1f668d8655daf455ebdf671713a7c089b3898080808080808080"),
@@ -110,10 +242,12 @@ mod tests {
     fn decode_full_node() {
         let node1: FullNode = rlp::decode(RAW_PROOF[0]).unwrap();
         let node2: FullNode = rlp::decode(RAW_PROOF[1]).unwrap();
-        assert_eq!(node1.children.len(), 17);
-        assert_eq!(node2.children.len(), 17);
-        assert_eq!(node1.children.iter().filter(|c| c.is_none()).count(), 8);
-        assert_eq!(node2.children.iter().filter(|c| c.is_none()).count(), 2);
+        assert_eq!(node1.children.len(), 16);
+        assert_eq!(node2.children.len(), 16);
+        assert_eq!(node1.children.iter().filter(|c| c.is_none()).count(), 7);
+        assert_eq!(node2.children.iter().filter(|c| c.is_none()).count(), 1);
+        assert!(node1.value.is_none());
+        assert!(node2.value.is_none());
 
         let result: Result<FullNode, rlp::DecoderError> = rlp::decode(RAW_PROOF[2]);
         assert!(result.is_err());
@@ -124,11 +258,254 @@ mod tests {
         // key + item value
         let node: ShortNode = rlp::decode(RAW_PROOF[2]).unwrap();
         assert_eq!(node.key, vec![32]);
-        assert!(node.value.len() > 0);
+        assert!(!node.value.is_empty());
 
         // key + item hash
         let node: ShortNode = rlp::decode(&hex!("e4820001a04fff54398cad4d05ea6abfd8b0f3b4fe14c04d7ff5f5211c5b927d9cf72ac1d8")).unwrap();
         assert_eq!(node.key, vec![0, 1]);
         assert_eq!(node.value, hex!("4fff54398cad4d05ea6abfd8b0f3b4fe14c04d7ff5f5211c5b927d9cf72ac1d8").to_vec());
     }
+
+    #[test]
+    fn decode_short_node_path() {
+        let leaf: ShortNode = rlp::decode(RAW_PROOF[2]).unwrap();
+        assert_eq!(leaf.decode_path(), Some((vec![], true)));
+
+        let extension: ShortNode = rlp::decode(&hex!("e4820001a04fff54398cad4d05ea6abfd8b0f3b4fe14c04d7ff5f5211c5b927d9cf72ac1d8")).unwrap();
+        assert_eq!(extension.decode_path(), Some((vec![0, 1], false)));
+
+        let odd_leaf = ShortNode { key: vec![0x3a, 0xbc], value: vec![1] };
+        assert_eq!(odd_leaf.decode_path(), Some((vec![0xa, 0xb, 0xc], true)));
+
+        let odd_extension = ShortNode { key: vec![0x1a], value: vec![1] };
+        assert_eq!(odd_extension.decode_path(), Some((vec![0xa], false)));
+
+        // Invalid flag, non-zero padding and empty key
+        assert_eq!(ShortNode { key: vec![0x40], value: vec![] }.decode_path(), None);
+        assert_eq!(ShortNode { key: vec![0x21], value: vec![] }.decode_path(), None);
+        assert_eq!(ShortNode { key: vec![], value: vec![] }.decode_path(), None);
+    }
+
+    #[test]
+    fn verify_proof_resolves_key_to_value() {
+        let proof: Vec<Vec<u8>> = RAW_PROOF.iter().map(|node| node.to_vec()).collect();
+        let leaf: ShortNode = rlp::decode(RAW_PROOF[2]).unwrap();
+
+        // Receipt at index 5, i.e. key rlp(5)
+        let (root, value) = verify_proof(&rlp::encode(&5u32), &proof).unwrap();
+        assert_eq!(root, hex!("fd5e397a84884641f53c496804f24b5276cbb8c5c9cfc2342246be8e3ce5ad02").into());
+        assert_eq!(value, leaf.value);
+    }
+
+    #[test]
+    fn verify_proof_rejects_wrong_key() {
+        let proof: Vec<Vec<u8>> = RAW_PROOF.iter().map(|node| node.to_vec()).collect();
+
+        // Same first nibbles but a longer path than the leaf
+        assert_eq!(verify_proof(&[0x05, 0x00], &proof), Err(ProofError::KeyNotFound));
+        // Diverges at the second branch, so the next node doesn't match the child
+        assert_eq!(verify_proof(&rlp::encode(&6u32), &proof), Err(ProofError::HashMismatch));
+        // Ends at the second branch, which holds no value
+        assert_eq!(verify_proof(&[0x0], &proof), Err(ProofError::KeyNotFound));
+    }
+
+    #[test]
+    fn verify_proof_rejects_malformed_proofs() {
+        let proof: Vec<Vec<u8>> = RAW_PROOF.iter().map(|node| node.to_vec()).collect();
+        let key = rlp::encode(&5u32);
+
+        assert_eq!(verify_proof(&key, &[]), Err(ProofError::EmptyProof));
+        assert_eq!(verify_proof(&key, &proof[0..2]), Err(ProofError::MissingNode));
+        assert_eq!(
+            verify_proof(&key, &[proof[0].clone(), proof[2].clone()]),
+            Err(ProofError::HashMismatch),
+        );
+        assert_eq!(
+            verify_proof(&key, &[proof.clone(), vec![proof[2].clone()]].concat()),
+            Err(ProofError::UnusedNodes),
+        );
+        assert_eq!(verify_proof(&key, &[proof[0][2..].to_vec()]), Err(ProofError::InvalidNode));
+    }
+
+    /// Minimal trie builder used as a reference implementation. Returns the
+    /// RLP-encoded root and records every node with the path leading to it.
+    fn build_trie(
+        entries: &[(Vec<u8>, Vec<u8>)],
+        prefix: Vec<u8>,
+        nodes: &mut Vec<(Vec<u8>, Vec<u8>)>,
+    ) -> Vec<u8> {
+        let depth = prefix.len();
+        let mut stream = rlp::RlpStream::new();
+
+        let common = (depth..)
+            .take_while(|&i| {
+                entries[0].0.len() > i && entries.iter().all(|(k, _)| k.len() > i && k[i] == entries[0].0[i])
+            })
+            .count();
+
+        if entries.len() == 1 {
+            stream.begin_list(2);
+            stream.append(&encode_path(&entries[0].0[depth..], true));
+            stream.append(&entries[0].1);
+        } else if common > 0 {
+            let path = entries[0].0[depth..depth + common].to_vec();
+            let child_prefix = [prefix.clone(), path.clone()].concat();
+            let child = build_trie(entries, child_prefix, nodes);
+            stream.begin_list(2);
+            stream.append(&encode_path(&path, false));
+            append_child(&mut stream, &child);
+        } else {
+            stream.begin_list(17);
+            for nibble in 0..16u8 {
+                let group: Vec<_> = entries.iter()
+                    .filter(|(k, _)| k.len() > depth && k[depth] == nibble)
+                    .cloned()
+                    .collect();
+                if group.is_empty() {
+                    stream.append_empty_data();
+                } else {
+                    let child_prefix = [prefix.clone(), vec![nibble]].concat();
+                    let child = build_trie(&group, child_prefix, nodes);
+                    append_child(&mut stream, &child);
+                }
+            }
+            match entries.iter().find(|(k, _)| k.len() == depth) {
+                Some((_, value)) => stream.append(value),
+                None => stream.append_empty_data(),
+            };
+        }
+
+        let node = stream.out().to_vec();
+        nodes.push((prefix, node.clone()));
+        node
+    }
+
+    fn append_child(stream: &mut rlp::RlpStream, child: &[u8]) {
+        if child.len() < 32 {
+            stream.append_raw(child, 1);
+        } else {
+            stream.append(&keccak_256(child).to_vec());
+        }
+    }
+
+    fn encode_path(nibbles: &[u8], is_leaf: bool) -> Vec<u8> {
+        let flag = if is_leaf { 2 } else { 0 } + (nibbles.len() % 2) as u8;
+        let mut rest = nibbles;
+        let mut encoded = vec![flag << 4];
+        if nibbles.len() % 2 == 1 {
+            encoded[0] |= nibbles[0];
+            rest = &nibbles[1..];
+        }
+        encoded.extend(rest.chunks(2).map(|pair| pair[0] << 4 | pair[1]));
+        encoded
+    }
+
+    /// Collect the proof for `key`: the root plus every hash-referenced node
+    /// on the path of `key`, ordered from the root.
+    fn build_proof(key: &[u8], nodes: &[(Vec<u8>, Vec<u8>)]) -> Vec<Vec<u8>> {
+        let path = to_nibbles(key);
+        let mut on_path: Vec<_> = nodes.iter()
+            .filter(|(prefix, node)| path.starts_with(prefix) && (prefix.is_empty() || node.len() >= 32))
+            .collect();
+        on_path.sort_by_key(|(prefix, _)| prefix.len());
+        on_path.into_iter().map(|(_, node)| node.clone()).collect()
+    }
+
+    fn random_entries(rng: &mut StdRng) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let mut entries = BTreeMap::new();
+        for _ in 0..rng.gen_range(1, 40) {
+            // Short keys over a small alphabet produce shared prefixes and keys
+            // that end at branch nodes. Short values produce embedded nodes.
+            let key: Vec<u8> = (0..rng.gen_range(1, 4)).map(|_| rng.gen_range(0, 4) * 0x11).collect();
+            let value: Vec<u8> = (0..rng.gen_range(1, 40)).map(|_| rng.gen()).collect();
+            entries.insert(key, value);
+        }
+        entries.into_iter().collect()
+    }
+
+    fn nibble_entries(entries: &[(Vec<u8>, Vec<u8>)]) -> Vec<(Vec<u8>, Vec<u8>)> {
+        entries.iter().map(|(k, v)| (to_nibbles(k), v.clone())).collect()
+    }
+
+    #[test]
+    fn verify_proof_matches_reference_trie() {
+        // Reference root for this trie is from the Ethereum trie tests.
+        let entries: Vec<(Vec<u8>, Vec<u8>)> = vec![
+            (b"do".to_vec(), b"verb".to_vec()),
+            (b"dog".to_vec(), b"puppy".to_vec()),
+            (b"doge".to_vec(), b"coin".to_vec()),
+            (b"horse".to_vec(), b"stallion".to_vec()),
+        ];
+        let mut nodes = Vec::new();
+        let root_node = build_trie(&nibble_entries(&entries), vec![], &mut nodes);
+        let expected_root: H256 = hex!("5991bb8c6514148a29db676a14ac506cd2cd5775ace63c30a4fe457715e9ac84").into();
+        assert_eq!(H256::from(keccak_256(&root_node)), expected_root);
+
+        // "do" ends at a branch node, and small nodes are embedded in their parent
+        for (key, value) in entries.iter() {
+            let proof = build_proof(key, &nodes);
+            assert_eq!(verify_proof(key, &proof), Ok((expected_root, value.clone())));
+        }
+        assert!(nodes.iter().any(|(_, node)| node.len() < 32));
+
+        assert_eq!(verify_proof(b"d", &build_proof(b"d", &nodes)), Err(ProofError::KeyNotFound));
+        assert_eq!(verify_proof(b"dogs", &build_proof(b"dogs", &nodes)), Err(ProofError::KeyNotFound));
+        assert_eq!(verify_proof(b"cat", &build_proof(b"cat", &nodes)), Err(ProofError::KeyNotFound));
+    }
+
+    #[test]
+    fn verify_proof_property_random_tries() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..200 {
+            let entries = random_entries(&mut rng);
+            let mut nodes = Vec::new();
+            let root_node = build_trie(&nibble_entries(&entries), vec![], &mut nodes);
+            let root: H256 = keccak_256(&root_node).into();
+
+            for (key, value) in entries.iter() {
+                // Every key resolves to its value under the trie root
+                let proof = build_proof(key, &nodes);
+                assert_eq!(verify_proof(key, &proof), Ok((root, value.clone())));
+
+                // A proof for one key can only prove another key if the other key's
+                // path is fully covered by it, and then only with the correct value
+                let (other_key, other_value) = &entries[rng.gen_range(0, entries.len())];
+                if let Ok(result) = verify_proof(other_key, &proof) {
+                    assert_eq!(result, (root, other_value.clone()));
+                }
+
+                // Tampering with any byte of the proof never proves the original value
+                let mut tampered = proof.clone();
+                let node = rng.gen_range(0, tampered.len());
+                let byte = rng.gen_range(0, tampered[node].len());
+                tampered[node][byte] ^= rng.gen_range(1, 255);
+                assert_ne!(verify_proof(key, &tampered), Ok((root, value.clone())));
+            }
+
+            // Keys that aren't in the trie can't be proven
+            let missing_key = vec![0xff];
+            assert!(verify_proof(&missing_key, &build_proof(&missing_key, &nodes)).is_err());
+        }
+    }
+
+    #[test]
+    fn verify_proof_fuzz_does_not_panic() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for _ in 0..1000 {
+            let key: Vec<u8> = (0..rng.gen_range(0, 4)).map(|_| rng.gen()).collect();
+            let proof: Vec<Vec<u8>> = (0..rng.gen_range(0, 4))
+                .map(|_| {
+                    // Random nodes, or random bytes with a plausible list header
+                    let mut node: Vec<u8> = (0..rng.gen_range(0, 80)).map(|_| rng.gen()).collect();
+                    if rng.gen() && !node.is_empty() {
+                        node[0] = 0xc0 + (node.len() as u8 - 1).min(0x37);
+                    }
+                    node
+                })
+                .collect();
+            let _ = verify_proof(&key, &proof);
+        }
+    }
 }
@@ -3,8 +3,17 @@ use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
 use crate::{Bloom, Log};
 
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Type of a legacy (untyped) receipt.
+pub const LEGACY_RECEIPT_TYPE: u8 = 0;
+
 #[derive(Clone, Default, Encode, Decode, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Receipt {
+	/// EIP-2718 transaction type, or `LEGACY_RECEIPT_TYPE` for legacy receipts.
+	pub receipt_type: u8,
 	pub post_state_or_status: Vec<u8>,
 	pub cumulative_gas_used: u64,
 	pub bloom: Bloom,
@@ -15,10 +24,16 @@ impl Receipt {
 	pub fn contains_log(&self, log: &Log) -> bool {
 		self.logs.iter().find(|&l| l == log).is_some()
 	}
-}
 
-impl rlp::Decodable for Receipt {
-	fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+	pub fn is_legacy(&self) -> bool {
+		self.receipt_type == LEGACY_RECEIPT_TYPE
+	}
+
+	fn decode_list(receipt_type: u8, rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+		if !rlp.is_list() {
+			return Err(rlp::DecoderError::RlpExpectedToBeList)
+		}
+
 		let mut iter = rlp.iter();
 
 		let post_state_or_status: Vec<u8> = match iter.next() {
@@ -41,7 +56,30 @@ impl rlp::Decodable for Receipt {
 			None => return Err(rlp::DecoderError::Custom("Expected receipt logs")) 
 		};
 
-		Ok(Self {post_state_or_status, cumulative_gas_used, bloom, logs})
+		Ok(Self {receipt_type, post_state_or_status, cumulative_gas_used, bloom, logs})
+	}
+}
+
+impl rlp::Decodable for Receipt {
+	fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+		if rlp.is_list() {
+			return Self::decode_list(LEGACY_RECEIPT_TYPE, rlp)
+		}
+
+		// Typed receipts (EIP-2718) are `type || rlp(receipt)`. In a receipts
+		// trie the envelope is the raw value, but when nested in other RLP it
+		// is wrapped in a byte string.
+		let raw = rlp.as_raw();
+		let envelope = match raw.first() {
+			Some(&first) if first < 0x80 => raw,
+			_ => rlp.data()?,
+		};
+
+		match envelope.split_first() {
+			Some((&receipt_type, payload)) if receipt_type != LEGACY_RECEIPT_TYPE && receipt_type < 0x80 =>
+				Self::decode_list(receipt_type, &rlp::Rlp::new(payload)),
+			_ => Err(rlp::DecoderError::Custom("Invalid receipt type")),
+		}
 	}
 }
 
@@ -50,6 +88,7 @@ mod tests {
 
     use super::Receipt;
     use hex_literal::hex;
+    use sp_std::prelude::*;
 
 	const RAW_RECEIPT: [u8; 1242] = hex!("
 		f904d701830652f0b901000420000000000000000000008002000000000001000000000001000000
@@ -89,6 +128,7 @@ mod tests {
     #[test]
     fn decode_receipt() {
 		let receipt: Receipt = rlp::decode(&RAW_RECEIPT).unwrap();
+		assert!(receipt.is_legacy());
 		assert_eq!(receipt.post_state_or_status, vec!(1));
 		assert_eq!(receipt.cumulative_gas_used, 414448);
 		assert_eq!(
@@ -106,4 +146,33 @@ mod tests {
 		);
 		assert_eq!(receipt.logs.len(), 6);
     }
+
+	#[test]
+	fn decode_typed_receipt() {
+		let legacy: Receipt = rlp::decode(&RAW_RECEIPT).unwrap();
+
+		// EIP-1559 receipt as stored in the receipts trie
+		let mut envelope = vec!(2u8);
+		envelope.extend_from_slice(&RAW_RECEIPT);
+		let receipt: Receipt = rlp::decode(&envelope).unwrap();
+		assert_eq!(receipt.receipt_type, 2);
+		assert!(!receipt.is_legacy());
+		assert_eq!(receipt.logs, legacy.logs);
+
+		// Same envelope wrapped in an RLP byte string
+		let receipt: Receipt = rlp::decode(&rlp::encode(&envelope)).unwrap();
+		assert_eq!(receipt.receipt_type, 2);
+		assert_eq!(receipt.logs, legacy.logs);
+	}
+
+	#[test]
+	fn decode_typed_receipt_rejects_invalid_envelopes() {
+		let mut explicit_legacy = vec!(0u8);
+		explicit_legacy.extend_from_slice(&RAW_RECEIPT);
+		assert!(rlp::decode::<Receipt>(&explicit_legacy).is_err());
+
+		assert!(rlp::decode::<Receipt>(&[1u8]).is_err());
+		assert!(rlp::decode::<Receipt>(&rlp::encode(&vec!(1u8, 0x80))).is_err());
+		assert!(rlp::decode::<Receipt>(&[]).is_err());
+	}
 }
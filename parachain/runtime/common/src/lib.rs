@@ -2,15 +2,15 @@
 
 use frame_support::{
     parameter_types,
-    dispatch::DispatchResult,
+    dispatch::{DispatchError, DispatchResult},
     weights::{DispatchClass, Weight},
 };
 use frame_system::limits::BlockWeights;
 use sp_runtime::{ModuleId, Perbill};
 use sp_std::marker::PhantomData;
-use sp_core::H160;
+use sp_core::{H160, U256};
 
-use artemis_core::{AssetId, ChannelId};
+use artemis_core::{AssetId, ChannelId, CommitmentMode, OutboundChannelRegistry};
 
 // This function replicates BlockWeights::with_sensible_defaults but uses custom
 // base block and extrinsic weights.
@@ -42,17 +42,18 @@ pub fn build_block_weights(
 
 pub const INDEXING_PREFIX: &'static [u8] = b"commitment";
 
-pub struct OutboundRouter<T>(PhantomData<T>);
+/// Routes messages from applications to the outbound channels registered in
+/// `Channels`, a tuple of [`artemis_core::OutboundChannel`]s.
+pub struct OutboundRouter<T, Channels>(PhantomData<(T, Channels)>);
 
-impl<T> artemis_core::OutboundRouter<T::AccountId> for OutboundRouter<T>
+impl<T, Channels> artemis_core::OutboundRouter<T::AccountId> for OutboundRouter<T, Channels>
 where
-	T: basic_channel::outbound::Config + incentivized_channel::outbound::Config
+	T: frame_system::Config,
+	Channels: OutboundChannelRegistry<T::AccountId>,
 {
-	fn submit(channel_id: ChannelId, who: &T::AccountId, target: H160, payload: &[u8]) -> DispatchResult {
-		match channel_id {
-			ChannelId::Basic => basic_channel::outbound::Module::<T>::submit(who, target, payload),
-			ChannelId::Incentivized => incentivized_channel::outbound::Module::<T>::submit(who, target, payload),
-		}
+	fn submit(channel_id: ChannelId, who: &T::AccountId, target: H160, payload: &[u8], max_fee: Option<U256>) -> DispatchResult {
+		Channels::submit(channel_id, who, target, payload, max_fee)
+			.unwrap_or_else(|| Err(DispatchError::Other("Unknown channel")))
 	}
 }
 
@@ -60,6 +61,16 @@ parameter_types! {
 	pub const Ether: AssetId = AssetId::ETH;
 	pub const MaxMessagePayloadSize: usize = 256;
 	pub const MaxMessagesPerCommit: usize = 20;
+	pub const MaxBytesPerCommit: usize = 4096;
+	pub const MaxQueuedMessages: u64 = 1000;
+	// The inbound channels on Ethereum verify the hash of the whole batch
+	pub const OutboundCommitmentMode: CommitmentMode = CommitmentMode::Batch;
+	pub const MaxPendingMessages: u64 = 32;
+	pub const MaxMessagesPerBatch: u32 = 16;
+	// Generous upper bound on the weight of calls dispatched from Ethereum
+	pub const MaxMessageWeight: Weight = 10_000_000_000;
+	// Keep failed messages for retrying for 7 days of 6 second blocks
+	pub const FailedMessageRetention: u32 = 7 * 24 * 600;
 }
 
 parameter_types! {
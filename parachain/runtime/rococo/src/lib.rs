@@ -8,7 +8,7 @@
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 use sp_core::{
-	U256, crypto::KeyTypeId, OpaqueMetadata,
+	H160, U256, crypto::KeyTypeId, OpaqueMetadata,
 	u32_trait::{_1, _2},
 };
 use sp_runtime::{
@@ -50,6 +50,8 @@ pub use artemis_core::{AssetId, ChannelId, MessageId};
 use dispatch::EnsureEthereumAccount;
 
 pub use verifier_lightclient::{EthereumHeader, EthereumDifficultyConfig};
+pub use dispatch::CallPermission;
+pub use incentivized_channel::outbound::FeeCurve;
 
 use polkadot_parachain::primitives::Sibling;
 use xcm::v0::{Junction, MultiLocation, NetworkId};
@@ -70,6 +72,13 @@ use runtime_common::{
 	Ether,
 	MaxMessagePayloadSize,
 	MaxMessagesPerCommit,
+	MaxBytesPerCommit,
+	MaxQueuedMessages,
+	OutboundCommitmentMode,
+	MaxPendingMessages,
+	MaxMessagesPerBatch,
+	MaxMessageWeight,
+	FailedMessageRetention,
 	DotModuleId,
 	TreasuryModuleId,
 };
@@ -427,12 +436,40 @@ impl Filter<Call> for CallFilter {
 	}
 }
 
+/// Calls that Ethereum accounts with a call permission for them make as their
+/// derived parachain accounts.
+pub struct SignedCallFilter;
+impl Filter<Call> for SignedCallFilter {
+	fn filter(call: &Call) -> bool {
+		match call {
+			Call::Balances(pallet_balances::Call::transfer(..))
+			| Call::Balances(pallet_balances::Call::transfer_keep_alive(..))
+			| Call::Assets(assets::Call::transfer(..)) => true,
+			_ => false
+		}
+	}
+}
+
+parameter_types! {
+	/// The apps allowed by `CallFilter` before call permissions were introduced.
+	pub AppCallPermissions: Vec<(H160, CallPermission)> = vec![
+		(DOT::address(), CallPermission::Pallet(b"DOT".to_vec())),
+		(ETH::address(), CallPermission::Pallet(b"ETH".to_vec())),
+		(ERC20::address(), CallPermission::Pallet(b"ERC20".to_vec())),
+	];
+}
+
 impl dispatch::Config for Runtime {
 	type Origin = Origin;
 	type Event = Event;
 	type MessageId = MessageId;
 	type Call = Call;
 	type CallFilter = CallFilter;
+	type SignedCallFilter = SignedCallFilter;
+	type AccountIdConverter = dispatch::HashedEthereumAccount<AccountId>;
+	type MaxMessageWeight = MaxMessageWeight;
+	type FailedMessageRetention = FailedMessageRetention;
+	type UpdateOrigin = EnsureRootOrHalfLocalCouncil;
 }
 
 use basic_channel::inbound as basic_channel_inbound;
@@ -440,10 +477,22 @@ use incentivized_channel::inbound as incentivized_channel_inbound;
 use basic_channel::outbound as basic_channel_outbound;
 use incentivized_channel::outbound as incentivized_channel_outbound;
 
+/// Outbound channels that applications can submit messages to, keyed by their
+/// `ChannelId`.
+pub type OutboundChannels = (BasicOutboundChannel, IncentivizedOutboundChannel);
+
+/// Inbound channels that relayers can submit messages from Ethereum to, keyed
+/// by their `ChannelId`.
+pub type InboundChannels = (BasicInboundChannel, IncentivizedInboundChannel);
+
 impl basic_channel_inbound::Config for Runtime {
 	type Event = Event;
 	type Verifier = verifier_lightclient::Module<Runtime>;
 	type MessageDispatch = dispatch::Module<Runtime>;
+	type MessageDelivery = BasicOutboundChannel;
+	type MaxPendingMessages = MaxPendingMessages;
+	type MaxMessagesPerBatch = MaxMessagesPerBatch;
+	type UpdateOrigin = EnsureRootOrHalfLocalCouncil;
 	type WeightInfo = weights::basic_channel_inbound_weights::WeightInfo<Runtime>;
 }
 
@@ -452,7 +501,10 @@ impl basic_channel_outbound::Config for Runtime {
 	type Event = Event;
 	type Hashing = Keccak256;
 	type MaxMessagePayloadSize = MaxMessagePayloadSize;
+	type CommitmentMode = OutboundCommitmentMode;
 	type MaxMessagesPerCommit = MaxMessagesPerCommit;
+	type MaxBytesPerCommit = MaxBytesPerCommit;
+	type MaxQueuedMessages = MaxQueuedMessages;
 	type SetPrincipalOrigin = EnsureRootOrHalfLocalCouncil;
 	type WeightInfo = weights::basic_channel_outbound_weights::WeightInfo<Runtime>;
 }
@@ -475,6 +527,9 @@ impl incentivized_channel_inbound::Config for Runtime {
 	type Event = Event;
 	type Verifier = verifier_lightclient::Module<Runtime>;
 	type MessageDispatch = dispatch::Module<Runtime>;
+	type MessageDelivery = IncentivizedOutboundChannel;
+	type MaxPendingMessages = MaxPendingMessages;
+	type MaxMessagesPerBatch = MaxMessagesPerBatch;
 	type Currency = Balances;
 	type SourceAccount = SourceAccount;
 	type TreasuryAccount = TreasuryAccount;
@@ -488,29 +543,31 @@ impl incentivized_channel_outbound::Config for Runtime {
 	type Event = Event;
 	type Hashing = Keccak256;
 	type MaxMessagePayloadSize = MaxMessagePayloadSize;
+	type CommitmentMode = OutboundCommitmentMode;
 	type MaxMessagesPerCommit = MaxMessagesPerCommit;
+	type MaxBytesPerCommit = MaxBytesPerCommit;
+	type MaxQueuedMessages = MaxQueuedMessages;
 	type FeeCurrency = SingleAssetAdaptor<Runtime, Ether>;
 	type SetFeeOrigin = EnsureRootOrHalfLocalCouncil;
 	type WeightInfo = weights::incentivized_channel_outbound_weights::WeightInfo<Runtime>;
 }
 
-pub const ROPSTEN_DIFFICULTY_CONFIG: EthereumDifficultyConfig = EthereumDifficultyConfig {
-	byzantium_fork_block: 1700000,
-	constantinople_fork_block: 4230000,
-	muir_glacier_fork_block: 7117117,
-};
+pub const ROPSTEN_DIFFICULTY_CONFIG: EthereumDifficultyConfig = EthereumDifficultyConfig::ropsten();
 
 parameter_types! {
+	/// Finality depth written to verifier storage by the runtime upgrade migration
 	pub const DescendantsUntilFinalized: u8 = 3;
 	pub const DifficultyConfig: EthereumDifficultyConfig = ROPSTEN_DIFFICULTY_CONFIG;
+	pub const LondonForkBlock: u64 = 10_499_401;
 	pub const VerifyPoW: bool = true;
 }
 
 impl verifier_lightclient::Config for Runtime {
 	type Event = Event;
-	type DescendantsUntilFinalized = DescendantsUntilFinalized;
 	type DifficultyConfig = DifficultyConfig;
 	type VerifyPoW = VerifyPoW;
+	type LondonForkBlock = LondonForkBlock;
+	type UpdateOrigin = EnsureRootOrHalfLocalCouncil;
 	type WeightInfo = weights::verifier_lightclient_weights::WeightInfo<Runtime>;
 }
 
@@ -526,7 +583,7 @@ parameter_types! {
 impl eth_app::Config for Runtime {
 	type Event = Event;
 	type Asset = assets::SingleAssetAdaptor<Runtime, EthAssetId>;
-	type OutboundRouter = OutboundRouter<Runtime>;
+	type OutboundRouter = OutboundRouter<Runtime, OutboundChannels>;
 	type CallOrigin = EnsureEthereumAccount;
 	type WeightInfo = weights::eth_app_weights::WeightInfo<Runtime>;
 }
@@ -534,7 +591,7 @@ impl eth_app::Config for Runtime {
 impl erc20_app::Config for Runtime {
 	type Event = Event;
 	type Assets = assets::Module<Runtime>;
-	type OutboundRouter = OutboundRouter<Runtime>;
+	type OutboundRouter = OutboundRouter<Runtime, OutboundChannels>;
 	type CallOrigin = EnsureEthereumAccount;
 	type WeightInfo = weights::erc20_app_weights::WeightInfo<Runtime>;
 }
@@ -546,7 +603,7 @@ parameter_types! {
 impl dot_app::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
-	type OutboundRouter = OutboundRouter<Runtime>;
+	type OutboundRouter = OutboundRouter<Runtime, OutboundChannels>;
 	type CallOrigin = EnsureEthereumAccount;
 	type ModuleId = DotModuleId;
 	type Decimals = Decimals;
@@ -571,11 +628,11 @@ construct_runtime!(
 		LocalCouncil: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>} = 7,
 		LocalCouncilMembership: pallet_membership::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>} = 8,
 
-		BasicInboundChannel: basic_channel_inbound::{Pallet, Call, Config, Storage, Event} = 9,
+		BasicInboundChannel: basic_channel_inbound::{Pallet, Call, Config, Storage, Event<T>} = 9,
 		BasicOutboundChannel: basic_channel_outbound::{Pallet, Config<T>, Storage, Event} = 10,
-		IncentivizedInboundChannel: incentivized_channel_inbound::{Pallet, Call, Config, Storage, Event} = 11,
+		IncentivizedInboundChannel: incentivized_channel_inbound::{Pallet, Call, Config, Storage, Event<T>} = 11,
 		IncentivizedOutboundChannel: incentivized_channel_outbound::{Pallet, Config<T>, Storage, Event} = 12,
-		Dispatch: dispatch::{Pallet, Call, Storage, Event<T>, Origin} = 13,
+		Dispatch: dispatch::{Pallet, Call, Config, Storage, Event<T>, Origin} = 13,
 		VerifierLightclient: verifier_lightclient::{Pallet, Call, Storage, Event, Config} = 14,
 		Assets: assets::{Pallet, Call, Config<T>, Storage, Event<T>} = 15,
 
@@ -623,6 +680,10 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPallets,
+	(
+		verifier_lightclient::migration::InitializeDescendantsUntilFinalized<Runtime, DescendantsUntilFinalized>,
+		dispatch::migration::GrantCallPermissions<Runtime, AppCallPermissions>,
+	),
 >;
 
 impl_runtime_apis! {
@@ -704,6 +765,55 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl verifier_lightclient_runtime_api::VerifierApi<Block> for Runtime {
+		fn verify_receipt(
+			proof: artemis_core::Proof,
+		) -> Result<
+			verifier_lightclient_runtime_api::Receipt,
+			verifier_lightclient_runtime_api::ReceiptVerificationError,
+		> {
+			VerifierLightclient::verify_receipt(&proof)
+		}
+	}
+
+	impl basic_channel_runtime_api::BasicOutboundChannelApi<Block> for Runtime {
+		fn commitment_key(commitment_hash: Hash) -> Vec<u8> {
+			BasicOutboundChannel::make_offchain_key(commitment_hash)
+		}
+
+		fn message_proof(
+			messages: Vec<basic_channel_runtime_api::Message>,
+			nonce: artemis_core::MessageNonce,
+		) -> Option<artemis_core::MerkleProof> {
+			BasicOutboundChannel::make_message_proof(&messages, nonce)
+		}
+
+		fn message_status(nonce: artemis_core::MessageNonce) -> artemis_core::MessageStatus {
+			BasicOutboundChannel::message_status(nonce)
+		}
+	}
+
+	impl incentivized_channel_runtime_api::IncentivizedOutboundChannelApi<Block> for Runtime {
+		fn commitment_key(commitment_hash: Hash) -> Vec<u8> {
+			IncentivizedOutboundChannel::make_offchain_key(commitment_hash)
+		}
+
+		fn message_proof(
+			messages: Vec<incentivized_channel_runtime_api::Message>,
+			nonce: artemis_core::MessageNonce,
+		) -> Option<artemis_core::MerkleProof> {
+			IncentivizedOutboundChannel::make_message_proof(&messages, nonce)
+		}
+
+		fn quote_fee(payload_len: u32) -> U256 {
+			IncentivizedOutboundChannel::quote_fee(payload_len)
+		}
+
+		fn message_status(nonce: artemis_core::MessageNonce) -> artemis_core::MessageStatus {
+			IncentivizedOutboundChannel::message_status(nonce)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(uxt: <Block as BlockT>::Extrinsic, len: u32) -> RuntimeDispatchInfo<Balance> {
 			TransactionPayment::query_info(uxt, len)
@@ -761,3 +871,36 @@ impl_runtime_apis! {
 }
 
 cumulus_pallet_parachain_system::register_validate_block!(Runtime, Executive);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::weights::{DispatchClass, GetDispatchInfo};
+	use artemis_core::{Message, Proof};
+
+	fn message() -> Message {
+		Message {
+			data: Vec::new(),
+			proof: Proof {
+				block_hash: Default::default(),
+				tx_index: 0,
+				data: (Vec::new(), Vec::new()),
+			},
+		}
+	}
+
+	#[test]
+	fn full_message_batch_fits_in_block() {
+		let max_extrinsic = BlockWeights::get()
+			.get(DispatchClass::Normal)
+			.max_extrinsic
+			.expect("Normal extrinsics have a max weight");
+		let messages = vec![message(); MaxMessagesPerBatch::get() as usize];
+
+		let call = Call::BasicInboundChannel(basic_channel_inbound::Call::submit_batch(messages.clone()));
+		assert!(call.get_dispatch_info().weight <= max_extrinsic);
+
+		let call = Call::IncentivizedInboundChannel(incentivized_channel_inbound::Call::submit_batch(messages));
+		assert!(call.get_dispatch_info().weight <= max_extrinsic);
+	}
+}
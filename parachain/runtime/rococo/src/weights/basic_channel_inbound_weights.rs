@@ -37,7 +37,29 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> basic_channel::inbound::WeightInfo for WeightInfo<T> {
 	fn submit() -> Weight {
 		(176_439_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	fn submit_batch(m: u32, ) -> Weight {
+		(6_498_000 as Weight)
+			// Standard Error: 41_000
+			.saturating_add((170_157_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(m as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(m as Weight)))
+	}
+	fn register_source_channel() -> Weight {
+		(12_358_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_source_channel_sunset() -> Weight {
+		(13_120_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_receipt_channel() -> Weight {
+		(11_046_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
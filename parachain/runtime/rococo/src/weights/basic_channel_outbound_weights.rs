@@ -42,7 +42,9 @@ impl<T: frame_system::Config> basic_channel::outbound::WeightInfo for WeightInfo
 			// Standard Error: 18_000
 			.saturating_add((907_000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(m as Weight)))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
 	}
 	fn on_initialize_non_interval() -> Weight {
 		(5_091_000 as Weight)
@@ -50,7 +52,7 @@ impl<T: frame_system::Config> basic_channel::outbound::WeightInfo for WeightInfo
 	}
 	fn on_initialize_no_messages() -> Weight {
 		(8_651_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 	}
 	fn set_principal() -> Weight {
 		(3_000_000 as Weight)
@@ -37,11 +37,38 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> incentivized_channel::inbound::WeightInfo for WeightInfo<T> {
 	fn submit() -> Weight {
 		(256_924_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
+	fn submit_batch(m: u32, ) -> Weight {
+		(7_046_000 as Weight)
+			// Standard Error: 41_000
+			.saturating_add((250_082_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((8 as Weight).saturating_mul(m as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(m as Weight)))
+	}
 	fn set_reward_fraction() -> Weight {
 		(3_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn claim_rewards() -> Weight {
+		(66_104_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn register_source_channel() -> Weight {
+		(12_358_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_source_channel_sunset() -> Weight {
+		(13_120_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_receipt_channel() -> Weight {
+		(11_046_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
@@ -42,7 +42,9 @@ impl<T: frame_system::Config> incentivized_channel::outbound::WeightInfo for Wei
 			// Standard Error: 18_000
 			.saturating_add((907_000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(m as Weight)))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
 	}
 	fn on_initialize_non_interval() -> Weight {
 		(5_091_000 as Weight)
@@ -50,9 +52,9 @@ impl<T: frame_system::Config> incentivized_channel::outbound::WeightInfo for Wei
 	}
 	fn on_initialize_no_messages() -> Weight {
 		(8_651_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 	}
-	fn set_fee() -> Weight {
+	fn set_fee_config() -> Weight {
 		(3_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
@@ -40,6 +40,12 @@ impl<T: frame_system::Config> verifier_lightclient::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(17 as Weight))
 			.saturating_add(T::DbWeight::get().writes(22 as Weight))
 	}
+	fn import_headers(n: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((1_433_779_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((17 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((22 as Weight).saturating_mul(n as Weight)))
+	}
 	fn import_header_not_new_finalized_with_max_prune() -> Weight {
 		(1_398_977_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(16 as Weight))
@@ -55,4 +61,23 @@ impl<T: frame_system::Config> verifier_lightclient::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
 			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
+	fn set_descendants_until_finalized() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_finalized_headers_to_keep() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_headers_to_prune_in_single_import() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn append_dag_merkle_roots(n: u32, ) -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
 }
@@ -6,9 +6,11 @@ use rococo_runtime::{
 	SystemConfig, VerifierLightclientConfig,
 	BasicInboundChannelConfig, IncentivizedInboundChannelConfig,
 	ETHConfig, ERC20Config, DOTConfig, AssetsConfig,
+	DispatchConfig, CallPermission,
 	ParachainInfoConfig,
 	BasicOutboundChannelConfig,
 	IncentivizedOutboundChannelConfig,
+	FeeCurve,
 	LocalCouncilMembershipConfig,
 	SudoConfig,
 	WASM_BINARY, Signature,
@@ -127,18 +129,22 @@ fn testnet_genesis(
 			phantom: Default::default()
 		},
 		basic_channel_inbound: BasicInboundChannelConfig {
-			source_channel: hex!["EE9170ABFbf9421Ad6DD07F6BDec9D89F2B581E0"].into(),
+			source_channels: vec![hex!["EE9170ABFbf9421Ad6DD07F6BDec9D89F2B581E0"].into()],
 		},
 		basic_channel_outbound: BasicOutboundChannelConfig {
 			principal: get_account_id_from_seed::<sr25519::Public>("Alice"),
 			interval: 1,
 		},
 		incentivized_channel_inbound: IncentivizedInboundChannelConfig {
-			source_channel: hex!["B8EA8cB425d85536b158d661da1ef0895Bb92F1D"].into(),
+			source_channels: vec![hex!["B8EA8cB425d85536b158d661da1ef0895Bb92F1D"].into()],
 			reward_fraction: Perbill::from_percent(80)
 		},
 		incentivized_channel_outbound: IncentivizedOutboundChannelConfig {
-			fee: U256::from_str_radix("10000000000000000", 10).unwrap(), // 0.01 SnowEther
+			fee_config: FeeCurve {
+				base: U256::from_str_radix("10000000000000000", 10).unwrap(), // 0.01 SnowEther
+				per_byte: U256::zero(),
+				congestion_multiplier: 1,
+			},
 			interval: 1,
 		},
 		assets: AssetsConfig {
@@ -169,8 +175,12 @@ fn testnet_genesis(
 					vec![ 160, 3, 99, 254, 41, 148, 9, 136, 202, 4, 55, 19, 132, 10, 201, 17, 179, 47, 42, 203, 77, 1, 14, 85, 150, 63, 45, 32, 29, 121, 249, 171, 87 ],
 					vec![ 136, 138, 229, 192, 112, 137, 44, 183, 12 ],
 				],
+				base_fee_per_gas: None,
 			},
 			initial_difficulty: 19755084633726428633088u128.into(),
+			descendants_until_finalized: 3,
+			finalized_headers_to_keep: 50_000,
+			headers_to_prune_in_single_import: 8,
 		},
 		eth_app: ETHConfig {
 			address: hex!["8cF6147918A5CBb672703F879f385036f8793a24"].into()
@@ -182,6 +192,13 @@ fn testnet_genesis(
 			address: hex!["4283d8996E5a7F4BEa58c6052b1471a2a9524C87"].into(),
 			phantom: Default::default(),
 		},
+		dispatch: DispatchConfig {
+			permissions: vec![
+				(hex!["4283d8996E5a7F4BEa58c6052b1471a2a9524C87"].into(), CallPermission::Pallet(b"DOT".to_vec())),
+				(hex!["8cF6147918A5CBb672703F879f385036f8793a24"].into(), CallPermission::Pallet(b"ETH".to_vec())),
+				(hex!["3f0839385DB9cBEa8E73AdA6fa0CFe07E321F61d"].into(), CallPermission::Pallet(b"ERC20".to_vec())),
+			],
+		},
 		parachain_info: ParachainInfoConfig { parachain_id: para_id },
 	}
 }
@@ -0,0 +1,44 @@
+//! Parachain-specific RPC extensions.
+
+use std::sync::Arc;
+
+use sc_client_api::Backend;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use artemis_basic_channel_rpc::{
+	BasicOutboundChannel, BasicOutboundChannelApi, BasicOutboundChannelRuntimeApi,
+};
+use artemis_incentivized_channel_rpc::{
+	IncentivizedOutboundChannel, IncentivizedOutboundChannelApi,
+	IncentivizedOutboundChannelRuntimeApi,
+};
+use pallet_verifier_lightclient_rpc::{Verifier, VerifierApi, VerifierRuntimeApi};
+
+/// Instantiate all RPC extensions for a full node.
+///
+/// The outbound channel RPCs are only available if the backend has offchain storage.
+pub fn create_full<C, B, Block>(client: Arc<C>, backend: Arc<B>) -> jsonrpc_core::IoHandler<sc_rpc::Metadata>
+where
+	Block: BlockT,
+	B: Backend<Block>,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: VerifierRuntimeApi<Block>,
+	C::Api: BasicOutboundChannelRuntimeApi<Block>,
+	C::Api: IncentivizedOutboundChannelRuntimeApi<Block>,
+{
+	let mut io = jsonrpc_core::IoHandler::default();
+	io.extend_with(VerifierApi::to_delegate(Verifier::new(client.clone())));
+
+	if let Some(storage) = backend.offchain_storage() {
+		io.extend_with(BasicOutboundChannelApi::to_delegate(
+			BasicOutboundChannel::new(client.clone(), storage.clone()),
+		));
+		io.extend_with(IncentivizedOutboundChannelApi::to_delegate(
+			IncentivizedOutboundChannel::new(client, storage),
+		));
+	}
+
+	io
+}
@@ -141,6 +141,7 @@ async fn start_node_impl<RB>(
 where
 	RB: Fn(
 			Arc<TFullClient<Block, RuntimeApi, Executor>>,
+			Arc<TFullBackend<Block>>,
 		) -> jsonrpc_core::IoHandler<sc_rpc::Metadata>
 		+ Send
 		+ 'static,
@@ -194,7 +195,10 @@ where
 		})?;
 
 	let rpc_client = client.clone();
-	let rpc_extensions_builder = Box::new(move |_, _| rpc_ext_builder(rpc_client.clone()));
+	let rpc_backend = backend.clone();
+	let rpc_extensions_builder = Box::new(move |_, _| {
+		rpc_ext_builder(rpc_client.clone(), rpc_backend.clone())
+	});
 
 	sc_service::spawn_tasks(sc_service::SpawnTasksParams {
 		on_demand: None,
@@ -281,7 +285,7 @@ pub async fn start_node(
 		polkadot_config,
 		id,
 		validator,
-		|_| Default::default(),
+		|client, backend| crate::rpc::create_full(client, backend),
 	)
 	.await
 }
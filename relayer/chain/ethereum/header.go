@@ -22,6 +22,28 @@ type HeaderID struct {
 	Hash   types.H256
 }
 
+// OptionU256 mirrors a SCALE-encoded Option<U256>
+type OptionU256 struct {
+	HasValue bool
+	Value    types.U256
+}
+
+func NewOptionU256(value types.U256) OptionU256 {
+	return OptionU256{HasValue: true, Value: value}
+}
+
+func NewOptionU256Empty() OptionU256 {
+	return OptionU256{HasValue: false}
+}
+
+func (o OptionU256) Encode(encoder scale.Encoder) error {
+	return encoder.EncodeOption(o.HasValue, o.Value)
+}
+
+func (o *OptionU256) Decode(decoder scale.Decoder) error {
+	return decoder.DecodeOption(&o.HasValue, &o.Value)
+}
+
 type headerSCALE struct {
 	ParentHash       types.H256
 	Timestamp        types.U64
@@ -37,6 +59,7 @@ type headerSCALE struct {
 	GasLimit         types.U256
 	Difficulty       types.U256
 	Seal             []types.Bytes
+	BaseFeePerGas    OptionU256
 }
 
 type Header struct {
@@ -119,6 +142,11 @@ func MakeHeaderData(gethheader *etypes.Header) (*Header, error) {
 		return nil, err
 	}
 
+	baseFeePerGas := NewOptionU256Empty()
+	if gethheader.BaseFee != nil {
+		baseFeePerGas = NewOptionU256(types.NewU256(*gethheader.BaseFee))
+	}
+
 	return &Header{
 		Fields: headerSCALE{
 			ParentHash:       types.NewH256(gethheader.ParentHash.Bytes()),
@@ -135,6 +163,7 @@ func MakeHeaderData(gethheader *etypes.Header) (*Header, error) {
 			GasLimit:         types.NewU256(gasLimit),
 			Difficulty:       types.NewU256(*gethheader.Difficulty),
 			Seal:             []types.Bytes{mixHashRLP, nonceRLP},
+			BaseFeePerGas:    baseFeePerGas,
 		},
 		header: gethheader,
 	}, nil
@@ -259,11 +259,15 @@ func (s *HeaderCacheState) GetEthashproofCache(number uint64) (*ethashproof.Data
 
 func (s *HeaderCacheState) makeTrie(items gethTypes.DerivableList) *gethTrie.Trie {
 	keybuf := new(bytes.Buffer)
+	valbuf := new(bytes.Buffer)
 	trie := new(gethTrie.Trie)
 	for i := 0; i < items.Len(); i++ {
 		keybuf.Reset()
 		rlp.Encode(keybuf, uint(i))
-		trie.Update(keybuf.Bytes(), items.GetRlp(i))
+		// Typed receipts and transactions are encoded with their type prefix
+		valbuf.Reset()
+		items.EncodeIndex(i, valbuf)
+		trie.Update(keybuf.Bytes(), gethCommon.CopyBytes(valbuf.Bytes()))
 	}
 	return trie
 }
@@ -115,6 +115,8 @@ func TestHeader_EncodeDecode11090290(t *testing.T) {
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 132, 160, 190, 58, 223, 176, 8, 123, 230, 43,
 		40, 183, 22, 226, 205, 243, 199, 147, 41, 223, 92, 170, 4, 201, 238, 224, 53, 211, 91, 93, 82,
 		16, 40, 21, 36, 136, 105, 53, 187, 231, 182, 60, 79, 142,
+		// No base fee before London
+		0,
 	}
 
 	header, err := ethereum.MakeHeaderData(&gethHeader)
@@ -136,6 +138,77 @@ func TestHeader_EncodeDecode11090290(t *testing.T) {
 	assert.Equal(t, header.Fields, decoded.Fields, "Decoded Substrate header should match ethereum.Header")
 }
 
+// Sepolia genesis block, which follows the London header format
+func gethHeaderSepoliaGenesis() etypes.Header {
+	json := `{
+		"baseFeePerGas": "0x3b9aca00",
+		"difficulty": "0x20000",
+		"extraData": "0x5365706f6c69612c20417468656e732c204174746963612c2047726565636521",
+		"gasLimit": "0x1c9c380",
+		"gasUsed": "0x0",
+		"hash": "0x25a5cc106eea7138acab33231d7160d69cb777ee0c2c553fcddf5138993e6dd9",
+		"logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+		"miner": "0x0000000000000000000000000000000000000000",
+		"mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+		"nonce": "0x0000000000000000",
+		"number": "0x0",
+		"parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+		"receiptsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+		"sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+		"stateRoot": "0x5eb6e371a698b8d68f665192350ffcecbbbf322916f4b51bd79bb6887da3f494",
+		"timestamp": "0x6159af19",
+		"transactionsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+	}`
+
+	var header etypes.Header
+	header.UnmarshalJSON([]byte(json))
+	if header.Hash() != ecommon.HexToHash("25a5cc106eea7138acab33231d7160d69cb777ee0c2c553fcddf5138993e6dd9") {
+		panic(fmt.Errorf("Geth header hash doesn't match the expected hash"))
+	}
+
+	return header
+}
+
+func TestHeader_EncodeDecodeLondon(t *testing.T) {
+	gethHeader := gethHeaderSepoliaGenesis()
+	// Substrate encoding of the same header, ending with Some(base fee)
+	expectedEncoded := ecommon.Hex2Bytes(
+		"000000000000000000000000000000000000000000000000000000000000000019af5961000000000000000000000000" +
+			"000000000000000000000000000000000000000056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5" +
+			"e363b4211dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347805365706f6c69612c204174" +
+			"68656e732c204174746963612c20477265656365215eb6e371a698b8d68f665192350ffcecbbbf322916f4b51bd79bb6" +
+			"887da3f49456e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b4210000000000000000000000" +
+			"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000" +
+			"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000" +
+			"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000" +
+			"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000" +
+			"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000" +
+			"0000000000000000000000000000000000000000000000000000000000000000000000000080c3c90100000000000000" +
+			"000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000" +
+			"00000000000884a000000000000000000000000000000000000000000000000000000000000000002488000000000000" +
+			"00000100ca9a3b00000000000000000000000000000000000000000000000000000000",
+	)
+
+	header, err := ethereum.MakeHeaderData(&gethHeader)
+	if err != nil {
+		panic(err)
+	}
+	assert.True(t, header.Fields.BaseFeePerGas.HasValue, "Header should have a base fee")
+
+	encoded, err := encodeToBytes(header)
+	if err != nil {
+		panic(err)
+	}
+	assert.Equal(t, expectedEncoded, encoded, "Encoded ethereum.Header should match Substrate header")
+
+	var decoded ethereum.Header
+	err = decodeFromBytes(encoded, &decoded)
+	if err != nil {
+		panic(err)
+	}
+	assert.Equal(t, header.Fields, decoded.Fields, "Decoded Substrate header should match ethereum.Header")
+}
+
 func TestProof_EncodeDecode(t *testing.T) {
 	t.Skip("Skipping test as it depends on external data.")
 
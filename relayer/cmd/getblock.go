@@ -149,7 +149,13 @@ func printEthBlockForSub(header *gethTypes.Header, format Format) error {
 	}
 
 	fmt.Println("")
+	baseFeePerGas := headerForSub.Fields.BaseFeePerGas
 	if format == RustFmt {
+		baseFee := "None"
+		if baseFeePerGas.HasValue {
+			baseFee = fmt.Sprintf("Some(%du64.into())", baseFeePerGas.Value)
+		}
+
 		fmt.Printf(
 			`EthereumHeader {
 			parent_hash: hex!("%x").into(),
@@ -169,6 +175,7 @@ func printEthBlockForSub(header *gethTypes.Header, format Format) error {
 				hex!("%x").to_vec(),
 				hex!("%x").to_vec(),
 			],
+			base_fee_per_gas: %s,
 		}`,
 			headerForSub.Fields.ParentHash,
 			header.Time,
@@ -185,6 +192,7 @@ func printEthBlockForSub(header *gethTypes.Header, format Format) error {
 			headerForSub.Fields.Difficulty,
 			headerForSub.Fields.Seal[0],
 			headerForSub.Fields.Seal[1],
+			baseFee,
 		)
 		fmt.Println("")
 	} else {
@@ -204,6 +212,10 @@ func printEthBlockForSub(header *gethTypes.Header, format Format) error {
 		if err != nil {
 			return err
 		}
+		baseFee := "null"
+		if baseFeePerGas.HasValue {
+			baseFee = fmt.Sprintf(`"%#x"`, baseFeePerGas.Value)
+		}
 
 		fmt.Printf(
 			`{
@@ -223,7 +235,8 @@ func printEthBlockForSub(header *gethTypes.Header, format Format) error {
 			"seal": [
 				%s,
 				%s
-			]
+			],
+			"base_fee_per_gas": %s
 		}`,
 			headerForSub.Fields.ParentHash.Hex(),
 			header.Time,
@@ -240,6 +253,7 @@ func printEthBlockForSub(header *gethTypes.Header, format Format) error {
 			headerForSub.Fields.Difficulty,
 			seal1,
 			seal2,
+			baseFee,
 		)
 		fmt.Println("")
 	}
@@ -7,7 +7,7 @@ require (
 	github.com/aristanetworks/goarista v0.0.0-20210107181124-fad53805024e // indirect
 	github.com/btcsuite/btcd v0.21.0-beta // indirect
 	github.com/cbergoon/merkletree v0.2.0 // indirect
-	github.com/ethereum/go-ethereum v1.9.25
+	github.com/ethereum/go-ethereum v1.10.8
 	github.com/go-ole/go-ole v1.2.5 // indirect
 	github.com/hashicorp/go-memdb v1.3.2
 	github.com/influxdata/influxdb v1.2.3-0.20180221223340-01288bdb0883
@@ -114,16 +114,16 @@ class SubClient {
     return foundData;
   }
 
-  async burnETH(account, recipient, amount, channelId) {
-    return await this.api.tx.eth.burn(channelId, recipient, amount).signAndSend(account);
+  async burnETH(account, recipient, amount, channelId, maxFee = null) {
+    return await this.api.tx.eth.burn(channelId, recipient, amount, maxFee).signAndSend(account);
   }
 
-  async burnERC20(account, assetId, recipient, amount, channelId) {
-    return await this.api.tx.erc20.burn(channelId, assetId, recipient, amount).signAndSend(account);
+  async burnERC20(account, assetId, recipient, amount, channelId, maxFee = null) {
+    return await this.api.tx.erc20.burn(channelId, assetId, recipient, amount, maxFee).signAndSend(account);
   }
 
-  async lockDOT(account, recipient, amount, channelId) {
-    return await this.api.tx.dot.lock(channelId, recipient, amount).signAndSend(account);
+  async lockDOT(account, recipient, amount, channelId, maxFee = null) {
+    return await this.api.tx.dot.lock(channelId, recipient, amount, maxFee).signAndSend(account);
   }
 
   async waitForNextBlock() {
@@ -3,14 +3,15 @@ import type { RegistryTypes, OverrideModuleType } from '@polkadot/types/types';
 export const types: RegistryTypes = {
     Address: "MultiAddress",
     LookupSource: "MultiAddress",
-    ChannelId: {
-      _enum: ["Basic", "Incentivized"]
-    },
+    ChannelId: "u8",
     MessageNonce: "u64",
     MessageId: {
       channelId: "ChannelId",
       nonce: "u64"
     },
+    MessageStatus: {
+      _enum: ["Unknown", "Queued", "Committed", "Delivered"]
+    },
     Message: {
       data: "Vec<u8>",
       proof: "Proof"
@@ -38,7 +39,8 @@ export const types: RegistryTypes = {
       gasUsed: "U256",
       gasLimit: "U256",
       difficulty: "U256",
-      seal: "Vec<Vec<u8>>"
+      seal: "Vec<Vec<u8>>",
+      baseFeePerGas: "Option<U256>"
     },
     StoredHeader: {
       submitter: "Option<AccountId>",
@@ -65,5 +67,21 @@ export const types: RegistryTypes = {
     },
     OutboundChannelData: {
       nonce: "u64"
+    },
+    CallPermission: {
+      _enum: {
+        Pallet: "Vec<u8>",
+        Call: "(Vec<u8>, Vec<u8>)"
+      }
+    },
+    FailedMessage: {
+      source: "H160",
+      payload: "Vec<u8>",
+      expiresAt: "BlockNumber"
+    },
+    FeeCurve: {
+      base: "U256",
+      perByte: "U256",
+      congestionMultiplier: "u32"
     }
   }